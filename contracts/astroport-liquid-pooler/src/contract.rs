@@ -1,20 +1,37 @@
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coin, ensure, to_json_binary, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env,
-    MessageInfo, Reply, Response, StdError, StdResult, SubMsg, SubMsgResult, Uint128, WasmMsg,
+    coin, ensure, from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps,
+    DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Response, StdError, StdResult, SubMsg,
+    SubMsgResult, Uint128, WasmMsg,
 };
 use covenant_utils::{
-    astroport::query_astro_pool_token,
+    astroport::{
+        query_astro_pool_price_observation, query_astro_pool_token, query_liquidity_token_address,
+        PriceObservation,
+    },
     op_mode::{verify_caller, ContractOperationMode},
-    withdraw_lp_helper::WithdrawLPMsgs,
+    WithdrawPriceGuard,
 };
 use cw2::set_contract_version;
+use liquid_pooler_interface::LiquidPoolerHolderMsg;
 
 use astroport::{
-    asset::{Asset, PairInfo},
+    asset::{Asset, AssetInfo, PairInfo},
     factory::PairType,
-    pair::{Cw20HookMsg, ExecuteMsg::ProvideLiquidity, PoolResponse, SimulationResponse},
+    generator::{
+        Config as GeneratorConfig, Cw20HookMsg as GeneratorCw20HookMsg,
+        ExecuteMsg as GeneratorExecuteMsg, PendingTokenResponse, QueryMsg as GeneratorQueryMsg,
+    },
+    pair::{
+        ConfigResponse, Cw20HookMsg, ExecuteMsg::ProvideLiquidity, PoolResponse, SimulationResponse,
+    },
+    pair_concentrated::ConcentratedPoolConfig,
+    router::{
+        ExecuteMsg as RouterExecuteMsg, QueryMsg as RouterQueryMsg, SimulateSwapOperationsResponse,
+        SwapOperation,
+    },
     DecimalCheckedOps,
 };
 use cw20::Cw20ExecuteMsg;
@@ -22,10 +39,13 @@ use cw20::Cw20ExecuteMsg;
 use crate::{
     error::ContractError,
     msg::{
-        ContractState, DecimalRange, ExecuteMsg, InstantiateMsg, LpConfig, MigrateMsg,
-        ProvidedLiquidityInfo, QueryMsg,
+        ContractState, DecimalRange, ExecuteMsg, InstantiateMsg, LiquidityPosition, LpConfig,
+        LpStakingConfig, MigrateMsg, ProvidedLiquidityInfo, QueryMsg, RewardsDestination,
+        StakedLiquidityInfo, ZapConfig,
+    },
+    state::{
+        HOLDER_ADDRESS, LP_CONFIG, LP_STAKING_CONFIG, PROVIDED_LIQUIDITY_INFO, TWAP_OBSERVATIONS,
     },
-    state::{HOLDER_ADDRESS, LP_CONFIG, PROVIDED_LIQUIDITY_INFO},
 };
 
 use neutron_sdk::NeutronResult;
@@ -39,10 +59,13 @@ const DOUBLE_SIDED_REPLY_ID: u64 = 321u64;
 const SINGLE_SIDED_REPLY_ID: u64 = 322u64;
 const SWAP_REPLY_ID: u64 = 323u64;
 
+/// custom pair type under which astroport registers concentrated (PCL) pairs
+const CONCENTRATED_PAIR_TYPE: &str = "concentrated";
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
@@ -64,6 +87,14 @@ pub fn instantiate(
         ContractError::PairTypeMismatch {}
     );
 
+    // other than xyk and stable pairs, only concentrated pairs are supported
+    if let PairType::Custom(pair_type) = &msg.pair_type {
+        ensure!(
+            pair_type == CONCENTRATED_PAIR_TYPE,
+            ContractError::UnsupportedPairType {}
+        );
+    }
+
     // contract starts at Instantiated state
     CONTRACT_STATE.save(deps.storage, &ContractState::Instantiated)?;
 
@@ -76,6 +107,17 @@ pub fn instantiate(
         msg.pool_price_config.acceptable_price_spread,
     )?;
 
+    if let Some(ZapConfig {
+        router_address: Some(router_address),
+    }) = &msg.zap_config
+    {
+        deps.api.addr_validate(router_address)?;
+    }
+
+    if let Some(withdraw_price_guard) = &msg.withdraw_price_guard {
+        validate_withdraw_price_guard(withdraw_price_guard)?;
+    }
+
     let lp_config = LpConfig {
         pool_address: pool_addr,
         single_side_lp_limits: msg.single_side_lp_limits,
@@ -83,9 +125,32 @@ pub fn instantiate(
         expected_pool_ratio_range: decimal_range,
         pair_type: msg.pair_type,
         asset_data: msg.assets,
+        zap_config: msg.zap_config,
+        twap_window_seconds: msg.twap_window_seconds,
+        withdraw_price_guard: msg.withdraw_price_guard,
     };
     LP_CONFIG.save(deps.storage, &lp_config)?;
 
+    // the twap window starts with the first observation
+    if let Some(window) = lp_config.twap_window_seconds {
+        ensure!(window > 0, ContractError::InvalidTwapWindow {});
+        TWAP_OBSERVATIONS.push_back(
+            deps.storage,
+            &query_pool_price_observation(deps.querier, &env, &lp_config)?,
+        )?;
+    }
+
+    let mut lp_staking_attributes = vec![];
+    if let Some(lp_staking_config) = msg.lp_staking_config {
+        validate_lp_staking_config(deps.as_ref(), &lp_staking_config)?;
+        ensure_holder_receives_rewards(deps.as_ref(), &lp_staking_config, &holder_addr)?;
+        LP_STAKING_CONFIG.save(deps.storage, &lp_staking_config)?;
+        lp_staking_attributes.push((
+            "generator_address",
+            lp_staking_config.generator_address.to_string(),
+        ));
+    }
+
     // we begin with no liquidity provided
     PROVIDED_LIQUIDITY_INFO.save(
         deps.storage,
@@ -98,7 +163,66 @@ pub fn instantiate(
     Ok(Response::default()
         .add_attribute("method", "lp_instantiate")
         .add_attribute("op_mode", format!("{:?}", op_mode))
-        .add_attributes(lp_config.to_response_attributes()))
+        .add_attributes(lp_config.to_response_attributes())
+        .add_attributes(lp_staking_attributes))
+}
+
+fn validate_lp_staking_config(
+    deps: Deps,
+    lp_staking_config: &LpStakingConfig,
+) -> Result<(), ContractError> {
+    deps.api
+        .addr_validate(&lp_staking_config.generator_address)?;
+    match &lp_staking_config.rewards_destination {
+        RewardsDestination::Compound { swap_pairs } => {
+            for swap_pair in swap_pairs {
+                deps.api.addr_validate(swap_pair)?;
+            }
+        }
+        RewardsDestination::Receiver { address } => {
+            deps.api.addr_validate(address)?;
+        }
+        RewardsDestination::Holder {} => (),
+    }
+    Ok(())
+}
+
+/// query exposed by the single party holder only
+#[cw_serde]
+enum SinglePartyHolderQueryMsg {
+    Withdrawer {},
+}
+
+/// single party holders only distribute the pool assets, so any rewards
+/// sent to them would get stuck. holders that do not exist yet can not
+/// be inspected, in which case the destination is accepted.
+fn ensure_holder_receives_rewards(
+    deps: Deps,
+    lp_staking_config: &LpStakingConfig,
+    holder_addr: &Addr,
+) -> Result<(), ContractError> {
+    if let RewardsDestination::Holder {} = lp_staking_config.rewards_destination {
+        let withdrawer: StdResult<Addr> = deps
+            .querier
+            .query_wasm_smart(holder_addr, &SinglePartyHolderQueryMsg::Withdrawer {});
+        ensure!(
+            withdrawer.is_err(),
+            ContractError::SinglePartyHolderRewards {}
+        );
+    }
+    Ok(())
+}
+
+fn validate_withdraw_price_guard(
+    withdraw_price_guard: &WithdrawPriceGuard,
+) -> Result<(), ContractError> {
+    if let WithdrawPriceGuard::PriceRange(pool_price_config) = withdraw_price_guard {
+        DecimalRange::try_from(
+            pool_price_config.expected_spot_price,
+            pool_price_config.acceptable_price_spread,
+        )?;
+    }
+    Ok(())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -110,7 +234,7 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match (CONTRACT_STATE.load(deps.storage)?, msg) {
         // if the contract is in the instantiated state, tick attempts to provide liquidity
-        (ContractState::Instantiated, ExecuteMsg::Tick {}) => try_lp(deps, env, info),
+        (ContractState::Instantiated, ExecuteMsg::Tick {}) => try_tick(deps, env, info),
         // withdraw is state independent
         (_, ExecuteMsg::Withdraw { percentage }) => try_withdraw(deps, env, info, percentage),
     }
@@ -141,8 +265,21 @@ fn try_withdraw(
         contract_address.to_string(),
     )?;
 
+    let lp_token = lp_token_info.pair_info.liquidity_token.to_string();
+    let lp_balance = lp_token_info.balance_response.balance;
+
+    // staked lp tokens are part of our position
+    let lp_staking_config = LP_STAKING_CONFIG.may_load(deps.storage)?;
+    let staked_amount = match &lp_staking_config {
+        Some(config) => {
+            query_staked_lp_amount(deps.as_ref(), config, &lp_token, &contract_address)?
+        }
+        None => Uint128::zero(),
+    };
+    let lp_position = lp_balance.checked_add(staked_amount)?;
+
     // if no lp tokens are available, we attempt to withdraw any available denoms
-    if lp_token_info.balance_response.balance.is_zero() {
+    if lp_position.is_zero() {
         let asset_a_bal = deps.querier.query_balance(
             &contract_address,
             lp_config.asset_data.asset_a_denom.as_str(),
@@ -161,7 +298,7 @@ fn try_withdraw(
 
         return Ok(Response::default().add_message(WasmMsg::Execute {
             contract_addr: holder_addr.to_string(),
-            msg: to_json_binary(&WithdrawLPMsgs::Distribute {})?,
+            msg: to_json_binary(&LiquidPoolerHolderMsg::Distribute {})?,
             funds,
         }));
     }
@@ -169,13 +306,37 @@ fn try_withdraw(
     // If percentage is 100%, use the whole balance
     // If percentage is less than 100%, calculate the percentage of share we want to withdraw
     let withdraw_shares_amount = if percent == Decimal::one() {
-        lp_token_info.balance_response.balance
+        lp_position
     } else {
-        Decimal::from_atomics(lp_token_info.balance_response.balance, 0)?
+        Decimal::from_atomics(lp_position, 0)?
             .checked_mul(percent)?
             .to_uint_floor()
     };
 
+    // if our lp token balance does not cover the withdrawal, we unstake
+    // the missing lp tokens. unstaking also claims the pending rewards,
+    // which we route to their destination.
+    let mut unstake_msgs = vec![];
+    if let Some(lp_staking_config) = lp_staking_config {
+        if withdraw_shares_amount > lp_balance {
+            unstake_msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: lp_staking_config.generator_address.to_string(),
+                msg: to_json_binary(&GeneratorExecuteMsg::Withdraw {
+                    lp_token: lp_token.to_string(),
+                    amount: withdraw_shares_amount.checked_sub(lp_balance)?,
+                })?,
+                funds: vec![],
+            }));
+            unstake_msgs.extend(get_rewards_routing_msgs(
+                deps.as_ref(),
+                &contract_address,
+                &lp_config,
+                &lp_staking_config,
+                &lp_token,
+            )?);
+        }
+    }
+
     // Clculate the withdrawn amount of A and B tokens from the shares we have
     let withdrawn_coins = deps
         .querier
@@ -189,10 +350,22 @@ fn try_withdraw(
         .map(|asset| asset.to_coin())
         .collect::<Result<Vec<Coin>, _>>()?;
 
+    // astroport pairs do not support minimum withdrawal amounts,
+    // so we validate the simulated withdrawal before exiting the pool
+    if let Some(withdraw_price_guard) = &lp_config.withdraw_price_guard {
+        ensure_withdraw_price_guard(
+            deps.as_ref(),
+            &lp_config,
+            withdraw_price_guard,
+            withdraw_shares_amount,
+            &withdrawn_coins,
+        )?;
+    }
+
     // exit pool and withdraw funds with the shares calculated
     let withdraw_liquidity_hook = &Cw20HookMsg::WithdrawLiquidity { assets: vec![] };
     let withdraw_msg = WasmMsg::Execute {
-        contract_addr: lp_token_info.pair_info.liquidity_token.to_string(),
+        contract_addr: lp_token,
         msg: to_json_binary(&Cw20ExecuteMsg::Send {
             contract: lp_config.pool_address.to_string(),
             amount: withdraw_shares_amount,
@@ -205,22 +378,339 @@ fn try_withdraw(
     // with the funds we withdrew from the pool
     let to_holder_msg = WasmMsg::Execute {
         contract_addr: holder_addr.to_string(),
-        msg: to_json_binary(&WithdrawLPMsgs::Distribute {})?,
+        msg: to_json_binary(&LiquidPoolerHolderMsg::Distribute {})?,
         funds: withdrawn_coins,
     };
 
     Ok(Response::default()
+        .add_messages(unstake_msgs)
         .add_message(withdraw_msg)
         .add_message(to_holder_msg))
 }
 
+/// validates that withdrawing the given amount of shares does not exit
+/// the pool at a price outside of the withdraw price guard
+fn ensure_withdraw_price_guard(
+    deps: Deps,
+    lp_config: &LpConfig,
+    withdraw_price_guard: &WithdrawPriceGuard,
+    withdraw_shares_amount: Uint128,
+    withdrawn_coins: &[Coin],
+) -> Result<(), ContractError> {
+    match withdraw_price_guard {
+        WithdrawPriceGuard::PriceRange(pool_price_config) => {
+            let pool_response: PoolResponse = deps.querier.query_wasm_smart(
+                lp_config.pool_address.to_string(),
+                &astroport::pair::QueryMsg::Pool {},
+            )?;
+            let (pool_price_a, pool_price_b) =
+                get_pool_price_amounts(deps, lp_config, pool_response.assets)?;
+
+            DecimalRange::try_from(
+                pool_price_config.expected_spot_price,
+                pool_price_config.acceptable_price_spread,
+            )?
+            .is_within_range(Decimal::from_ratio(pool_price_a, pool_price_b))
+        }
+        WithdrawPriceGuard::MinAssetsPerShare { asset_a, asset_b } => {
+            let withdraw_shares = Decimal::from_atomics(withdraw_shares_amount, 0)?;
+            for (denom, min_per_share) in [
+                (&lp_config.asset_data.asset_a_denom, asset_a),
+                (&lp_config.asset_data.asset_b_denom, asset_b),
+            ] {
+                let expected = coin(
+                    withdraw_shares
+                        .checked_mul(*min_per_share)?
+                        .to_uint_floor()
+                        .u128(),
+                    denom,
+                );
+                let received = withdrawn_coins
+                    .iter()
+                    .find(|c| &c.denom == denom)
+                    .cloned()
+                    .unwrap_or_else(|| coin(0, denom));
+
+                ensure!(
+                    received.amount >= expected.amount,
+                    ContractError::MinWithdrawAmountError { expected, received }
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+/// attempts to provide the available liquidity. if lp staking is enabled,
+/// also stakes the available lp tokens and claims the staking rewards.
+/// failing to provide liquidity fails the tick, so that none of its
+/// state changes get committed. staking failures are only reported,
+/// as they do not write to storage.
+fn try_tick(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+
+    let staking_msgs = match LP_STAKING_CONFIG.may_load(deps.storage)? {
+        Some(lp_staking_config) => {
+            Some(get_lp_staking_msgs(deps.as_ref(), &env, &lp_staking_config))
+        }
+        None => None,
+    };
+
+    let response = try_lp(deps, env)?;
+    match staking_msgs {
+        None => Ok(response),
+        Some(Ok(staking_msgs)) => Ok(response.add_messages(staking_msgs)),
+        Some(Err(err)) => Ok(response.add_attribute("staking_error", err.to_string())),
+    }
+}
+
+/// stakes the lp tokens held by the contract and claims the rewards
+/// of the staked ones. lp tokens obtained during this tick get staked
+/// on the next one.
+fn get_lp_staking_msgs(
+    deps: Deps,
+    env: &Env,
+    lp_staking_config: &LpStakingConfig,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let lp_config = LP_CONFIG.load(deps.storage)?;
+    let contract_address = env.contract.address.to_string();
+
+    let lp_token_info = query_astro_pool_token(
+        deps.querier,
+        lp_config.pool_address.to_string(),
+        contract_address.to_string(),
+    )?;
+    let lp_token = lp_token_info.pair_info.liquidity_token.to_string();
+    let lp_balance = lp_token_info.balance_response.balance;
+    let staked_amount =
+        query_staked_lp_amount(deps, lp_staking_config, &lp_token, &contract_address)?;
+
+    let mut msgs = vec![];
+    // depositing to the generator claims the pending rewards as well,
+    // so we only claim them explicitly if there is nothing to stake
+    if !lp_balance.is_zero() {
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lp_token.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                contract: lp_staking_config.generator_address.to_string(),
+                amount: lp_balance,
+                msg: to_json_binary(&GeneratorCw20HookMsg::Deposit {})?,
+            })?,
+            funds: vec![],
+        }));
+    } else if !staked_amount.is_zero() {
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: lp_staking_config.generator_address.to_string(),
+            msg: to_json_binary(&GeneratorExecuteMsg::ClaimRewards {
+                lp_tokens: vec![lp_token.to_string()],
+            })?,
+            funds: vec![],
+        }));
+    }
+
+    // rewards only accrue to already staked lp tokens
+    if !staked_amount.is_zero() {
+        msgs.extend(get_rewards_routing_msgs(
+            deps,
+            &contract_address,
+            &lp_config,
+            lp_staking_config,
+            &lp_token,
+        )?);
+    }
+
+    Ok(msgs)
+}
+
+/// builds the messages routing the pending staking rewards to their destination.
+/// expects the rewards to be claimed before these messages get executed.
+fn get_rewards_routing_msgs(
+    deps: Deps,
+    contract_address: &str,
+    lp_config: &LpConfig,
+    lp_staking_config: &LpStakingConfig,
+    lp_token: &str,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let holder_addr = HOLDER_ADDRESS.load(deps.storage)?;
+    let pending_rewards =
+        query_pending_rewards(deps, lp_staking_config, lp_token, contract_address)?;
+
+    let pool_assets: Vec<AssetInfo> = lp_config
+        .asset_data
+        .to_asset_vec(Uint128::zero(), Uint128::zero())
+        .into_iter()
+        .map(|asset| asset.info)
+        .collect();
+
+    let mut msgs = vec![];
+    for reward in pending_rewards {
+        match &lp_staking_config.rewards_destination {
+            RewardsDestination::Holder {} => {
+                msgs.push(get_asset_transfer_msg(reward, &holder_addr)?);
+            }
+            RewardsDestination::Receiver { address } => {
+                msgs.push(get_asset_transfer_msg(
+                    reward,
+                    &deps.api.addr_validate(address)?,
+                )?);
+            }
+            RewardsDestination::Compound { swap_pairs } => {
+                // rewards in pool assets get provided on the following ticks
+                if pool_assets.contains(&reward.info) {
+                    continue;
+                }
+                match find_reward_swap_pair(deps, swap_pairs, &reward.info, &pool_assets)? {
+                    Some((swap_pair, ask_asset_info)) => {
+                        msgs.push(get_reward_swap_msg(
+                            reward,
+                            swap_pair,
+                            ask_asset_info,
+                            lp_config.slippage_tolerance,
+                        )?);
+                    }
+                    None => msgs.push(get_asset_transfer_msg(reward, &holder_addr)?),
+                }
+            }
+        }
+    }
+
+    Ok(msgs)
+}
+
+/// finds the first of the given pairs that swaps the reward asset into
+/// one of the pool assets. returns the pair along with the ask asset.
+fn find_reward_swap_pair(
+    deps: Deps,
+    swap_pairs: &[String],
+    reward_info: &AssetInfo,
+    pool_assets: &[AssetInfo],
+) -> StdResult<Option<(String, AssetInfo)>> {
+    for swap_pair in swap_pairs {
+        let pair_info: PairInfo = deps
+            .querier
+            .query_wasm_smart(swap_pair, &astroport::pair::QueryMsg::Pair {})?;
+        if !pair_info.asset_infos.contains(reward_info) {
+            continue;
+        }
+        if let Some(ask_asset_info) = pair_info
+            .asset_infos
+            .into_iter()
+            .find(|info| pool_assets.contains(info))
+        {
+            return Ok(Some((swap_pair.to_string(), ask_asset_info)));
+        }
+    }
+    Ok(None)
+}
+
+fn get_reward_swap_msg(
+    offer_asset: Asset,
+    swap_pair: String,
+    ask_asset_info: AssetInfo,
+    max_spread: Option<Decimal>,
+) -> StdResult<CosmosMsg> {
+    let msg = match &offer_asset.info {
+        AssetInfo::NativeToken { denom } => WasmMsg::Execute {
+            contract_addr: swap_pair,
+            msg: to_json_binary(&astroport::pair::ExecuteMsg::Swap {
+                offer_asset: offer_asset.clone(),
+                ask_asset_info: Some(ask_asset_info),
+                belief_price: None,
+                max_spread,
+                to: None,
+            })?,
+            funds: vec![coin(offer_asset.amount.u128(), denom)],
+        },
+        AssetInfo::Token { contract_addr } => WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                contract: swap_pair,
+                amount: offer_asset.amount,
+                msg: to_json_binary(&Cw20HookMsg::Swap {
+                    ask_asset_info: Some(ask_asset_info),
+                    belief_price: None,
+                    max_spread,
+                    to: None,
+                })?,
+            })?,
+            funds: vec![],
+        },
+    };
+    Ok(msg.into())
+}
+
+fn get_asset_transfer_msg(asset: Asset, recipient: &Addr) -> StdResult<CosmosMsg> {
+    let msg = match asset.info {
+        AssetInfo::NativeToken { denom } => BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![coin(asset.amount.u128(), denom)],
+        }
+        .into(),
+        AssetInfo::Token { contract_addr } => WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount: asset.amount,
+            })?,
+            funds: vec![],
+        }
+        .into(),
+    };
+    Ok(msg)
+}
+
+/// queries the amount of lp tokens staked by the given user
+fn query_staked_lp_amount(
+    deps: Deps,
+    lp_staking_config: &LpStakingConfig,
+    lp_token: &str,
+    user: &str,
+) -> StdResult<Uint128> {
+    deps.querier.query_wasm_smart(
+        &lp_staking_config.generator_address,
+        &GeneratorQueryMsg::Deposit {
+            lp_token: lp_token.to_string(),
+            user: user.to_string(),
+        },
+    )
+}
+
+/// queries the non-zero rewards pending to the given user
+fn query_pending_rewards(
+    deps: Deps,
+    lp_staking_config: &LpStakingConfig,
+    lp_token: &str,
+    user: &str,
+) -> StdResult<Vec<Asset>> {
+    let pending: PendingTokenResponse = deps.querier.query_wasm_smart(
+        &lp_staking_config.generator_address,
+        &GeneratorQueryMsg::PendingToken {
+            lp_token: lp_token.to_string(),
+            user: user.to_string(),
+        },
+    )?;
+    let generator_config: GeneratorConfig = deps.querier.query_wasm_smart(
+        &lp_staking_config.generator_address,
+        &GeneratorQueryMsg::Config {},
+    )?;
+
+    let mut rewards = vec![Asset {
+        info: generator_config.astro_token,
+        amount: pending.pending,
+    }];
+    rewards.extend(pending.pending_on_proxy.unwrap_or_default());
+
+    Ok(rewards
+        .into_iter()
+        .filter(|reward| !reward.amount.is_zero())
+        .collect())
+}
+
 /// method which attempts to provision liquidity to the pool.
 /// if both desired asset balances are non-zero, double sided liquidity
 /// is provided.
 /// otherwise, single-sided liquidity provision is attempted.
-fn try_lp(mut deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
-
+fn try_lp(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
     let lp_config = LP_CONFIG.load(deps.storage)?;
     let contract_address = env.contract.address.to_string();
 
@@ -228,19 +718,53 @@ fn try_lp(mut deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Co
         .querier
         .query_wasm_smart(&lp_config.pool_address, &astroport::pair::QueryMsg::Pool {})?;
 
-    let (pool_token_a_bal, pool_token_b_bal) = get_pool_asset_amounts(
-        pool_response.assets,
-        lp_config.asset_data.asset_a_denom.as_str(),
-        lp_config.asset_data.asset_b_denom.as_str(),
-    )?;
-
-    // `get_pool_asset_amounts` ensures that both a and b balances are non-zero so this is safe
-    let a_to_b_ratio = Decimal::from_ratio(pool_token_a_bal, pool_token_b_bal);
-
-    // validate the current pool ratio against our expectations
-    lp_config
-        .expected_pool_ratio_range
-        .is_within_range(a_to_b_ratio)?;
+    let (pool_price_a, pool_price_b) =
+        get_pool_price_amounts(deps.as_ref(), &lp_config, pool_response.assets)?;
+
+    // `get_pool_price_amounts` ensures that both a and b amounts are non-zero so this is safe
+    let a_to_b_ratio = Decimal::from_ratio(pool_price_a, pool_price_b);
+
+    // if enabled, the latest price observation is recorded before validating
+    // any prices, and the time weighted average price must be within the
+    // expected range as well. we do not error out on either price being out
+    // of range so that the observation is recorded either way.
+    let price_status = match lp_config.twap_window_seconds {
+        Some(window) => {
+            let twap = update_twap(deps.branch(), &env, &lp_config, window)?;
+            if lp_config
+                .expected_pool_ratio_range
+                .is_within_range(a_to_b_ratio)
+                .is_err()
+            {
+                Some("spot price out of range")
+            } else {
+                match twap {
+                    Some(twap)
+                        if lp_config
+                            .expected_pool_ratio_range
+                            .is_within_range(twap)
+                            .is_ok() =>
+                    {
+                        None
+                    }
+                    Some(_) => Some("twap out of range"),
+                    None => Some("awaiting twap window"),
+                }
+            }
+        }
+        None => {
+            // validate the current pool ratio against our expectations
+            lp_config
+                .expected_pool_ratio_range
+                .is_within_range(a_to_b_ratio)?;
+            None
+        }
+    };
+    if let Some(status) = price_status {
+        return Ok(Response::default()
+            .add_attribute("method", "try_lp")
+            .add_attribute("status", status));
+    }
 
     // first we query our own balances
     let coin_a = deps.querier.query_balance(
@@ -260,10 +784,15 @@ fn try_lp(mut deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Co
     match (coin_a.amount.is_zero(), coin_b.amount.is_zero()) {
         // asset_b balance is non-zero, we attempt single-side
         (true, false) => {
-            ensure!(
-                coin_b.amount <= lp_config.single_side_lp_limits.asset_b_limit,
-                ContractError::SingleSideLpLimitError {}
-            );
+            // balances beyond single side limits can only be zapped
+            if coin_b.amount > lp_config.single_side_lp_limits.asset_b_limit {
+                let limit = lp_config.single_side_lp_limits.asset_b_limit;
+                let zap_submsgs =
+                    try_get_zap_submsgs(deps.branch(), contract_address, coin_b, limit, lp_config)?;
+                return Ok(Response::default()
+                    .add_submessages(zap_submsgs)
+                    .add_attribute("method", "zap_lp"));
+            }
 
             let single_sided_submsgs = try_get_single_side_lp_submsg(
                 deps.branch(),
@@ -280,10 +809,15 @@ fn try_lp(mut deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Co
         }
         // asset_a balance is non-zero, we attempt single-side
         (false, true) => {
-            ensure!(
-                coin_a.amount <= lp_config.single_side_lp_limits.asset_a_limit,
-                ContractError::SingleSideLpLimitError {}
-            );
+            // balances beyond single side limits can only be zapped
+            if coin_a.amount > lp_config.single_side_lp_limits.asset_a_limit {
+                let limit = lp_config.single_side_lp_limits.asset_a_limit;
+                let zap_submsgs =
+                    try_get_zap_submsgs(deps.branch(), contract_address, coin_a, limit, lp_config)?;
+                return Ok(Response::default()
+                    .add_submessages(zap_submsgs)
+                    .add_attribute("method", "zap_lp"));
+            }
             let single_sided_submsgs = try_get_single_side_lp_submsg(
                 deps.branch(),
                 contract_address,
@@ -304,7 +838,7 @@ fn try_lp(mut deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Co
                 contract_address,
                 (coin_a, coin_b),
                 a_to_b_ratio,
-                (pool_token_a_bal, pool_token_b_bal),
+                (pool_price_a, pool_price_b),
                 lp_config,
             )?;
             if let Some(msg) = double_sided_submsg {
@@ -326,13 +860,13 @@ fn try_lp(mut deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Co
 /// attempts to get a double sided ProvideLiquidity submessage.
 /// amounts here do not matter. as long as we have non-zero balances of both
 /// a and b tokens, the maximum amount of liquidity is provided to maintain
-/// the existing pool ratio.
+/// the existing pool price.
 fn try_get_double_side_lp_submsg(
     deps: DepsMut,
     contract_address: String,
     (token_a, token_b): (Coin, Coin),
     pool_token_ratio: Decimal,
-    (pool_token_a_bal, pool_token_b_bal): (Uint128, Uint128),
+    (pool_price_a, pool_price_b): (Uint128, Uint128),
     lp_config: LpConfig,
 ) -> Result<Option<SubMsg>, ContractError> {
     // we thus find the required token amount to enter into the position using all available b tokens:
@@ -352,7 +886,7 @@ fn try_get_double_side_lp_submsg(
         // sided liquidity using all of our b tokens.
         // this means that we should provide all of our available a tokens,
         // and as many b tokens as needed to satisfy the existing ratio
-        let ratio = Decimal::from_ratio(pool_token_b_bal, pool_token_a_bal);
+        let ratio = Decimal::from_ratio(pool_price_b, pool_price_a);
         lp_config
             .asset_data
             .to_tuple(token_a.amount, ratio.checked_mul_uint128(token_a.amount)?)
@@ -400,6 +934,10 @@ fn try_get_single_side_lp_submsg(
     lp_config: LpConfig,
 ) -> Result<Vec<SubMsg>, ContractError> {
     match lp_config.pair_type {
+        // other custom pair types are rejected on instantiation
+        PairType::Custom(ref pair_type) if pair_type != CONCENTRATED_PAIR_TYPE => {
+            Err(ContractError::UnsupportedPairType {})
+        }
         // xyk pools do not allow for automatic single-sided liquidity provision.
         // we therefore perform a manual swap with 1/2 of the available denom, and execute
         // two-sided lp provision with the resulting assets.
@@ -413,72 +951,36 @@ fn try_get_single_side_lp_submsg(
                 amount: coin.amount / Uint128::from(2u128),
             };
 
-            let (offer_asset, offer_coin, mut ask_asset) = {
+            let (offer_asset, mut ask_asset) = {
                 if assets[0].to_coin()?.denom == halved_coin.denom {
                     assets[0].amount = halved_coin.amount;
-                    (assets[0].clone(), halved_coin, assets[1].clone())
+                    (assets[0].clone(), assets[1].clone())
                 } else {
                     assets[1].amount = halved_coin.amount;
-                    (assets[1].clone(), halved_coin, assets[0].clone())
+                    (assets[1].clone(), assets[0].clone())
                 }
             };
 
             // we simulate a swap with 1/2 of the offer asset
-            let simulation: SimulationResponse = deps.querier.query_wasm_smart(
-                &lp_config.pool_address,
-                &astroport::pair::QueryMsg::Simulation {
-                    offer_asset: offer_asset.clone(),
-                    ask_asset_info: None,
-                },
+            ask_asset.amount = simulate_swap(
+                deps.as_ref(),
+                &lp_config,
+                None,
+                &offer_asset,
+                &ask_asset.info,
             )?;
-            ask_asset.amount = simulation.return_amount;
-            let ask_coin = ask_asset.to_coin()?;
 
-            let swap_wasm_msg: CosmosMsg = WasmMsg::Execute {
-                contract_addr: lp_config.pool_address.to_string(),
-                msg: to_json_binary(&astroport::pair::ExecuteMsg::Swap {
-                    offer_asset: offer_asset.clone(),
-                    max_spread: lp_config.slippage_tolerance,
-                    belief_price: None,
-                    to: None,
-                    ask_asset_info: None,
-                })?,
-                funds: vec![offer_coin.clone()],
-            }
-            .into();
-
-            PROVIDED_LIQUIDITY_INFO.update(deps.storage, |mut info| -> StdResult<_> {
-                if offer_coin.denom == info.provided_coin_a.denom {
-                    info.provided_coin_a.amount =
-                        info.provided_coin_a.amount.checked_add(offer_coin.amount)?;
-                    info.provided_coin_b.amount =
-                        info.provided_coin_b.amount.checked_add(ask_coin.amount)?;
-                } else {
-                    info.provided_coin_b.amount =
-                        info.provided_coin_b.amount.checked_add(offer_coin.amount)?;
-                    info.provided_coin_a.amount =
-                        info.provided_coin_a.amount.checked_add(ask_coin.amount)?;
-                }
-                Ok(info)
-            })?;
-
-            let provide_liquidity_msg: CosmosMsg = WasmMsg::Execute {
-                contract_addr: lp_config.pool_address.to_string(),
-                msg: to_json_binary(&ProvideLiquidity {
-                    assets: vec![offer_asset, ask_asset],
-                    slippage_tolerance: lp_config.slippage_tolerance,
-                    auto_stake: Some(false),
-                    receiver: Some(contract_address),
-                })?,
-                funds: vec![offer_coin, ask_coin],
-            }
-            .into();
-            let swap_submsg = SubMsg::reply_on_success(swap_wasm_msg, SWAP_REPLY_ID);
-            let provide_liquidity_submsg =
-                SubMsg::reply_on_success(provide_liquidity_msg, DOUBLE_SIDED_REPLY_ID);
-
-            Ok(vec![swap_submsg, provide_liquidity_submsg])
+            get_swap_and_provide_submsgs(
+                deps,
+                contract_address,
+                offer_asset,
+                ask_asset,
+                None,
+                &lp_config,
+            )
         }
+        // stable and concentrated pools accept single-sided liquidity provision
+        // by charging the imbalance fee, so we provide the asset as is
         PairType::Stable {} | PairType::Custom(_) => {
             // given one non-zero asset, we build the ProvideLiquidity message
             let single_sided_liq_msg = ProvideLiquidity {
@@ -514,6 +1016,321 @@ fn try_get_single_side_lp_submsg(
     }
 }
 
+/// swaps half of the given coin, capped by its single side limit, into the
+/// other pool asset and provides both of them as double sided liquidity.
+/// the remaining balance gets zapped on the following ticks.
+fn try_get_zap_submsgs(
+    deps: DepsMut,
+    contract_address: String,
+    coin: Coin,
+    single_side_limit: Uint128,
+    lp_config: LpConfig,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let zap_config = lp_config
+        .zap_config
+        .clone()
+        .ok_or(ContractError::SingleSideLpLimitError {})?;
+
+    let offer_amount = std::cmp::min(coin.amount / Uint128::from(2u128), single_side_limit);
+    ensure!(
+        !offer_amount.is_zero(),
+        ContractError::SingleSideLpLimitError {}
+    );
+
+    let (asset_a, asset_b) = lp_config
+        .asset_data
+        .to_tuple(Uint128::zero(), Uint128::zero());
+    let offer_is_a = coin.denom == lp_config.asset_data.asset_a_denom;
+    let (mut offer_asset, mut ask_asset) = if offer_is_a {
+        (asset_a, asset_b)
+    } else {
+        (asset_b, asset_a)
+    };
+    offer_asset.amount = offer_amount;
+    ask_asset.amount = simulate_swap(
+        deps.as_ref(),
+        &lp_config,
+        zap_config.router_address.as_ref(),
+        &offer_asset,
+        &ask_asset.info,
+    )?;
+    ensure!(
+        !ask_asset.amount.is_zero(),
+        ContractError::PriceRangeError {}
+    );
+
+    // validate the swap price against our expectations
+    let a_to_b_price = if offer_is_a {
+        Decimal::from_ratio(offer_asset.amount, ask_asset.amount)
+    } else {
+        Decimal::from_ratio(ask_asset.amount, offer_asset.amount)
+    };
+    lp_config
+        .expected_pool_ratio_range
+        .is_within_range(a_to_b_price)?;
+
+    get_swap_and_provide_submsgs(
+        deps,
+        contract_address,
+        offer_asset,
+        ask_asset,
+        zap_config.router_address.as_ref(),
+        &lp_config,
+    )
+}
+
+/// simulates swapping the offer asset into the ask asset through the
+/// router if one is given, or through the pool otherwise
+fn simulate_swap(
+    deps: Deps,
+    lp_config: &LpConfig,
+    router_address: Option<&String>,
+    offer_asset: &Asset,
+    ask_asset_info: &AssetInfo,
+) -> StdResult<Uint128> {
+    match router_address {
+        Some(router_address) => {
+            let simulation: SimulateSwapOperationsResponse = deps.querier.query_wasm_smart(
+                router_address,
+                &RouterQueryMsg::SimulateSwapOperations {
+                    offer_amount: offer_asset.amount,
+                    operations: vec![SwapOperation::AstroSwap {
+                        offer_asset_info: offer_asset.info.clone(),
+                        ask_asset_info: ask_asset_info.clone(),
+                    }],
+                },
+            )?;
+            Ok(simulation.amount)
+        }
+        None => {
+            let simulation: SimulationResponse = deps.querier.query_wasm_smart(
+                &lp_config.pool_address,
+                &astroport::pair::QueryMsg::Simulation {
+                    offer_asset: offer_asset.clone(),
+                    ask_asset_info: None,
+                },
+            )?;
+            Ok(simulation.return_amount)
+        }
+    }
+}
+
+/// swaps the offer asset into the simulated ask asset amount and
+/// provides both of them as double sided liquidity
+fn get_swap_and_provide_submsgs(
+    deps: DepsMut,
+    contract_address: String,
+    offer_asset: Asset,
+    ask_asset: Asset,
+    router_address: Option<&String>,
+    lp_config: &LpConfig,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let offer_coin = offer_asset.to_coin()?;
+    let ask_coin = ask_asset.to_coin()?;
+
+    let swap_wasm_msg: CosmosMsg = match router_address {
+        // the simulated amount is received in the same transaction,
+        // so we expect no less than that
+        Some(router_address) => WasmMsg::Execute {
+            contract_addr: router_address.to_string(),
+            msg: to_json_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                operations: vec![SwapOperation::AstroSwap {
+                    offer_asset_info: offer_asset.info.clone(),
+                    ask_asset_info: ask_asset.info.clone(),
+                }],
+                minimum_receive: Some(ask_asset.amount),
+                to: None,
+                max_spread: lp_config.slippage_tolerance,
+            })?,
+            funds: vec![offer_coin.clone()],
+        },
+        None => WasmMsg::Execute {
+            contract_addr: lp_config.pool_address.to_string(),
+            msg: to_json_binary(&astroport::pair::ExecuteMsg::Swap {
+                offer_asset: offer_asset.clone(),
+                max_spread: lp_config.slippage_tolerance,
+                belief_price: None,
+                to: None,
+                ask_asset_info: None,
+            })?,
+            funds: vec![offer_coin.clone()],
+        },
+    }
+    .into();
+
+    PROVIDED_LIQUIDITY_INFO.update(deps.storage, |mut info| -> StdResult<_> {
+        if offer_coin.denom == info.provided_coin_a.denom {
+            info.provided_coin_a.amount =
+                info.provided_coin_a.amount.checked_add(offer_coin.amount)?;
+            info.provided_coin_b.amount =
+                info.provided_coin_b.amount.checked_add(ask_coin.amount)?;
+        } else {
+            info.provided_coin_b.amount =
+                info.provided_coin_b.amount.checked_add(offer_coin.amount)?;
+            info.provided_coin_a.amount =
+                info.provided_coin_a.amount.checked_add(ask_coin.amount)?;
+        }
+        Ok(info)
+    })?;
+
+    let provide_liquidity_msg: CosmosMsg = WasmMsg::Execute {
+        contract_addr: lp_config.pool_address.to_string(),
+        msg: to_json_binary(&ProvideLiquidity {
+            assets: vec![offer_asset, ask_asset],
+            slippage_tolerance: lp_config.slippage_tolerance,
+            auto_stake: Some(false),
+            receiver: Some(contract_address),
+        })?,
+        funds: vec![offer_coin, ask_coin],
+    }
+    .into();
+    let swap_submsg = SubMsg::reply_on_success(swap_wasm_msg, SWAP_REPLY_ID);
+    let provide_liquidity_submsg =
+        SubMsg::reply_on_success(provide_liquidity_msg, DOUBLE_SIDED_REPLY_ID);
+
+    Ok(vec![swap_submsg, provide_liquidity_submsg])
+}
+
+/// observes the cumulative price of asset b denominated in asset a,
+/// matching the pool ratio we validate
+fn query_pool_price_observation(
+    querier: QuerierWrapper,
+    env: &Env,
+    lp_config: &LpConfig,
+) -> StdResult<PriceObservation> {
+    query_astro_pool_price_observation(
+        querier,
+        lp_config.pool_address.as_str(),
+        &lp_config.asset_data.asset_b_denom,
+        &lp_config.asset_data.asset_a_denom,
+        env.block.time,
+    )
+}
+
+/// returns whether the time between the two observations covers the window
+fn covers_twap_window(start: &PriceObservation, end: &PriceObservation, window: u64) -> bool {
+    end.timestamp
+        .seconds()
+        .saturating_sub(start.timestamp.seconds())
+        >= window
+}
+
+/// records the latest pool price observation and returns the time weighted
+/// average price over (at least) the window, if the recorded observations
+/// cover it. observations no longer needed for the window are dropped.
+fn update_twap(
+    deps: DepsMut,
+    env: &Env,
+    lp_config: &LpConfig,
+    window: u64,
+) -> StdResult<Option<Decimal>> {
+    let latest = query_pool_price_observation(deps.querier, env, lp_config)?;
+
+    // the newest observation covering the window is the one we average from
+    while let Some(next) = TWAP_OBSERVATIONS.get(deps.storage, 1)? {
+        if !covers_twap_window(&next, &latest, window) {
+            break;
+        }
+        TWAP_OBSERVATIONS.pop_front(deps.storage)?;
+    }
+
+    let twap = match TWAP_OBSERVATIONS.front(deps.storage)? {
+        Some(start) if covers_twap_window(&start, &latest, window) => {
+            Some(start.get_twap(&latest)?)
+        }
+        _ => None,
+    };
+
+    // we record at most one observation per block
+    let is_new_block = match TWAP_OBSERVATIONS.back(deps.storage)? {
+        Some(last) => last.timestamp < latest.timestamp,
+        None => true,
+    };
+    if is_new_block {
+        TWAP_OBSERVATIONS.push_back(deps.storage, &latest)?;
+    }
+
+    Ok(twap)
+}
+
+/// returns a and b token amounts whose ratio reflects the current pool price.
+/// for xyk and stable pools these are the pool balances. concentrated pools
+/// keep their liquidity around the internal price scale, which their balances
+/// do not reflect, so the amounts are derived from the price scale instead.
+/// the price scale is what provided liquidity gets balanced against, which
+/// makes it a better reference than the last trade price for provisions.
+/// it is denominated in whole tokens, so the amounts get scaled by the
+/// token precisions in order to be comparable with raw pool balances.
+fn get_pool_price_amounts(
+    deps: Deps,
+    lp_config: &LpConfig,
+    pool_assets: Vec<Asset>,
+) -> Result<(Uint128, Uint128), ContractError> {
+    let a_denom = lp_config.asset_data.asset_a_denom.as_str();
+    let b_denom = lp_config.asset_data.asset_b_denom.as_str();
+
+    // pool balances are validated for every pair type
+    let (pool_token_a_bal, pool_token_b_bal) =
+        get_pool_asset_amounts(pool_assets.clone(), a_denom, b_denom)?;
+
+    match &lp_config.pair_type {
+        PairType::Custom(pair_type) if pair_type == CONCENTRATED_PAIR_TYPE => {
+            let config: ConfigResponse = deps.querier.query_wasm_smart(
+                &lp_config.pool_address,
+                &astroport::pair_concentrated::QueryMsg::Config {},
+            )?;
+            let params: ConcentratedPoolConfig = from_json(config.params.ok_or_else(|| {
+                StdError::generic_err("concentrated pool config params not found")
+            })?)?;
+
+            // price scale is the price of the second pool asset
+            // denominated in the first one
+            let price_scale = params.price_scale.atomics();
+            let unit = Decimal::one().atomics();
+            let (a_amount, b_amount, a_info, b_info) = if pool_assets[0].to_coin()?.denom == a_denom
+            {
+                (
+                    price_scale,
+                    unit,
+                    &pool_assets[0].info,
+                    &pool_assets[1].info,
+                )
+            } else {
+                (
+                    unit,
+                    price_scale,
+                    &pool_assets[1].info,
+                    &pool_assets[0].info,
+                )
+            };
+
+            // scale the amounts from whole tokens to their precisions.
+            // pairs are created by the factory, whose registry holds the precisions
+            let factory_addr = deps.api.addr_validate(
+                &deps
+                    .querier
+                    .query_wasm_contract_info(&lp_config.pool_address)?
+                    .creator,
+            )?;
+            let a_decimals = a_info.decimals(&deps.querier, &factory_addr)?;
+            let b_decimals = b_info.decimals(&deps.querier, &factory_addr)?;
+            let min_decimals = a_decimals.min(b_decimals);
+            let a_scale = Uint128::new(10)
+                .checked_pow((a_decimals - min_decimals).into())
+                .map_err(StdError::overflow)?;
+            let b_scale = Uint128::new(10)
+                .checked_pow((b_decimals - min_decimals).into())
+                .map_err(StdError::overflow)?;
+
+            Ok((
+                a_amount.checked_mul(a_scale).map_err(StdError::overflow)?,
+                b_amount.checked_mul(b_scale).map_err(StdError::overflow)?,
+            ))
+        }
+        _ => Ok((pool_token_a_bal, pool_token_b_bal)),
+    }
+}
+
 /// filters out irrelevant balances and returns a and b token amounts
 fn get_pool_asset_amounts(
     assets: Vec<Asset>,
@@ -553,6 +1370,78 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProvidedLiquidityInfo {} => Ok(to_json_binary(
             &PROVIDED_LIQUIDITY_INFO.load(deps.storage)?,
         )?),
+        QueryMsg::LiquidityPosition {} => {
+            let lp_config = LP_CONFIG.load(deps.storage)?;
+            let lp_token_info = query_astro_pool_token(
+                deps.querier,
+                lp_config.pool_address.to_string(),
+                env.contract.address.to_string(),
+            )?;
+            let lp_token = lp_token_info.pair_info.liquidity_token.to_string();
+            // staked lp tokens are part of the position
+            let staked_amount = match LP_STAKING_CONFIG.may_load(deps.storage)? {
+                Some(config) => {
+                    query_staked_lp_amount(deps, &config, &lp_token, env.contract.address.as_str())?
+                }
+                None => Uint128::zero(),
+            };
+            Ok(to_json_binary(&LiquidityPosition {
+                lp_token,
+                amount: lp_token_info
+                    .balance_response
+                    .balance
+                    .checked_add(staked_amount)?,
+            })?)
+        }
+        QueryMsg::Twap {} => {
+            let lp_config = LP_CONFIG.load(deps.storage)?;
+            let twap = match lp_config.twap_window_seconds {
+                Some(window) => {
+                    let latest = query_pool_price_observation(deps.querier, &env, &lp_config)?;
+                    let observations = TWAP_OBSERVATIONS
+                        .iter(deps.storage)?
+                        .collect::<StdResult<Vec<PriceObservation>>>()?;
+                    // we average from the newest observation covering the window
+                    match observations
+                        .iter()
+                        .rev()
+                        .find(|start| covers_twap_window(start, &latest, window))
+                    {
+                        Some(start) => Some(start.get_twap(&latest)?),
+                        None => None,
+                    }
+                }
+                None => None,
+            };
+            Ok(to_json_binary(&twap)?)
+        }
+        QueryMsg::StakedLiquidity {} => {
+            let staked_liquidity = match LP_STAKING_CONFIG.may_load(deps.storage)? {
+                Some(config) => {
+                    let lp_config = LP_CONFIG.load(deps.storage)?;
+                    let lp_token = query_liquidity_token_address(
+                        deps.querier,
+                        lp_config.pool_address.to_string(),
+                    )?;
+                    let contract_address = env.contract.address.to_string();
+                    let staked_amount =
+                        query_staked_lp_amount(deps, &config, &lp_token, &contract_address)?;
+                    let pending_rewards = if staked_amount.is_zero() {
+                        vec![]
+                    } else {
+                        query_pending_rewards(deps, &config, &lp_token, &contract_address)?
+                    };
+                    Some(StakedLiquidityInfo {
+                        generator_address: config.generator_address,
+                        lp_token,
+                        staked_amount,
+                        pending_rewards,
+                    })
+                }
+                None => None,
+            };
+            Ok(to_json_binary(&staked_liquidity)?)
+        }
         QueryMsg::OperationMode {} => {
             Ok(to_json_binary(&CONTRACT_OP_MODE.may_load(deps.storage)?)?)
         }
@@ -566,6 +1455,7 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> NeutronResult<Respo
             op_mode,
             holder_address,
             lp_config,
+            lp_staking_config,
         } => {
             let mut response = Response::default().add_attribute("method", "update_config");
 
@@ -583,12 +1473,45 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> NeutronResult<Respo
             }
 
             if let Some(config) = lp_config {
-                // validate the address before storing it
+                // validate the addresses before storing them
                 deps.api.addr_validate(config.pool_address.as_str())?;
+                if let Some(ZapConfig {
+                    router_address: Some(router_address),
+                }) = &config.zap_config
+                {
+                    deps.api.addr_validate(router_address)?;
+                }
+                if config.twap_window_seconds == Some(0) {
+                    return Err(StdError::generic_err(
+                        ContractError::InvalidTwapWindow {}.to_string(),
+                    )
+                    .into());
+                }
+                if let Some(withdraw_price_guard) = &config.withdraw_price_guard {
+                    validate_withdraw_price_guard(withdraw_price_guard)
+                        .map_err(|err| StdError::generic_err(err.to_string()))?;
+                }
                 LP_CONFIG.save(deps.storage, &config)?;
                 response = response.add_attributes(config.to_response_attributes());
             }
 
+            if let Some(config) = lp_staking_config {
+                validate_lp_staking_config(deps.as_ref(), &config)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+                LP_STAKING_CONFIG.save(deps.storage, &config)?;
+                response = response.add_attribute("generator_address", config.generator_address);
+            }
+
+            // rewards destination depends on both the holder and the staking config
+            if let Some(config) = LP_STAKING_CONFIG.may_load(deps.storage)? {
+                ensure_holder_receives_rewards(
+                    deps.as_ref(),
+                    &config,
+                    &HOLDER_ADDRESS.load(deps.storage)?,
+                )
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            }
+
             Ok(response)
         }
         MigrateMsg::UpdateCodeId { data: _ } => {
@@ -1,4 +1,4 @@
-use cosmwasm_std::{DecimalRangeExceeded, OverflowError, StdError};
+use cosmwasm_std::{Coin, DecimalRangeExceeded, OverflowError, StdError};
 use covenant_utils::op_mode::ContractOperationError;
 use neutron_sdk::NeutronError;
 use thiserror::Error;
@@ -44,6 +44,12 @@ pub enum ContractError {
     #[error("Pair type mismatch")]
     PairTypeMismatch {},
 
+    #[error("Unsupported pair type")]
+    UnsupportedPairType {},
+
+    #[error("TWAP window must be non-zero")]
+    InvalidTwapWindow {},
+
     #[error("Only holder can withdraw the position")]
     NotHolder {},
 
@@ -52,4 +58,10 @@ pub enum ContractError {
 
     #[error("Withdraw percentage range must belong to range (0.0, 1.0]")]
     WithdrawPercentageRangeError {},
+
+    #[error("Single party holder cannot distribute staking rewards")]
+    SinglePartyHolderRewards {},
+
+    #[error("Withdrawal below the minimum amount: expected {expected}, got {received}")]
+    MinWithdrawAmountError { expected: Coin, received: Coin },
 }
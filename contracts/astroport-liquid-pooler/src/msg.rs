@@ -4,13 +4,18 @@ use astroport::{
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
-    ensure, to_json_binary, Addr, Attribute, Binary, Coin, Decimal, StdResult, Uint128, WasmMsg,
+    ensure, to_json_binary, Addr, Attribute, Binary, Decimal, StdResult, Uint128, WasmMsg,
 };
 use covenant_macros::{clocked, covenant_deposit_address, covenant_lper_withdraw};
 use covenant_utils::{
     instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
-    PoolPriceConfig, SingleSideLpLimits,
+    PoolPriceConfig, SingleSideLpLimits, WithdrawPriceGuard,
 };
+use liquid_pooler_interface::{
+    LiquidPoolerExecuteMsg, LiquidPoolerInstantiate, LiquidPoolerInstantiateFields,
+    LiquidPoolerQueryMsg,
+};
+pub use liquid_pooler_interface::{LiquidityPosition, ProvidedLiquidityInfo};
 
 use crate::error::ContractError;
 
@@ -23,6 +28,18 @@ pub struct InstantiateMsg {
     pub pool_price_config: PoolPriceConfig,
     pub pair_type: PairType,
     pub holder_address: String,
+    /// staking of the lp tokens in the astroport generator.
+    /// lp tokens are not staked if not set
+    pub lp_staking_config: Option<LpStakingConfig>,
+    /// swapping of the balances exceeding the single side limits.
+    /// such balances are not provided if not set
+    pub zap_config: Option<ZapConfig>,
+    /// window (in seconds) of the time weighted average pool price that
+    /// must be within the expected price range. only spot price is validated if not set
+    pub twap_window_seconds: Option<u64>,
+    /// guard against withdrawing liquidity from a manipulated pool.
+    /// liquidity is withdrawn at any pool price if not set
+    pub withdraw_price_guard: Option<WithdrawPriceGuard>,
     // Contract Operation Mode.
     // The contract operation (the Tick function mostly) can either be a permissionless
     // (aka non-privileged) operation, or a permissioned operation, that is,
@@ -55,6 +72,10 @@ pub struct AstroportLiquidPoolerConfig {
     pub asset_a_denom: String,
     pub asset_b_denom: String,
     pub single_side_lp_limits: SingleSideLpLimits,
+    pub lp_staking_config: Option<LpStakingConfig>,
+    pub zap_config: Option<ZapConfig>,
+    pub twap_window_seconds: Option<u64>,
+    pub withdraw_price_guard: Option<WithdrawPriceGuard>,
 }
 
 impl AstroportLiquidPoolerConfig {
@@ -70,6 +91,10 @@ impl AstroportLiquidPoolerConfig {
             pool_price_config,
             pair_type: self.pool_pair_type.clone(),
             holder_address,
+            lp_staking_config: self.lp_staking_config.clone(),
+            zap_config: self.zap_config.clone(),
+            twap_window_seconds: self.twap_window_seconds,
+            withdraw_price_guard: self.withdraw_price_guard.clone(),
             slippage_tolerance: None,
             assets: AssetData {
                 asset_a_denom: self.asset_a_denom.to_string(),
@@ -80,6 +105,16 @@ impl AstroportLiquidPoolerConfig {
     }
 }
 
+impl LiquidPoolerInstantiate for AstroportLiquidPoolerConfig {
+    fn to_instantiate_binary(&self, fields: LiquidPoolerInstantiateFields) -> StdResult<Binary> {
+        to_json_binary(&self.to_instantiate_msg(
+            fields.holder_address,
+            fields.pool_price_config,
+            fields.op_mode_cfg,
+        ))
+    }
+}
+
 #[cw_serde]
 pub struct DecimalRange {
     min: Decimal,
@@ -117,6 +152,12 @@ pub struct LpConfig {
     pub expected_pool_ratio_range: DecimalRange,
     /// pair type specified in the covenant
     pub pair_type: PairType,
+    /// swapping of the balances exceeding the single side limits, if enabled
+    pub zap_config: Option<ZapConfig>,
+    /// window (in seconds) of the validated time weighted average price, if enabled
+    pub twap_window_seconds: Option<u64>,
+    /// guard validated upon liquidity withdrawal, if enabled
+    pub withdraw_price_guard: Option<WithdrawPriceGuard>,
 }
 
 impl LpConfig {
@@ -142,6 +183,49 @@ impl LpConfig {
     }
 }
 
+/// configuration of swapping ("zapping") the balances exceeding the single
+/// side limits into the other pool asset to provide double sided liquidity.
+/// the amount swapped on each tick is capped by the single side limits,
+/// and the swap price must be within the expected pool price range.
+#[cw_serde]
+pub struct ZapConfig {
+    /// astroport router to swap through. swaps go through the pool if not set
+    pub router_address: Option<String>,
+}
+
+/// configuration of staking the lp tokens in the astroport generator
+#[cw_serde]
+pub struct LpStakingConfig {
+    /// address of the astroport generator to stake the lp tokens in
+    pub generator_address: String,
+    /// where the claimed staking rewards are sent to
+    pub rewards_destination: RewardsDestination,
+}
+
+#[cw_serde]
+pub enum RewardsDestination {
+    /// rewards are swapped into the pool assets through the given astroport
+    /// pairs, and provided to the pool on the following ticks.
+    /// rewards denominated in pool assets are provided as they are, and
+    /// rewards that none of the pairs can swap are sent to the holder.
+    Compound { swap_pairs: Vec<String> },
+    /// rewards are sent to the holder, to be distributed along its splits.
+    /// not supported by single party holders, which only distribute the pool assets.
+    Holder {},
+    /// rewards are sent to the given address as they are
+    Receiver { address: String },
+}
+
+/// lp tokens staked in the astroport generator
+#[cw_serde]
+pub struct StakedLiquidityInfo {
+    pub generator_address: String,
+    pub lp_token: String,
+    pub staked_amount: Uint128,
+    /// rewards accrued since the last claim
+    pub pending_rewards: Vec<Asset>,
+}
+
 /// holds the both asset denoms relevant for providing liquidity
 #[cw_serde]
 pub struct AssetData {
@@ -191,6 +275,15 @@ impl AssetData {
 #[cw_serde]
 pub enum ExecuteMsg {}
 
+impl From<LiquidPoolerExecuteMsg> for ExecuteMsg {
+    fn from(msg: LiquidPoolerExecuteMsg) -> Self {
+        match msg {
+            LiquidPoolerExecuteMsg::Tick {} => ExecuteMsg::Tick {},
+            LiquidPoolerExecuteMsg::Withdraw { percentage } => ExecuteMsg::Withdraw { percentage },
+        }
+    }
+}
+
 #[covenant_deposit_address]
 #[cw_serde]
 #[derive(QueryResponses)]
@@ -203,29 +296,41 @@ pub enum QueryMsg {
     LpConfig {},
     #[returns(ProvidedLiquidityInfo)]
     ProvidedLiquidityInfo {},
+    #[returns(LiquidityPosition)]
+    LiquidityPosition {},
+    #[returns(Option<StakedLiquidityInfo>)]
+    StakedLiquidity {},
+    /// Returns the time weighted average price of asset b denominated
+    /// in asset a over the configured window, if enough price history is available
+    #[returns(Option<Decimal>)]
+    Twap {},
     #[returns(covenant_utils::op_mode::ContractOperationMode)]
     OperationMode {},
 }
 
+impl From<LiquidPoolerQueryMsg> for QueryMsg {
+    fn from(msg: LiquidPoolerQueryMsg) -> Self {
+        match msg {
+            LiquidPoolerQueryMsg::DepositAddress {} => QueryMsg::DepositAddress {},
+            LiquidPoolerQueryMsg::ProvidedLiquidityInfo {} => QueryMsg::ProvidedLiquidityInfo {},
+            LiquidPoolerQueryMsg::LiquidityPosition {} => QueryMsg::LiquidityPosition {},
+        }
+    }
+}
+
 #[cw_serde]
 pub enum MigrateMsg {
     UpdateConfig {
         op_mode: Option<ContractOperationModeConfig>,
         holder_address: Option<String>,
         lp_config: Option<Box<LpConfig>>,
+        lp_staking_config: Option<LpStakingConfig>,
     },
     UpdateCodeId {
         data: Option<Binary>,
     },
 }
 
-/// keeps track of provided asset liquidities in `Uint128`.
-#[cw_serde]
-pub struct ProvidedLiquidityInfo {
-    pub provided_coin_a: Coin,
-    pub provided_coin_b: Coin,
-}
-
 /// state of the LP state machine
 #[cw_serde]
 pub enum ContractState {
@@ -1,8 +1,8 @@
 use cosmwasm_std::Addr;
-use covenant_utils::op_mode::ContractOperationMode;
-use cw_storage_plus::Item;
+use covenant_utils::{astroport::PriceObservation, op_mode::ContractOperationMode};
+use cw_storage_plus::{Deque, Item};
 
-use crate::msg::{ContractState, LpConfig, ProvidedLiquidityInfo};
+use crate::msg::{ContractState, LpConfig, LpStakingConfig, ProvidedLiquidityInfo};
 
 /// contract state tracks the state machine progress
 pub const CONTRACT_STATE: Item<ContractState> = Item::new("contract_state");
@@ -18,3 +18,9 @@ pub const PROVIDED_LIQUIDITY_INFO: Item<ProvidedLiquidityInfo> =
 
 /// configuration relevant to entering into an LP position
 pub const LP_CONFIG: Item<LpConfig> = Item::new("lp_config");
+
+/// configuration relevant to staking the LP tokens, if enabled
+pub const LP_STAKING_CONFIG: Item<LpStakingConfig> = Item::new("lp_staking_config");
+
+/// pool price observations covering the twap window, oldest first
+pub const TWAP_OBSERVATIONS: Deque<PriceObservation> = Deque::new("twap_observations");
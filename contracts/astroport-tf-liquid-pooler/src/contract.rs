@@ -4,8 +4,8 @@ use cosmwasm_std::{
     coin, coins, ensure, to_json_binary, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env,
     MessageInfo, Reply, Response, StdError, StdResult, SubMsg, SubMsgResult, Uint128, WasmMsg,
 };
-use covenant_utils::withdraw_lp_helper::WithdrawLPMsgs;
 use cw2::set_contract_version;
+use liquid_pooler_interface::LiquidPoolerHolderMsg;
 use valence_clock::helpers::{enqueue_msg, verify_clock};
 
 use astroport::{
@@ -21,8 +21,8 @@ use astroport::{
 use crate::{
     error::ContractError,
     msg::{
-        ContractState, DecimalRange, ExecuteMsg, InstantiateMsg, LpConfig, MigrateMsg,
-        ProvidedLiquidityInfo, QueryMsg,
+        ContractState, DecimalRange, ExecuteMsg, InstantiateMsg, LiquidityPosition, LpConfig,
+        MigrateMsg, ProvidedLiquidityInfo, QueryMsg,
     },
     state::{HOLDER_ADDRESS, LP_CONFIG, PROVIDED_LIQUIDITY_INFO},
 };
@@ -168,7 +168,7 @@ fn try_withdraw(
 
         return Ok(Response::default().add_message(WasmMsg::Execute {
             contract_addr: holder_addr.to_string(),
-            msg: to_json_binary(&WithdrawLPMsgs::Distribute {})?,
+            msg: to_json_binary(&LiquidPoolerHolderMsg::Distribute {})?,
             funds,
         }));
     }
@@ -212,7 +212,7 @@ fn try_withdraw(
     // with the funds we withdrew from the pool
     let to_holder_msg = WasmMsg::Execute {
         contract_addr: holder_addr.to_string(),
-        msg: to_json_binary(&WithdrawLPMsgs::Distribute {})?,
+        msg: to_json_binary(&LiquidPoolerHolderMsg::Distribute {})?,
         funds: withdrawn_coins,
     };
 
@@ -561,6 +561,19 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProvidedLiquidityInfo {} => Ok(to_json_binary(
             &PROVIDED_LIQUIDITY_INFO.load(deps.storage)?,
         )?),
+        QueryMsg::LiquidityPosition {} => {
+            let lp_config = LP_CONFIG.load(deps.storage)?;
+            let pair_info: PairInfo = deps
+                .querier
+                .query_wasm_smart(lp_config.pool_address, &astroport::pair::QueryMsg::Pair {})?;
+            let lp_token_bal = deps
+                .querier
+                .query_balance(env.contract.address, pair_info.liquidity_token)?;
+            Ok(to_json_binary(&LiquidityPosition {
+                lp_token: lp_token_bal.denom,
+                amount: lp_token_bal.amount,
+            })?)
+        }
     }
 }
 
@@ -4,7 +4,7 @@ use astroport::{
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
-    ensure, to_json_binary, Addr, Attribute, Binary, Coin, Decimal, StdResult, Uint128, WasmMsg,
+    ensure, to_json_binary, Addr, Attribute, Binary, Decimal, StdResult, Uint128, WasmMsg,
 };
 use covenant_macros::{
     clocked, covenant_clock_address, covenant_deposit_address, covenant_lper_withdraw,
@@ -12,6 +12,8 @@ use covenant_macros::{
 use covenant_utils::{
     instantiate2_helper::Instantiate2HelperConfig, PoolPriceConfig, SingleSideLpLimits,
 };
+use liquid_pooler_interface::{LiquidPoolerExecuteMsg, LiquidPoolerQueryMsg};
+pub use liquid_pooler_interface::{LiquidityPosition, ProvidedLiquidityInfo};
 
 use crate::error::ContractError;
 
@@ -188,6 +190,15 @@ impl AssetData {
 #[cw_serde]
 pub enum ExecuteMsg {}
 
+impl From<LiquidPoolerExecuteMsg> for ExecuteMsg {
+    fn from(msg: LiquidPoolerExecuteMsg) -> Self {
+        match msg {
+            LiquidPoolerExecuteMsg::Tick {} => ExecuteMsg::Tick {},
+            LiquidPoolerExecuteMsg::Withdraw { percentage } => ExecuteMsg::Withdraw { percentage },
+        }
+    }
+}
+
 #[covenant_clock_address]
 #[covenant_deposit_address]
 #[cw_serde]
@@ -201,6 +212,18 @@ pub enum QueryMsg {
     LpConfig {},
     #[returns(ProvidedLiquidityInfo)]
     ProvidedLiquidityInfo {},
+    #[returns(LiquidityPosition)]
+    LiquidityPosition {},
+}
+
+impl From<LiquidPoolerQueryMsg> for QueryMsg {
+    fn from(msg: LiquidPoolerQueryMsg) -> Self {
+        match msg {
+            LiquidPoolerQueryMsg::DepositAddress {} => QueryMsg::DepositAddress {},
+            LiquidPoolerQueryMsg::ProvidedLiquidityInfo {} => QueryMsg::ProvidedLiquidityInfo {},
+            LiquidPoolerQueryMsg::LiquidityPosition {} => QueryMsg::LiquidityPosition {},
+        }
+    }
 }
 
 #[cw_serde]
@@ -215,13 +238,6 @@ pub enum MigrateMsg {
     },
 }
 
-/// keeps track of provided asset liquidities in `Uint128`.
-#[cw_serde]
-pub struct ProvidedLiquidityInfo {
-    pub provided_coin_a: Coin,
-    pub provided_coin_b: Coin,
-}
-
 /// state of the LP state machine
 #[cw_serde]
 pub enum ContractState {
@@ -0,0 +1,418 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    coin, ensure, to_json_binary, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdError, StdResult, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use covenant_utils::op_mode::{verify_caller, ContractOperationMode};
+use cw2::set_contract_version;
+use liquid_pooler_interface::LiquidPoolerHolderMsg;
+
+use crate::{
+    dex::{
+        get_pair_id, DepositOptions, MsgDeposit, MsgWithdrawal, Pool, QueryPoolRequest,
+        QueryPoolResponse,
+    },
+    error::ContractError,
+    msg::{
+        ContractState, DecimalRange, ExecuteMsg, InstantiateMsg, LiquidityPosition, LpConfig,
+        MigrateMsg, ProvidedLiquidityInfo, QueryMsg,
+    },
+    state::{
+        CONTRACT_OP_MODE, CONTRACT_STATE, HOLDER_ADDRESS, LP_CONFIG, PENDING_BALANCES,
+        PROVIDED_LIQUIDITY_INFO,
+    },
+};
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const DEPOSIT_REPLY_ID: u64 = 321u64;
+const WITHDRAWAL_REPLY_ID: u64 = 322u64;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let op_mode = ContractOperationMode::try_init(deps.api, msg.op_mode_cfg.clone())?;
+
+    // validate the contract addresses
+    let holder_addr = deps.api.addr_validate(&msg.holder_address)?;
+
+    let decimal_range = DecimalRange::try_from(
+        msg.pool_price_config.expected_spot_price,
+        msg.pool_price_config.acceptable_price_spread,
+    )?;
+
+    let lp_config = LpConfig {
+        asset_data: msg.assets,
+        tick_range: msg.tick_range,
+        expected_pool_ratio_range: decimal_range,
+    };
+    lp_config.validate()?;
+
+    // contract starts at Instantiated state
+    CONTRACT_STATE.save(deps.storage, &ContractState::Instantiated)?;
+
+    // store the relevant module addresses
+    CONTRACT_OP_MODE.save(deps.storage, &op_mode)?;
+    HOLDER_ADDRESS.save(deps.storage, &holder_addr)?;
+    LP_CONFIG.save(deps.storage, &lp_config)?;
+
+    // we begin with no liquidity provided
+    PROVIDED_LIQUIDITY_INFO.save(
+        deps.storage,
+        &ProvidedLiquidityInfo {
+            provided_coin_a: coin(0, lp_config.asset_data.asset_a_denom.as_str()),
+            provided_coin_b: coin(0, lp_config.asset_data.asset_b_denom.as_str()),
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "lp_instantiate")
+        .add_attribute("op_mode", format!("{:?}", op_mode))
+        .add_attributes(lp_config.to_response_attributes()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match (CONTRACT_STATE.load(deps.storage)?, msg) {
+        // if the contract is in the instantiated state, tick attempts to provide liquidity
+        (ContractState::Instantiated, ExecuteMsg::Tick {}) => try_lp(deps, env, info),
+        // withdraw is state independent
+        (_, ExecuteMsg::Withdraw { percentage }) => try_withdraw(deps, env, info, percentage),
+    }
+}
+
+/// attempts to deposit the available balances into the configured pool.
+/// deposits are made with autoswap disabled, so only the amounts matching
+/// the pool reserves ratio get deposited and the rest remains in the contract.
+/// pools without reserves are seeded with the available balances.
+fn try_lp(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+
+    let lp_config = LP_CONFIG.load(deps.storage)?;
+    let contract_address = env.contract.address.to_string();
+
+    // liquidity is deposited at the price of the configured tick,
+    // which must be within our expectations
+    lp_config.validate_tick_price()?;
+
+    let coin_a = deps
+        .querier
+        .query_balance(&contract_address, &lp_config.asset_data.asset_a_denom)?;
+    let coin_b = deps
+        .querier
+        .query_balance(&contract_address, &lp_config.asset_data.asset_b_denom)?;
+
+    // both assets are needed to deposit at the pool ratio
+    if coin_a.amount.is_zero() || coin_b.amount.is_zero() {
+        return Ok(Response::default()
+            .add_attribute("method", "try_lp")
+            .add_attribute("status", "awaiting funds"));
+    }
+
+    let deposit_msg = MsgDeposit {
+        creator: contract_address.to_string(),
+        receiver: contract_address,
+        token_a: coin_a.denom.to_string(),
+        token_b: coin_b.denom.to_string(),
+        amounts_a: vec![coin_a.amount.to_string()],
+        amounts_b: vec![coin_b.amount.to_string()],
+        tick_indexes_a_to_b: vec![lp_config.tick_range.tick_index],
+        fees: vec![lp_config.tick_range.fee],
+        options: vec![DepositOptions {
+            disable_autoswap: true,
+        }],
+    };
+
+    // the deposited amounts are determined upon reply
+    PENDING_BALANCES.save(deps.storage, &(coin_a.clone(), coin_b.clone()))?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_lp")
+        .add_attribute("coin_a", coin_a.to_string())
+        .add_attribute("coin_b", coin_b.to_string())
+        .add_submessage(SubMsg::reply_on_success(deposit_msg, DEPOSIT_REPLY_ID)))
+}
+
+fn try_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    percent: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let percent = percent.unwrap_or(Decimal::one());
+    ensure!(
+        percent > Decimal::zero() && percent <= Decimal::one(),
+        ContractError::WithdrawPercentageRangeError {}
+    );
+
+    let holder_addr = HOLDER_ADDRESS.load(deps.storage)?;
+    ensure!(info.sender == holder_addr, ContractError::NotHolder {});
+
+    let contract_address = env.contract.address.to_string();
+    let lp_config = LP_CONFIG.load(deps.storage)?;
+
+    let coin_a = deps
+        .querier
+        .query_balance(&contract_address, &lp_config.asset_data.asset_a_denom)?;
+    let coin_b = deps
+        .querier
+        .query_balance(&contract_address, &lp_config.asset_data.asset_b_denom)?;
+
+    // a pool that does not exist yet holds no shares
+    let shares = match query_pool(deps.as_ref(), &lp_config) {
+        Ok(pool) => {
+            deps.querier
+                .query_balance(&contract_address, pool.shares_denom())?
+                .amount
+        }
+        Err(_) => Uint128::zero(),
+    };
+
+    // if no pool shares are available, we attempt to withdraw any available denoms
+    if shares.is_zero() {
+        let funds: Vec<Coin> = [coin_a, coin_b]
+            .into_iter()
+            .filter(|coin| !coin.amount.is_zero())
+            .collect();
+
+        ensure!(!funds.is_empty(), ContractError::NothingToWithdraw {});
+
+        return Ok(Response::default().add_message(WasmMsg::Execute {
+            contract_addr: holder_addr.to_string(),
+            msg: to_json_binary(&LiquidPoolerHolderMsg::Distribute {})?,
+            funds,
+        }));
+    }
+
+    // If percentage is 100%, use the whole balance
+    // If percentage is less than 100%, calculate the percentage of share we want to withdraw
+    let withdraw_shares_amount = if percent == Decimal::one() {
+        shares
+    } else {
+        Decimal::from_atomics(shares, 0)?
+            .checked_mul(percent)?
+            .to_uint_floor()
+    };
+
+    let withdrawal_msg = MsgWithdrawal {
+        creator: contract_address.to_string(),
+        receiver: contract_address,
+        token_a: lp_config.asset_data.asset_a_denom.to_string(),
+        token_b: lp_config.asset_data.asset_b_denom.to_string(),
+        shares_to_remove: vec![withdraw_shares_amount.to_string()],
+        tick_indexes_a_to_b: vec![lp_config.tick_range.tick_index],
+        fees: vec![lp_config.tick_range.fee],
+    };
+
+    // the withdrawn amounts are sent to the holder upon reply. full withdrawals
+    // also send the undeposited balances, so we track them from zero.
+    let pending_balances = if percent == Decimal::one() {
+        (coin(0, coin_a.denom), coin(0, coin_b.denom))
+    } else {
+        (coin_a, coin_b)
+    };
+    PENDING_BALANCES.save(deps.storage, &pending_balances)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_withdraw")
+        .add_attribute("shares", withdraw_shares_amount.to_string())
+        .add_submessage(SubMsg::reply_on_success(
+            withdrawal_msg,
+            WITHDRAWAL_REPLY_ID,
+        )))
+}
+
+/// queries the pool of the configured tick range
+fn query_pool(deps: Deps, lp_config: &LpConfig) -> StdResult<Pool> {
+    let asset_a_denom = lp_config.asset_data.asset_a_denom.as_str();
+    let asset_b_denom = lp_config.asset_data.asset_b_denom.as_str();
+
+    // pools are indexed by the tick index from token0 to token1
+    let tick_index = if asset_a_denom < asset_b_denom {
+        lp_config.tick_range.tick_index
+    } else {
+        -lp_config.tick_range.tick_index
+    };
+
+    let response: QueryPoolResponse = deps.querier.query(
+        &QueryPoolRequest {
+            pair_id: get_pair_id(asset_a_denom, asset_b_denom),
+            tick_index,
+            fee: lp_config.tick_range.fee,
+        }
+        .into(),
+    )?;
+
+    Ok(response.pool)
+}
+
+/// returns the asset balances gained (or spent) since the pending balances were recorded
+fn get_pending_balances_delta(
+    deps: Deps,
+    env: &Env,
+    spent: bool,
+) -> Result<(Coin, Coin), ContractError> {
+    let (coin_a, coin_b) = PENDING_BALANCES.load(deps.storage)?;
+
+    let mut deltas = vec![];
+    for pending_coin in [coin_a, coin_b] {
+        let balance = deps
+            .querier
+            .query_balance(env.contract.address.to_string(), &pending_coin.denom)?;
+        let amount = if spent {
+            pending_coin.amount.checked_sub(balance.amount)?
+        } else {
+            balance.amount.checked_sub(pending_coin.amount)?
+        };
+        deltas.push(coin(amount.u128(), pending_coin.denom));
+    }
+
+    Ok((deltas[0].clone(), deltas[1].clone()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::ContractState {} => Ok(to_json_binary(&CONTRACT_STATE.may_load(deps.storage)?)?),
+        QueryMsg::HolderAddress {} => Ok(to_json_binary(&HOLDER_ADDRESS.may_load(deps.storage)?)?),
+        QueryMsg::LpConfig {} => Ok(to_json_binary(&LP_CONFIG.may_load(deps.storage)?)?),
+        // the deposit address for LP module is the contract itself
+        QueryMsg::DepositAddress {} => {
+            Ok(to_json_binary(&Some(&env.contract.address.to_string()))?)
+        }
+        QueryMsg::ProvidedLiquidityInfo {} => Ok(to_json_binary(
+            &PROVIDED_LIQUIDITY_INFO.load(deps.storage)?,
+        )?),
+        QueryMsg::LiquidityPosition {} => {
+            let lp_config = LP_CONFIG.load(deps.storage)?;
+            let lp_token = query_pool(deps, &lp_config)?.shares_denom();
+            let balance = deps
+                .querier
+                .query_balance(env.contract.address, lp_token.as_str())?;
+            Ok(to_json_binary(&LiquidityPosition {
+                lp_token,
+                amount: balance.amount,
+            })?)
+        }
+        QueryMsg::OperationMode {} => Ok(to_json_binary(&CONTRACT_OP_MODE.load(deps.storage)?)?),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    match msg {
+        MigrateMsg::UpdateConfig {
+            op_mode,
+            holder_address,
+            lp_config,
+        } => {
+            let mut response = Response::default().add_attribute("method", "update_config");
+
+            if let Some(op_mode_cfg) = op_mode {
+                let updated_op_mode = ContractOperationMode::try_init(deps.api, op_mode_cfg)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+                CONTRACT_OP_MODE.save(deps.storage, &updated_op_mode)?;
+                response = response.add_attribute("op_mode", format!("{:?}", updated_op_mode));
+            }
+
+            if let Some(holder_address) = holder_address {
+                HOLDER_ADDRESS.save(deps.storage, &deps.api.addr_validate(&holder_address)?)?;
+                response = response.add_attribute("holder_address", holder_address);
+            }
+
+            if let Some(config) = lp_config {
+                config
+                    .validate()
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+                LP_CONFIG.save(deps.storage, &config)?;
+                response = response.add_attributes(config.to_response_attributes());
+            }
+
+            Ok(response)
+        }
+        MigrateMsg::UpdateCodeId { data: _ } => {
+            // This is a migrate message to update code id,
+            // Data is optional base64 that we can parse to any data we would like in the future
+            // let data: SomeStruct = from_binary(&data)?;
+            Ok(Response::default())
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.result {
+        SubMsgResult::Ok(_) => match msg.id {
+            DEPOSIT_REPLY_ID => handle_deposit_reply(deps, env),
+            WITHDRAWAL_REPLY_ID => handle_withdrawal_reply(deps, env),
+            _ => Err(ContractError::from(StdError::generic_err(format!(
+                "unknown reply id: {}",
+                msg.id
+            )))),
+        },
+        SubMsgResult::Err(e) => Err(ContractError::from(StdError::generic_err(e))),
+    }
+}
+
+/// records the amounts that got deposited into the pool
+fn handle_deposit_reply(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let (deposited_coin_a, deposited_coin_b) =
+        get_pending_balances_delta(deps.as_ref(), &env, true)?;
+    PENDING_BALANCES.remove(deps.storage);
+
+    PROVIDED_LIQUIDITY_INFO.update(deps.storage, |mut info| -> StdResult<_> {
+        info.provided_coin_a.amount = info
+            .provided_coin_a
+            .amount
+            .checked_add(deposited_coin_a.amount)?;
+        info.provided_coin_b.amount = info
+            .provided_coin_b
+            .amount
+            .checked_add(deposited_coin_b.amount)?;
+        Ok(info)
+    })?;
+
+    Ok(Response::default()
+        .add_attribute("method", "handle_deposit_reply")
+        .add_attribute("deposited_coin_a", deposited_coin_a.to_string())
+        .add_attribute("deposited_coin_b", deposited_coin_b.to_string()))
+}
+
+/// sends the withdrawn amounts, along with any undeposited
+/// balances on full withdrawals, to the holder
+fn handle_withdrawal_reply(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let (withdrawn_coin_a, withdrawn_coin_b) =
+        get_pending_balances_delta(deps.as_ref(), &env, false)?;
+    PENDING_BALANCES.remove(deps.storage);
+
+    let funds: Vec<Coin> = [withdrawn_coin_a, withdrawn_coin_b]
+        .into_iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .collect();
+
+    // send message to holder that we finished with the withdrawal
+    // with the funds we withdrew from the pool
+    let to_holder_msg = WasmMsg::Execute {
+        contract_addr: HOLDER_ADDRESS.load(deps.storage)?.to_string(),
+        msg: to_json_binary(&LiquidPoolerHolderMsg::Distribute {})?,
+        funds,
+    };
+
+    Ok(Response::default()
+        .add_attribute("method", "handle_withdrawal_reply")
+        .add_message(to_holder_msg))
+}
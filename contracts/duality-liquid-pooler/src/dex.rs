@@ -0,0 +1,151 @@
+//! Messages and queries of the Neutron dex (Duality) module.
+//!
+//! The neutron-sdk version in use does not ship bindings for the dex
+//! module, so the relevant protobuf messages are defined here and
+//! submitted as stargate messages and queries.
+
+use cosmwasm_std::{Binary, CosmosMsg, Empty, QueryRequest, Uint128, Uint64};
+use prost::Message;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const MSG_DEPOSIT_TYPE_URL: &str = "/neutron.dex.MsgDeposit";
+pub const MSG_WITHDRAWAL_TYPE_URL: &str = "/neutron.dex.MsgWithdrawal";
+pub const QUERY_POOL_PATH: &str = "/neutron.dex.Query/Pool";
+
+/// prefix of the denoms of the pool shares minted upon deposit
+pub const POOL_SHARES_DENOM_PREFIX: &str = "neutron/pool/";
+
+#[derive(Clone, PartialEq, Message)]
+pub struct DepositOptions {
+    #[prost(bool, tag = "1")]
+    pub disable_autoswap: bool,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgDeposit {
+    #[prost(string, tag = "1")]
+    pub creator: String,
+    #[prost(string, tag = "2")]
+    pub receiver: String,
+    #[prost(string, tag = "3")]
+    pub token_a: String,
+    #[prost(string, tag = "4")]
+    pub token_b: String,
+    #[prost(string, repeated, tag = "5")]
+    pub amounts_a: Vec<String>,
+    #[prost(string, repeated, tag = "6")]
+    pub amounts_b: Vec<String>,
+    #[prost(int64, repeated, tag = "7")]
+    pub tick_indexes_a_to_b: Vec<i64>,
+    #[prost(uint64, repeated, tag = "8")]
+    pub fees: Vec<u64>,
+    #[prost(message, repeated, tag = "9")]
+    pub options: Vec<DepositOptions>,
+}
+
+impl From<MsgDeposit> for CosmosMsg {
+    fn from(msg: MsgDeposit) -> Self {
+        CosmosMsg::Stargate {
+            type_url: MSG_DEPOSIT_TYPE_URL.to_string(),
+            value: Binary(msg.encode_to_vec()),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgWithdrawal {
+    #[prost(string, tag = "1")]
+    pub creator: String,
+    #[prost(string, tag = "2")]
+    pub receiver: String,
+    #[prost(string, tag = "3")]
+    pub token_a: String,
+    #[prost(string, tag = "4")]
+    pub token_b: String,
+    #[prost(string, repeated, tag = "5")]
+    pub shares_to_remove: Vec<String>,
+    #[prost(int64, repeated, tag = "6")]
+    pub tick_indexes_a_to_b: Vec<i64>,
+    #[prost(uint64, repeated, tag = "7")]
+    pub fees: Vec<u64>,
+}
+
+impl From<MsgWithdrawal> for CosmosMsg {
+    fn from(msg: MsgWithdrawal) -> Self {
+        CosmosMsg::Stargate {
+            type_url: MSG_WITHDRAWAL_TYPE_URL.to_string(),
+            value: Binary(msg.encode_to_vec()),
+        }
+    }
+}
+
+/// queries the pool of the given pair at the given tick index
+/// (from token0 to token1) and fee
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryPoolRequest {
+    #[prost(string, tag = "1")]
+    pub pair_id: String,
+    #[prost(int64, tag = "2")]
+    pub tick_index: i64,
+    #[prost(uint64, tag = "3")]
+    pub fee: u64,
+}
+
+impl From<QueryPoolRequest> for QueryRequest<Empty> {
+    fn from(request: QueryPoolRequest) -> Self {
+        QueryRequest::Stargate {
+            path: QUERY_POOL_PATH.to_string(),
+            data: Binary(request.encode_to_vec()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QueryPoolResponse {
+    pub pool: Pool,
+}
+
+/// only the fields relevant to the liquid pooler are deserialized
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Pool {
+    pub id: Uint64,
+    /// token0 reserves of the pool
+    pub lower_tick0: Option<PoolReserves>,
+    /// token1 reserves of the pool
+    pub upper_tick1: Option<PoolReserves>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PoolReserves {
+    pub reserves_maker_denom: Uint128,
+}
+
+impl Pool {
+    pub fn shares_denom(&self) -> String {
+        format!("{POOL_SHARES_DENOM_PREFIX}{}", self.id)
+    }
+
+    pub fn token_0_reserves(&self) -> Uint128 {
+        self.lower_tick0
+            .as_ref()
+            .map(|reserves| reserves.reserves_maker_denom)
+            .unwrap_or_default()
+    }
+
+    pub fn token_1_reserves(&self) -> Uint128 {
+        self.upper_tick1
+            .as_ref()
+            .map(|reserves| reserves.reserves_maker_denom)
+            .unwrap_or_default()
+    }
+}
+
+/// dex pairs are identified by their lexicographically sorted denoms
+pub fn get_pair_id(denom_a: &str, denom_b: &str) -> String {
+    if denom_a < denom_b {
+        format!("{denom_a}<>{denom_b}")
+    } else {
+        format!("{denom_b}<>{denom_a}")
+    }
+}
@@ -0,0 +1,33 @@
+use cosmwasm_std::{DecimalRangeExceeded, OverflowError, StdError};
+use covenant_utils::op_mode::ContractOperationError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    OverflowError(#[from] OverflowError),
+
+    #[error(transparent)]
+    DecimalRangeExceeded(#[from] DecimalRangeExceeded),
+
+    #[error(transparent)]
+    ContractOperationError(#[from] ContractOperationError),
+
+    #[error("Asset denoms must differ")]
+    IdenticalAssetDenoms {},
+
+    #[error("Price range error")]
+    PriceRangeError {},
+
+    #[error("Only holder can withdraw the position")]
+    NotHolder {},
+
+    #[error("no covenant denom or lp tokens available")]
+    NothingToWithdraw {},
+
+    #[error("Withdraw percentage range must belong to range (0.0, 1.0]")]
+    WithdrawPercentageRangeError {},
+}
@@ -0,0 +1,7 @@
+#![warn(clippy::unwrap_used, clippy::expect_used)]
+
+pub mod contract;
+pub mod dex;
+pub mod error;
+pub mod msg;
+pub mod state;
@@ -0,0 +1,241 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{ensure, to_json_binary, Addr, Attribute, Binary, Decimal, StdResult, WasmMsg};
+use covenant_macros::{clocked, covenant_deposit_address, covenant_lper_withdraw};
+use covenant_utils::{
+    instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
+    PoolPriceConfig,
+};
+use liquid_pooler_interface::{
+    LiquidPoolerExecuteMsg, LiquidPoolerInstantiate, LiquidPoolerInstantiateFields,
+    LiquidPoolerQueryMsg,
+};
+pub use liquid_pooler_interface::{LiquidityPosition, ProvidedLiquidityInfo};
+
+use crate::error::ContractError;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub assets: AssetData,
+    /// tick range of the pool to deposit the liquidity into
+    pub tick_range: TickRange,
+    pub pool_price_config: PoolPriceConfig,
+    pub holder_address: String,
+    // Contract Operation Mode.
+    // The contract operation (the Tick function mostly) can either be a permissionless
+    // (aka non-privileged) operation, or a permissioned operation, that is,
+    // restricted to being executed by one of the configured privileged accounts.
+    pub op_mode_cfg: ContractOperationModeConfig,
+}
+
+impl InstantiateMsg {
+    pub fn to_instantiate2_msg(
+        &self,
+        instantiate2_helper: &Instantiate2HelperConfig,
+        admin: String,
+        label: String,
+    ) -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Instantiate2 {
+            admin: Some(admin),
+            code_id: instantiate2_helper.code,
+            label,
+            msg: to_json_binary(self)?,
+            funds: vec![],
+            salt: instantiate2_helper.salt.clone(),
+        })
+    }
+}
+
+#[cw_serde]
+pub struct DualityLiquidPoolerConfig {
+    pub asset_a_denom: String,
+    pub asset_b_denom: String,
+    pub tick_range: TickRange,
+}
+
+impl DualityLiquidPoolerConfig {
+    pub fn to_instantiate_msg(
+        &self,
+        holder_address: String,
+        pool_price_config: PoolPriceConfig,
+        op_mode_cfg: ContractOperationModeConfig,
+    ) -> InstantiateMsg {
+        InstantiateMsg {
+            assets: AssetData {
+                asset_a_denom: self.asset_a_denom.to_string(),
+                asset_b_denom: self.asset_b_denom.to_string(),
+            },
+            tick_range: self.tick_range.clone(),
+            pool_price_config,
+            holder_address,
+            op_mode_cfg,
+        }
+    }
+}
+
+impl LiquidPoolerInstantiate for DualityLiquidPoolerConfig {
+    fn to_instantiate_binary(&self, fields: LiquidPoolerInstantiateFields) -> StdResult<Binary> {
+        to_json_binary(&self.to_instantiate_msg(
+            fields.holder_address,
+            fields.pool_price_config,
+            fields.op_mode_cfg,
+        ))
+    }
+}
+
+/// duality pools are identified by their tick index and fee.
+/// liquidity deposited into a pool is placed at the ticks
+/// `tick_index - fee` (asset a) and `tick_index + fee` (asset b).
+/// the liquid pooler deposits into this single pool only.
+#[cw_serde]
+pub struct TickRange {
+    /// tick index of the pool, from asset a to asset b
+    pub tick_index: i64,
+    /// fee of the pool, in ticks
+    pub fee: u64,
+}
+
+impl TickRange {
+    /// price of the pool (a/b), given by `1.0001^tick_index`
+    pub fn get_price(&self) -> Result<Decimal, ContractError> {
+        let exponent = u32::try_from(self.tick_index.unsigned_abs())
+            .map_err(|_| ContractError::PriceRangeError {})?;
+        let price = Decimal::from_ratio(10_001u128, 10_000u128).checked_pow(exponent)?;
+        if self.tick_index < 0 {
+            price.inv().ok_or(ContractError::PriceRangeError {})
+        } else {
+            Ok(price)
+        }
+    }
+}
+
+#[cw_serde]
+pub struct DecimalRange {
+    min: Decimal,
+    max: Decimal,
+}
+
+impl DecimalRange {
+    pub fn try_from(mid: Decimal, delta: Decimal) -> Result<DecimalRange, ContractError> {
+        Ok(DecimalRange {
+            min: mid.checked_sub(delta)?,
+            max: mid.checked_add(delta)?,
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), ContractError> {
+        ensure!(self.min <= self.max, ContractError::PriceRangeError {});
+        Ok(())
+    }
+
+    pub fn is_within_range(&self, value: Decimal) -> Result<(), ContractError> {
+        ensure!(
+            value >= self.min && value <= self.max,
+            ContractError::PriceRangeError {}
+        );
+        Ok(())
+    }
+}
+
+#[cw_serde]
+pub struct LpConfig {
+    /// denoms of both parties
+    pub asset_data: AssetData,
+    /// tick range of the pool we plan to enter
+    pub tick_range: TickRange,
+    /// expected range of the pool price (a/b)
+    pub expected_pool_ratio_range: DecimalRange,
+}
+
+impl LpConfig {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        ensure!(
+            self.asset_data.asset_a_denom != self.asset_data.asset_b_denom,
+            ContractError::IdenticalAssetDenoms {}
+        );
+        self.expected_pool_ratio_range.validate()?;
+        self.validate_tick_price()
+    }
+
+    /// the price of the configured pool is fixed by its tick index,
+    /// so it is validated against the expected price range
+    pub fn validate_tick_price(&self) -> Result<(), ContractError> {
+        self.expected_pool_ratio_range
+            .is_within_range(self.tick_range.get_price()?)
+    }
+
+    pub fn to_response_attributes(self) -> Vec<Attribute> {
+        vec![
+            Attribute::new("party_a_denom", self.asset_data.asset_a_denom),
+            Attribute::new("party_b_denom", self.asset_data.asset_b_denom),
+            Attribute::new("tick_index", self.tick_range.tick_index.to_string()),
+            Attribute::new("fee", self.tick_range.fee.to_string()),
+        ]
+    }
+}
+
+/// holds the both asset denoms relevant for providing liquidity
+#[cw_serde]
+pub struct AssetData {
+    pub asset_a_denom: String,
+    pub asset_b_denom: String,
+}
+
+#[clocked]
+#[covenant_lper_withdraw]
+#[cw_serde]
+pub enum ExecuteMsg {}
+
+impl From<LiquidPoolerExecuteMsg> for ExecuteMsg {
+    fn from(msg: LiquidPoolerExecuteMsg) -> Self {
+        match msg {
+            LiquidPoolerExecuteMsg::Tick {} => ExecuteMsg::Tick {},
+            LiquidPoolerExecuteMsg::Withdraw { percentage } => ExecuteMsg::Withdraw { percentage },
+        }
+    }
+}
+
+#[covenant_deposit_address]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ContractState)]
+    ContractState {},
+    #[returns(Addr)]
+    HolderAddress {},
+    #[returns(LpConfig)]
+    LpConfig {},
+    #[returns(ProvidedLiquidityInfo)]
+    ProvidedLiquidityInfo {},
+    #[returns(LiquidityPosition)]
+    LiquidityPosition {},
+    #[returns(covenant_utils::op_mode::ContractOperationMode)]
+    OperationMode {},
+}
+
+impl From<LiquidPoolerQueryMsg> for QueryMsg {
+    fn from(msg: LiquidPoolerQueryMsg) -> Self {
+        match msg {
+            LiquidPoolerQueryMsg::DepositAddress {} => QueryMsg::DepositAddress {},
+            LiquidPoolerQueryMsg::ProvidedLiquidityInfo {} => QueryMsg::ProvidedLiquidityInfo {},
+            LiquidPoolerQueryMsg::LiquidityPosition {} => QueryMsg::LiquidityPosition {},
+        }
+    }
+}
+
+#[cw_serde]
+pub enum MigrateMsg {
+    UpdateConfig {
+        op_mode: Option<ContractOperationModeConfig>,
+        holder_address: Option<String>,
+        lp_config: Option<Box<LpConfig>>,
+    },
+    UpdateCodeId {
+        data: Option<Binary>,
+    },
+}
+
+/// state of the LP state machine
+#[cw_serde]
+pub enum ContractState {
+    Instantiated,
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{Addr, Coin};
+use covenant_utils::op_mode::ContractOperationMode;
+use cw_storage_plus::Item;
+
+use crate::msg::{ContractState, LpConfig, ProvidedLiquidityInfo};
+
+/// contract state tracks the state machine progress
+pub const CONTRACT_STATE: Item<ContractState> = Item::new("contract_state");
+
+pub const CONTRACT_OP_MODE: Item<ContractOperationMode> = Item::new("contract_op_mode");
+
+/// holder module address to verify withdrawal requests
+pub const HOLDER_ADDRESS: Item<Addr> = Item::new("holder_address");
+
+/// keeps track of both token amounts we provided to the pool
+pub const PROVIDED_LIQUIDITY_INFO: Item<ProvidedLiquidityInfo> =
+    Item::new("provided_liquidity_info");
+
+/// configuration relevant to entering into an LP position
+pub const LP_CONFIG: Item<LpConfig> = Item::new("lp_config");
+
+/// asset balances prior to the pending dex interaction, used
+/// to determine the deposited or withdrawn amounts upon reply
+pub const PENDING_BALANCES: Item<(Coin, Coin)> = Item::new("pending_balances");
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary, Decimal, StdError, StdResult, Uint64};
+use covenant_utils::{
+    emergency_committee::EmergencyCommitteeConfig, op_mode::ContractOperationModeConfig,
+    split::SplitConfig, PoolPriceConfig,
+};
+use cw_utils::Expiration;
+use valence_covenant_two_party_pol::msg::{
+    CovenantPartyConfig, LiquidPoolerConfig, LiquidPoolerMigrateMsg, RouterMigrateMsg, Timeouts,
+};
+use valence_multi_party_pol_holder::msg::{MultiPartyPolCovenantParty, RagequitConfig};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub label: String,
+    pub timeouts: Timeouts,
+    pub contract_codes: CovenantContractCodeIds,
+    pub clock_tick_max_gas: Option<Uint64>,
+    pub lockup_config: Expiration,
+    /// covenant parties along with their allocations.
+    /// allocations must add up to 1.0.
+    pub parties: Vec<MultiPartyCovenantPartyConfig>,
+    pub ragequit_config: Option<RagequitConfig>,
+    pub deposit_deadline: Expiration,
+    pub pool_price_config: PoolPriceConfig,
+    /// split for any denoms not contributed by the parties,
+    /// keyed by the party final receiver addresses
+    pub fallback_split: Option<SplitConfig>,
+    pub emergency_committee: Option<EmergencyCommitteeConfig>,
+    pub liquid_pooler_config: LiquidPoolerConfig,
+    pub fallback_address: Option<String>,
+    pub operation_mode: ContractOperationModeConfig,
+}
+
+#[cw_serde]
+pub struct MultiPartyCovenantPartyConfig {
+    pub config: CovenantPartyConfig,
+    /// fraction of the LP position owned by the party
+    pub allocation: Decimal,
+}
+
+impl MultiPartyCovenantPartyConfig {
+    /// neutron address authorized by the party, used to identify it
+    pub fn get_host_address(&self) -> String {
+        self.config.to_covenant_party().addr
+    }
+
+    pub fn to_multi_party_pol_party(&self, router: String) -> MultiPartyPolCovenantParty {
+        let party = self.config.to_two_party_pol_party(self.allocation, router);
+        MultiPartyPolCovenantParty {
+            contribution: party.contribution,
+            host_addr: party.host_addr,
+            controller_addr: party.controller_addr,
+            allocation: party.allocation,
+            router: party.router,
+        }
+    }
+}
+
+/// remaps the split receivers from party final receiver
+/// addresses to the addresses of their routers
+pub fn remap_split_to_routers(
+    split: &SplitConfig,
+    parties: &[MultiPartyCovenantPartyConfig],
+    routers: &[Addr],
+) -> StdResult<SplitConfig> {
+    let mut receivers = BTreeMap::new();
+    for (receiver, share) in split.receivers.iter() {
+        let router = parties
+            .iter()
+            .zip(routers.iter())
+            .find(|(party, _)| &party.config.get_final_receiver_address() == receiver)
+            .map(|(_, router)| router)
+            .ok_or_else(|| StdError::not_found(format!("receiver {receiver:?} not found")))?;
+        receivers.insert(router.to_string(), *share);
+    }
+
+    Ok(SplitConfig { receivers })
+}
+
+#[cw_serde]
+pub struct CovenantContractCodeIds {
+    pub ibc_forwarder_code: u64,
+    pub holder_code: u64,
+    pub clock_code: u64,
+    pub interchain_router_code: u64,
+    pub native_router_code: u64,
+    pub liquid_pooler_code: u64,
+}
+
+impl CovenantContractCodeIds {
+    pub fn get_router_code_id(&self, party_config: &CovenantPartyConfig) -> u64 {
+        match party_config {
+            CovenantPartyConfig::Interchain(_) => self.interchain_router_code,
+            CovenantPartyConfig::Native(_) | CovenantPartyConfig::NativeBasket(_) => {
+                self.native_router_code
+            }
+        }
+    }
+
+    pub(crate) fn to_covenant_codes_config(&self) -> CovenantContractCodes {
+        CovenantContractCodes {
+            clock: self.clock_code,
+            holder: self.holder_code,
+            liquid_pooler: self.liquid_pooler_code,
+            native_router: self.native_router_code,
+            interchain_router: self.interchain_router_code,
+            ibc_forwarder: self.ibc_forwarder_code,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct CovenantContractCodes {
+    pub clock: u64,
+    pub holder: u64,
+    pub liquid_pooler: u64,
+    pub native_router: u64,
+    pub interchain_router: u64,
+    pub ibc_forwarder: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Addr)]
+    ClockAddress {},
+    #[returns(Addr)]
+    HolderAddress {},
+    /// `party` is the neutron address of the party
+    #[returns(Addr)]
+    IbcForwarderAddress { party: String },
+    /// `party` is the neutron address of the party
+    #[returns(Addr)]
+    InterchainRouterAddress { party: String },
+    #[returns(Addr)]
+    LiquidPoolerAddress {},
+    /// `party` is the neutron address of the party
+    #[returns(Addr)]
+    PartyDepositAddress { party: String },
+    #[returns(CovenantContractCodes)]
+    ContractCodes {},
+}
+
+#[allow(clippy::large_enum_variant)]
+#[cw_serde]
+pub enum MigrateMsg {
+    UpdateCovenant {
+        codes: Option<CovenantContractCodes>,
+        clock: Option<valence_clock::msg::MigrateMsg>,
+        holder: Option<valence_multi_party_pol_holder::msg::MigrateMsg>,
+        liquid_pooler: Option<LiquidPoolerMigrateMsg>,
+        /// router migrations keyed by the party neutron address
+        party_routers: BTreeMap<String, RouterMigrateMsg>,
+        /// ibc forwarder migrations keyed by the party neutron address
+        party_forwarders: BTreeMap<String, valence_ibc_forwarder::msg::MigrateMsg>,
+    },
+    UpdateCodeId {
+        data: Option<Binary>,
+    },
+}
@@ -0,0 +1,14 @@
+use crate::msg::CovenantContractCodes;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+
+pub const COVENANT_CLOCK_ADDR: Item<Addr> = Item::new("covenant_clock_addr");
+pub const COVENANT_POL_HOLDER_ADDR: Item<Addr> = Item::new("covenant_multi_party_pol_holder_addr");
+pub const LIQUID_POOLER_ADDR: Item<Addr> = Item::new("liquid_pooler_addr");
+
+/// maps party host addresses to their routers
+pub const PARTY_ROUTER_ADDRS: Map<String, Addr> = Map::new("party_router_addrs");
+/// maps interchain party host addresses to their ibc forwarders
+pub const PARTY_IBC_FORWARDER_ADDRS: Map<String, Addr> = Map::new("party_ibc_forwarder_addrs");
+
+pub(crate) const CONTRACT_CODES: Item<CovenantContractCodes> = Item::new("contract_codes");
@@ -0,0 +1,932 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{
+    ensure, to_json_binary, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut,
+    Env, Fraction, MessageInfo, Order, Reply, Response, StdError, StdResult, Uint128,
+};
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+
+use covenant_utils::emergency_committee::{
+    approve_emergency_action, propose_emergency_action, query_pending_emergency_actions,
+    save_emergency_committee, take_executable_emergency_action, EmergencyAction,
+    EMERGENCY_COMMITTEE,
+};
+use covenant_utils::history::{query_history, record_history, HistoryAction};
+use covenant_utils::op_mode::{verify_caller, ContractOperationMode};
+use covenant_utils::split::SplitConfig;
+use cw2::set_contract_version;
+use liquid_pooler_interface::LiquidPoolerContract;
+
+use crate::state::{
+    WithdrawState, CONTRACT_OP_MODE, FALLBACK_SPLIT, LIQUID_POOLER_ADDRESS, PARTY_DEPOSITS,
+    WITHDRAW_STATE,
+};
+use crate::{
+    error::ContractError,
+    msg::{
+        ContractState, DepositsResponse, ExecuteMsg, InstantiateMsg, MigrateMsg,
+        MultiPartyPolCovenantConfig, QueryMsg, RagequitConfig,
+    },
+    state::{CONTRACT_STATE, COVENANT_CONFIG, DEPOSIT_DEADLINE, LOCKUP_CONFIG, RAGEQUIT_CONFIG},
+};
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let next_contract = deps.api.addr_validate(&msg.next_contract)?;
+    let op_mode = ContractOperationMode::try_init(deps.api, msg.op_mode_cfg.clone())?;
+
+    // ensure that the deposit deadline is in the future
+    ensure!(
+        !msg.deposit_deadline.is_expired(&env.block),
+        ContractError::DepositDeadlineValidationError {}
+    );
+
+    // validate that lockup expiration is after the deposit deadline
+    match msg.deposit_deadline.partial_cmp(&msg.lockup_config) {
+        Some(ordering) => ensure!(
+            ordering == Ordering::Less,
+            ContractError::LockupValidationError {}
+        ),
+        // we validate incompatible expirations
+        None => return Err(ContractError::ExpirationValidationError {}),
+    };
+
+    let mut resp =
+        Response::default().add_attribute("method", "multi_party_pol_holder_instantiate");
+
+    if let Some(committee_config) = &msg.emergency_committee {
+        let committee = committee_config.validate(deps.api)?;
+        save_emergency_committee(deps.storage, &committee)?;
+        resp = resp.add_attributes(committee.get_response_attributes());
+    }
+
+    msg.covenant_config.validate(deps.api)?;
+    msg.ragequit_config.validate(&msg.covenant_config.parties)?;
+
+    if let Some(split) = &msg.fallback_split {
+        validate_fallback_split(deps.api, split, &msg.covenant_config)?;
+        FALLBACK_SPLIT.save(deps.storage, split)?;
+    }
+
+    LIQUID_POOLER_ADDRESS.save(deps.storage, &next_contract)?;
+    CONTRACT_OP_MODE.save(deps.storage, &op_mode)?;
+    LOCKUP_CONFIG.save(deps.storage, &msg.lockup_config)?;
+    RAGEQUIT_CONFIG.save(deps.storage, &msg.ragequit_config)?;
+    CONTRACT_STATE.save(deps.storage, &ContractState::Instantiated)?;
+    COVENANT_CONFIG.save(deps.storage, &msg.covenant_config)?;
+    DEPOSIT_DEADLINE.save(deps.storage, &msg.deposit_deadline)?;
+
+    Ok(resp.add_attributes(msg.get_response_attributes()))
+}
+
+/// fallback split shares must add up to 1.0 and only route to party routers
+fn validate_fallback_split(
+    api: &dyn Api,
+    split: &SplitConfig,
+    covenant_config: &MultiPartyPolCovenantConfig,
+) -> Result<(), ContractError> {
+    split.validate_shares_and_receiver_addresses(api)?;
+    ensure!(
+        split.receivers.keys().all(|receiver| covenant_config
+            .parties
+            .iter()
+            .any(|party| &party.router == receiver)),
+        ContractError::FallbackSplitReceiverError {}
+    );
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match (CONTRACT_STATE.load(deps.storage)?, msg) {
+        // incoming ticks when in instantiated state try to deposit the funds
+        (ContractState::Instantiated, ExecuteMsg::Tick {}) => try_deposit(deps, env, info),
+        // incoming ticks when in active state are used to check for expiration
+        (ContractState::Active, ExecuteMsg::Tick {}) => check_expiration(deps, env, info),
+        // incoming ticks when in expired state are no-ops
+        (ContractState::Expired, ExecuteMsg::Tick {}) => Ok(Response::default()
+            .add_attribute("method", "tick")
+            .add_attribute("contract_state", "expired")),
+        // incoming ticks when in completed state are used to refund the parties
+        (ContractState::Complete, ExecuteMsg::Tick {}) => try_refund(deps, env, info),
+        // party deposits are only recorded before the funds are forwarded
+        (ContractState::Instantiated, ExecuteMsg::Deposit {}) => try_party_deposit(deps, env, info),
+        (_, ExecuteMsg::Deposit {}) => Err(ContractError::DepositStateError {}),
+        // ragequit is only possible from active state, validated in the handler
+        (current_state, ExecuteMsg::Ragequit {}) => try_ragequit(deps, env, info, current_state),
+        // emergency actions are state-independent
+        (_, ExecuteMsg::ProposeEmergencyAction { action }) => {
+            try_propose_emergency_action(deps, env, info, action)
+        }
+        (_, ExecuteMsg::ApproveEmergencyAction { id }) => {
+            try_approve_emergency_action(deps, info, id)
+        }
+        (_, ExecuteMsg::ExecuteEmergencyAction { id }) => {
+            try_execute_emergency_action(deps, env, info, id)
+        }
+        // claims can only be performed from expired state
+        (ContractState::Expired, ExecuteMsg::Claim {}) => try_claim(deps, env, info),
+        (_, ExecuteMsg::Claim {}) => Err(ContractError::ClaimError {}),
+        // receiving distribute callback is state-independent
+        (_, ExecuteMsg::Distribute {}) => try_distribute(deps, env, info),
+        // receiving withdraw failed callback is state-independent
+        (_, ExecuteMsg::WithdrawFailed {}) => try_withdraw_failed(deps, info),
+        // distributing fallback splits is state-independent
+        (_, ExecuteMsg::DistributeFallbackSplit { denoms }) => {
+            try_distribute_fallback_split(deps, env, denoms)
+        }
+    }
+}
+
+fn try_distribute_fallback_split(
+    deps: DepsMut,
+    env: Env,
+    denoms: Vec<String>,
+) -> Result<Response, ContractError> {
+    let contribution_denoms = COVENANT_CONFIG
+        .load(deps.storage)?
+        .get_contribution_denoms();
+    let contract_addr = env.contract.address.to_string();
+
+    let mut available_balances = Vec::with_capacity(denoms.len());
+    for denom in denoms {
+        // contribution denoms are distributed via claim/ragequit
+        ensure!(
+            !contribution_denoms.contains(&denom),
+            ContractError::UnauthorizedDenomDistribution {}
+        );
+        let queried_coin = deps.querier.query_balance(&contract_addr, denom)?;
+        if !queried_coin.amount.is_zero() {
+            available_balances.push(queried_coin);
+        }
+    }
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if let Some(fallback_split) = FALLBACK_SPLIT.may_load(deps.storage)? {
+        for coin in available_balances.iter() {
+            messages.extend(fallback_split.get_transfer_messages(
+                coin.amount,
+                coin.denom.to_string(),
+                None,
+            )?);
+        }
+    }
+
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Distribution,
+        None,
+        available_balances,
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_distribute_fallback_split")
+        .add_messages(messages))
+}
+
+/// On claim, we ask the LPer to withdraw the claiming party allocation
+/// and execute a Distribute msg on the holder
+fn try_claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    ensure!(
+        !WITHDRAW_STATE.exists(deps.storage),
+        ContractError::WithdrawAlreadyStarted {}
+    );
+
+    let covenant_config = COVENANT_CONFIG.load(deps.storage)?;
+    let claim_party = covenant_config.authorize_sender(info.sender.as_str())?;
+
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Claim,
+        Some(claim_party.host_addr.to_string()),
+        vec![],
+    )?;
+
+    WITHDRAW_STATE.save(
+        deps.storage,
+        &WithdrawState::Processing {
+            claimer_addr: claim_party.host_addr,
+        },
+    )?;
+
+    let lper = LIQUID_POOLER_ADDRESS.load(deps.storage)?;
+    let withdraw_msg = LiquidPoolerContract(lper).withdraw_msg(Some(claim_party.allocation))?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_claim")
+        .add_message(withdraw_msg))
+}
+
+fn try_propose_emergency_action(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: EmergencyAction,
+) -> Result<Response, ContractError> {
+    let pending_action =
+        propose_emergency_action(deps.storage, deps.api, &env.block, info.sender, action)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_propose_emergency_action")
+        .add_attribute("action_id", pending_action.id.to_string())
+        .add_attribute("action", format!("{:?}", pending_action.action))
+        .add_attribute(
+            "executable_after",
+            pending_action.executable_after.to_string(),
+        ))
+}
+
+fn try_approve_emergency_action(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let pending_action = approve_emergency_action(deps.storage, info.sender, id)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_approve_emergency_action")
+        .add_attribute("action_id", id.to_string())
+        .add_attribute("approvals", pending_action.approvals.len().to_string()))
+}
+
+/// Executing an emergency action withdraws all of the liquidity.
+/// Funds are then handled on the distribute callback according to the action.
+fn try_execute_emergency_action(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    ensure!(
+        !WITHDRAW_STATE.exists(deps.storage),
+        ContractError::WithdrawAlreadyStarted {}
+    );
+
+    let receiver =
+        match take_executable_emergency_action(deps.storage, &env.block, info.sender, id)? {
+            EmergencyAction::WithdrawToParties {} => None,
+            EmergencyAction::WithdrawTo { receiver } => Some(deps.api.addr_validate(&receiver)?),
+        };
+
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::EmergencyWithdrawal,
+        None,
+        vec![],
+    )?;
+    WITHDRAW_STATE.save(deps.storage, &WithdrawState::Emergency { receiver })?;
+
+    let lper = LIQUID_POOLER_ADDRESS.load(deps.storage)?;
+    let withdraw_msg = LiquidPoolerContract(lper).withdraw_msg(None)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_execute_emergency_action")
+        .add_attribute("action_id", id.to_string())
+        .add_message(withdraw_msg))
+}
+
+fn try_distribute(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    // Only pooler can call this
+    ensure!(
+        info.sender == LIQUID_POOLER_ADDRESS.load(deps.storage)?,
+        ContractError::Unauthorized {}
+    );
+
+    let withdraw_state = WITHDRAW_STATE
+        .load(deps.storage)
+        .map_err(|_| ContractError::WithdrawStateNotStarted {})?;
+
+    let distribution_party = match &withdraw_state {
+        WithdrawState::Processing { claimer_addr }
+        | WithdrawState::ProcessingRagequit { claimer_addr, .. } => Some(claimer_addr.to_string()),
+        WithdrawState::Emergency { .. } => None,
+    };
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Distribution,
+        distribution_party,
+        info.funds.clone(),
+    )?;
+
+    WITHDRAW_STATE.remove(deps.storage);
+    let covenant_config = COVENANT_CONFIG.load(deps.storage)?;
+
+    match withdraw_state {
+        // ragequit penalty was already accounted for in the withdrawn
+        // percentage, so both exits are distributed the same way
+        WithdrawState::Processing { claimer_addr }
+        | WithdrawState::ProcessingRagequit { claimer_addr, .. } => {
+            try_distribute_to_party(deps, claimer_addr, info.funds, covenant_config)
+        }
+        WithdrawState::Emergency {
+            receiver: Some(receiver),
+        } => try_emergency_withdraw_to(deps, receiver, info.funds, covenant_config),
+        WithdrawState::Emergency { receiver: None } => {
+            try_distribute_pro_rata(deps, info.funds, covenant_config)
+        }
+    }
+}
+
+/// Sends the withdrawn funds to the exiting party router and rescales
+/// the allocations of the remaining parties.
+fn try_distribute_to_party(
+    deps: DepsMut,
+    claimer_addr: String,
+    funds: Vec<Coin>,
+    mut covenant_config: MultiPartyPolCovenantConfig,
+) -> Result<Response, ContractError> {
+    let party = covenant_config.get_party(&claimer_addr)?.clone();
+    covenant_config.exit_party(&claimer_addr)?;
+    COVENANT_CONFIG.save(deps.storage, &covenant_config)?;
+
+    let mut resp = Response::default()
+        .add_attribute("method", "distribute_to_party")
+        .add_attribute("party", party.host_addr);
+    if !funds.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: party.router,
+            amount: funds,
+        });
+    }
+
+    // once every party exited, the covenant is complete
+    if covenant_config.get_active_parties().is_empty() {
+        let dequeue_messages = ContractState::complete_and_get_dequeue_msgs(deps)?;
+        resp = resp
+            .add_attribute("contract_state", "complete")
+            .add_submessages(dequeue_messages);
+    }
+
+    Ok(resp)
+}
+
+/// Sends all of the withdrawn funds to the receiver approved by the
+/// emergency committee and completes the covenant.
+fn try_emergency_withdraw_to(
+    deps: DepsMut,
+    receiver: Addr,
+    funds: Vec<Coin>,
+    mut covenant_config: MultiPartyPolCovenantConfig,
+) -> Result<Response, ContractError> {
+    for party in covenant_config.parties.iter_mut() {
+        party.allocation = Decimal::zero();
+    }
+    COVENANT_CONFIG.save(deps.storage, &covenant_config)?;
+
+    let dequeue_messages = ContractState::complete_and_get_dequeue_msgs(deps)?;
+
+    let mut resp = Response::default()
+        .add_attribute("method", "emergency_withdraw_to")
+        .add_attribute("receiver", receiver.to_string())
+        .add_submessages(dequeue_messages);
+    if !funds.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: receiver.to_string(),
+            amount: funds,
+        });
+    }
+    Ok(resp)
+}
+
+/// Splits the withdrawn funds between the party routers according to
+/// their allocations and completes the covenant.
+fn try_distribute_pro_rata(
+    deps: DepsMut,
+    funds: Vec<Coin>,
+    mut covenant_config: MultiPartyPolCovenantConfig,
+) -> Result<Response, ContractError> {
+    let active_parties = covenant_config.get_active_parties();
+    let mut party_coins: Vec<Vec<Coin>> = vec![vec![]; active_parties.len()];
+
+    for coin in funds {
+        let mut distributed = Uint128::zero();
+        for (i, party) in active_parties.iter().enumerate() {
+            // last party receives the remainder to avoid leaving dust behind
+            let amount = if i == active_parties.len() - 1 {
+                coin.amount - distributed
+            } else {
+                coin.amount
+                    .checked_multiply_ratio(
+                        party.allocation.numerator(),
+                        party.allocation.denominator(),
+                    )
+                    .map_err(|e| StdError::generic_err(e.to_string()))?
+            };
+            distributed += amount;
+            if !amount.is_zero() {
+                party_coins[i].push(Coin {
+                    denom: coin.denom.to_string(),
+                    amount,
+                });
+            }
+        }
+    }
+
+    let messages: Vec<CosmosMsg> = active_parties
+        .iter()
+        .zip(party_coins)
+        .filter(|(_, coins)| !coins.is_empty())
+        .map(|(party, coins)| {
+            BankMsg::Send {
+                to_address: party.router.to_string(),
+                amount: coins,
+            }
+            .into()
+        })
+        .collect();
+
+    for party in covenant_config.parties.iter_mut() {
+        party.allocation = Decimal::zero();
+    }
+    COVENANT_CONFIG.save(deps.storage, &covenant_config)?;
+
+    let dequeue_messages = ContractState::complete_and_get_dequeue_msgs(deps)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "distribute_pro_rata")
+        .add_messages(messages)
+        .add_submessages(dequeue_messages))
+}
+
+/// We don't do much on failed withdraw, as nothing changed so far.
+/// We only change state on distribute msg.
+fn try_withdraw_failed(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    // Assert the caller is the pooler
+    let pooler_addr = LIQUID_POOLER_ADDRESS.load(deps.storage)?;
+    ensure!(info.sender == pooler_addr, ContractError::Unauthorized {});
+
+    WITHDRAW_STATE.remove(deps.storage);
+
+    Ok(Response::default())
+}
+
+/// attempts to route any available covenant party contribution denoms to
+/// the parties that were responsible for contributing that denom.
+fn try_refund(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    // Verify caller is an authorized address
+    verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+
+    let config = COVENANT_CONFIG.load(deps.storage)?;
+    let balances = query_contribution_balances(deps.as_ref(), &env, &config)?;
+    let deposits = query_deposits(deps.as_ref(), &config, &balances)?;
+    // shared denoms that reached the holder without a party deposit
+    // (e.g. ibc forwarder transfers) are split between their contributors
+    let unattributed = config.split_unattributed_balances(&balances, &deposits.parties);
+
+    // refunded deposits are no longer held on behalf of the parties
+    PARTY_DEPOSITS.clear(deps.storage);
+
+    let mut refund_messages: Vec<CosmosMsg> = vec![];
+    for ((deposit, unattributed), party) in deposits
+        .parties
+        .into_iter()
+        .zip(unattributed.into_iter())
+        .zip(config.parties.iter())
+    {
+        let mut refund_coins: Vec<Coin> = deposit
+            .deposited
+            .into_iter()
+            .filter(|c| !c.amount.is_zero())
+            .collect();
+        for coin in unattributed {
+            match refund_coins.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(refund_coin) => refund_coin.amount += coin.amount,
+                None => refund_coins.push(coin),
+            }
+        }
+
+        if !refund_coins.is_empty() {
+            record_history(
+                deps.storage,
+                &env.block,
+                HistoryAction::Refund,
+                Some(party.host_addr.to_string()),
+                refund_coins.clone(),
+            )?;
+            refund_messages.push(
+                BankMsg::Send {
+                    to_address: party.router.to_string(),
+                    amount: refund_coins,
+                }
+                .into(),
+            );
+        }
+    }
+
+    Ok(Response::default()
+        .add_attribute("contract_state", "complete")
+        .add_attribute("method", "try_refund")
+        .add_messages(refund_messages))
+}
+
+/// records the attached funds as the contribution of the sender party
+/// so that denoms shared between parties can be attributed to the
+/// party that actually deposited them
+fn try_party_deposit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure!(
+        !DEPOSIT_DEADLINE.load(deps.storage)?.is_expired(&env.block),
+        ContractError::DepositStateError {}
+    );
+    ensure!(!info.funds.is_empty(), ContractError::EmptyDeposit {});
+
+    let config = COVENANT_CONFIG.load(deps.storage)?;
+    let party = config
+        .get_party(info.sender.as_str())
+        .map_err(|_| ContractError::Unauthorized {})?;
+
+    for coin in info.funds.iter() {
+        ensure!(
+            party.contribution.iter().any(|c| c.denom == coin.denom),
+            ContractError::DepositDenomError(coin.denom.to_string())
+        );
+        PARTY_DEPOSITS.update(
+            deps.storage,
+            (party.host_addr.as_str(), coin.denom.as_str()),
+            |deposited| -> StdResult<_> {
+                Ok(deposited.unwrap_or_default().checked_add(coin.amount)?)
+            },
+        )?;
+    }
+
+    let deposited_str = info
+        .funds
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    Ok(Response::default()
+        .add_attribute("method", "try_party_deposit")
+        .add_attribute("party", party.host_addr.to_string())
+        .add_attribute("deposited", deposited_str))
+}
+
+fn try_deposit(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    // Verify caller is an authorized address
+    verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+
+    let deposit_deadline = DEPOSIT_DEADLINE.load(deps.storage)?;
+    if deposit_deadline.is_expired(&env.block) {
+        CONTRACT_STATE.save(deps.storage, &ContractState::Complete)?;
+        return Ok(Response::default()
+            .add_attribute("method", "try_deposit")
+            .add_attribute("deposit_deadline", "expired")
+            .add_attribute("action", "complete"));
+    }
+
+    let config = COVENANT_CONFIG.load(deps.storage)?;
+    let balances = query_contribution_balances(deps.as_ref(), &env, &config)?;
+    let deposits = query_deposits(deps.as_ref(), &config, &balances)?;
+
+    // if any party did not fulfill their deposit, we error out
+    ensure!(
+        deposits.parties.iter().all(|d| d.is_fulfilled()),
+        ContractError::InsufficientDeposits {}
+    );
+
+    // anything deposited on top of the agreed contribution is
+    // refunded to the party router before we forward the funds
+    let mut resp = Response::default().add_attribute("method", "deposit_to_next_contract");
+    for (deposit, party) in deposits.parties.iter().zip(config.parties.iter()) {
+        let excess = deposit.get_excess();
+        if !excess.is_empty() {
+            record_history(
+                deps.storage,
+                &env.block,
+                HistoryAction::Refund,
+                Some(party.host_addr.to_string()),
+                excess.clone(),
+            )?;
+            let excess_str = excess
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            resp = resp
+                .add_attribute("excess_refund", format!("{excess_str} -> {}", party.router))
+                .add_message(BankMsg::Send {
+                    to_address: party.router.to_string(),
+                    amount: excess,
+                });
+        }
+    }
+
+    // contributions of the same denom are merged into a single coin
+    let mut forwarded_amounts: BTreeMap<String, Uint128> = BTreeMap::new();
+    for party in config.parties.iter() {
+        record_history(
+            deps.storage,
+            &env.block,
+            HistoryAction::Deposit,
+            Some(party.host_addr.to_string()),
+            party.contribution.clone(),
+        )?;
+        for coin in party.contribution.iter() {
+            *forwarded_amounts.entry(coin.denom.to_string()).or_default() += coin.amount;
+        }
+    }
+
+    // LiquidPooler is the next contract
+    let liquid_pooler = LIQUID_POOLER_ADDRESS.load(deps.storage)?;
+    let msg = BankMsg::Send {
+        to_address: liquid_pooler.to_string(),
+        amount: forwarded_amounts
+            .into_iter()
+            .map(|(denom, amount)| Coin { denom, amount })
+            .collect(),
+    };
+
+    // the deposit ledger is settled once the funds are forwarded
+    PARTY_DEPOSITS.clear(deps.storage);
+
+    // advance the state to Active
+    CONTRACT_STATE.save(deps.storage, &ContractState::Active)?;
+
+    Ok(resp.add_message(msg))
+}
+
+/// queries the holder balances of all contribution denoms
+fn query_contribution_balances(
+    deps: Deps,
+    env: &Env,
+    config: &MultiPartyPolCovenantConfig,
+) -> StdResult<Vec<Coin>> {
+    let contract_addr = env.contract.address.to_string();
+    config
+        .get_contribution_denoms()
+        .into_iter()
+        .map(|denom| deps.querier.query_balance(&contract_addr, denom))
+        .collect()
+}
+
+/// attributes the holder balances of contribution denoms
+/// to the parties based on the deposit ledger
+fn query_deposits(
+    deps: Deps,
+    config: &MultiPartyPolCovenantConfig,
+    balances: &[Coin],
+) -> StdResult<DepositsResponse> {
+    let ledger = PARTY_DEPOSITS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<BTreeMap<(String, String), Uint128>>>()?;
+
+    Ok(DepositsResponse {
+        parties: config.attribute_balances(balances, &ledger),
+    })
+}
+
+fn check_expiration(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    // Verify caller is an authorized address
+    verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+
+    let lockup_config = LOCKUP_CONFIG.load(deps.storage)?;
+
+    if !lockup_config.is_expired(&env.block) {
+        return Ok(Response::default()
+            .add_attribute("method", "check_expiration")
+            .add_attribute("result", "not_due"));
+    }
+
+    // advance state to Expired to enable claims
+    CONTRACT_STATE.save(deps.storage, &ContractState::Expired)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "check_expiration")
+        .add_attribute("contract_state", "expired"))
+}
+
+fn try_ragequit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    current_state: ContractState,
+) -> Result<Response, ContractError> {
+    let lockup_config = LOCKUP_CONFIG.load(deps.storage)?;
+    let covenant_config = COVENANT_CONFIG.load(deps.storage)?;
+    let lper = LIQUID_POOLER_ADDRESS.load(deps.storage)?;
+
+    // first we error out if ragequit is disabled
+    let rq_terms = match RAGEQUIT_CONFIG.load(deps.storage)? {
+        RagequitConfig::Disabled => return Err(ContractError::RagequitDisabled {}),
+        RagequitConfig::Enabled(terms) => terms,
+    };
+
+    // ragequit is only possible when contract is in Active state.
+    ensure!(
+        current_state == ContractState::Active,
+        ContractError::NotActive {}
+    );
+    // ensure no withdrawal is in progress
+    ensure!(
+        !WITHDRAW_STATE.exists(deps.storage),
+        ContractError::WithdrawAlreadyStarted {}
+    );
+
+    // we also validate an edge case where it did expire but
+    // did not receive a tick yet. tick is then required to advance.
+    if lockup_config.is_expired(&env.block) {
+        return Err(ContractError::Expired {});
+    }
+
+    // authorize the message sender
+    let rq_party = covenant_config.authorize_sender(info.sender.as_str())?;
+
+    // penalty is redistributed among the remaining parties,
+    // so there must be at least one of them
+    ensure!(
+        covenant_config.get_active_parties().len() > 1,
+        ContractError::RagequitLastParty {}
+    );
+
+    // penalty is left in the position for the remaining parties
+    let withdraw_percentage = rq_party
+        .allocation
+        .checked_sub(rq_terms.penalty)
+        .map_err(StdError::overflow)?;
+
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Ragequit,
+        Some(rq_party.host_addr.to_string()),
+        vec![],
+    )?;
+
+    WITHDRAW_STATE.save(
+        deps.storage,
+        &WithdrawState::ProcessingRagequit {
+            claimer_addr: rq_party.host_addr,
+            terms: rq_terms,
+        },
+    )?;
+
+    let withdraw_msg = LiquidPoolerContract(lper).withdraw_msg(Some(withdraw_percentage))?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_ragequit")
+        .add_message(withdraw_msg))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::ContractState {} => Ok(to_json_binary(&CONTRACT_STATE.load(deps.storage)?)?),
+        QueryMsg::RagequitConfig {} => Ok(to_json_binary(&RAGEQUIT_CONFIG.load(deps.storage)?)?),
+        QueryMsg::LockupConfig {} => Ok(to_json_binary(&LOCKUP_CONFIG.load(deps.storage)?)?),
+        QueryMsg::OperationMode {} => Ok(to_json_binary(&CONTRACT_OP_MODE.load(deps.storage)?)?),
+        QueryMsg::NextContract {} => {
+            Ok(to_json_binary(&LIQUID_POOLER_ADDRESS.load(deps.storage)?)?)
+        }
+        QueryMsg::DepositDeadline {} => Ok(to_json_binary(&DEPOSIT_DEADLINE.load(deps.storage)?)?),
+        QueryMsg::Config {} => Ok(to_json_binary(&COVENANT_CONFIG.load(deps.storage)?)?),
+        QueryMsg::Party { host_addr } => {
+            let config = COVENANT_CONFIG.load(deps.storage)?;
+            let party = config
+                .get_party(&host_addr)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+            Ok(to_json_binary(party)?)
+        }
+        QueryMsg::DepositAddress {} => Ok(to_json_binary(&env.contract.address)?),
+        QueryMsg::FallbackSplit {} => Ok(to_json_binary(&FALLBACK_SPLIT.may_load(deps.storage)?)?),
+        QueryMsg::EmergencyCommittee {} => Ok(to_json_binary(
+            &EMERGENCY_COMMITTEE.may_load(deps.storage)?,
+        )?),
+        QueryMsg::PendingEmergencyActions {} => Ok(to_json_binary(
+            &query_pending_emergency_actions(deps.storage)?,
+        )?),
+        QueryMsg::History { start_after, limit } => Ok(to_json_binary(&query_history(
+            deps.storage,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::Deposits {} => {
+            ensure!(
+                CONTRACT_STATE.load(deps.storage)? == ContractState::Instantiated,
+                StdError::generic_err("deposits are only tracked in instantiated state")
+            );
+            let config = COVENANT_CONFIG.load(deps.storage)?;
+            let balances = query_contribution_balances(deps, &env, &config)?;
+            Ok(to_json_binary(&query_deposits(deps, &config, &balances)?)?)
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    match msg {
+        MigrateMsg::UpdateConfig {
+            op_mode,
+            next_contract,
+            emergency_committee,
+            lockup_config,
+            deposit_deadline,
+            ragequit_config,
+            covenant_config,
+            fallback_split,
+        } => {
+            let mut resp = Response::default().add_attribute("method", "update_config");
+
+            if let Some(op_mode_cfg) = op_mode {
+                let updated_op_mode = ContractOperationMode::try_init(deps.api, op_mode_cfg)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+                CONTRACT_OP_MODE.save(deps.storage, &updated_op_mode)?;
+                resp = resp.add_attribute("op_mode", format!("{:?}", updated_op_mode));
+            }
+
+            if let Some(addr) = next_contract {
+                let next_contract_addr = deps.api.addr_validate(&addr)?;
+                LIQUID_POOLER_ADDRESS.save(deps.storage, &next_contract_addr)?;
+                resp = resp.add_attribute("next_contract", addr);
+            }
+
+            if let Some(expiry_config) = lockup_config {
+                if expiry_config.is_expired(&env.block) {
+                    return Err(StdError::generic_err("lockup config is already past"));
+                }
+                LOCKUP_CONFIG.save(deps.storage, &expiry_config)?;
+                resp = resp.add_attribute("lockup_config", expiry_config.to_string());
+            }
+
+            if let Some(expiry_config) = deposit_deadline {
+                if expiry_config.is_expired(&env.block) {
+                    return Err(StdError::generic_err("deposit deadline is already past"));
+                }
+                DEPOSIT_DEADLINE.save(deps.storage, &expiry_config)?;
+                resp = resp.add_attribute("deposit_deadline", expiry_config.to_string());
+            }
+
+            if let Some(committee_config) = emergency_committee {
+                let committee = committee_config.validate(deps.api)?;
+                save_emergency_committee(deps.storage, &committee)?;
+                resp = resp.add_attributes(committee.get_response_attributes());
+            }
+
+            if let Some(config) = *ragequit_config {
+                RAGEQUIT_CONFIG.save(deps.storage, &config)?;
+                resp = resp.add_attributes(config.get_response_attributes());
+            }
+
+            if let Some(config) = *covenant_config {
+                config
+                    .validate(deps.api)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?;
+                COVENANT_CONFIG.save(deps.storage, &config)?;
+                resp = resp.add_attribute("covenant_config", format!("{:?}", config));
+            }
+
+            if let Some(split) = fallback_split {
+                let config = COVENANT_CONFIG.load(deps.storage)?;
+                validate_fallback_split(deps.api, &split, &config)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?;
+                resp = resp.add_attribute("fallback_split", format!("{:?}", split));
+                FALLBACK_SPLIT.save(deps.storage, &split)?;
+            }
+
+            Ok(resp)
+        }
+        MigrateMsg::UpdateCodeId { data: _ } => {
+            // This is a migrate message to update code id,
+            // Data is optional base64 that we can parse to any data we would like in the future
+            // let data: SomeStruct = from_binary(&data)?;
+            Ok(Response::default())
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    // if we get a reply with id u64::MAX, we can assume it is a dequeue message
+    if msg.id == u64::MAX {
+        // Do nothing, whether it fails or not (dequeue messages are "fire & forget" style messages)
+        Ok(Response::default())
+    } else {
+        Err(ContractError::UnexpectedReplyId {})
+    }
+}
@@ -0,0 +1,101 @@
+use cosmwasm_std::StdError;
+use covenant_utils::{
+    emergency_committee::EmergencyCommitteeError, op_mode::ContractOperationError,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    ContractOperationError(#[from] ContractOperationError),
+
+    #[error(transparent)]
+    EmergencyCommitteeError(#[from] EmergencyCommitteeError),
+
+    #[error("covenant must have at least two parties")]
+    PartyCountError {},
+
+    #[error("party {0} is provided more than once")]
+    DuplicateParty(String),
+
+    #[error("party allocations must be positive and add up to 1.0")]
+    AllocationValidationError {},
+
+    #[error("Ragequit penalty must be in range of [0.0, 1.0)")]
+    RagequitPenaltyRangeError {},
+
+    #[error("Ragequit penalty exceeds party allocation")]
+    RagequitPenaltyExceedsPartyAllocationError {},
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("contract needs to be in expired state in order to claim")]
+    ClaimError {},
+
+    #[error("covenant is not in active state")]
+    NotActive {},
+
+    #[error("unexpected reply id")]
+    UnexpectedReplyId {},
+
+    #[error("covenant is active but expired; tick to proceed")]
+    Expired {},
+
+    #[error("not all parties have deposited")]
+    InsufficientDeposits {},
+
+    #[error("lockup deadline must be after the deposit deadline")]
+    LockupValidationError {},
+
+    #[error("cannot validate deposit and lockup expirations")]
+    ExpirationValidationError {},
+
+    #[error("deposit deadline is already past")]
+    DepositDeadlineValidationError {},
+
+    #[error("deposits are only accepted before the covenant becomes active")]
+    DepositStateError {},
+
+    #[error("no funds attached to the deposit")]
+    EmptyDeposit {},
+
+    #[error("denom {0} is not part of the party contribution")]
+    DepositDenomError(String),
+
+    #[error("unknown party")]
+    PartyNotFound {},
+
+    #[error("ragequit is disabled")]
+    RagequitDisabled {},
+
+    #[error("ragequit attempt with lockup period passed")]
+    RagequitWithLockupPassed {},
+
+    #[error("last party with an active position cannot ragequit")]
+    RagequitLastParty {},
+
+    #[error("unauthorized to distribute explicitly defined denom")]
+    UnauthorizedDenomDistribution {},
+
+    #[error("fallback split receivers must be party routers")]
+    FallbackSplitReceiverError {},
+
+    #[error("A withdraw process already started")]
+    WithdrawAlreadyStarted {},
+
+    #[error("A withdraw process wasn't started yet")]
+    WithdrawStateNotStarted {},
+
+    #[error("Claimer already claimed his share")]
+    PartyAllocationIsZero {},
+
+    #[error("Party contribution cannot be zero")]
+    PartyContributionConfigError {},
+
+    #[error("Contribution denom {0} is provided more than once")]
+    DuplicateContributionDenom(String),
+}
@@ -0,0 +1,6 @@
+extern crate core;
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
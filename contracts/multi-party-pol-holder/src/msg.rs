@@ -0,0 +1,611 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    ensure, to_json_binary, Api, Attribute, Binary, Coin, Decimal, DepsMut, StdError, StdResult,
+    SubMsg, Uint128, WasmMsg,
+};
+use covenant_macros::{
+    clocked, covenant_deposit_address, covenant_holder_distribute,
+    covenant_holder_emergency_withdraw, covenant_next_contract,
+};
+use covenant_utils::{
+    clock::dequeue_msg,
+    emergency_committee::{EmergencyAction, EmergencyCommitteeConfig},
+    instantiate2_helper::Instantiate2HelperConfig,
+    op_mode::{ContractOperationMode, ContractOperationModeConfig},
+    split::SplitConfig,
+};
+use cw_utils::Expiration;
+
+use crate::{
+    error::ContractError,
+    state::{CONTRACT_OP_MODE, CONTRACT_STATE},
+};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    // Contract Operation Mode.
+    // The contract operation (the Tick function mostly) can either be a permissionless
+    // (aka non-privileged) operation, or a permissioned operation, that is,
+    // restricted to being executed by one of the configured privileged accounts.
+    pub op_mode_cfg: ContractOperationModeConfig,
+    /// liquid pooler address
+    pub next_contract: String,
+    /// config describing the agreed upon duration of POL
+    pub lockup_config: Expiration,
+    /// config describing early exit dynamics
+    pub ragequit_config: RagequitConfig,
+    /// deadline for all parties to deposit their funds
+    pub deposit_deadline: Expiration,
+    /// config describing the covenant parties
+    pub covenant_config: MultiPartyPolCovenantConfig,
+    /// a split for all denoms that are not contributed
+    /// by any of the parties
+    pub fallback_split: Option<SplitConfig>,
+    /// optional emergency committee allowed to withdraw the
+    /// liquidity with M-of-N approvals after a timelock
+    pub emergency_committee: Option<EmergencyCommitteeConfig>,
+}
+
+impl InstantiateMsg {
+    pub fn to_instantiate2_msg(
+        &self,
+        instantiate2_helper: &Instantiate2HelperConfig,
+        admin: String,
+        label: String,
+    ) -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Instantiate2 {
+            admin: Some(admin),
+            code_id: instantiate2_helper.code,
+            label,
+            msg: to_json_binary(self)?,
+            funds: vec![],
+            salt: instantiate2_helper.salt.clone(),
+        })
+    }
+
+    pub fn get_response_attributes(&self) -> Vec<Attribute> {
+        let fallback_attr = match self.fallback_split.as_ref() {
+            Some(split) => split.get_response_attribute("fallback_split".to_string()),
+            None => Attribute::new("fallback_split".to_string(), "none".to_string()),
+        };
+
+        let mut attrs = vec![
+            Attribute::new("op_mode", format!("{:?}", self.op_mode_cfg)),
+            Attribute::new("next_contract", self.next_contract.to_string()),
+            Attribute::new("lockup_config", self.lockup_config.to_string()),
+            Attribute::new("deposit_deadline", self.deposit_deadline.to_string()),
+            fallback_attr,
+        ];
+        attrs.extend(self.ragequit_config.get_response_attributes());
+        attrs.extend(self.covenant_config.get_response_attributes());
+        attrs
+    }
+}
+
+#[cw_serde]
+pub struct MultiPartyPolCovenantConfig {
+    pub parties: Vec<MultiPartyPolCovenantParty>,
+}
+
+impl MultiPartyPolCovenantConfig {
+    pub fn validate(&self, api: &dyn Api) -> Result<(), ContractError> {
+        ensure!(self.parties.len() >= 2, ContractError::PartyCountError {});
+
+        let mut host_addrs = BTreeSet::new();
+        let mut total_allocation = Decimal::zero();
+        for party in self.parties.iter() {
+            api.addr_validate(&party.router)?;
+            api.addr_validate(&party.host_addr)?;
+            ensure!(
+                host_addrs.insert(party.host_addr.to_string()),
+                ContractError::DuplicateParty(party.host_addr.to_string())
+            );
+
+            ensure!(
+                !party.contribution.is_empty()
+                    && party.contribution.iter().all(|c| !c.amount.is_zero()),
+                ContractError::PartyContributionConfigError {}
+            );
+            // multiple parties may contribute the same denom, but a single
+            // party can only list each denom once
+            let mut contribution_denoms = BTreeSet::new();
+            for coin in party.contribution.iter() {
+                ensure!(
+                    contribution_denoms.insert(coin.denom.to_string()),
+                    ContractError::DuplicateContributionDenom(coin.denom.to_string())
+                );
+            }
+
+            ensure!(
+                !party.allocation.is_zero(),
+                ContractError::AllocationValidationError {}
+            );
+            total_allocation = total_allocation
+                .checked_add(party.allocation)
+                .map_err(|_| ContractError::AllocationValidationError {})?;
+        }
+
+        ensure!(
+            total_allocation == Decimal::one(),
+            ContractError::AllocationValidationError {}
+        );
+
+        Ok(())
+    }
+
+    pub fn get_response_attributes(&self) -> Vec<Attribute> {
+        self.parties
+            .iter()
+            .flat_map(|party| party.get_response_attributes())
+            .collect()
+    }
+
+    /// returns the party with the given host address
+    pub fn get_party(&self, host_addr: &str) -> Result<&MultiPartyPolCovenantParty, ContractError> {
+        self.parties
+            .iter()
+            .find(|party| party.host_addr == host_addr)
+            .ok_or(ContractError::PartyNotFound {})
+    }
+
+    /// if authorized, returns the party. otherwise errors
+    pub fn authorize_sender(
+        &self,
+        sender: &str,
+    ) -> Result<MultiPartyPolCovenantParty, ContractError> {
+        let party = self
+            .get_party(sender)
+            .map_err(|_| ContractError::Unauthorized {})?
+            .clone();
+
+        ensure!(
+            !party.allocation.is_zero(),
+            ContractError::PartyAllocationIsZero {}
+        );
+
+        Ok(party)
+    }
+
+    /// returns the parties that still own a part of the position
+    pub fn get_active_parties(&self) -> Vec<&MultiPartyPolCovenantParty> {
+        self.parties
+            .iter()
+            .filter(|party| !party.allocation.is_zero())
+            .collect()
+    }
+
+    /// returns the set of denoms contributed by any of the parties
+    pub fn get_contribution_denoms(&self) -> BTreeSet<String> {
+        self.parties
+            .iter()
+            .flat_map(|party| party.contribution.iter().map(|c| c.denom.to_string()))
+            .collect()
+    }
+
+    /// sets the allocation of the exiting party to zero and rescales
+    /// the allocations of the remaining parties to add up to 1.0 of the
+    /// remaining position. any ragequit penalty left in the position is
+    /// therefore redistributed pro-rata to the remaining allocations.
+    pub fn exit_party(&mut self, host_addr: &str) -> Result<(), ContractError> {
+        let exiting_allocation = self.get_party(host_addr)?.allocation;
+        let remaining_allocation = Decimal::one()
+            .checked_sub(exiting_allocation)
+            .map_err(StdError::overflow)?;
+
+        // last remaining party absorbs any rounding errors
+        let last_active_index = self
+            .parties
+            .iter()
+            .rposition(|party| party.host_addr != host_addr && !party.allocation.is_zero());
+
+        let mut rescaled_total = Decimal::zero();
+        for (i, party) in self.parties.iter_mut().enumerate() {
+            if party.host_addr == host_addr {
+                party.allocation = Decimal::zero();
+            } else if Some(i) != last_active_index && !party.allocation.is_zero() {
+                party.allocation = party
+                    .allocation
+                    .checked_div(remaining_allocation)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?;
+                rescaled_total += party.allocation;
+            }
+        }
+
+        if let Some(i) = last_active_index {
+            self.parties[i].allocation = Decimal::one()
+                .checked_sub(rescaled_total)
+                .map_err(StdError::overflow)?;
+        }
+
+        Ok(())
+    }
+
+    /// returns the denoms that are contributed by more than one party
+    pub fn get_shared_contribution_denoms(&self) -> BTreeSet<String> {
+        let mut seen = BTreeSet::new();
+        let mut shared = BTreeSet::new();
+        for coin in self
+            .parties
+            .iter()
+            .flat_map(|party| party.contribution.iter())
+        {
+            if !seen.insert(coin.denom.to_string()) {
+                shared.insert(coin.denom.to_string());
+            }
+        }
+        shared
+    }
+
+    /// attributes the holder balances of contribution denoms to the parties.
+    /// denoms contributed by a single party are attributed to that party
+    /// in full. denoms contributed by multiple parties are attributed
+    /// according to the amounts each party deposited, as recorded in the
+    /// `ledger` keyed by (host_addr, denom).
+    pub fn attribute_balances(
+        &self,
+        balances: &[Coin],
+        ledger: &BTreeMap<(String, String), Uint128>,
+    ) -> Vec<PartyDeposit> {
+        let mut remaining: BTreeMap<String, Uint128> = balances
+            .iter()
+            .map(|c| (c.denom.to_string(), c.amount))
+            .collect();
+        let shared_denoms = self.get_shared_contribution_denoms();
+
+        self.parties
+            .iter()
+            .map(|party| {
+                let deposited = party
+                    .contribution
+                    .iter()
+                    .map(|contribution| {
+                        let available = remaining
+                            .get(&contribution.denom)
+                            .copied()
+                            .unwrap_or_default();
+                        let amount = if shared_denoms.contains(&contribution.denom) {
+                            ledger
+                                .get(&(party.host_addr.to_string(), contribution.denom.to_string()))
+                                .copied()
+                                .unwrap_or_default()
+                                .min(available)
+                        } else {
+                            available
+                        };
+                        remaining.insert(contribution.denom.to_string(), available - amount);
+                        Coin {
+                            denom: contribution.denom.to_string(),
+                            amount,
+                        }
+                    })
+                    .collect();
+
+                PartyDeposit {
+                    host_addr: party.host_addr.to_string(),
+                    contribution: party.contribution.clone(),
+                    deposited,
+                }
+            })
+            .collect()
+    }
+
+    /// splits the balances of shared contribution denoms that are not
+    /// attributed to any party (e.g. sent with a plain bank transfer
+    /// instead of `Deposit {}`) between the parties contributing the
+    /// denom, pro rata to their agreed contribution amounts.
+    /// returns the coins owed to each party, in the order of the parties.
+    pub fn split_unattributed_balances(
+        &self,
+        balances: &[Coin],
+        deposits: &[PartyDeposit],
+    ) -> Vec<Vec<Coin>> {
+        let mut splits: Vec<Vec<Coin>> = vec![vec![]; self.parties.len()];
+
+        for denom in self.get_shared_contribution_denoms() {
+            let balance = balances
+                .iter()
+                .find(|c| c.denom == denom)
+                .map(|c| c.amount)
+                .unwrap_or_default();
+            let attributed: Uint128 = deposits
+                .iter()
+                .flat_map(|d| d.deposited.iter())
+                .filter(|c| c.denom == denom)
+                .map(|c| c.amount)
+                .sum();
+            let unattributed = balance.saturating_sub(attributed);
+
+            let contributors: Vec<(usize, Uint128)> = self
+                .parties
+                .iter()
+                .enumerate()
+                .filter_map(|(i, party)| {
+                    party
+                        .contribution
+                        .iter()
+                        .find(|c| c.denom == denom)
+                        .map(|c| (i, c.amount))
+                })
+                .collect();
+            let total_contribution: Uint128 = contributors.iter().map(|(_, amount)| *amount).sum();
+            if unattributed.is_zero() || total_contribution.is_zero() {
+                continue;
+            }
+
+            // the last contributor receives the rounding remainder
+            let mut remaining = unattributed;
+            for (n, (i, contribution)) in contributors.iter().enumerate() {
+                let share = if n == contributors.len() - 1 {
+                    remaining
+                } else {
+                    unattributed.multiply_ratio(*contribution, total_contribution)
+                };
+                remaining -= share;
+                if !share.is_zero() {
+                    splits[*i].push(Coin {
+                        denom: denom.to_string(),
+                        amount: share,
+                    });
+                }
+            }
+        }
+
+        splits
+    }
+}
+
+#[cw_serde]
+pub struct MultiPartyPolCovenantParty {
+    /// the coins to be contributed by the party
+    pub contribution: Vec<Coin>,
+    /// neutron address authorized by the party to perform claims/ragequits
+    pub host_addr: String,
+    /// address of the party on the controller chain (final receiver)
+    pub controller_addr: String,
+    /// fraction of the entire LP position owned by the party.
+    /// upon exiting it becomes 0.00 and the allocations of the
+    /// remaining parties are rescaled to add up to 1.00.
+    pub allocation: Decimal,
+    /// address of the interchain router associated with this party
+    pub router: String,
+}
+
+impl MultiPartyPolCovenantParty {
+    pub fn get_response_attributes(&self) -> Vec<Attribute> {
+        vec![
+            Attribute::new(
+                "contribution",
+                self.contribution
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join(","),
+            ),
+            Attribute::new("host_addr", self.host_addr.to_string()),
+            Attribute::new("controller_addr", self.controller_addr.to_string()),
+            Attribute::new("allocation", self.allocation.to_string()),
+            Attribute::new("router", self.router.to_string()),
+        ]
+    }
+}
+
+#[clocked]
+#[covenant_holder_distribute]
+#[covenant_holder_emergency_withdraw]
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// deposit the attached funds as the contribution of the sender party.
+    /// denoms contributed by multiple parties must be deposited this way
+    /// in order to be attributed to the sender.
+    Deposit {},
+    /// initiate the ragequit
+    Ragequit {},
+    /// withdraw the liquidity party is entitled to
+    Claim {},
+    /// distribute any non-contribution denoms
+    DistributeFallbackSplit { denoms: Vec<String> },
+}
+
+#[cw_serde]
+pub enum MigrateMsg {
+    UpdateConfig {
+        op_mode: Option<ContractOperationModeConfig>,
+        next_contract: Option<String>,
+        emergency_committee: Option<EmergencyCommitteeConfig>,
+        lockup_config: Option<Expiration>,
+        deposit_deadline: Option<Expiration>,
+        ragequit_config: Box<Option<RagequitConfig>>,
+        covenant_config: Box<Option<MultiPartyPolCovenantConfig>>,
+        fallback_split: Option<SplitConfig>,
+    },
+    UpdateCodeId {
+        data: Option<Binary>,
+    },
+}
+
+#[cw_serde]
+pub enum ContractState {
+    /// contract is instantiated and awaiting for deposits from
+    /// all parties involved
+    Instantiated,
+    /// funds have been forwarded to the LP module. from the perspective
+    /// of this contract that indicates an active LP position.
+    Active,
+    /// covenant has reached its expiration date.
+    Expired,
+    /// underlying funds have been withdrawn.
+    Complete,
+}
+
+impl ContractState {
+    pub fn complete_and_get_dequeue_msgs(deps: DepsMut) -> Result<Vec<SubMsg>, StdError> {
+        let msgs = match CONTRACT_OP_MODE.load(deps.storage)? {
+            ContractOperationMode::Permissionless => vec![],
+            ContractOperationMode::Permissioned(privileged_addrs) => {
+                let mut dequeue_msgs: Vec<SubMsg> = vec![];
+                for addr in privileged_addrs.to_vec() {
+                    if deps.querier.query_wasm_contract_info(addr.as_str()).is_ok() {
+                        let dequeue_submsg =
+                            SubMsg::reply_on_error(dequeue_msg(addr.as_str())?, u64::MAX);
+                        dequeue_msgs.push(dequeue_submsg);
+                    }
+                }
+                dequeue_msgs
+            }
+        };
+        CONTRACT_STATE.save(deps.storage, &ContractState::Complete)?;
+
+        Ok(msgs)
+    }
+}
+
+impl fmt::Display for ContractState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ContractState::Instantiated => write!(f, "instantiated"),
+            ContractState::Active => write!(f, "active"),
+            ContractState::Expired => write!(f, "expired"),
+            ContractState::Complete => write!(f, "complete"),
+        }
+    }
+}
+
+#[covenant_next_contract]
+#[covenant_deposit_address]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ContractState)]
+    ContractState {},
+    #[returns(RagequitConfig)]
+    RagequitConfig {},
+    #[returns(Expiration)]
+    LockupConfig {},
+    #[returns(Expiration)]
+    DepositDeadline {},
+    #[returns(MultiPartyPolCovenantConfig)]
+    Config {},
+    #[returns(MultiPartyPolCovenantParty)]
+    Party { host_addr: String },
+    #[returns(Option<SplitConfig>)]
+    FallbackSplit {},
+    #[returns(Option<covenant_utils::emergency_committee::EmergencyCommittee>)]
+    EmergencyCommittee {},
+    #[returns(Vec<covenant_utils::emergency_committee::PendingEmergencyAction>)]
+    PendingEmergencyActions {},
+    #[returns(covenant_utils::op_mode::ContractOperationMode)]
+    OperationMode {},
+    /// amounts funded by each party while awaiting deposits.
+    /// only available in `Instantiated` state.
+    #[returns(DepositsResponse)]
+    Deposits {},
+    /// paginated log of the actions performed by the holder
+    #[returns(Vec<covenant_utils::history::HistoryEntry>)]
+    History {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct DepositsResponse {
+    pub parties: Vec<PartyDeposit>,
+}
+
+#[cw_serde]
+pub struct PartyDeposit {
+    pub host_addr: String,
+    /// coins the party agreed to contribute
+    pub contribution: Vec<Coin>,
+    /// amounts of the contribution denoms attributed to the party,
+    /// in the same order as the contribution
+    pub deposited: Vec<Coin>,
+}
+
+impl PartyDeposit {
+    /// the deposit is fulfilled once every denom of the contribution
+    /// is held in at least the agreed amount
+    pub fn is_fulfilled(&self) -> bool {
+        self.contribution.iter().all(|contribution| {
+            self.deposited
+                .iter()
+                .any(|d| d.denom == contribution.denom && d.amount >= contribution.amount)
+        })
+    }
+
+    /// returns the amounts deposited on top of the agreed contribution
+    pub fn get_excess(&self) -> Vec<Coin> {
+        self.contribution
+            .iter()
+            .filter_map(|contribution| {
+                let deposited = self
+                    .deposited
+                    .iter()
+                    .find(|d| d.denom == contribution.denom)?;
+                let excess = deposited.amount.checked_sub(contribution.amount).ok()?;
+                if excess.is_zero() {
+                    None
+                } else {
+                    Some(Coin {
+                        denom: contribution.denom.to_string(),
+                        amount: excess,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+#[cw_serde]
+pub enum RagequitConfig {
+    /// ragequit is disabled
+    Disabled,
+    /// ragequit is enabled with `RagequitTerms`
+    Enabled(RagequitTerms),
+}
+
+impl RagequitConfig {
+    pub fn get_response_attributes(&self) -> Vec<Attribute> {
+        match self {
+            RagequitConfig::Disabled => vec![Attribute::new("ragequit_config", "disabled")],
+            RagequitConfig::Enabled(c) => vec![
+                Attribute::new("ragequit_config", "enabled"),
+                Attribute::new("ragequit_penalty", c.penalty.to_string()),
+            ],
+        }
+    }
+
+    pub fn validate(&self, parties: &[MultiPartyPolCovenantParty]) -> Result<(), ContractError> {
+        match self {
+            RagequitConfig::Disabled => Ok(()),
+            RagequitConfig::Enabled(terms) => {
+                // first we validate the range: [0.00, 1.00)
+                if terms.penalty >= Decimal::one() {
+                    return Err(ContractError::RagequitPenaltyRangeError {});
+                }
+                // then validate that rq penalty is below every party allocation
+                if parties
+                    .iter()
+                    .any(|party| terms.penalty >= party.allocation)
+                {
+                    return Err(ContractError::RagequitPenaltyExceedsPartyAllocationError {});
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cw_serde]
+pub struct RagequitTerms {
+    /// decimal based penalty to be applied on a party
+    /// for initiating ragequit. Must be in the range of [0.00, 1.00).
+    /// Also must not exceed any party allocation in raw values.
+    pub penalty: Decimal,
+}
@@ -0,0 +1,54 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use covenant_utils::{op_mode::ContractOperationMode, split::SplitConfig};
+use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
+
+use crate::msg::{ContractState, MultiPartyPolCovenantConfig, RagequitConfig, RagequitTerms};
+
+pub const CONTRACT_STATE: Item<ContractState> = Item::new("contract_state");
+
+pub const CONTRACT_OP_MODE: Item<ContractOperationMode> = Item::new("contract_op_mode");
+
+/// address of the liquidity pool to which we provide liquidity
+pub const LIQUID_POOLER_ADDRESS: Item<Addr> = Item::new("pooler_address");
+
+/// configuration describing the lockup period after which parties are
+/// no longer subject to ragequit penalties in order to exit their position
+pub const LOCKUP_CONFIG: Item<Expiration> = Item::new("lockup_config");
+
+/// configuration describing the deposit period during which parties
+/// are expected to fulfill their parts of the covenant
+pub const DEPOSIT_DEADLINE: Item<Expiration> = Item::new("deposit_deadline");
+
+/// configuration describing the penalty applied to the allocation
+/// of the party initiating the ragequit
+pub const RAGEQUIT_CONFIG: Item<RagequitConfig> = Item::new("ragequit_config");
+
+/// configuration storing all parties information
+pub const COVENANT_CONFIG: Item<MultiPartyPolCovenantConfig> = Item::new("covenant_config");
+
+/// split for distributing any denoms that are not contributed by the parties
+pub const FALLBACK_SPLIT: Item<SplitConfig> = Item::new("fallback_split");
+
+/// amounts of contribution denoms deposited by each party,
+/// keyed by (party host_addr, denom)
+pub const PARTY_DEPOSITS: Map<(&str, &str), Uint128> = Map::new("party_deposits");
+
+pub const WITHDRAW_STATE: Item<WithdrawState> = Item::new("withdraw_state");
+
+#[cw_serde]
+pub enum WithdrawState {
+    Processing {
+        claimer_addr: String,
+    },
+    ProcessingRagequit {
+        claimer_addr: String,
+        terms: RagequitTerms,
+    },
+    /// emergency withdrawal approved by the emergency committee.
+    /// if receiver is not set, funds are routed to the parties.
+    Emergency {
+        receiver: Option<Addr>,
+    },
+}
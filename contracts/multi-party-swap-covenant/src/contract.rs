@@ -0,0 +1,382 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    ensure, to_json_binary, Binary, CanonicalAddr, Deps, DepsMut, Env, MessageInfo, Response,
+    StdError, StdResult, WasmMsg,
+};
+use covenant_utils::{
+    instantiate2_helper::get_instantiate2_salt_and_address, op_mode::ContractOperationModeConfig,
+};
+use cw2::set_contract_version;
+use valence_covenant_swap::msg::{CovenantPartyConfig, RouterMigrateMsg};
+use valence_ibc_forwarder::msg::InstantiateMsg as IbcForwarderInstantiateMsg;
+
+use crate::{
+    error::ContractError,
+    msg::{
+        get_party_address, remap_split_to_routers, to_swap_party, InstantiateMsg, MigrateMsg,
+        QueryMsg,
+    },
+    state::{
+        CONTRACT_CODES, COVENANT_CLOCK_ADDR, COVENANT_SPLITTER_ADDR, COVENANT_SWAP_HOLDER_ADDR,
+        PARTY_IBC_FORWARDER_ADDRS, PARTY_ROUTER_ADDRS,
+    },
+};
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const CLOCK_SALT: &[u8] = b"clock";
+pub const HOLDER_SALT: &[u8] = b"holder";
+pub const SPLITTER_SALT: &[u8] = b"splitter";
+
+/// salt of the router belonging to the party at the given index
+pub fn get_party_router_salt(index: usize) -> Vec<u8> {
+    format!("router_{index}").into_bytes()
+}
+
+/// salt of the ibc forwarder belonging to the party at the given index
+pub fn get_party_forwarder_salt(index: usize) -> Vec<u8> {
+    format!("forwarder_{index}").into_bytes()
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let mut resp = Response::default().add_attribute("method", "instantiate");
+    let creator_address: CanonicalAddr =
+        deps.api.addr_canonicalize(env.contract.address.as_str())?;
+    let covenant_denoms: BTreeSet<String> = msg.splits.keys().map(|k| k.to_string()).collect();
+
+    // we validate that denoms explicitly defined in splits are the
+    // same denoms that parties are expected to contribute
+    for party in msg.parties.iter() {
+        ensure!(
+            msg.splits.contains_key(&party.get_native_denom()),
+            ContractError::DenomMisconfigurationError(
+                party.get_native_denom(),
+                format!("{:?}", covenant_denoms)
+            )
+        );
+    }
+
+    let clock_instantiate2_config = get_instantiate2_salt_and_address(
+        deps.as_ref(),
+        CLOCK_SALT,
+        &creator_address,
+        msg.contract_codes.clock_code,
+    )?;
+
+    // If the contract operation mode is permissioned, we will add the clock address to the list of addresses that can tick
+    let op_mode_cfg = match msg.operation_mode {
+        ContractOperationModeConfig::Permissioned(mut whitelist) => {
+            for addr in whitelist.iter() {
+                deps.api.addr_validate(addr)?;
+            }
+            whitelist.push(clock_instantiate2_config.addr.to_string());
+            ContractOperationModeConfig::Permissioned(whitelist)
+        }
+        ContractOperationModeConfig::Permissionless => ContractOperationModeConfig::Permissionless,
+    };
+
+    let holder_instantiate2_config = get_instantiate2_salt_and_address(
+        deps.as_ref(),
+        HOLDER_SALT,
+        &creator_address,
+        msg.contract_codes.holder_code,
+    )?;
+    let splitter_instantiate2_config = get_instantiate2_salt_and_address(
+        deps.as_ref(),
+        SPLITTER_SALT,
+        &creator_address,
+        msg.contract_codes.splitter_code,
+    )?;
+
+    let mut router_instantiate2_configs = Vec::with_capacity(msg.parties.len());
+    for (i, party) in msg.parties.iter().enumerate() {
+        router_instantiate2_configs.push(get_instantiate2_salt_and_address(
+            deps.as_ref(),
+            &get_party_router_salt(i),
+            &creator_address,
+            msg.contract_codes.get_router_code_id(party),
+        )?);
+    }
+    let router_addrs: Vec<_> = router_instantiate2_configs
+        .iter()
+        .map(|config| config.addr.clone())
+        .collect();
+
+    let mut clock_initial_queue = vec![holder_instantiate2_config.addr.to_string()];
+    clock_initial_queue.extend(router_addrs.iter().map(|addr| addr.to_string()));
+    clock_initial_queue.push(splitter_instantiate2_config.addr.to_string());
+
+    let holder_instantiate2_msg = valence_multi_party_swap_holder::msg::InstantiateMsg {
+        op_mode_cfg: op_mode_cfg.clone(),
+        next_contract: splitter_instantiate2_config.addr.to_string(),
+        lockup_config: msg.lockup_config,
+        parties: msg
+            .parties
+            .iter()
+            .zip(router_addrs.iter())
+            .map(|(party, router)| to_swap_party(party, router.to_string()))
+            .collect(),
+    }
+    .to_instantiate2_msg(
+        &holder_instantiate2_config,
+        env.contract.address.to_string(),
+        format!("{}_swap_holder", msg.label),
+    )?;
+
+    let mut splits = BTreeMap::new();
+    for (denom, split) in msg.splits.iter() {
+        splits.insert(
+            denom.to_string(),
+            remap_split_to_routers(split, &msg.parties, &router_addrs)?,
+        );
+    }
+    let splitter_instantiate2_msg = valence_native_splitter::msg::InstantiateMsg {
+        op_mode_cfg: op_mode_cfg.clone(),
+        splits,
+        fallback_split: match &msg.fallback_split {
+            Some(config) => Some(remap_split_to_routers(config, &msg.parties, &router_addrs)?),
+            None => None,
+        },
+    }
+    .to_instantiate2_msg(
+        &splitter_instantiate2_config,
+        env.contract.address.to_string(),
+        format!("{}_splitter", msg.label),
+    )?;
+
+    let mut messages = vec![holder_instantiate2_msg, splitter_instantiate2_msg];
+
+    for (i, (party, router_instantiate2_config)) in msg
+        .parties
+        .iter()
+        .zip(router_instantiate2_configs.into_iter())
+        .enumerate()
+    {
+        let party_addr = get_party_address(party);
+
+        messages.push(party.get_router_instantiate2_wasm_msg(
+            format!("{}_party_{i}_router", msg.label),
+            env.contract.address.to_string(),
+            op_mode_cfg.clone(),
+            covenant_denoms.clone(),
+            router_instantiate2_config.clone(),
+        )?);
+        PARTY_ROUTER_ADDRS.save(
+            deps.storage,
+            party_addr.to_string(),
+            &router_instantiate2_config.addr,
+        )?;
+        resp = resp.add_attribute(
+            format!("party_{i}_router_addr"),
+            router_instantiate2_config.addr,
+        );
+
+        // native parties deposit directly to the holder,
+        // interchain parties get an ibc forwarder
+        if let CovenantPartyConfig::Interchain(config) = party {
+            let forwarder_instantiate2_config = get_instantiate2_salt_and_address(
+                deps.as_ref(),
+                &get_party_forwarder_salt(i),
+                &creator_address,
+                msg.contract_codes.ibc_forwarder_code,
+            )?;
+            PARTY_IBC_FORWARDER_ADDRS.save(
+                deps.storage,
+                party_addr,
+                &forwarder_instantiate2_config.addr,
+            )?;
+            clock_initial_queue.push(forwarder_instantiate2_config.addr.to_string());
+            let instantiate_msg = IbcForwarderInstantiateMsg {
+                op_mode_cfg: op_mode_cfg.clone(),
+                next_contract: holder_instantiate2_config.addr.to_string(),
+                remote_chain_connection_id: config.party_chain_connection_id.to_string(),
+                remote_chain_channel_id: config.party_to_host_chain_channel_id.to_string(),
+                denom: config.remote_chain_denom.to_string(),
+                amount: config.contribution.amount,
+                ica_timeout: msg.timeouts.ica_timeout,
+                ibc_transfer_timeout: msg.timeouts.ibc_transfer_timeout,
+                fallback_address: msg.fallback_address.clone(),
+            };
+
+            messages.push(instantiate_msg.to_instantiate2_msg(
+                &forwarder_instantiate2_config,
+                env.contract.address.to_string(),
+                format!("{}_party_{i}_ibc_forwarder", msg.label),
+            )?);
+            resp = resp.add_attribute(
+                format!("party_{i}_forwarder_addr"),
+                forwarder_instantiate2_config.addr,
+            );
+        }
+    }
+
+    messages.insert(
+        0,
+        valence_clock::msg::InstantiateMsg {
+            tick_max_gas: msg.clock_tick_max_gas,
+            whitelist: vec![],
+            initial_queue: clock_initial_queue,
+        }
+        .to_instantiate2_msg(
+            clock_instantiate2_config.code,
+            clock_instantiate2_config.salt,
+            env.contract.address.to_string(),
+            format!("{}-clock", msg.label),
+        )?,
+    );
+
+    CONTRACT_CODES.save(deps.storage, &msg.contract_codes.to_covenant_codes_config())?;
+    COVENANT_SWAP_HOLDER_ADDR.save(deps.storage, &holder_instantiate2_config.addr)?;
+    COVENANT_SPLITTER_ADDR.save(deps.storage, &splitter_instantiate2_config.addr)?;
+    COVENANT_CLOCK_ADDR.save(deps.storage, &clock_instantiate2_config.addr)?;
+
+    Ok(resp
+        .add_attribute("clock_addr", clock_instantiate2_config.addr)
+        .add_attribute("splitter_addr", splitter_instantiate2_config.addr)
+        .add_attribute("holder_addr", holder_instantiate2_config.addr)
+        .add_messages(messages))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::ClockAddress {} => Ok(to_json_binary(
+            &COVENANT_CLOCK_ADDR.may_load(deps.storage)?,
+        )?),
+        QueryMsg::HolderAddress {} => Ok(to_json_binary(
+            &COVENANT_SWAP_HOLDER_ADDR.may_load(deps.storage)?,
+        )?),
+        QueryMsg::SplitterAddress {} => Ok(to_json_binary(
+            &COVENANT_SPLITTER_ADDR.may_load(deps.storage)?,
+        )?),
+        QueryMsg::IbcForwarderAddress { party } => Ok(to_json_binary(
+            &PARTY_IBC_FORWARDER_ADDRS.may_load(deps.storage, party)?,
+        )?),
+        QueryMsg::InterchainRouterAddress { party } => {
+            let resp = PARTY_ROUTER_ADDRS
+                .may_load(deps.storage, party)?
+                .ok_or_else(|| StdError::not_found("not found"))?;
+            Ok(to_json_binary(&resp)?)
+        }
+        QueryMsg::PartyDepositAddress { party } => {
+            if !PARTY_ROUTER_ADDRS.has(deps.storage, party.to_string()) {
+                return Err(StdError::not_found("not found"));
+            }
+            // if the party has an ibc forwarder, we query it for a deposit
+            // address which should return the address of ICA on a remote chain.
+            // if no ibc forwarder is saved, we return the holder.
+            let resp: Option<_> = match PARTY_IBC_FORWARDER_ADDRS.may_load(deps.storage, party)? {
+                Some(addr) => deps.querier.query_wasm_smart(
+                    addr,
+                    &covenant_utils::neutron::QueryMsg::DepositAddress {},
+                )?,
+                None => COVENANT_SWAP_HOLDER_ADDR.may_load(deps.storage)?,
+            };
+            Ok(to_json_binary(&resp)?)
+        }
+        QueryMsg::ContractCodes {} => Ok(to_json_binary(&CONTRACT_CODES.load(deps.storage)?)?),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    match msg {
+        MigrateMsg::UpdateCovenant {
+            codes,
+            clock,
+            holder,
+            splitter,
+            party_routers,
+            party_forwarders,
+        } => {
+            let mut migrate_msgs = vec![];
+            let mut resp = Response::default().add_attribute("method", "migrate_contracts");
+
+            if let Some(new_codes) = codes {
+                CONTRACT_CODES.save(deps.storage, &new_codes)?;
+                let code_binary = to_json_binary(&new_codes)?;
+                resp = resp.add_attribute("contract_codes_migrate", code_binary.to_base64());
+            }
+
+            let contract_codes = CONTRACT_CODES.load(deps.storage)?;
+
+            if let Some(clock) = clock {
+                let msg = to_json_binary(&clock)?;
+                resp = resp.add_attribute("clock_migrate", msg.to_base64());
+                migrate_msgs.push(WasmMsg::Migrate {
+                    contract_addr: COVENANT_CLOCK_ADDR.load(deps.storage)?.to_string(),
+                    new_code_id: contract_codes.clock,
+                    msg,
+                });
+            }
+
+            for (party, router_migrate_msg) in party_routers {
+                let (msg, new_code_id) = match router_migrate_msg {
+                    RouterMigrateMsg::Interchain(msg) => {
+                        (to_json_binary(&msg)?, contract_codes.interchain_router)
+                    }
+                    RouterMigrateMsg::Native(msg) => {
+                        (to_json_binary(&msg)?, contract_codes.native_router)
+                    }
+                };
+                resp = resp.add_attribute(format!("{party}_router_migrate"), msg.to_base64());
+                migrate_msgs.push(WasmMsg::Migrate {
+                    contract_addr: PARTY_ROUTER_ADDRS.load(deps.storage, party)?.to_string(),
+                    new_code_id,
+                    msg,
+                });
+            }
+
+            for (party, forwarder) in party_forwarders {
+                let msg: Binary = to_json_binary(&forwarder)?;
+                resp = resp.add_attribute(format!("{party}_forwarder_migrate"), msg.to_base64());
+                migrate_msgs.push(WasmMsg::Migrate {
+                    contract_addr: PARTY_IBC_FORWARDER_ADDRS
+                        .load(deps.storage, party)?
+                        .to_string(),
+                    new_code_id: contract_codes.ibc_forwarder,
+                    msg,
+                });
+            }
+
+            if let Some(holder) = holder {
+                let msg: Binary = to_json_binary(&holder)?;
+                resp = resp.add_attribute("holder_migrate", msg.to_base64());
+                migrate_msgs.push(WasmMsg::Migrate {
+                    contract_addr: COVENANT_SWAP_HOLDER_ADDR.load(deps.storage)?.to_string(),
+                    new_code_id: contract_codes.holder,
+                    msg,
+                });
+            }
+
+            if let Some(splitter) = splitter {
+                let msg: Binary = to_json_binary(&splitter)?;
+                resp = resp.add_attribute("splitter_migrate", msg.to_base64());
+                migrate_msgs.push(WasmMsg::Migrate {
+                    contract_addr: COVENANT_SPLITTER_ADDR.load(deps.storage)?.to_string(),
+                    new_code_id: contract_codes.splitter,
+                    msg,
+                });
+            }
+
+            Ok(resp.add_messages(migrate_msgs))
+        }
+        MigrateMsg::UpdateCodeId { data: _ } => {
+            // This is a migrate message to update code id,
+            // Data is optional base64 that we can parse to any data we would like in the future
+            // let data: SomeStruct = from_binary(&data)?;
+            Ok(Response::default())
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::{Instantiate2AddressError, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    InstantiationError(#[from] Instantiate2AddressError),
+
+    #[error("{0} contribution missing an explicit split configuration (got {1})")]
+    DenomMisconfigurationError(String, String),
+}
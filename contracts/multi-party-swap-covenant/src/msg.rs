@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{coin, Addr, Binary, StdError, StdResult, Uint64};
+use covenant_utils::{op_mode::ContractOperationModeConfig, split::SplitConfig};
+use cw_utils::Expiration;
+use valence_covenant_swap::msg::{CovenantPartyConfig, RouterMigrateMsg, Timeouts};
+use valence_multi_party_swap_holder::msg::SwapParty;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub label: String,
+    pub timeouts: Timeouts,
+    pub contract_codes: CovenantContractCodeIds,
+    pub clock_tick_max_gas: Option<Uint64>,
+    pub lockup_config: Expiration,
+    /// parties engaged in the swap along with their contributions.
+    /// every party is expected to contribute a distinct denom.
+    pub parties: Vec<CovenantPartyConfig>,
+    /// split of every contributed denom,
+    /// keyed by the party final receiver addresses
+    pub splits: BTreeMap<String, SplitConfig>,
+    /// split for any denoms not contributed by the parties,
+    /// keyed by the party final receiver addresses
+    pub fallback_split: Option<SplitConfig>,
+    pub fallback_address: Option<String>,
+    pub operation_mode: ContractOperationModeConfig,
+}
+
+/// neutron address authorized by the party, used to identify it
+pub fn get_party_address(party: &CovenantPartyConfig) -> String {
+    party.to_covenant_party().addr
+}
+
+/// swap holder terms of the party, refunding to its router
+pub fn to_swap_party(party: &CovenantPartyConfig, router: String) -> SwapParty {
+    SwapParty {
+        addr: get_party_address(party),
+        contribution: coin(
+            party.get_contribution().amount.u128(),
+            party.get_native_denom(),
+        ),
+        refund_address: router,
+    }
+}
+
+/// remaps the split receivers from party final receiver
+/// addresses to the addresses of their routers
+pub fn remap_split_to_routers(
+    split: &SplitConfig,
+    parties: &[CovenantPartyConfig],
+    routers: &[Addr],
+) -> StdResult<SplitConfig> {
+    let mut receivers = BTreeMap::new();
+    for (receiver, share) in split.receivers.iter() {
+        let router = parties
+            .iter()
+            .zip(routers.iter())
+            .find(|(party, _)| &party.get_final_receiver_address() == receiver)
+            .map(|(_, router)| router)
+            .ok_or_else(|| StdError::not_found(format!("receiver {receiver:?} not found")))?;
+        receivers.insert(router.to_string(), *share);
+    }
+
+    Ok(SplitConfig { receivers })
+}
+
+#[cw_serde]
+pub struct CovenantContractCodeIds {
+    pub ibc_forwarder_code: u64,
+    pub interchain_router_code: u64,
+    pub native_router_code: u64,
+    pub splitter_code: u64,
+    pub holder_code: u64,
+    pub clock_code: u64,
+}
+
+impl CovenantContractCodeIds {
+    pub fn get_router_code_id(&self, party_config: &CovenantPartyConfig) -> u64 {
+        match party_config {
+            CovenantPartyConfig::Interchain(_) => self.interchain_router_code,
+            CovenantPartyConfig::Native(_) => self.native_router_code,
+        }
+    }
+
+    pub(crate) fn to_covenant_codes_config(&self) -> CovenantContractCodes {
+        CovenantContractCodes {
+            clock: self.clock_code,
+            holder: self.holder_code,
+            splitter: self.splitter_code,
+            native_router: self.native_router_code,
+            interchain_router: self.interchain_router_code,
+            ibc_forwarder: self.ibc_forwarder_code,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct CovenantContractCodes {
+    pub clock: u64,
+    pub holder: u64,
+    pub splitter: u64,
+    pub native_router: u64,
+    pub interchain_router: u64,
+    pub ibc_forwarder: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Addr)]
+    ClockAddress {},
+    #[returns(Addr)]
+    HolderAddress {},
+    #[returns(Addr)]
+    SplitterAddress {},
+    /// `party` is the neutron address of the party
+    #[returns(Addr)]
+    IbcForwarderAddress { party: String },
+    /// `party` is the neutron address of the party
+    #[returns(Addr)]
+    InterchainRouterAddress { party: String },
+    /// `party` is the neutron address of the party
+    #[returns(Addr)]
+    PartyDepositAddress { party: String },
+    #[returns(CovenantContractCodes)]
+    ContractCodes {},
+}
+
+#[cw_serde]
+pub enum MigrateMsg {
+    UpdateCovenant {
+        codes: Option<CovenantContractCodes>,
+        clock: Option<valence_clock::msg::MigrateMsg>,
+        holder: Option<valence_multi_party_swap_holder::msg::MigrateMsg>,
+        splitter: Option<valence_native_splitter::msg::MigrateMsg>,
+        /// router migrations keyed by the party neutron address
+        party_routers: BTreeMap<String, RouterMigrateMsg>,
+        /// ibc forwarder migrations keyed by the party neutron address
+        party_forwarders: BTreeMap<String, valence_ibc_forwarder::msg::MigrateMsg>,
+    },
+    UpdateCodeId {
+        data: Option<Binary>,
+    },
+}
@@ -0,0 +1,14 @@
+use crate::msg::CovenantContractCodes;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+
+pub const COVENANT_CLOCK_ADDR: Item<Addr> = Item::new("covenant_clock_addr");
+pub const COVENANT_SWAP_HOLDER_ADDR: Item<Addr> = Item::new("covenant_swap_holder_addr");
+pub const COVENANT_SPLITTER_ADDR: Item<Addr> = Item::new("covenant_splitter_addr");
+
+/// maps party neutron addresses to their routers
+pub const PARTY_ROUTER_ADDRS: Map<String, Addr> = Map::new("party_router_addrs");
+/// maps interchain party neutron addresses to their ibc forwarders
+pub const PARTY_IBC_FORWARDER_ADDRS: Map<String, Addr> = Map::new("party_ibc_forwarder_addrs");
+
+pub(crate) const CONTRACT_CODES: Item<CovenantContractCodes> = Item::new("contract_codes");
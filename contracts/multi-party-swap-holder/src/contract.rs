@@ -0,0 +1,302 @@
+use cosmwasm_std::{
+    coin, ensure, to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
+    MessageInfo, Reply, Response, StdError, StdResult, SubMsg,
+};
+use covenant_utils::{
+    clock::dequeue_msg,
+    history::{query_history, record_history, HistoryAction},
+    op_mode::{verify_caller, ContractOperationMode},
+};
+
+use crate::{
+    error::ContractError,
+    msg::{validate_parties, ContractState, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    state::{CONTRACT_OP_MODE, CONTRACT_STATE, LOCKUP_CONFIG, NEXT_CONTRACT, PARTIES},
+};
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cw2::set_contract_version;
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let next_contract = deps.api.addr_validate(&msg.next_contract)?;
+    let op_mode = ContractOperationMode::try_init(deps.api, msg.op_mode_cfg.clone())?;
+
+    validate_parties(deps.api, &msg.parties)?;
+    ensure!(
+        !msg.lockup_config.is_expired(&env.block),
+        ContractError::LockupConfigValidationError {}
+    );
+
+    NEXT_CONTRACT.save(deps.storage, &next_contract)?;
+    CONTRACT_OP_MODE.save(deps.storage, &op_mode)?;
+    LOCKUP_CONFIG.save(deps.storage, &msg.lockup_config)?;
+    PARTIES.save(deps.storage, &msg.parties)?;
+    CONTRACT_STATE.save(deps.storage, &ContractState::Instantiated)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "multi_party_swap_holder_instantiate")
+        .add_attributes(msg.get_response_attributes()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    // Verify caller is the clock
+    verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+
+    match (CONTRACT_STATE.load(deps.storage)?, msg) {
+        // from instantiated state we attempt to forward the funds
+        (ContractState::Instantiated, ExecuteMsg::Tick {}) => try_forward(deps, env),
+        // from expired state we attempt to refund any available funds
+        (ContractState::Expired, ExecuteMsg::Tick {}) => try_refund(deps, env),
+        // completed state is terminal, noop
+        (ContractState::Complete, ExecuteMsg::Tick {}) => Ok(Response::default()
+            .add_attribute("contract_state", "complete")
+            .add_attribute("method", "try_tick")),
+    }
+}
+
+/// refunds every party the available balance of its contribution denom
+fn try_refund(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let parties = PARTIES.load(deps.storage)?;
+
+    let mut refund_messages: Vec<CosmosMsg> = vec![];
+    for party in parties {
+        let bal = deps
+            .querier
+            .query_balance(&env.contract.address, &party.contribution.denom)?;
+        if bal.amount.is_zero() {
+            continue;
+        }
+        record_history(
+            deps.storage,
+            &env.block,
+            HistoryAction::Refund,
+            Some(party.addr),
+            vec![bal.clone()],
+        )?;
+        refund_messages.push(
+            BankMsg::Send {
+                to_address: party.refund_address,
+                amount: vec![bal],
+            }
+            .into(),
+        );
+    }
+
+    Ok(Response::default()
+        .add_attribute("contract_state", "expired")
+        .add_attribute("method", "try_refund")
+        .add_messages(refund_messages))
+}
+
+/// forwards the contributions of every party to the next contract once
+/// all of them are deposited, and refunds any over-deposits
+fn try_forward(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let lockup_config = LOCKUP_CONFIG.load(deps.storage)?;
+
+    // check if covenant is expired
+    if lockup_config.is_expired(&env.block) {
+        CONTRACT_STATE.save(deps.storage, &ContractState::Expired)?;
+        return Ok(Response::default()
+            .add_attribute("method", "try_forward")
+            .add_attribute("result", "covenant_expired")
+            .add_attribute("contract_state", "expired"));
+    }
+
+    let parties = PARTIES.load(deps.storage)?;
+
+    let mut balances: Vec<Coin> = Vec::with_capacity(parties.len());
+    for party in parties.iter() {
+        let bal = deps
+            .querier
+            .query_balance(&env.contract.address, &party.contribution.denom)?;
+        // if any of the parties did not deposit its part,
+        // we are not ready for the swap yet
+        if bal.amount < party.contribution.amount {
+            return Err(ContractError::InsufficientFunds {});
+        }
+        balances.push(bal);
+    }
+
+    // first we query the deposit address of next module
+    let next_contract = NEXT_CONTRACT.load(deps.storage)?;
+    let deposit_address_query = deps.querier.query_wasm_smart(
+        next_contract,
+        &covenant_utils::neutron::QueryMsg::DepositAddress {},
+    )?;
+
+    // if query returns None, then we error and wait
+    let Some(deposit_address) = deposit_address_query else {
+        return Err(
+            StdError::not_found("Next contract is not ready for receiving the funds yet").into(),
+        );
+    };
+
+    // Transition contract state to complete
+    ContractState::complete(deps.branch())?;
+
+    let mut forward_coins: Vec<Coin> = Vec::with_capacity(parties.len());
+    let mut refund_messages: Vec<CosmosMsg> = vec![];
+    for (party, bal) in parties.into_iter().zip(balances.into_iter()) {
+        record_history(
+            deps.storage,
+            &env.block,
+            HistoryAction::Deposit,
+            Some(party.addr.to_string()),
+            vec![party.contribution.clone()],
+        )?;
+
+        // only the agreed amounts are forwarded, surplus goes back to the party
+        let surplus = bal
+            .amount
+            .checked_sub(party.contribution.amount)
+            .map_err(StdError::overflow)?;
+        if !surplus.is_zero() {
+            let refund_coin = coin(surplus.u128(), bal.denom);
+            record_history(
+                deps.storage,
+                &env.block,
+                HistoryAction::Refund,
+                Some(party.addr),
+                vec![refund_coin.clone()],
+            )?;
+            refund_messages.push(
+                BankMsg::Send {
+                    to_address: party.refund_address,
+                    amount: vec![refund_coin],
+                }
+                .into(),
+            );
+        }
+
+        forward_coins.push(party.contribution);
+    }
+
+    // bank sends expect the coins to be sorted by denom
+    forward_coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+    let bank_msg = BankMsg::Send {
+        to_address: deposit_address,
+        amount: forward_coins,
+    };
+
+    let mut submsgs: Vec<SubMsg> = vec![];
+    if let ContractOperationMode::Permissioned(privileged_accounts) =
+        CONTRACT_OP_MODE.load(deps.storage)?
+    {
+        // given that we successfully forward the expected funds,
+        // we can now dequeue from the clock and complete
+        for addr in privileged_accounts.to_vec() {
+            if deps.querier.query_wasm_contract_info(addr.as_str()).is_ok() {
+                submsgs.push(SubMsg::reply_on_error(
+                    dequeue_msg(addr.as_str())?,
+                    u64::MAX,
+                ));
+            }
+        }
+    }
+
+    Ok(Response::default()
+        .add_attribute("method", "try_forward")
+        .add_attribute("contract_state", "complete")
+        .add_message(bank_msg)
+        .add_messages(refund_messages)
+        .add_submessages(submsgs))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::NextContract {} => Ok(to_json_binary(&NEXT_CONTRACT.may_load(deps.storage)?)?),
+        QueryMsg::LockupConfig {} => Ok(to_json_binary(&LOCKUP_CONFIG.may_load(deps.storage)?)?),
+        QueryMsg::Parties {} => Ok(to_json_binary(&PARTIES.may_load(deps.storage)?)?),
+        QueryMsg::OperationMode {} => {
+            Ok(to_json_binary(&CONTRACT_OP_MODE.may_load(deps.storage)?)?)
+        }
+        QueryMsg::ContractState {} => Ok(to_json_binary(&CONTRACT_STATE.may_load(deps.storage)?)?),
+        // the deposit address for the holder is the contract itself
+        QueryMsg::DepositAddress {} => Ok(to_json_binary(&Some(env.contract.address))?),
+        QueryMsg::History { start_after, limit } => Ok(to_json_binary(&query_history(
+            deps.storage,
+            start_after,
+            limit,
+        )?)?),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    match msg {
+        MigrateMsg::UpdateConfig {
+            op_mode,
+            next_contract,
+            lockup_config,
+            parties,
+        } => {
+            let mut resp = Response::default().add_attribute("method", "update_config");
+
+            if let Some(op_mode_cfg) = op_mode {
+                let updated_op_mode = ContractOperationMode::try_init(deps.api, op_mode_cfg)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+                CONTRACT_OP_MODE.save(deps.storage, &updated_op_mode)?;
+                resp = resp.add_attribute("op_mode", format!("{:?}", updated_op_mode));
+            }
+
+            if let Some(addr) = next_contract {
+                let next_contract_addr = deps.api.addr_validate(&addr)?;
+                NEXT_CONTRACT.save(deps.storage, &next_contract_addr)?;
+                resp = resp.add_attribute("next_contract", addr);
+            }
+
+            if let Some(expiry_config) = lockup_config {
+                if expiry_config.is_expired(&env.block) {
+                    return Err(StdError::generic_err("lockup config is already past"));
+                }
+                LOCKUP_CONFIG.save(deps.storage, &expiry_config)?;
+                resp = resp.add_attribute("lockup_config", expiry_config.to_string());
+            }
+
+            if let Some(parties) = parties {
+                validate_parties(deps.api, &parties)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+                PARTIES.save(deps.storage, &parties)?;
+                resp = resp.add_attribute("parties", format!("{parties:?}"));
+            }
+
+            Ok(resp)
+        }
+        MigrateMsg::UpdateCodeId { data: _ } => {
+            // This is a migrate message to update code id,
+            // Data is optional base64 that we can parse to any data we would like in the future
+            // let data: SomeStruct = from_binary(&data)?;
+            Ok(Response::default())
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    // if we get a reply with id u64::MAX, we can assume it is a dequeue message
+    if msg.id == u64::MAX {
+        // Do nothing, whether it fails or not (dequeue messages are "fire & forget" style messages)
+        Ok(Response::default())
+    } else {
+        Err(ContractError::UnexpectedReplyId {})
+    }
+}
@@ -0,0 +1,33 @@
+use cosmwasm_std::StdError;
+use covenant_utils::op_mode::ContractOperationError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    ContractOperationError(#[from] ContractOperationError),
+
+    #[error("Insufficient funds to forward")]
+    InsufficientFunds {},
+
+    #[error("unexpected reply id")]
+    UnexpectedReplyId {},
+
+    #[error("Lockup config must be in the future")]
+    LockupConfigValidationError {},
+
+    #[error("multi party swap requires at least two parties")]
+    PartyCountError {},
+
+    #[error("Party contribution cannot be zero")]
+    PartyContributionConfigError {},
+
+    #[error("Party {0} is configured more than once")]
+    DuplicateParty(String),
+
+    #[error("Contribution denom {0} is provided more than once")]
+    DuplicateContributionDenom(String),
+}
@@ -0,0 +1,159 @@
+use std::collections::BTreeSet;
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Api, Attribute, Binary, Coin, DepsMut, StdError, StdResult, WasmMsg,
+};
+use covenant_macros::{clocked, covenant_deposit_address};
+use covenant_utils::{
+    instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
+};
+use cw_utils::Expiration;
+
+use crate::{error::ContractError, state::CONTRACT_STATE};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    // Contract Operation Mode.
+    // The contract operation (the Tick function mostly) can either be a permissionless
+    // (aka non-privileged) operation, or a permissioned operation, that is,
+    // restricted to being executed by one of the configured privileged accounts.
+    pub op_mode_cfg: ContractOperationModeConfig,
+    /// address of the next contract to forward the funds to.
+    /// usually expected to be the splitter.
+    pub next_contract: String,
+    /// block height of covenant expiration. Parties are refunded
+    /// upon reaching that height if the swap did not settle.
+    pub lockup_config: Expiration,
+    /// parties engaged in the swap along with their deposit terms
+    pub parties: Vec<SwapParty>,
+}
+
+impl InstantiateMsg {
+    pub fn to_instantiate2_msg(
+        &self,
+        instantiate2_helper: &Instantiate2HelperConfig,
+        admin: String,
+        label: String,
+    ) -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Instantiate2 {
+            admin: Some(admin),
+            code_id: instantiate2_helper.code,
+            label,
+            msg: to_json_binary(self)?,
+            funds: vec![],
+            salt: instantiate2_helper.salt.clone(),
+        })
+    }
+
+    pub fn get_response_attributes(self) -> Vec<Attribute> {
+        let mut attrs = vec![
+            Attribute::new("op_mode", format!("{:?}", self.op_mode_cfg)),
+            Attribute::new("next_contract", self.next_contract),
+            Attribute::new("lockup_config", self.lockup_config.to_string()),
+        ];
+        for (i, party) in self.parties.into_iter().enumerate() {
+            attrs.extend(vec![
+                Attribute::new(format!("party_{i}_addr"), party.addr),
+                Attribute::new(
+                    format!("party_{i}_contribution"),
+                    party.contribution.to_string(),
+                ),
+                Attribute::new(format!("party_{i}_refund_address"), party.refund_address),
+            ]);
+        }
+        attrs
+    }
+}
+
+#[cw_serde]
+pub struct SwapParty {
+    /// neutron address of the party, used to identify it
+    pub addr: String,
+    /// coin the party is expected to deposit
+    pub contribution: Coin,
+    /// address receiving the party refunds,
+    /// usually expected to be the party router
+    pub refund_address: String,
+}
+
+/// validates that there are at least two parties, each contributing
+/// a non-zero amount of a denom that no other party contributes
+pub fn validate_parties(api: &dyn Api, parties: &[SwapParty]) -> Result<(), ContractError> {
+    if parties.len() < 2 {
+        return Err(ContractError::PartyCountError {});
+    }
+
+    let mut addrs = BTreeSet::new();
+    let mut denoms = BTreeSet::new();
+    for party in parties {
+        api.addr_validate(&party.refund_address)?;
+        if party.contribution.amount.is_zero() {
+            return Err(ContractError::PartyContributionConfigError {});
+        }
+        if !addrs.insert(party.addr.to_string()) {
+            return Err(ContractError::DuplicateParty(party.addr.to_string()));
+        }
+        if !denoms.insert(party.contribution.denom.to_string()) {
+            return Err(ContractError::DuplicateContributionDenom(
+                party.contribution.denom.to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[clocked]
+#[cw_serde]
+pub enum ExecuteMsg {}
+
+#[covenant_deposit_address]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(String)]
+    NextContract {},
+    #[returns(Expiration)]
+    LockupConfig {},
+    #[returns(Vec<SwapParty>)]
+    Parties {},
+    #[returns(ContractState)]
+    ContractState {},
+    #[returns(covenant_utils::op_mode::ContractOperationMode)]
+    OperationMode {},
+    /// paginated log of the actions performed by the holder
+    #[returns(Vec<covenant_utils::history::HistoryEntry>)]
+    History {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub enum ContractState {
+    Instantiated,
+    /// covenant has reached its expiration date.
+    Expired,
+    /// underlying funds have been forwarded.
+    Complete,
+}
+
+impl ContractState {
+    pub fn complete(deps: DepsMut) -> Result<(), StdError> {
+        CONTRACT_STATE.save(deps.storage, &ContractState::Complete)
+    }
+}
+
+#[cw_serde]
+pub enum MigrateMsg {
+    UpdateConfig {
+        op_mode: Option<ContractOperationModeConfig>,
+        next_contract: Option<String>,
+        lockup_config: Option<Expiration>,
+        parties: Option<Vec<SwapParty>>,
+    },
+    UpdateCodeId {
+        data: Option<Binary>,
+    },
+}
@@ -0,0 +1,13 @@
+use cosmwasm_std::Addr;
+use covenant_utils::op_mode::ContractOperationMode;
+use cw_storage_plus::Item;
+use cw_utils::Expiration;
+
+use crate::msg::{ContractState, SwapParty};
+
+pub const CONTRACT_STATE: Item<ContractState> = Item::new("contract_state");
+pub const CONTRACT_OP_MODE: Item<ContractOperationMode> = Item::new("contract_op_mode");
+pub const NEXT_CONTRACT: Item<Addr> = Item::new("next_contract");
+pub const LOCKUP_CONFIG: Item<Expiration> = Item::new("lockup_config");
+/// parties engaged in the swap along with their deposit terms
+pub const PARTIES: Item<Vec<SwapParty>> = Item::new("parties");
@@ -3,17 +3,18 @@ use std::collections::HashMap;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coins, ensure, to_json_binary, to_json_string, Attribute, Binary, Coin, CosmosMsg, Decimal,
-    Env, Fraction, IbcTimeout, MessageInfo, Response, StdError, StdResult, Uint128, WasmMsg,
+    coins, ensure, to_json_binary, to_json_string, Attribute, Binary, BlockInfo, Coin, CosmosMsg,
+    Decimal, Env, Fraction, IbcTimeout, MessageInfo, Response, StdError, StdResult, Storage,
+    Uint128, WasmMsg,
 };
 use covenant_utils::{
     op_mode::{verify_caller, ContractOperationMode},
     polytone::get_polytone_execute_msg_binary,
-    withdraw_lp_helper::WithdrawLPMsgs,
     ForwardMetadata, PacketMetadata,
 };
 use cw2::set_contract_version;
 use cw_utils::Expiration;
+use liquid_pooler_interface::{LiquidPoolerHolderMsg, LiquidityPosition, ProvidedLiquidityInfo};
 use neutron_sdk::{
     bindings::{
         msg::{IbcFee, NeutronMsg},
@@ -24,21 +25,24 @@ use neutron_sdk::{
     NeutronResult,
 };
 use polytone::callbacks::CallbackRequest;
-use valence_outpost_osmo_liquid_pooler::msg::OutpostWithdrawLiquidityConfig;
+use valence_outpost_osmo_liquid_pooler::msg::{
+    OutpostWithdrawConcentratedLiquidityConfig, OutpostWithdrawLiquidityConfig,
+};
 
 use crate::{
     error::ContractError,
     msg::{
         ContractState, ExecuteMsg, IbcConfig, InstantiateMsg, LiquidityProvisionConfig, MigrateMsg,
-        PartyChainInfo, QueryMsg,
+        PartyChainInfo, PartyDenomInfo, PendingCallback, QueryMsg, StateTimeouts,
     },
     polytone_handlers::{
         get_ibc_pfm_withdraw_coin_message, get_ibc_withdraw_coin_message,
         get_note_execute_neutron_msg, get_proxy_query_balances_message, try_handle_callback,
     },
     state::{
-        CONTRACT_OP_MODE, HOLDER_ADDRESS, IBC_CONFIG, LIQUIDITY_PROVISIONING_CONFIG, NOTE_ADDRESS,
-        POLYTONE_CALLBACKS, PROXY_ADDRESS,
+        CONCENTRATED_LIQUIDITY_POSITION, CONTRACT_OP_MODE, HOLDER_ADDRESS, IBC_CONFIG,
+        LIQUIDITY_PROVISIONING_CONFIG, NOTE_ADDRESS, PENDING_CALLBACK, POLYTONE_CALLBACKS,
+        PROXY_ADDRESS, STATE_DEADLINE, STATE_TIMEOUTS,
     },
 };
 
@@ -91,7 +95,11 @@ pub fn instantiate(
         pool_price_config: msg.pool_price_config,
         funding_duration: msg.funding_duration,
         single_side_lp_limits: msg.single_side_lp_limits,
+        withdraw_price_guard: msg.withdraw_price_guard,
+        tick_range: msg.tick_range,
+        single_asset_exit: msg.single_asset_exit,
     };
+    lp_config.validate()?;
     LIQUIDITY_PROVISIONING_CONFIG.save(deps.storage, &lp_config)?;
 
     let ibc_config = IbcConfig {
@@ -102,11 +110,18 @@ pub fn instantiate(
     };
     IBC_CONFIG.save(deps.storage, &ibc_config)?;
 
+    let state_timeouts = msg
+        .state_timeouts
+        .unwrap_or_else(|| StateTimeouts::from_osmo_ibc_timeout(ibc_config.osmo_ibc_timeout));
+    state_timeouts.validate(ibc_config.osmo_ibc_timeout)?;
+    STATE_TIMEOUTS.save(deps.storage, &state_timeouts)?;
+
     Ok(Response::default()
         .add_attribute("method", "osmosis_lp_instantiate")
         .add_attribute("contract_state", "instantiated")
         .add_attributes(lp_config.to_response_attributes())
         .add_attributes(ibc_config.to_response_attributes())
+        .add_attributes(state_timeouts.to_response_attributes())
         .add_attribute("note_address", note_addr)
         .add_attribute("holder_address", holder_addr)
         .add_attribute("op_mode", format!("{:?}", op_mode)))
@@ -119,6 +134,33 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> NeutronResult<Response<NeutronMsg>> {
+    if let ExecuteMsg::Tick {} = msg {
+        verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+
+        // while a polytone callback is pending there is nothing to do.
+        // once its deadline passes the callback is considered lost, and
+        // the state machine retries the action that requested it.
+        if let Some(pending_callback) = PENDING_CALLBACK.may_load(deps.storage)? {
+            if !pending_callback.deadline.is_expired(&env.block) {
+                return Ok(Response::default()
+                    .add_attribute("method", "tick")
+                    .add_attribute(
+                        "awaiting_callback",
+                        pending_callback.callback_id.to_string(),
+                    )
+                    .add_attribute("callback_deadline", pending_callback.deadline.to_string()));
+            }
+            clear_pending_callback(deps.storage)?;
+        }
+
+        // withdrawals that do not complete in time fall back to recovery
+        if let Some(state_deadline) = STATE_DEADLINE.may_load(deps.storage)? {
+            if state_deadline.is_expired(&env.block) {
+                return try_start_recovery(deps, env);
+            }
+        }
+    }
+
     match (CONTRACT_STATE.load(deps.storage)?, msg) {
         // ticks to instantiated state try to create the proxy
         (ContractState::Instantiated, ExecuteMsg::Tick {}) => try_create_proxy(deps, env, info),
@@ -138,12 +180,127 @@ pub fn execute(
         (ContractState::Distributing { coins }, ExecuteMsg::Tick {}) => {
             try_distribute(deps, env, info, coins)
         }
+        // ticks to recovering state try to distribute the proxy balances
+        (ContractState::Recovering {}, ExecuteMsg::Tick {}) => try_recover(deps, env),
+        // privileged accounts may force a stuck withdrawal into recovery
+        (state, ExecuteMsg::Recover {}) => try_privileged_recovery(deps, env, info, state),
         // callback processing is state-independent
         (_, ExecuteMsg::Callback(callback_msg)) => {
             try_handle_callback(env, deps, info, callback_msg)
         }
         // withdraw processing is state-independent
-        (_, ExecuteMsg::Withdraw { percentage }) => try_initiate_withdrawal(deps, info, percentage),
+        (_, ExecuteMsg::Withdraw { percentage }) => {
+            try_initiate_withdrawal(deps, env, info, percentage)
+        }
+    }
+}
+
+/// poolers instantiated before the state timeouts were introduced
+/// fall back to the defaults derived from the osmosis ibc timeout
+pub(crate) fn get_state_timeouts(storage: &dyn Storage) -> StdResult<StateTimeouts> {
+    match STATE_TIMEOUTS.may_load(storage)? {
+        Some(state_timeouts) => Ok(state_timeouts),
+        None => Ok(StateTimeouts::from_osmo_ibc_timeout(
+            IBC_CONFIG.load(storage)?.osmo_ibc_timeout,
+        )),
+    }
+}
+
+/// saves the contract state. states that must not last
+/// indefinitely get their deadline stamped along.
+pub(crate) fn save_contract_state(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    state: &ContractState,
+) -> StdResult<()> {
+    let state_timeouts = get_state_timeouts(storage)?;
+    match state {
+        ContractState::PendingWithdrawal { .. } => {
+            STATE_DEADLINE.save(storage, &state_timeouts.withdrawal_timeout.after(block))?
+        }
+        ContractState::Distributing { .. } => {
+            STATE_DEADLINE.save(storage, &state_timeouts.distribution_timeout.after(block))?
+        }
+        _ => STATE_DEADLINE.remove(storage),
+    }
+    CONTRACT_STATE.save(storage, state)
+}
+
+/// records the polytone callback that ticks should wait on
+fn await_callback(storage: &mut dyn Storage, block: &BlockInfo, callback_id: u8) -> StdResult<()> {
+    let deadline = get_state_timeouts(storage)?.callback_timeout.after(block);
+    PENDING_CALLBACK.save(
+        storage,
+        &PendingCallback {
+            callback_id,
+            deadline,
+        },
+    )
+}
+
+/// drops the pending callback and resets the proxy balances,
+/// so that the action awaiting it is retried after a fresh sync
+fn clear_pending_callback(storage: &mut dyn Storage) -> StdResult<()> {
+    PENDING_CALLBACK.remove(storage);
+    reset_latest_proxy_balances(storage)
+}
+
+pub(crate) fn reset_latest_proxy_balances(storage: &mut dyn Storage) -> StdResult<()> {
+    LIQUIDITY_PROVISIONING_CONFIG.update(storage, |mut lp_config| -> StdResult<_> {
+        lp_config.reset_latest_proxy_balances();
+        Ok(lp_config)
+    })?;
+    Ok(())
+}
+
+/// moves a stuck withdrawal to `Recovering` and resyncs the proxy
+/// balances, so that whatever the proxy holds can be distributed
+fn try_start_recovery(deps: ExecuteDeps, env: Env) -> NeutronResult<Response<NeutronMsg>> {
+    let stuck_state = CONTRACT_STATE.load(deps.storage)?;
+    save_contract_state(deps.storage, &env.block, &ContractState::Recovering {})?;
+    clear_pending_callback(deps.storage)?;
+
+    Ok(query_proxy_balances(deps, env)?
+        .add_attribute("contract_state", "recovering")
+        .add_attribute("stuck_state", format!("{:?}", stuck_state)))
+}
+
+fn try_privileged_recovery(
+    deps: ExecuteDeps,
+    env: Env,
+    info: MessageInfo,
+    state: ContractState,
+) -> NeutronResult<Response<NeutronMsg>> {
+    // permissionless poolers rely on the state deadlines alone
+    match CONTRACT_OP_MODE.load(deps.storage)? {
+        ContractOperationMode::Permissioned(privileged_accounts)
+            if privileged_accounts.is_privileged(&info.sender) => {}
+        _ => return Err(ContractError::Unauthorized {}.into()),
+    }
+
+    ensure!(
+        matches!(
+            state,
+            ContractState::PendingWithdrawal { .. }
+                | ContractState::Distributing { .. }
+                | ContractState::Recovering {}
+        ),
+        ContractError::StateMachineError(format!(
+            "recovery is only available during withdrawals, current state: {:?}",
+            state
+        ))
+    );
+
+    try_start_recovery(deps, env)
+}
+
+fn try_recover(deps: ExecuteDeps, env: Env) -> NeutronResult<Response<NeutronMsg>> {
+    let lp_config = LIQUIDITY_PROVISIONING_CONFIG.load(deps.storage)?;
+    match lp_config.get_proxy_balances() {
+        Some((party_1_bal, party_2_bal, _)) => {
+            withdraw_party_denoms(deps, &env, party_1_bal, party_2_bal)
+        }
+        None => query_proxy_balances(deps, env),
     }
 }
 
@@ -179,10 +336,18 @@ fn try_confirm_liquidity_provision(
     // Active. it will enable withdrawals and start pulling
     // any non-LP tokens from proxy back to this contract.
     if funding_expiration.is_expired(&env.block) {
-        CONTRACT_STATE.save(deps.storage, &ContractState::Active)?;
+        save_contract_state(deps.storage, &env.block, &ContractState::Active)?;
         Ok(Response::default()
             .add_attribute("method", "tick")
             .add_attribute("contract_state", "active"))
+    } else if LIQUIDITY_PROVISIONING_CONFIG
+        .load(deps.storage)?
+        .get_proxy_balances()
+        .is_none()
+    {
+        // liquidity is provided with the latest proxy balances,
+        // so we resync them if unknown
+        query_proxy_balances(deps, env)
     } else {
         // otherwise we attempt to provide liquidity
         try_provide_liquidity(deps, env, info)
@@ -192,6 +357,7 @@ fn try_confirm_liquidity_provision(
 /// we initiate the withdrawal phase by setting the contract state to `PendingWithdrawal`
 fn try_initiate_withdrawal(
     deps: ExecuteDeps,
+    env: Env,
     info: MessageInfo,
     percentage: Option<Decimal>,
 ) -> NeutronResult<Response<NeutronMsg>> {
@@ -208,8 +374,9 @@ fn try_initiate_withdrawal(
     );
 
     // we advance the contract state to `PendingWithdrawal` and force latest balances sync
-    CONTRACT_STATE.save(
+    save_contract_state(
         deps.storage,
+        &env.block,
         &ContractState::PendingWithdrawal {
             share: withdraw_share,
         },
@@ -232,6 +399,7 @@ fn try_initiate_withdrawal(
 /// to active state.
 fn withdraw_party_denoms(
     deps: ExecuteDeps,
+    env: &Env,
     p1_proxy_bal: &Coin,
     p2_proxy_bal: &Coin,
 ) -> NeutronResult<Response<NeutronMsg>> {
@@ -246,10 +414,10 @@ fn withdraw_party_denoms(
         // and ping a WithdrawFailed message to the holder.
         let withdraw_failed_msg = WasmMsg::Execute {
             contract_addr: HOLDER_ADDRESS.load(deps.storage)?.to_string(),
-            msg: to_json_binary(&WithdrawLPMsgs::WithdrawFailed {})?,
+            msg: to_json_binary(&LiquidPoolerHolderMsg::WithdrawFailed {})?,
             funds: vec![],
         };
-        CONTRACT_STATE.save(deps.storage, &ContractState::Active)?;
+        save_contract_state(deps.storage, &env.block, &ContractState::Active)?;
         Ok(Response::default()
             .add_attribute("method", "withdraw_party_denoms")
             .add_attribute("contract_state", "active")
@@ -261,8 +429,9 @@ fn withdraw_party_denoms(
         // this will cause incoming ticks to assert whether this contract had received the funds.
         // if the funds are not received, the tick will attempt to withdraw them again.
         // if all expected coins are received, the contract will submit `Distribute` message to the holder.
-        CONTRACT_STATE.save(
+        save_contract_state(
             deps.storage,
+            &env.block,
             &ContractState::Distributing {
                 coins: withdraw_coins.clone(),
             },
@@ -290,10 +459,50 @@ pub fn try_withdraw(
     let note_address = NOTE_ADDRESS.load(deps.storage)?;
     let ibc_config = IBC_CONFIG.load(deps.storage)?;
 
+    // concentrated liquidity positions are withdrawn by their id
+    if lp_config.tick_range.is_some() {
+        return match CONCENTRATED_LIQUIDITY_POSITION.may_load(deps.storage)? {
+            Some(position_id) => {
+                let withdraw_position_message: CosmosMsg = WasmMsg::Execute {
+                    contract_addr: lp_config.outpost.to_string(),
+                    msg: to_json_binary(
+                        &valence_outpost_osmo_liquid_pooler::msg::ExecuteMsg::WithdrawConcentratedLiquidity {
+                            config: OutpostWithdrawConcentratedLiquidityConfig {
+                                position_id,
+                                percentage: withdraw_share,
+                                price_guard: lp_config.get_osmo_outpost_withdraw_price_guard(),
+                            },
+                        },
+                    )?,
+                    funds: vec![],
+                }
+                .into();
+
+                await_callback(deps.storage, &env.block, WITHDRAW_LIQUIDITY_CALLBACK_ID)?;
+                let withdraw_position_note_msg = get_note_execute_neutron_msg(
+                    vec![withdraw_position_message],
+                    ibc_config.osmo_ibc_timeout,
+                    note_address,
+                    Some(CallbackRequest {
+                        receiver: env.contract.address.to_string(),
+                        msg: to_json_binary(&WITHDRAW_LIQUIDITY_CALLBACK_ID)?,
+                    }),
+                )?;
+
+                Ok(Response::default()
+                    .add_attribute("method", "try_withdraw")
+                    .add_attribute("position_id", position_id)
+                    .add_message(withdraw_position_note_msg))
+            }
+            // no position was created, we withdraw the party denoms directly
+            None => withdraw_party_denoms(deps, &env, party_1_bal, party_2_bal),
+        };
+    }
+
     // if there are 0 available lp token balances, we attempt to
     // withdraw the party denoms directly.
     if lp_bal.amount.is_zero() {
-        return withdraw_party_denoms(deps, party_1_bal, party_2_bal);
+        return withdraw_party_denoms(deps, &env, party_1_bal, party_2_bal);
     }
 
     let lp_redeem_amount = lp_bal
@@ -307,6 +516,8 @@ pub fn try_withdraw(
             &valence_outpost_osmo_liquid_pooler::msg::ExecuteMsg::WithdrawLiquidity {
                 config: OutpostWithdrawLiquidityConfig {
                     pool_id: lp_config.pool_id,
+                    price_guard: lp_config.get_osmo_outpost_withdraw_price_guard(),
+                    single_asset_exit: lp_config.single_asset_exit.clone(),
                 },
             },
         )?,
@@ -326,6 +537,7 @@ pub fn try_withdraw(
         &to_json_string(&exit_pool_message)?,
     )?;
 
+    await_callback(deps.storage, &env.block, WITHDRAW_LIQUIDITY_CALLBACK_ID)?;
     let exit_pool_note_msg = CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: note_address.to_string(),
         msg: get_polytone_execute_msg_binary(
@@ -391,7 +603,7 @@ fn try_distribute(
     // send tokens to holder and revert state to active
     if denom_1_withdrawn && denom_2_withdrawn {
         let holder_addr = HOLDER_ADDRESS.load(deps.storage)?;
-        CONTRACT_STATE.save(deps.storage, &ContractState::Active)?;
+        save_contract_state(deps.storage, &env.block, &ContractState::Active)?;
 
         // submit a Distribute message to the holder,
         // which carries the withdrawn coins along.
@@ -399,8 +611,12 @@ fn try_distribute(
         // the withdrawal flow had been handled successfully.
         let holder_distribute_callback_msg = WasmMsg::Execute {
             contract_addr: holder_addr.to_string(),
-            msg: to_json_binary(&WithdrawLPMsgs::Distribute {})?,
-            funds: vec![denom_1_balance, denom_2_balance],
+            msg: to_json_binary(&LiquidPoolerHolderMsg::Distribute {})?,
+            // positions exited out of range return a single denom
+            funds: vec![denom_1_balance, denom_2_balance]
+                .into_iter()
+                .filter(|c| !c.amount.is_zero())
+                .collect(),
         };
 
         // reset the proxy balances to trigger a query
@@ -545,6 +761,7 @@ fn try_sync_proxy_balances(
     let proxy_address = PROXY_ADDRESS.load(deps.storage)?;
 
     // get the message to query proxy for its balances
+    await_callback(deps.storage, &env.block, PROXY_BALANCES_QUERY_CALLBACK_ID)?;
     let note_query_balances_msg = get_proxy_query_balances_message(
         env,
         proxy_address,
@@ -578,6 +795,7 @@ fn try_create_proxy(
     let note_address = NOTE_ADDRESS.load(deps.storage)?;
     let ibc_config = IBC_CONFIG.load(deps.storage)?;
 
+    await_callback(deps.storage, &env.block, CREATE_PROXY_CALLBACK_ID)?;
     let polytone_execute_msg_binary = get_polytone_execute_msg_binary(
         vec![],
         Some(CallbackRequest {
@@ -619,8 +837,9 @@ fn try_deliver_funds(
                 // otherwise we advance the state machine and store an
                 // expiration time for the funding period
                 let funding_expiration = lp_config.funding_duration.after(&env.block);
-                CONTRACT_STATE.save(
+                save_contract_state(
                     deps.storage,
+                    &env.block,
                     &ContractState::ProxyFunded { funding_expiration },
                 )?;
                 Ok(Response::default()
@@ -713,7 +932,10 @@ fn try_provide_liquidity(
 
     // we generate a provide_liquidity message for the outpost
     // and wrap it in a note message
-    let outpost_msg = lp_config.get_osmo_outpost_provide_liquidity_message()?;
+    let outpost_msg = lp_config.get_osmo_outpost_provide_liquidity_message(
+        CONCENTRATED_LIQUIDITY_POSITION.may_load(deps.storage)?,
+    )?;
+    await_callback(deps.storage, &env.block, PROVIDE_LIQUIDITY_CALLBACK_ID)?;
     let note_outpost_liquidity_msg = get_note_execute_neutron_msg(
         vec![outpost_msg],
         ibc_config.osmo_ibc_timeout,
@@ -752,6 +974,7 @@ fn query_proxy_balances(deps: ExecuteDeps, env: Env) -> NeutronResult<Response<N
     let proxy_address = PROXY_ADDRESS.load(deps.storage)?;
     let lp_config = LIQUIDITY_PROVISIONING_CONFIG.load(deps.storage)?;
 
+    await_callback(deps.storage, &env.block, PROXY_BALANCES_QUERY_CALLBACK_ID)?;
     let note_balance_query_msg = get_proxy_query_balances_message(
         env,
         proxy_address,
@@ -846,9 +1069,63 @@ pub fn query(deps: QueryDeps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             Ok(to_json_binary(&vals)?)
         }
         QueryMsg::OperationMode {} => Ok(to_json_binary(&CONTRACT_OP_MODE.load(deps.storage)?)?),
+        QueryMsg::ProvidedLiquidityInfo {} => {
+            Ok(to_json_binary(&query_provided_liquidity_info(deps)?)?)
+        }
+        QueryMsg::LiquidityPosition {} => {
+            let lp_config = LIQUIDITY_PROVISIONING_CONFIG.load(deps.storage)?;
+            let amount = match lp_config.tick_range {
+                // concentrated liquidity positions are not fungible,
+                // so we report the number of positions held
+                Some(_) => match CONCENTRATED_LIQUIDITY_POSITION.may_load(deps.storage)? {
+                    Some(_) => Uint128::one(),
+                    None => Uint128::zero(),
+                },
+                // lp token balance as of the latest proxy balances query
+                None => lp_config
+                    .get_lp_token_proxy_balance()
+                    .map(|c| c.amount)
+                    .unwrap_or_default(),
+            };
+            Ok(to_json_binary(&LiquidityPosition {
+                lp_token: lp_config.lp_token_denom,
+                amount,
+            })?)
+        }
+        QueryMsg::StateTimeouts {} => Ok(to_json_binary(&get_state_timeouts(deps.storage)?)?),
+        QueryMsg::PendingCallback {} => {
+            Ok(to_json_binary(&PENDING_CALLBACK.may_load(deps.storage)?)?)
+        }
+        QueryMsg::StateDeadline {} => Ok(to_json_binary(&STATE_DEADLINE.may_load(deps.storage)?)?),
     }
 }
 
+/// the party contributions are provided to the pool in full once
+/// the pooler becomes active, until the position gets withdrawn
+fn query_provided_liquidity_info(deps: QueryDeps) -> StdResult<ProvidedLiquidityInfo> {
+    let lp_config = LIQUIDITY_PROVISIONING_CONFIG.load(deps.storage)?;
+    let provided = matches!(
+        CONTRACT_STATE.load(deps.storage)?,
+        ContractState::Active | ContractState::PendingWithdrawal { .. }
+    );
+    let provided_coin = |denom_info: &PartyDenomInfo| {
+        let amount = if provided {
+            denom_info.get_osmo_bal()
+        } else {
+            Uint128::zero()
+        };
+        Coin {
+            denom: denom_info.local_denom.to_string(),
+            amount,
+        }
+    };
+
+    Ok(ProvidedLiquidityInfo {
+        provided_coin_a: provided_coin(&lp_config.party_1_denom_info),
+        provided_coin_b: provided_coin(&lp_config.party_2_denom_info),
+    })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: ExecuteDeps, _env: Env, msg: MigrateMsg) -> NeutronResult<Response> {
     match msg {
@@ -858,8 +1135,10 @@ pub fn migrate(deps: ExecuteDeps, _env: Env, msg: MigrateMsg) -> NeutronResult<R
             note_address,
             ibc_config,
             lp_config,
+            state_timeouts,
         } => {
             let mut response = Response::default().add_attribute("method", "update_config");
+            let validate_state_timeouts = ibc_config.is_some() || state_timeouts.is_some();
 
             if let Some(op_mode_cfg) = op_mode {
                 let updated_op_mode = ContractOperationMode::try_init(deps.api, op_mode_cfg)
@@ -886,10 +1165,22 @@ pub fn migrate(deps: ExecuteDeps, _env: Env, msg: MigrateMsg) -> NeutronResult<R
             }
 
             if let Some(config) = *lp_config {
+                config.validate()?;
                 LIQUIDITY_PROVISIONING_CONFIG.save(deps.storage, &config)?;
                 response = response.add_attributes(config.to_response_attributes());
             }
 
+            if let Some(state_timeouts) = state_timeouts {
+                STATE_TIMEOUTS.save(deps.storage, &state_timeouts)?;
+                response = response.add_attributes(state_timeouts.to_response_attributes());
+            }
+
+            // the state timeouts are validated against the resulting ibc config
+            if validate_state_timeouts {
+                get_state_timeouts(deps.storage)?
+                    .validate(IBC_CONFIG.load(deps.storage)?.osmo_ibc_timeout)?;
+            }
+
             Ok(response)
         }
         MigrateMsg::UpdateCodeId { data: _ } => {
@@ -2,17 +2,26 @@ use std::collections::HashMap;
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
-    to_json_binary, Attribute, Binary, Coin, CosmosMsg, Decimal, StdResult, Uint128, Uint64,
-    WasmMsg,
+    ensure, to_json_binary, Attribute, Binary, Coin, CosmosMsg, Decimal, StdResult, Uint128,
+    Uint64, WasmMsg,
 };
 use covenant_macros::{clocked, covenant_deposit_address, covenant_lper_withdraw};
 use covenant_utils::{
     instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
-    ForwardMetadata, PoolPriceConfig, SingleSideLpLimits,
+    ForwardMetadata, PoolPriceConfig, SingleSideLpLimits, WithdrawPriceGuard,
 };
 use cw_utils::{Duration, Expiration};
+use liquid_pooler_interface::{
+    LiquidPoolerExecuteMsg, LiquidPoolerInstantiate, LiquidPoolerInstantiateFields,
+    LiquidPoolerQueryMsg, LiquidityPosition, ProvidedLiquidityInfo,
+};
 use polytone::callbacks::CallbackMessage;
-use valence_outpost_osmo_liquid_pooler::msg::OutpostProvideLiquidityConfig;
+
+use crate::error::ContractError;
+use valence_outpost_osmo_liquid_pooler::msg::{
+    OutpostProvideConcentratedLiquidityConfig, OutpostProvideLiquidityConfig,
+    OutpostSingleAssetExit, OutpostWithdrawPriceGuard, TickRange,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -36,6 +45,18 @@ pub struct InstantiateMsg {
     pub pool_price_config: PoolPriceConfig,
     pub funding_duration: Duration,
     pub single_side_lp_limits: SingleSideLpLimits,
+    /// guard against exiting a manipulated pool upon withdrawal.
+    /// pool is exited at any price if not set
+    pub withdraw_price_guard: Option<WithdrawPriceGuard>,
+    /// tick range of the position if the pool is a concentrated liquidity pool.
+    /// must not be set for gamm pools
+    pub tick_range: Option<TickRange>,
+    /// deadlines used to detect and recover from stuck states.
+    /// derived from `osmo_ibc_timeout` if not set
+    pub state_timeouts: Option<StateTimeouts>,
+    /// exits gamm pools entirely into one of the party denoms upon withdrawal.
+    /// both party denoms are received if not set
+    pub single_asset_exit: Option<OutpostSingleAssetExit>,
 }
 
 impl InstantiateMsg {
@@ -70,6 +91,10 @@ pub struct OsmosisLiquidPoolerConfig {
     pub party_2_denom_info: PartyDenomInfo,
     pub funding_duration: Duration,
     pub single_side_lp_limits: SingleSideLpLimits,
+    pub withdraw_price_guard: Option<WithdrawPriceGuard>,
+    pub tick_range: Option<TickRange>,
+    pub state_timeouts: Option<StateTimeouts>,
+    pub single_asset_exit: Option<OutpostSingleAssetExit>,
 }
 
 impl OsmosisLiquidPoolerConfig {
@@ -96,10 +121,24 @@ impl OsmosisLiquidPoolerConfig {
             pool_price_config,
             funding_duration: self.funding_duration,
             single_side_lp_limits: self.single_side_lp_limits.clone(),
+            withdraw_price_guard: self.withdraw_price_guard.clone(),
+            tick_range: self.tick_range.clone(),
+            state_timeouts: self.state_timeouts.clone(),
+            single_asset_exit: self.single_asset_exit.clone(),
         }
     }
 }
 
+impl LiquidPoolerInstantiate for OsmosisLiquidPoolerConfig {
+    fn to_instantiate_binary(&self, fields: LiquidPoolerInstantiateFields) -> StdResult<Binary> {
+        to_json_binary(&self.to_instantiate_msg(
+            fields.op_mode_cfg,
+            fields.holder_address,
+            fields.pool_price_config,
+        ))
+    }
+}
+
 #[cw_serde]
 pub struct LiquidityProvisionConfig {
     pub latest_balances: HashMap<String, Coin>,
@@ -112,6 +151,11 @@ pub struct LiquidityProvisionConfig {
     pub pool_price_config: PoolPriceConfig,
     pub funding_duration: Duration,
     pub single_side_lp_limits: SingleSideLpLimits,
+    pub withdraw_price_guard: Option<WithdrawPriceGuard>,
+    /// set for concentrated liquidity pools
+    pub tick_range: Option<TickRange>,
+    /// gamm pools only
+    pub single_asset_exit: Option<OutpostSingleAssetExit>,
 }
 
 #[cw_serde]
@@ -122,6 +166,81 @@ pub struct IbcConfig {
     pub osmo_ibc_timeout: Uint64,
 }
 
+/// deadlines after which the state machine considers itself stuck
+#[cw_serde]
+pub struct StateTimeouts {
+    /// time to wait for a polytone callback before retrying
+    /// the action that requested it
+    pub callback_timeout: Duration,
+    /// time allowed to complete a withdrawal before
+    /// falling back to recovery
+    pub withdrawal_timeout: Duration,
+    /// time allowed to route the withdrawn funds back and
+    /// distribute them before falling back to recovery
+    pub distribution_timeout: Duration,
+}
+
+impl StateTimeouts {
+    /// defaults used when no timeouts are configured. polytone
+    /// callbacks are expected within two ibc timeouts, while
+    /// withdrawals and distributions span multiple ibc round trips.
+    pub fn from_osmo_ibc_timeout(osmo_ibc_timeout: Uint64) -> Self {
+        StateTimeouts {
+            callback_timeout: Duration::Time(osmo_ibc_timeout.u64() * 2),
+            withdrawal_timeout: Duration::Time(osmo_ibc_timeout.u64() * 10),
+            distribution_timeout: Duration::Time(osmo_ibc_timeout.u64() * 10),
+        }
+    }
+
+    pub fn validate(&self, osmo_ibc_timeout: Uint64) -> Result<(), ContractError> {
+        for timeout in [
+            &self.callback_timeout,
+            &self.withdrawal_timeout,
+            &self.distribution_timeout,
+        ] {
+            if let Duration::Height(0) | Duration::Time(0) = timeout {
+                return Err(ContractError::StateMachineError(
+                    "state timeouts must be non-zero".to_string(),
+                ));
+            }
+        }
+
+        // callbacks may only be considered lost once the
+        // polytone packets requesting them have timed out
+        ensure!(
+            matches!(
+                self.callback_timeout,
+                Duration::Time(timeout) if timeout > osmo_ibc_timeout.u64()
+            ),
+            ContractError::StateMachineError(format!(
+                "callback timeout must exceed the osmo ibc timeout of {osmo_ibc_timeout} seconds"
+            ))
+        );
+        Ok(())
+    }
+
+    pub fn to_response_attributes(&self) -> Vec<Attribute> {
+        vec![
+            Attribute::new("callback_timeout", format!("{:?}", self.callback_timeout)),
+            Attribute::new(
+                "withdrawal_timeout",
+                format!("{:?}", self.withdrawal_timeout),
+            ),
+            Attribute::new(
+                "distribution_timeout",
+                format!("{:?}", self.distribution_timeout),
+            ),
+        ]
+    }
+}
+
+/// polytone callback the state machine is waiting on
+#[cw_serde]
+pub struct PendingCallback {
+    pub callback_id: u8,
+    pub deadline: Expiration,
+}
+
 impl IbcConfig {
     pub fn to_response_attributes(self) -> Vec<Attribute> {
         let mut attributes = vec![
@@ -145,6 +264,60 @@ impl IbcConfig {
 }
 
 impl LiquidityProvisionConfig {
+    /// validates the concentrated liquidity position, single asset
+    /// join and exit, and withdraw price guard configurations
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if let Some(tick_range) = &self.tick_range {
+            tick_range
+                .validate_bounds()
+                .map_err(|e| ContractError::OsmosisPoolError(e.to_string()))?;
+            ensure!(
+                !matches!(
+                    self.withdraw_price_guard,
+                    Some(WithdrawPriceGuard::MinAssetsPerShare { .. })
+                ),
+                ContractError::OsmosisPoolError(
+                    "min assets per share withdraw price guard does not apply to positions"
+                        .to_string()
+                )
+            );
+            ensure!(
+                self.single_asset_exit.is_none(),
+                ContractError::OsmosisPoolError(
+                    "single asset exits do not apply to positions".to_string()
+                )
+            );
+        }
+
+        if let Some(single_asset_exit) = &self.single_asset_exit {
+            let denom = &single_asset_exit.denom;
+            ensure!(
+                denom == &self.party_1_denom_info.osmosis_coin.denom
+                    || denom == &self.party_2_denom_info.osmosis_coin.denom,
+                ContractError::OsmosisPoolError(format!("{denom} is not a party denom"))
+            );
+            ensure!(
+                single_asset_exit.slippage_tolerance < Decimal::one(),
+                ContractError::OsmosisPoolError(
+                    "single asset exit slippage tolerance must be below 1".to_string()
+                )
+            );
+        }
+
+        // the price range must not exceed the decimal bounds
+        if let Some(WithdrawPriceGuard::PriceRange(pool_price_config)) = &self.withdraw_price_guard
+        {
+            pool_price_config
+                .expected_spot_price
+                .checked_sub(pool_price_config.acceptable_price_spread)?;
+            pool_price_config
+                .expected_spot_price
+                .checked_add(pool_price_config.acceptable_price_spread)?;
+        }
+
+        Ok(())
+    }
+
     pub fn get_party_1_proxy_balance(&self) -> Option<&Coin> {
         self.latest_balances
             .get(&self.party_1_denom_info.osmosis_coin.denom)
@@ -159,32 +332,77 @@ impl LiquidityProvisionConfig {
         self.latest_balances.get(&self.lp_token_denom)
     }
 
-    pub fn get_osmo_outpost_provide_liquidity_message(&self) -> StdResult<CosmosMsg> {
-        let mut funds = vec![];
-        if let Some(c) = self.get_party_1_proxy_balance() {
-            funds.push(c.clone());
-        }
-        if let Some(c) = self.get_party_2_proxy_balance() {
-            funds.push(c.clone());
-        }
+    /// maps the withdraw price guard onto the outpost one.
+    /// party 1 and 2 denoms correspond to outpost pool assets 1 and 2
+    pub fn get_osmo_outpost_withdraw_price_guard(&self) -> Option<OutpostWithdrawPriceGuard> {
+        self.withdraw_price_guard.as_ref().map(|guard| match guard {
+            WithdrawPriceGuard::PriceRange(pool_price_config) => {
+                OutpostWithdrawPriceGuard::PriceRange {
+                    expected_spot_price: pool_price_config.expected_spot_price,
+                    acceptable_price_spread: pool_price_config.acceptable_price_spread,
+                }
+            }
+            WithdrawPriceGuard::MinAssetsPerShare { asset_a, asset_b } => {
+                OutpostWithdrawPriceGuard::MinAssetsPerShare {
+                    asset_1: *asset_a,
+                    asset_2: *asset_b,
+                }
+            }
+        })
+    }
 
-        let outpost_config = OutpostProvideLiquidityConfig {
-            pool_id: Uint64::new(self.pool_id.u64()),
-            expected_spot_price: self.pool_price_config.expected_spot_price,
-            acceptable_price_spread: self.pool_price_config.acceptable_price_spread,
-            // if no slippage tolerance is passed, we use 0
-            slippage_tolerance: self.slippage_tolerance.unwrap_or_default(),
-            asset_1_single_side_lp_limit: self.single_side_lp_limits.asset_a_limit,
-            asset_2_single_side_lp_limit: self.single_side_lp_limits.asset_b_limit,
+    /// concentrated liquidity provisions are added to the
+    /// existing position, if any
+    pub fn get_osmo_outpost_provide_liquidity_message(
+        &self,
+        position_id: Option<Uint64>,
+    ) -> StdResult<CosmosMsg> {
+        let funds: Vec<Coin> = [
+            self.get_party_1_proxy_balance(),
+            self.get_party_2_proxy_balance(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|c| !c.amount.is_zero())
+        .cloned()
+        .collect();
+
+        // if the proxy holds only one of the party denoms,
+        // the gamm pool is joined with that denom alone
+        let single_asset_join_denom = match funds.as_slice() {
+            [single_coin] => Some(single_coin.denom.to_string()),
+            _ => None,
+        };
+
+        let outpost_msg = match &self.tick_range {
+            Some(tick_range) => {
+                valence_outpost_osmo_liquid_pooler::msg::ExecuteMsg::ProvideConcentratedLiquidity {
+                    config: OutpostProvideConcentratedLiquidityConfig {
+                        pool_id: Uint64::new(self.pool_id.u64()),
+                        tick_range: tick_range.clone(),
+                        expected_spot_price: self.pool_price_config.expected_spot_price,
+                        acceptable_price_spread: self.pool_price_config.acceptable_price_spread,
+                        position_id,
+                    },
+                }
+            }
+            None => valence_outpost_osmo_liquid_pooler::msg::ExecuteMsg::ProvideLiquidity {
+                config: OutpostProvideLiquidityConfig {
+                    pool_id: Uint64::new(self.pool_id.u64()),
+                    expected_spot_price: self.pool_price_config.expected_spot_price,
+                    acceptable_price_spread: self.pool_price_config.acceptable_price_spread,
+                    // if no slippage tolerance is passed, we use 0
+                    slippage_tolerance: self.slippage_tolerance.unwrap_or_default(),
+                    asset_1_single_side_lp_limit: self.single_side_lp_limits.asset_a_limit,
+                    asset_2_single_side_lp_limit: self.single_side_lp_limits.asset_b_limit,
+                    single_asset_join_denom,
+                },
+            },
         };
 
         Ok(WasmMsg::Execute {
             contract_addr: self.outpost.to_string(),
-            msg: to_json_binary(
-                &valence_outpost_osmo_liquid_pooler::msg::ExecuteMsg::ProvideLiquidity {
-                    config: outpost_config,
-                },
-            )?,
+            msg: to_json_binary(&outpost_msg)?,
             funds,
         }
         .into())
@@ -247,6 +465,26 @@ impl LiquidityProvisionConfig {
             self.party_1_denom_info
                 .to_response_attributes("party_2".to_string()),
         );
+        if let Some(single_asset_exit) = self.single_asset_exit {
+            attributes.push(Attribute::new(
+                "single_asset_exit_denom",
+                single_asset_exit.denom,
+            ));
+            attributes.push(Attribute::new(
+                "single_asset_exit_slippage_tolerance",
+                single_asset_exit.slippage_tolerance.to_string(),
+            ));
+        }
+        if let Some(tick_range) = self.tick_range {
+            attributes.push(Attribute::new(
+                "lower_tick",
+                tick_range.lower_tick.to_string(),
+            ));
+            attributes.push(Attribute::new(
+                "upper_tick",
+                tick_range.upper_tick.to_string(),
+            ));
+        }
         attributes.extend(proxy_bals);
 
         attributes
@@ -286,6 +524,18 @@ impl PartyDenomInfo {
 pub enum ExecuteMsg {
     // polytone callback listener
     Callback(CallbackMessage),
+    /// privileged escape hatch for a stuck withdrawal. resyncs the
+    /// proxy balances and distributes whatever the proxy holds
+    Recover {},
+}
+
+impl From<LiquidPoolerExecuteMsg> for ExecuteMsg {
+    fn from(msg: LiquidPoolerExecuteMsg) -> Self {
+        match msg {
+            LiquidPoolerExecuteMsg::Tick {} => ExecuteMsg::Tick {},
+            LiquidPoolerExecuteMsg::Withdraw { percentage } => ExecuteMsg::Withdraw { percentage },
+        }
+    }
 }
 
 #[covenant_deposit_address]
@@ -306,6 +556,26 @@ pub enum QueryMsg {
     Callbacks {},
     #[returns(covenant_utils::op_mode::ContractOperationMode)]
     OperationMode {},
+    #[returns(ProvidedLiquidityInfo)]
+    ProvidedLiquidityInfo {},
+    #[returns(LiquidityPosition)]
+    LiquidityPosition {},
+    #[returns(StateTimeouts)]
+    StateTimeouts {},
+    #[returns(Option<PendingCallback>)]
+    PendingCallback {},
+    #[returns(Option<Expiration>)]
+    StateDeadline {},
+}
+
+impl From<LiquidPoolerQueryMsg> for QueryMsg {
+    fn from(msg: LiquidPoolerQueryMsg) -> Self {
+        match msg {
+            LiquidPoolerQueryMsg::DepositAddress {} => QueryMsg::DepositAddress {},
+            LiquidPoolerQueryMsg::ProvidedLiquidityInfo {} => QueryMsg::ProvidedLiquidityInfo {},
+            LiquidPoolerQueryMsg::LiquidityPosition {} => QueryMsg::LiquidityPosition {},
+        }
+    }
 }
 
 /// state of the LP state machine
@@ -313,10 +583,19 @@ pub enum QueryMsg {
 pub enum ContractState {
     Instantiated,
     ProxyCreated,
-    ProxyFunded { funding_expiration: Expiration },
+    ProxyFunded {
+        funding_expiration: Expiration,
+    },
     Active,
-    Distributing { coins: Vec<Coin> },
-    PendingWithdrawal { share: Decimal },
+    Distributing {
+        coins: Vec<Coin>,
+    },
+    PendingWithdrawal {
+        share: Decimal,
+    },
+    /// withdrawal got stuck. proxy balances are being resynced
+    /// in order to distribute whatever the proxy holds
+    Recovering {},
 }
 
 #[cw_serde]
@@ -377,6 +656,7 @@ pub enum MigrateMsg {
         note_address: Option<String>,
         ibc_config: Box<Option<IbcConfig>>,
         lp_config: Box<Option<LiquidityProvisionConfig>>,
+        state_timeouts: Option<StateTimeouts>,
     },
     UpdateCodeId {
         data: Option<Binary>,
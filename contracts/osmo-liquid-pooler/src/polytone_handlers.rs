@@ -2,15 +2,13 @@ use std::str::FromStr;
 
 use cosmwasm_std::{
     coin, ensure, from_json, to_json_binary, Addr, Binary, Coin, CosmosMsg, DepsMut, Empty, Env,
-    IbcMsg, IbcTimeout, MessageInfo, QueryRequest, Response, StdResult, Uint128, Uint64, WasmMsg,
+    IbcMsg, IbcTimeout, MessageInfo, QueryRequest, Response, StdResult, Storage, Uint128, Uint64,
+    WasmMsg,
 };
-use covenant_utils::{
-    polytone::{
-        get_polytone_execute_msg_binary, get_polytone_query_msg_binary,
-        query_polytone_proxy_address,
-    },
-    withdraw_lp_helper::WithdrawLPMsgs,
+use covenant_utils::polytone::{
+    get_polytone_execute_msg_binary, get_polytone_query_msg_binary, query_polytone_proxy_address,
 };
+use liquid_pooler_interface::LiquidPoolerHolderMsg;
 use neutron_sdk::{
     bindings::{msg::NeutronMsg, query::NeutronQuery},
     NeutronResult,
@@ -19,14 +17,16 @@ use osmosis_std::types::cosmos::bank::v1beta1::QueryBalanceResponse;
 
 use crate::{
     contract::{
-        CREATE_PROXY_CALLBACK_ID, PROVIDE_LIQUIDITY_CALLBACK_ID, PROXY_BALANCES_QUERY_CALLBACK_ID,
+        reset_latest_proxy_balances, save_contract_state, CREATE_PROXY_CALLBACK_ID,
+        PROVIDE_LIQUIDITY_CALLBACK_ID, PROXY_BALANCES_QUERY_CALLBACK_ID,
         WITHDRAW_LIQUIDITY_CALLBACK_ID,
     },
     error::ContractError,
     msg::{ContractState, IbcConfig, LiquidityProvisionConfig},
     state::{
-        CONTRACT_STATE, HOLDER_ADDRESS, LIQUIDITY_PROVISIONING_CONFIG, NOTE_ADDRESS,
-        POLYTONE_CALLBACKS, PROXY_ADDRESS,
+        CONCENTRATED_LIQUIDITY_POSITION, CONTRACT_STATE, HOLDER_ADDRESS,
+        LIQUIDITY_PROVISIONING_CONFIG, NOTE_ADDRESS, PENDING_CALLBACK, POLYTONE_CALLBACKS,
+        PROXY_ADDRESS,
     },
 };
 
@@ -50,6 +50,15 @@ pub fn try_handle_callback(
         ContractError::Unauthorized {}
     );
 
+    // the callback we were waiting on has arrived, ticks can resume
+    let callback_id: u8 = from_json(&msg.initiator_msg)?;
+    if PENDING_CALLBACK
+        .may_load(deps.storage)?
+        .is_some_and(|pending_callback| pending_callback.callback_id == callback_id)
+    {
+        PENDING_CALLBACK.remove(deps.storage);
+    }
+
     match msg.result {
         PolytoneCallback::Query(resp) => process_query_callback(env, deps, resp, msg.initiator_msg),
         PolytoneCallback::Execute(resp) => {
@@ -89,7 +98,19 @@ fn process_execute_callback(
     let initiator_msg: u8 = from_json(initiator_msg)?;
     let callback_result: ExecutionResponse = match execute_callback_result {
         Ok(val) => val,
-        Err(e) => return Err(ContractError::PolytoneError(e).into()),
+        Err(e) => {
+            // the remote execution failed. we record the error and
+            // resync the proxy balances before the next attempt
+            POLYTONE_CALLBACKS.save(
+                deps.storage,
+                format!("execute_error : {:?}", env.block.height.to_string()),
+                &e,
+            )?;
+            reset_latest_proxy_balances(deps.storage)?;
+            return Ok(Response::default()
+                .add_attribute("method", "process_execute_callback")
+                .add_attribute("execute_error", e));
+        }
     };
 
     match initiator_msg {
@@ -103,6 +124,8 @@ fn process_execute_callback(
                 &to_json_binary(&callback_result)?.to_string(),
             )?;
 
+            update_concentrated_liquidity_position(deps.storage, &callback_result)?;
+
             for submsg_response in callback_result.result {
                 if submsg_response.data.is_some() {
                     if let Some(response_binary) = submsg_response.data {
@@ -143,6 +166,29 @@ fn process_execute_callback(
             }
         }
         WITHDRAW_LIQUIDITY_CALLBACK_ID => {
+            update_concentrated_liquidity_position(deps.storage, &callback_result)?;
+
+            // callbacks arriving after the withdrawal fell back to recovery are
+            // ignored, as recovery distributes whatever the proxy holds
+            let contract_state = CONTRACT_STATE.load(deps.storage)?;
+            if !matches!(contract_state, ContractState::PendingWithdrawal { .. }) {
+                POLYTONE_CALLBACKS.save(
+                    deps.storage,
+                    format!(
+                        "withdraw_liquidity_callback_ignored : {:?}",
+                        env.block.height.to_string()
+                    ),
+                    &to_json_binary(&callback_result)?.to_string(),
+                )?;
+                return Ok(Response::default()
+                    .add_attribute("method", "process_execute_callback")
+                    .add_attribute(
+                        "ignored_callback",
+                        WITHDRAW_LIQUIDITY_CALLBACK_ID.to_string(),
+                    )
+                    .add_attribute("contract_state", format!("{:?}", contract_state)));
+            }
+
             // decode the response attribute here
             // callback_result.result[0] contains the events
             // query the events for one that has "type" == "wasm"
@@ -168,8 +214,9 @@ fn process_execute_callback(
                                     }
                                 };
 
-                                CONTRACT_STATE.save(
+                                save_contract_state(
                                     deps.storage,
+                                    &env.block,
                                     &ContractState::Distributing {
                                         coins: refunded_coins,
                                     },
@@ -210,12 +257,15 @@ fn process_execute_callback(
                     )?;
 
                     // if we are not in a distributing state, withdraw had failed.
-                    // we submit the appropriate callback to the holder.
+                    // we revert to active state and submit the appropriate
+                    // callback to the holder.
+                    save_contract_state(deps.storage, &env.block, &ContractState::Active)?;
+                    reset_latest_proxy_balances(deps.storage)?;
                     let holder = HOLDER_ADDRESS.load(deps.storage)?;
                     return Ok(Response::default().add_message(CosmosMsg::Wasm(
                         WasmMsg::Execute {
                             contract_addr: holder.to_string(),
-                            msg: to_json_binary(&WithdrawLPMsgs::WithdrawFailed {})?,
+                            msg: to_json_binary(&LiquidPoolerHolderMsg::WithdrawFailed {})?,
                             funds: vec![],
                         },
                     )));
@@ -228,6 +278,42 @@ fn process_execute_callback(
     Ok(Response::default())
 }
 
+/// tracks the concentrated liquidity position held by our proxy.
+/// the outpost reports closed positions under the "closed_position_id"
+/// attribute, and newly created positions under the "position_id" one.
+fn update_concentrated_liquidity_position(
+    storage: &mut dyn Storage,
+    callback_result: &ExecutionResponse,
+) -> StdResult<()> {
+    let mut closed_position_id: Option<Uint64> = None;
+    let mut created_position_id: Option<Uint64> = None;
+
+    for callback_response in &callback_result.result {
+        for event in callback_response.events.iter().filter(|e| e.ty == "wasm") {
+            for attr in &event.attributes {
+                match attr.key.as_str() {
+                    "closed_position_id" => {
+                        closed_position_id = Some(Uint64::from_str(&attr.value)?)
+                    }
+                    "position_id" => created_position_id = Some(Uint64::from_str(&attr.value)?),
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    if let Some(closed_position_id) = closed_position_id {
+        if CONCENTRATED_LIQUIDITY_POSITION.may_load(storage)? == Some(closed_position_id) {
+            CONCENTRATED_LIQUIDITY_POSITION.remove(storage);
+        }
+    }
+    if let Some(created_position_id) = created_position_id {
+        CONCENTRATED_LIQUIDITY_POSITION.save(storage, &created_position_id)?;
+    }
+
+    Ok(())
+}
+
 fn process_fatal_error_callback(
     env: Env,
     deps: ExecuteDeps,
@@ -238,6 +324,9 @@ fn process_fatal_error_callback(
         format!("fatal_error : {:?}", env.block.height.to_string()),
         &response,
     )?;
+    // the pending action is retried on the next tick,
+    // after resyncing the proxy balances
+    reset_latest_proxy_balances(deps.storage)?;
     Ok(Response::default())
 }
 
@@ -262,7 +351,20 @@ fn handle_proxy_balances_callback(
             }
             val
         }
-        Err(err) => return Err(ContractError::PolytoneError(err.error).into()),
+        Err(err) => {
+            // balances remain unknown, so the next tick queries them again
+            POLYTONE_CALLBACKS.save(
+                deps.storage,
+                format!(
+                    "proxy_balances_callback_error : {:?}",
+                    env.block.height.to_string()
+                ),
+                &err.error,
+            )?;
+            return Ok(Response::default()
+                .add_attribute("method", "handle_proxy_balances_callback")
+                .add_attribute("query_error", err.error));
+        }
     };
 
     // store the latest prices in lp config
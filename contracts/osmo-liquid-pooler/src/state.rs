@@ -1,8 +1,11 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Uint64};
 use covenant_utils::op_mode::ContractOperationMode;
 use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
 
-use crate::msg::{ContractState, IbcConfig, LiquidityProvisionConfig};
+use crate::msg::{
+    ContractState, IbcConfig, LiquidityProvisionConfig, PendingCallback, StateTimeouts,
+};
 
 /// contract state tracks the state machine progress
 pub const CONTRACT_STATE: Item<ContractState> = Item::new("contract_state");
@@ -21,8 +24,20 @@ pub const PROXY_ADDRESS: Item<String> = Item::new("proxy_address");
 // fields relevant for providing liquidity
 pub const LIQUIDITY_PROVISIONING_CONFIG: Item<LiquidityProvisionConfig> = Item::new("lp_config");
 
+// id of the concentrated liquidity position held by the outpost on our behalf
+pub const CONCENTRATED_LIQUIDITY_POSITION: Item<Uint64> = Item::new("cl_position");
+
 // ibc-related fields
 pub const IBC_CONFIG: Item<IbcConfig> = Item::new("ibc_config");
 
 // timestamp to message
 pub const POLYTONE_CALLBACKS: Map<String, String> = Map::new("callbacks");
+
+// deadlines used to detect stuck states
+pub const STATE_TIMEOUTS: Item<StateTimeouts> = Item::new("state_timeouts");
+
+// polytone callback we are waiting on, along with its deadline
+pub const PENDING_CALLBACK: Item<PendingCallback> = Item::new("pending_callback");
+
+// deadline of the current state, if it has one
+pub const STATE_DEADLINE: Item<Expiration> = Item::new("state_deadline");
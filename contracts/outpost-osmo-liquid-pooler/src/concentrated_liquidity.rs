@@ -0,0 +1,274 @@
+//! osmosis concentrated liquidity and pool manager types which are not
+//! available in the osmosis-std version we depend on.
+//! only the fields relevant to the outpost are declared.
+
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    Binary, CosmosMsg, Decimal, Decimal256, Empty, QueryRequest, StdError, Uint256, Uint64,
+};
+use osmosis_std::{shim::Any, types::cosmos::base::v1beta1::Coin as ProtoCoin};
+use prost::Message;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+
+pub const MSG_CREATE_POSITION_TYPE_URL: &str =
+    "/osmosis.concentratedliquidity.v1beta1.MsgCreatePosition";
+pub const MSG_ADD_TO_POSITION_TYPE_URL: &str =
+    "/osmosis.concentratedliquidity.v1beta1.MsgAddToPosition";
+pub const MSG_WITHDRAW_POSITION_TYPE_URL: &str =
+    "/osmosis.concentratedliquidity.v1beta1.MsgWithdrawPosition";
+pub const CONCENTRATED_LIQUIDITY_POOL_TYPE_URL: &str =
+    "/osmosis.concentratedliquidity.v1beta1.Pool";
+pub const QUERY_POOL_PATH: &str = "/osmosis.poolmanager.v1beta1.Query/Pool";
+pub const QUERY_ESTIMATE_SWAP_EXACT_AMOUNT_IN_PATH: &str =
+    "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn";
+pub const QUERY_POSITION_BY_ID_PATH: &str =
+    "/osmosis.concentratedliquidity.v1beta1.Query/PositionById";
+
+/// osmosis encodes `BigDec` values as their atomics in protobuf messages
+const BIG_DEC_DECIMAL_PLACES: u32 = 36;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgCreatePosition {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+    #[prost(string, tag = "2")]
+    pub sender: String,
+    #[prost(int64, tag = "3")]
+    pub lower_tick: i64,
+    #[prost(int64, tag = "4")]
+    pub upper_tick: i64,
+    #[prost(message, repeated, tag = "5")]
+    pub tokens_provided: Vec<ProtoCoin>,
+    #[prost(string, tag = "6")]
+    pub token_min_amount0: String,
+    #[prost(string, tag = "7")]
+    pub token_min_amount1: String,
+}
+
+impl From<MsgCreatePosition> for CosmosMsg {
+    fn from(msg: MsgCreatePosition) -> Self {
+        CosmosMsg::Stargate {
+            type_url: MSG_CREATE_POSITION_TYPE_URL.to_string(),
+            value: Binary(msg.encode_to_vec()),
+        }
+    }
+}
+
+/// `MsgCreatePosition` and `MsgAddToPosition` responses
+/// both carry the resulting position id under the same tag
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgPositionResponse {
+    #[prost(uint64, tag = "1")]
+    pub position_id: u64,
+}
+
+/// withdraws the existing position and creates a new one
+/// with the added amounts
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgAddToPosition {
+    #[prost(uint64, tag = "1")]
+    pub position_id: u64,
+    #[prost(string, tag = "2")]
+    pub sender: String,
+    #[prost(string, tag = "3")]
+    pub amount0: String,
+    #[prost(string, tag = "4")]
+    pub amount1: String,
+    #[prost(string, tag = "5")]
+    pub token_min_amount0: String,
+    #[prost(string, tag = "6")]
+    pub token_min_amount1: String,
+}
+
+impl From<MsgAddToPosition> for CosmosMsg {
+    fn from(msg: MsgAddToPosition) -> Self {
+        CosmosMsg::Stargate {
+            type_url: MSG_ADD_TO_POSITION_TYPE_URL.to_string(),
+            value: Binary(msg.encode_to_vec()),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgWithdrawPosition {
+    #[prost(uint64, tag = "1")]
+    pub position_id: u64,
+    #[prost(string, tag = "2")]
+    pub sender: String,
+    /// `Dec` atomics of the liquidity to withdraw
+    #[prost(string, tag = "3")]
+    pub liquidity_amount: String,
+}
+
+impl MsgWithdrawPosition {
+    pub fn new(position_id: u64, sender: String, liquidity_amount: Decimal256) -> Self {
+        MsgWithdrawPosition {
+            position_id,
+            sender,
+            liquidity_amount: liquidity_amount.atomics().to_string(),
+        }
+    }
+}
+
+impl From<MsgWithdrawPosition> for CosmosMsg {
+    fn from(msg: MsgWithdrawPosition) -> Self {
+        CosmosMsg::Stargate {
+            type_url: MSG_WITHDRAW_POSITION_TYPE_URL.to_string(),
+            value: Binary(msg.encode_to_vec()),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct ConcentratedLiquidityPool {
+    #[prost(string, tag = "1")]
+    pub address: String,
+    #[prost(uint64, tag = "4")]
+    pub id: u64,
+    #[prost(string, tag = "6")]
+    pub token0: String,
+    #[prost(string, tag = "7")]
+    pub token1: String,
+    /// `BigDec` atomics of the current sqrt price (token1 per token0)
+    #[prost(string, tag = "8")]
+    pub current_sqrt_price: String,
+    #[prost(int64, tag = "9")]
+    pub current_tick: i64,
+    #[prost(uint64, tag = "10")]
+    pub tick_spacing: u64,
+}
+
+impl ConcentratedLiquidityPool {
+    /// spot price of the pool expressed in amount of token0 per token1.
+    /// this matches the gamm pool spot price convention (asset 1 / asset 2).
+    pub fn get_spot_price(&self) -> Result<Decimal256, ContractError> {
+        let sqrt_price_atomics = Uint256::from_str(&self.current_sqrt_price)?;
+        let sqrt_price = Decimal256::from_atomics(sqrt_price_atomics, BIG_DEC_DECIMAL_PLACES)
+            .map_err(|e| ContractError::OsmosisPoolError(e.to_string()))?;
+        let price = sqrt_price.checked_mul(sqrt_price)?;
+
+        price.inv().ok_or_else(|| {
+            ContractError::OsmosisPoolError("pool spot price must be non-zero".to_string())
+        })
+    }
+}
+
+impl TryFrom<Option<Any>> for ConcentratedLiquidityPool {
+    type Error = StdError;
+
+    fn try_from(pool: Option<Any>) -> Result<Self, Self::Error> {
+        match pool {
+            Some(any) if any.type_url == CONCENTRATED_LIQUIDITY_POOL_TYPE_URL => {
+                ConcentratedLiquidityPool::decode(any.value.as_slice())
+                    .map_err(|e| StdError::parse_err("ConcentratedLiquidityPool", e.to_string()))
+            }
+            Some(any) => Err(StdError::generic_err(format!(
+                "expected concentrated liquidity pool, got {}",
+                any.type_url
+            ))),
+            None => Err(StdError::NotFound {
+                kind: "shim not found".to_string(),
+            }),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryPoolRequest {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+}
+
+impl From<QueryPoolRequest> for QueryRequest<Empty> {
+    fn from(request: QueryPoolRequest) -> Self {
+        QueryRequest::Stargate {
+            path: QUERY_POOL_PATH.to_string(),
+            data: Binary(request.encode_to_vec()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct QueryPoolResponse {
+    pub pool: Option<Any>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct SwapAmountInRoute {
+    #[prost(uint64, tag = "1")]
+    pub pool_id: u64,
+    #[prost(string, tag = "2")]
+    pub token_out_denom: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct EstimateSwapExactAmountInRequest {
+    /// coin string of the token to swap, e.g. `100uosmo`
+    #[prost(string, tag = "3")]
+    pub token_in: String,
+    #[prost(message, repeated, tag = "4")]
+    pub routes: Vec<SwapAmountInRoute>,
+}
+
+impl From<EstimateSwapExactAmountInRequest> for QueryRequest<Empty> {
+    fn from(request: EstimateSwapExactAmountInRequest) -> Self {
+        QueryRequest::Stargate {
+            path: QUERY_ESTIMATE_SWAP_EXACT_AMOUNT_IN_PATH.to_string(),
+            data: Binary(request.encode_to_vec()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct EstimateSwapExactAmountInResponse {
+    pub token_out_amount: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryPositionByIdRequest {
+    #[prost(uint64, tag = "1")]
+    pub position_id: u64,
+}
+
+impl From<QueryPositionByIdRequest> for QueryRequest<Empty> {
+    fn from(request: QueryPositionByIdRequest) -> Self {
+        QueryRequest::Stargate {
+            path: QUERY_POSITION_BY_ID_PATH.to_string(),
+            data: Binary(request.encode_to_vec()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QueryPositionByIdResponse {
+    pub position: FullPositionBreakdown,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FullPositionBreakdown {
+    pub position: Position,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Position {
+    pub position_id: Uint64,
+    pub pool_id: Uint64,
+    /// liquidity of the position, as a decimal string
+    pub liquidity: Decimal256,
+}
+
+impl Position {
+    /// returns the liquidity amount to withdraw for the given share of the position.
+    /// full withdrawals use the exact position liquidity to close the position.
+    pub fn get_liquidity_to_withdraw(&self, share: Decimal) -> Result<Decimal256, ContractError> {
+        if share == Decimal::one() {
+            return Ok(self.liquidity);
+        }
+
+        Ok(self.liquidity.checked_mul(share.into())?)
+    }
+}
@@ -1,39 +1,49 @@
 use std::str::FromStr;
 
 use crate::{
+    concentrated_liquidity::{
+        ConcentratedLiquidityPool, EstimateSwapExactAmountInRequest,
+        EstimateSwapExactAmountInResponse, MsgAddToPosition, MsgCreatePosition,
+        MsgPositionResponse, MsgWithdrawPosition, QueryPoolRequest as PoolManagerQueryPoolRequest,
+        QueryPoolResponse as PoolManagerQueryPoolResponse, QueryPositionByIdRequest,
+        QueryPositionByIdResponse, SwapAmountInRoute,
+    },
     error::ContractError,
     msg::{
-        CallerContext, ExecuteMsg, InstantiateMsg, MigrateMsg, OsmosisPool,
-        OutpostProvideLiquidityConfig, OutpostWithdrawLiquidityConfig, QueryMsg,
+        CallerContext, ExecuteMsg, GammPool, InstantiateMsg, MigrateMsg, OsmosisPool,
+        OutpostProvideConcentratedLiquidityConfig, OutpostProvideLiquidityConfig,
+        OutpostSingleAssetExit, OutpostWithdrawConcentratedLiquidityConfig,
+        OutpostWithdrawLiquidityConfig, OutpostWithdrawPriceGuard, QueryMsg,
     },
-    state::PENDING_REPLY,
+    state::{PENDING_REPLY, POSITION_OWNERS},
 };
 
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, to_json_string, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env,
-    Fraction, MessageInfo, Reply, Response, StdError, StdResult, SubMsg, Uint128,
+    ensure, to_json_string, BankMsg, Binary, Coin, CosmosMsg, Decimal, Decimal256, Deps, DepsMut,
+    Env, Fraction, MessageInfo, Reply, Response, StdError, StdResult, SubMsg, SubMsgResponse,
+    SubMsgResult, Uint128, Uint64,
 };
 use cw2::set_contract_version;
 use cw_utils::must_pay;
-use osmosis_std::{
-    shim::Any,
-    types::{
-        cosmos::base::v1beta1::Coin as ProtoCoin,
-        osmosis::gamm::v1beta1::{
-            MsgExitPool, MsgJoinPool, MsgJoinSwapExternAmountIn, Pool,
-            QueryCalcExitPoolCoinsFromSharesRequest, QueryCalcExitPoolCoinsFromSharesResponse,
-            QueryCalcJoinPoolNoSwapSharesRequest, QueryCalcJoinPoolNoSwapSharesResponse,
-            QueryCalcJoinPoolSharesRequest, QueryCalcJoinPoolSharesResponse, QueryPoolRequest,
-            QueryPoolResponse,
-        },
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as ProtoCoin,
+    osmosis::gamm::v1beta1::{
+        MsgExitPool, MsgExitSwapShareAmountIn, MsgJoinPool, MsgJoinSwapExternAmountIn,
+        QueryCalcExitPoolCoinsFromSharesRequest, QueryCalcExitPoolCoinsFromSharesResponse,
+        QueryCalcJoinPoolNoSwapSharesRequest, QueryCalcJoinPoolNoSwapSharesResponse,
+        QueryCalcJoinPoolSharesRequest, QueryCalcJoinPoolSharesResponse, QueryPoolRequest,
+        QueryPoolResponse,
     },
 };
+use prost::Message;
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const OSMO_POOL_REPLY_ID: u64 = 1;
+const CL_POSITION_REPLY_ID: u64 = 2;
+const CL_WITHDRAW_POSITION_REPLY_ID: u64 = 3;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -56,6 +66,12 @@ pub fn execute(
     match msg {
         ExecuteMsg::ProvideLiquidity { config } => try_provide_liquidity(deps, env, info, config),
         ExecuteMsg::WithdrawLiquidity { config } => try_withdraw_liquidity(deps, env, info, config),
+        ExecuteMsg::ProvideConcentratedLiquidity { config } => {
+            try_provide_concentrated_liquidity(deps, env, info, config)
+        }
+        ExecuteMsg::WithdrawConcentratedLiquidity { config } => {
+            try_withdraw_concentrated_liquidity(deps, env, info, config)
+        }
     }
 }
 
@@ -72,16 +88,9 @@ fn try_withdraw_liquidity(
         }
         .into(),
     )?;
-    let osmo_pool: Pool = decode_osmo_pool_binary(query_response.pool)?;
+    let osmo_pool = GammPool::try_from(query_response.pool)?;
 
-    let pool_shares_coin = match osmo_pool.total_shares {
-        Some(coin) => coin,
-        None => {
-            return Err(ContractError::OsmosisPoolError(
-                "no shares coin in pool".to_string(),
-            ))
-        }
-    };
+    let pool_shares_coin = osmo_pool.get_gamm_cw_coin()?;
 
     // we assert that the correct lp token is being redeemed
     let shares_to_redeem = must_pay(&info, &pool_shares_coin.denom)?;
@@ -101,30 +110,143 @@ fn try_withdraw_liquidity(
         ContractError::OsmosisPoolError("exit pool simulation must return 2 denoms".to_string())
     );
 
-    // build the exit pool request based on the exit pool simulation
-    let exit_pool_request: CosmosMsg = MsgExitPool {
-        sender: env.contract.address.to_string(),
-        pool_id: config.pool_id.u64(),
-        share_in_amount: shares_to_redeem.to_string(),
-        token_out_mins: calc_exit_query_response.tokens_out.clone(),
+    // validate the exit against the price guard, if any
+    if let Some(price_guard) = config.price_guard {
+        osmo_pool.validate_pool_assets_length()?;
+        let pool_assets = osmo_pool.get_pool_cw_coins()?;
+        match price_guard {
+            OutpostWithdrawPriceGuard::PriceRange {
+                expected_spot_price,
+                acceptable_price_spread,
+            } => validate_spot_price(
+                osmo_pool.get_spot_price()?,
+                expected_spot_price,
+                acceptable_price_spread,
+            )?,
+            OutpostWithdrawPriceGuard::MinAssetsPerShare { asset_1, asset_2 } => {
+                for (pool_asset, min_per_share) in pool_assets.iter().zip([asset_1, asset_2]) {
+                    let min_amount = shares_to_redeem.checked_multiply_ratio(
+                        min_per_share.numerator(),
+                        min_per_share.denominator(),
+                    )?;
+                    let amount_out = match calc_exit_query_response
+                        .tokens_out
+                        .iter()
+                        .find(|token| token.denom == pool_asset.denom)
+                    {
+                        Some(token) => Uint128::from_str(&token.amount)?,
+                        None => Uint128::zero(),
+                    };
+                    ensure!(
+                        amount_out >= min_amount,
+                        ContractError::MinWithdrawAmountError(
+                            Coin::new(min_amount.u128(), pool_asset.denom.to_string()).to_string(),
+                            Coin::new(amount_out.u128(), pool_asset.denom.to_string()).to_string(),
+                        )
+                    );
+                }
+            }
+        }
     }
-    .into();
+
+    let mut response = Response::default().add_attribute("method", "try_withdraw_liquidity");
+
+    // build the exit pool request based on the exit pool simulation
+    let exit_pool_request: CosmosMsg = match config.single_asset_exit {
+        // exit entirely into one of the pool assets
+        Some(single_asset_exit) => {
+            let token_out_min_amount = get_single_asset_exit_min_amount(
+                deps.as_ref(),
+                &osmo_pool,
+                &calc_exit_query_response.tokens_out,
+                &single_asset_exit,
+            )?;
+            response = response
+                .add_attribute("single_asset_exit_denom", &single_asset_exit.denom)
+                .add_attribute("token_out_min_amount", token_out_min_amount);
+
+            MsgExitSwapShareAmountIn {
+                sender: env.contract.address.to_string(),
+                pool_id: config.pool_id.u64(),
+                token_out_denom: single_asset_exit.denom,
+                share_in_amount: shares_to_redeem.to_string(),
+                token_out_min_amount: token_out_min_amount.to_string(),
+            }
+            .into()
+        }
+        None => MsgExitPool {
+            sender: env.contract.address.to_string(),
+            pool_id: config.pool_id.u64(),
+            share_in_amount: shares_to_redeem.to_string(),
+            token_out_mins: calc_exit_query_response.tokens_out.clone(),
+        }
+        .into(),
+    };
 
     // we build a context helper that will be used to
     // return the resulting funds (and/or leftovers) to the sender
     let callback_context = CallerContext {
         sender: info.sender.to_string(),
-        gamm_denom: pool_shares_coin.denom.to_string(),
+        gamm_denom: Some(pool_shares_coin.denom.to_string()),
         pool_denom_1: calc_exit_query_response.tokens_out[0].denom.to_string(),
         pool_denom_2: calc_exit_query_response.tokens_out[1].denom.to_string(),
+        closed_position_id: None,
     };
 
     // store the callback context to be loaded in the callback
     PENDING_REPLY.save(deps.storage, &callback_context)?;
 
-    Ok(Response::default()
-        .add_attribute("method", "try_withdraw_liquidity")
-        .add_submessage(SubMsg::reply_always(exit_pool_request, OSMO_POOL_REPLY_ID)))
+    Ok(response.add_submessage(SubMsg::reply_always(exit_pool_request, OSMO_POOL_REPLY_ID)))
+}
+
+/// estimates the amount of a single pool asset to be received for the
+/// simulated exit: the simulated amount of that asset plus the estimated
+/// swap of the simulated amount of the other asset through the pool,
+/// less slippage
+fn get_single_asset_exit_min_amount(
+    deps: Deps,
+    osmo_pool: &GammPool,
+    tokens_out: &[ProtoCoin],
+    single_asset_exit: &OutpostSingleAssetExit,
+) -> Result<Uint128, ContractError> {
+    ensure!(
+        single_asset_exit.slippage_tolerance < Decimal::one(),
+        ContractError::SlippageError {}
+    );
+    osmo_pool.validate_pool_assets_length()?;
+    let pool_assets = osmo_pool.get_pool_cw_coins()?;
+    ensure!(
+        pool_assets
+            .iter()
+            .any(|asset| asset.denom == single_asset_exit.denom),
+        ContractError::OsmosisPoolError(format!("{} is not a pool asset", single_asset_exit.denom))
+    );
+
+    let mut estimated_amount = Uint128::zero();
+    for token in tokens_out {
+        let amount = Uint128::from_str(&token.amount)?;
+        let amount_in_exit_denom = if token.denom == single_asset_exit.denom || amount.is_zero() {
+            amount
+        } else {
+            // the exit swaps the other asset into the pool, so we
+            // simulate that swap rather than valuing it at spot
+            let estimate_response: EstimateSwapExactAmountInResponse = deps.querier.query(
+                &EstimateSwapExactAmountInRequest {
+                    token_in: format!("{}{}", amount, token.denom),
+                    routes: vec![SwapAmountInRoute {
+                        pool_id: osmo_pool.get_id(),
+                        token_out_denom: single_asset_exit.denom.to_string(),
+                    }],
+                }
+                .into(),
+            )?;
+            Uint128::from_str(&estimate_response.token_out_amount)?
+        };
+        estimated_amount = estimated_amount.checked_add(amount_in_exit_denom)?;
+    }
+
+    let min_ratio = Decimal::one() - single_asset_exit.slippage_tolerance;
+    Ok(estimated_amount.checked_multiply_ratio(min_ratio.numerator(), min_ratio.denominator())?)
 }
 
 fn try_provide_liquidity(
@@ -144,28 +266,23 @@ fn try_provide_liquidity(
         }
         .into(),
     )?;
-    let osmo_pool: Pool = decode_osmo_pool_binary(query_response.pool)?;
+    let osmo_pool = GammPool::try_from(query_response.pool)?;
 
     // validate that the pool we wish to provide liquidity
     // to is composed of two assets
     osmo_pool.validate_pool_assets_length()?;
 
-    // only gamm 50:50 pools are supported (for now)
-    osmo_pool.validate_pool_asset_weights()?;
-
     // collect the pool assets into cw coins
     let pool_assets = osmo_pool.get_pool_cw_coins()?;
     // get the total gamm shares cw_std coin
     let gamm_shares_coin = osmo_pool.get_gamm_cw_coin()?;
 
-    // validate the price against our expectations
-    let pool_spot_price = Decimal::from_ratio(pool_assets[0].amount, pool_assets[1].amount);
-    let min_acceptable_spot_price = config.expected_spot_price - config.acceptable_price_spread;
-    let max_acceptable_spot_price = config.expected_spot_price + config.acceptable_price_spread;
-
-    if min_acceptable_spot_price > pool_spot_price || max_acceptable_spot_price < pool_spot_price {
-        return Err(ContractError::PriceRangeError {});
-    }
+    // validate the (weighted) price against our expectations
+    validate_spot_price(
+        osmo_pool.get_spot_price()?,
+        config.expected_spot_price,
+        config.acceptable_price_spread,
+    )?;
 
     // get the amounts paid of pool denoms
     let asset_1_received = Coin {
@@ -181,11 +298,40 @@ fn try_provide_liquidity(
     // return the resulting funds to the sender
     let callback_context = CallerContext {
         sender: info.sender.to_string(),
-        gamm_denom: gamm_shares_coin.denom.to_string(),
+        gamm_denom: Some(gamm_shares_coin.denom.to_string()),
         pool_denom_1: asset_1_received.denom.to_string(),
         pool_denom_2: asset_2_received.denom.to_string(),
+        closed_position_id: None,
     };
 
+    // joining with a single configured asset leaves
+    // the other asset (if any) to be refunded
+    if let Some(denom) = config.single_asset_join_denom {
+        let (asset_received, single_side_limit) = if denom == asset_1_received.denom {
+            (asset_1_received, config.asset_1_single_side_lp_limit)
+        } else if denom == asset_2_received.denom {
+            (asset_2_received, config.asset_2_single_side_lp_limit)
+        } else {
+            return Err(ContractError::LiquidityProvisionError(format!(
+                "{denom} is not a pool asset"
+            )));
+        };
+        ensure!(
+            !asset_received.amount.is_zero(),
+            ContractError::LiquidityProvisionError(format!("no {denom} provided"))
+        );
+
+        return provide_single_sided_liquidity(
+            deps,
+            osmo_pool.get_id(),
+            asset_received,
+            env.contract.address.to_string(),
+            config.slippage_tolerance,
+            single_side_limit,
+            callback_context,
+        );
+    }
+
     // depending on which assets we have available,
     // we construct different liquidity provision message
     match (
@@ -196,7 +342,7 @@ fn try_provide_liquidity(
         (true, true) => provide_double_sided_liquidity(
             deps,
             env,
-            osmo_pool,
+            osmo_pool.get_id(),
             vec![asset_1_received, asset_2_received],
             config.slippage_tolerance,
             callback_context,
@@ -204,7 +350,7 @@ fn try_provide_liquidity(
         // only asset 1 is provided, attempt to provide single sided
         (true, false) => provide_single_sided_liquidity(
             deps,
-            osmo_pool,
+            osmo_pool.get_id(),
             asset_1_received,
             env.contract.address.to_string(),
             config.slippage_tolerance,
@@ -214,7 +360,7 @@ fn try_provide_liquidity(
         // only asset 2 is provided, attempt to provide single sided
         (false, true) => provide_single_sided_liquidity(
             deps,
-            osmo_pool,
+            osmo_pool.get_id(),
             asset_2_received,
             env.contract.address.to_string(),
             config.slippage_tolerance,
@@ -231,7 +377,7 @@ fn try_provide_liquidity(
 fn provide_double_sided_liquidity(
     deps: DepsMut,
     env: Env,
-    pool: Pool,
+    pool_id: u64,
     assets_paid: Vec<Coin>,
     slippage_tolerance: Decimal,
     callback_ctx: CallerContext,
@@ -242,7 +388,7 @@ fn provide_double_sided_liquidity(
     // first we query the expected gamm amount
     let query_response: QueryCalcJoinPoolNoSwapSharesResponse = deps.querier.query(
         &QueryCalcJoinPoolNoSwapSharesRequest {
-            pool_id: pool.id,
+            pool_id,
             tokens_in: token_in_maxs.clone(),
         }
         .into(),
@@ -250,14 +396,14 @@ fn provide_double_sided_liquidity(
 
     // expected gamm tokens
     let response_gamm_coin = Coin {
-        denom: callback_ctx.gamm_denom.to_string(),
+        denom: callback_ctx.gamm_denom.clone().unwrap_or_default(),
         amount: Uint128::from_str(&query_response.shares_out)?,
     };
     let expected_gamm_coin = apply_slippage(slippage_tolerance, response_gamm_coin)?;
 
     let osmo_msg: CosmosMsg = MsgJoinPool {
         sender: env.contract.address.to_string(),
-        pool_id: pool.id,
+        pool_id,
         // exact number of shares we wish to receive
         share_out_amount: expected_gamm_coin.amount.to_string(),
         token_in_maxs,
@@ -274,7 +420,7 @@ fn provide_double_sided_liquidity(
 
 fn provide_single_sided_liquidity(
     deps: DepsMut,
-    pool: Pool,
+    pool_id: u64,
     asset_paid: Coin,
     outpost: String,
     slippage_tolerance: Decimal,
@@ -291,21 +437,21 @@ fn provide_single_sided_liquidity(
     // first we query the expected gamm amount
     let query_response: QueryCalcJoinPoolSharesResponse = deps.querier.query(
         &QueryCalcJoinPoolSharesRequest {
-            pool_id: pool.id,
+            pool_id,
             tokens_in: vec![asset_paid.clone().into()],
         }
         .into(),
     )?;
 
     let response_gamm_coin = Coin {
-        denom: callback_ctx.gamm_denom.to_string(),
+        denom: callback_ctx.gamm_denom.clone().unwrap_or_default(),
         amount: Uint128::from_str(&query_response.share_out_amount)?,
     };
     let expected_gamm_coin = apply_slippage(slippage_tolerance, response_gamm_coin)?;
 
     let join_pool_msg: CosmosMsg = MsgJoinSwapExternAmountIn {
         sender: outpost,
-        pool_id: pool.id,
+        pool_id,
         token_in: Some(asset_paid.clone().into()),
         share_out_min_amount: expected_gamm_coin.amount.to_string(),
     }
@@ -319,6 +465,181 @@ fn provide_single_sided_liquidity(
         .add_submessage(SubMsg::reply_always(join_pool_msg, OSMO_POOL_REPLY_ID)))
 }
 
+fn try_provide_concentrated_liquidity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: OutpostProvideConcentratedLiquidityConfig,
+) -> Result<Response, ContractError> {
+    // first we query the pool for validation and info
+    let pool = query_concentrated_liquidity_pool(deps.as_ref(), config.pool_id.u64())?;
+
+    // validate that the position can be created in this pool
+    config.tick_range.validate(pool.tick_spacing)?;
+
+    // validate the price against our expectations
+    validate_spot_price(
+        pool.get_spot_price()?,
+        config.expected_spot_price,
+        config.acceptable_price_spread,
+    )?;
+
+    // get the amounts paid of pool denoms
+    let token_0_received = Coin {
+        denom: pool.token0.to_string(),
+        amount: get_paid_denom_amount(&info, &pool.token0).unwrap_or(Uint128::zero()),
+    };
+    let token_1_received = Coin {
+        denom: pool.token1.to_string(),
+        amount: get_paid_denom_amount(&info, &pool.token1).unwrap_or(Uint128::zero()),
+    };
+
+    ensure!(
+        !token_0_received.amount.is_zero() || !token_1_received.amount.is_zero(),
+        ContractError::LiquidityProvisionError("no funds provided".to_string())
+    );
+
+    // the spot price is validated within this same transaction,
+    // so we do not apply any additional minimum amounts
+    let osmo_msg: CosmosMsg = match config.position_id {
+        // adding to an existing position replaces it with a new one
+        Some(position_id) => {
+            ensure_position_owner(deps.as_ref(), &info, position_id)?;
+            MsgAddToPosition {
+                position_id: position_id.u64(),
+                sender: env.contract.address.to_string(),
+                amount0: token_0_received.amount.to_string(),
+                amount1: token_1_received.amount.to_string(),
+                token_min_amount0: Uint128::zero().to_string(),
+                token_min_amount1: Uint128::zero().to_string(),
+            }
+            .into()
+        }
+        None => {
+            let mut tokens_provided: Vec<Coin> =
+                vec![token_0_received.clone(), token_1_received.clone()]
+                    .into_iter()
+                    .filter(|c| !c.amount.is_zero())
+                    .collect();
+            tokens_provided.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+            MsgCreatePosition {
+                pool_id: pool.id,
+                sender: env.contract.address.to_string(),
+                lower_tick: config.tick_range.lower_tick,
+                upper_tick: config.tick_range.upper_tick,
+                tokens_provided: tokens_provided.into_iter().map(|c| c.into()).collect(),
+                token_min_amount0: Uint128::zero().to_string(),
+                token_min_amount1: Uint128::zero().to_string(),
+            }
+            .into()
+        }
+    };
+
+    // we build a context helper that will be used to
+    // return the leftover funds to the sender
+    let callback_ctx = CallerContext {
+        sender: info.sender.to_string(),
+        pool_denom_1: token_0_received.denom,
+        pool_denom_2: token_1_received.denom,
+        gamm_denom: None,
+        closed_position_id: config.position_id,
+    };
+    PENDING_REPLY.save(deps.storage, &callback_ctx)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_provide_concentrated_liquidity")
+        .add_submessage(SubMsg::reply_always(osmo_msg, CL_POSITION_REPLY_ID)))
+}
+
+fn try_withdraw_concentrated_liquidity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: OutpostWithdrawConcentratedLiquidityConfig,
+) -> Result<Response, ContractError> {
+    ensure!(
+        config.percentage > Decimal::zero() && config.percentage <= Decimal::one(),
+        ContractError::WithdrawPercentageError(config.percentage.to_string())
+    );
+    ensure_position_owner(deps.as_ref(), &info, config.position_id)?;
+
+    let position_response: QueryPositionByIdResponse = deps.querier.query(
+        &QueryPositionByIdRequest {
+            position_id: config.position_id.u64(),
+        }
+        .into(),
+    )?;
+    let position = position_response.position.position;
+    let pool = query_concentrated_liquidity_pool(deps.as_ref(), position.pool_id.u64())?;
+
+    // validate the exit against the price guard, if any
+    match config.price_guard {
+        Some(OutpostWithdrawPriceGuard::PriceRange {
+            expected_spot_price,
+            acceptable_price_spread,
+        }) => validate_spot_price(
+            pool.get_spot_price()?,
+            expected_spot_price,
+            acceptable_price_spread,
+        )?,
+        Some(OutpostWithdrawPriceGuard::MinAssetsPerShare { .. }) => {
+            return Err(ContractError::ConcentratedLiquidityError(
+                "min assets per share guard does not apply to positions".to_string(),
+            ))
+        }
+        None => (),
+    }
+
+    let liquidity_amount = position.get_liquidity_to_withdraw(config.percentage)?;
+    ensure!(
+        liquidity_amount > Decimal256::zero(),
+        ContractError::ConcentratedLiquidityError("nothing to withdraw".to_string())
+    );
+
+    let withdraw_msg: CosmosMsg = MsgWithdrawPosition::new(
+        config.position_id.u64(),
+        env.contract.address.to_string(),
+        liquidity_amount,
+    )
+    .into();
+
+    // withdrawing the entire liquidity closes the position
+    let closed_position_id = if liquidity_amount == position.liquidity {
+        Some(config.position_id)
+    } else {
+        None
+    };
+
+    let callback_ctx = CallerContext {
+        sender: info.sender.to_string(),
+        pool_denom_1: pool.token0,
+        pool_denom_2: pool.token1,
+        gamm_denom: None,
+        closed_position_id,
+    };
+    PENDING_REPLY.save(deps.storage, &callback_ctx)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_withdraw_concentrated_liquidity")
+        .add_attribute("liquidity_amount", liquidity_amount.to_string())
+        .add_submessage(SubMsg::reply_always(
+            withdraw_msg,
+            CL_WITHDRAW_POSITION_REPLY_ID,
+        )))
+}
+
+fn ensure_position_owner(
+    deps: Deps,
+    info: &MessageInfo,
+    position_id: Uint64,
+) -> Result<(), ContractError> {
+    match POSITION_OWNERS.may_load(deps.storage, position_id.u64())? {
+        Some(owner) if owner == info.sender => Ok(()),
+        _ => Err(ContractError::PositionOwnershipError(position_id.u64())),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(_deps: Deps, _env: Env, _msg: QueryMsg) -> StdResult<Binary> {
     Err(StdError::NotFound {
@@ -330,6 +651,9 @@ pub fn query(_deps: Deps, _env: Env, _msg: QueryMsg) -> StdResult<Binary> {
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg.id {
         OSMO_POOL_REPLY_ID => handle_pool_interaction_reply(deps, env),
+        CL_POSITION_REPLY_ID | CL_WITHDRAW_POSITION_REPLY_ID => {
+            handle_concentrated_liquidity_reply(deps, env, msg)
+        }
         _ => Err(ContractError::UnknownReplyId(msg.id)),
     }
 }
@@ -351,26 +675,85 @@ fn handle_pool_interaction_reply(deps: DepsMut, env: Env) -> Result<Response, Co
     let callback_ctx = PENDING_REPLY.load(deps.storage)?;
     PENDING_REPLY.remove(deps.storage);
 
-    // we query the balances of relevant denoms
-    let available_gamm = deps.querier.query_balance(
-        env.contract.address.to_string(),
-        callback_ctx.gamm_denom.to_string(),
-    )?;
-    let leftover_asset_1 = deps
-        .querier
-        .query_balance(env.contract.address.to_string(), callback_ctx.pool_denom_1)?;
-    let leftover_asset_2 = deps
-        .querier
-        .query_balance(env.contract.address.to_string(), callback_ctx.pool_denom_2)?;
+    let response = Response::default().add_attribute("method", "handle_pool_interaction_reply");
+
+    refund_caller(deps, env, callback_ctx, response)
+}
+
+/// records the ownership changes of the concentrated liquidity positions
+/// and refunds the resulting funds to the caller
+fn handle_concentrated_liquidity_reply(
+    deps: DepsMut,
+    env: Env,
+    msg: Reply,
+) -> Result<Response, ContractError> {
+    // load and clear the pending reply that we are processing
+    let callback_ctx = PENDING_REPLY.load(deps.storage)?;
+    PENDING_REPLY.remove(deps.storage);
+
+    let mut response = Response::default()
+        .add_attribute("method", "handle_concentrated_liquidity_reply")
+        .add_attribute("success", msg.result.is_ok().to_string());
+
+    if let SubMsgResult::Ok(SubMsgResponse { data, .. }) = msg.result {
+        if let Some(position_id) = callback_ctx.closed_position_id {
+            POSITION_OWNERS.remove(deps.storage, position_id.u64());
+            response = response.add_attribute("closed_position_id", position_id);
+        }
 
-    // and collect them into tokens to be refunded (if any)
-    let refund_tokens: Vec<Coin> = vec![available_gamm, leftover_asset_1, leftover_asset_2]
-        .into_iter()
-        .filter(|c| c.amount > Uint128::zero())
-        .collect();
+        // created positions are recorded as owned by the caller
+        if let (CL_POSITION_REPLY_ID, Some(data)) = (msg.id, data) {
+            let position_id = MsgPositionResponse::decode(data.as_slice())
+                .map_err(|e| StdError::parse_err("MsgPositionResponse", e.to_string()))?
+                .position_id;
+            POSITION_OWNERS.save(
+                deps.storage,
+                position_id,
+                &deps.api.addr_validate(&callback_ctx.sender)?,
+            )?;
+            response = response.add_attribute("position_id", position_id.to_string());
+        }
+    }
+
+    refund_caller(deps, env, callback_ctx, response)
+}
 
-    let mut response = Response::default().add_attribute("method", "handle_pool_interaction_reply");
+/// sends any pool assets (and gamm shares) held by the outpost to the caller.
+/// concentrated liquidity interactions also claim the spread rewards and
+/// incentives of the position, which may come in any denom.
+fn refund_caller(
+    deps: DepsMut,
+    env: Env,
+    callback_ctx: CallerContext,
+    response: Response,
+) -> Result<Response, ContractError> {
+    let refund_tokens: Vec<Coin> = match callback_ctx.gamm_denom {
+        Some(gamm_denom) => {
+            // we query the balances of relevant denoms
+            // and collect them into tokens to be refunded (if any)
+            let mut refund_tokens: Vec<Coin> = vec![];
+            for denom in [
+                gamm_denom,
+                callback_ctx.pool_denom_1,
+                callback_ctx.pool_denom_2,
+            ] {
+                let balance = deps
+                    .querier
+                    .query_balance(env.contract.address.to_string(), denom)?;
+                if balance.amount > Uint128::zero() {
+                    refund_tokens.push(balance);
+                }
+            }
+            refund_tokens
+        }
+        // the outpost holds no funds in between transactions,
+        // so every balance belongs to the caller
+        None => deps
+            .querier
+            .query_all_balances(env.contract.address.to_string())?,
+    };
 
+    let mut response = response;
     if !refund_tokens.is_empty() {
         response = response.add_message(BankMsg::Send {
             to_address: callback_ctx.sender,
@@ -381,6 +764,25 @@ fn handle_pool_interaction_reply(deps: DepsMut, env: Env) -> Result<Response, Co
     Ok(response.add_attribute("refund_tokens", to_json_string(&refund_tokens)?))
 }
 
+/// validates that the pool spot price is within
+/// the acceptable spread of the expected price
+fn validate_spot_price(
+    pool_spot_price: Decimal256,
+    expected_spot_price: Decimal,
+    acceptable_price_spread: Decimal,
+) -> Result<(), ContractError> {
+    // a spread beyond the expected price leaves the range without a lower bound
+    let min_acceptable_spot_price =
+        Decimal256::from(expected_spot_price.saturating_sub(acceptable_price_spread));
+    let max_acceptable_spot_price =
+        Decimal256::from(expected_spot_price.saturating_add(acceptable_price_spread));
+
+    if min_acceptable_spot_price > pool_spot_price || max_acceptable_spot_price < pool_spot_price {
+        return Err(ContractError::PriceRangeError {});
+    }
+    Ok(())
+}
+
 /// cw-utils must pay requires specifically one coin, this is a helper
 /// for multi-coin inputs
 fn get_paid_denom_amount(info: &MessageInfo, target_denom: &str) -> StdResult<Uint128> {
@@ -392,22 +794,14 @@ fn get_paid_denom_amount(info: &MessageInfo, target_denom: &str) -> StdResult<Ui
     Err(StdError::not_found(target_denom))
 }
 
-fn decode_osmo_pool_binary(pool: Option<Any>) -> StdResult<Pool> {
-    let osmo_shim = match pool {
-        Some(shim) => shim,
-        None => {
-            return Err(StdError::NotFound {
-                kind: "shim not found".to_string(),
-            })
-        }
-    };
-
-    match osmo_shim.try_into() {
-        Ok(result) => Ok(result),
-        Err(err) => Err(StdError::InvalidBase64 {
-            msg: err.to_string(),
-        }),
-    }
+fn query_concentrated_liquidity_pool(
+    deps: Deps,
+    pool_id: u64,
+) -> StdResult<ConcentratedLiquidityPool> {
+    let query_response: PoolManagerQueryPoolResponse = deps
+        .querier
+        .query(&PoolManagerQueryPoolRequest { pool_id }.into())?;
+    ConcentratedLiquidityPool::try_from(query_response.pool)
 }
 
 fn apply_slippage(slippage: Decimal, coin: Coin) -> Result<Coin, ContractError> {
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{CheckedMultiplyRatioError, OverflowError, StdError};
 use cw_utils::PaymentError;
 use thiserror::Error;
 
@@ -10,8 +10,11 @@ pub enum ContractError {
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
-    #[error("only 50:50 pools are supported, got {0}")]
-    PoolRatioError(String),
+    #[error("{0}")]
+    CheckedMultiplyRatioError(#[from] CheckedMultiplyRatioError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
 
     #[error("Osmosis pool error: {0}")]
     OsmosisPoolError(String),
@@ -28,9 +31,21 @@ pub enum ContractError {
     #[error("Price range error")]
     PriceRangeError {},
 
+    #[error("withdrawal below the minimum amount: expected {0}, got {1}")]
+    MinWithdrawAmountError(String, String),
+
     #[error("single side lp error: limit = {0}, got = {1}")]
     SingleSideLiquidityProvisionError(String, String),
 
+    #[error("concentrated liquidity error: {0}")]
+    ConcentratedLiquidityError(String),
+
+    #[error("position {0} is not owned by the sender")]
+    PositionOwnershipError(u64),
+
+    #[error("withdraw percentage must be in range (0, 1], got {0}")]
+    WithdrawPercentageError(String),
+
     #[error("unknown reply id: {0}")]
     UnknownReplyId(u64),
 }
@@ -1,6 +1,8 @@
 extern crate core;
 
+pub mod concentrated_liquidity;
 pub mod contract;
 pub mod error;
 pub mod msg;
+pub mod stableswap;
 pub mod state;
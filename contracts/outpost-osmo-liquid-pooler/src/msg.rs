@@ -1,10 +1,19 @@
 use std::str::FromStr;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Binary, Coin, Decimal, Uint128, Uint64};
-use osmosis_std::types::osmosis::gamm::v1beta1::Pool;
+use cosmwasm_std::{Binary, Coin, Decimal, Decimal256, StdError, Uint128, Uint256, Uint64};
+use osmosis_std::{
+    shim::Any,
+    types::{cosmos::base::v1beta1::Coin as ProtoCoin, osmosis::gamm::v1beta1::Pool},
+};
+use prost::Message;
 
-use crate::error::ContractError;
+use crate::{
+    error::ContractError,
+    stableswap::{get_stableswap_spot_price, StableswapPool, STABLESWAP_POOL_TYPE_URL},
+};
+
+pub const BALANCER_POOL_TYPE_URL: &str = "/osmosis.gamm.v1beta1.Pool";
 
 #[cw_serde]
 pub struct InstantiateMsg {}
@@ -17,6 +26,12 @@ pub enum ExecuteMsg {
     WithdrawLiquidity {
         config: OutpostWithdrawLiquidityConfig,
     },
+    ProvideConcentratedLiquidity {
+        config: OutpostProvideConcentratedLiquidityConfig,
+    },
+    WithdrawConcentratedLiquidity {
+        config: OutpostWithdrawConcentratedLiquidityConfig,
+    },
 }
 
 #[cw_serde]
@@ -32,12 +47,103 @@ pub struct OutpostProvideLiquidityConfig {
     /// limits for single-side liquidity provision
     pub asset_1_single_side_lp_limit: Uint128,
     pub asset_2_single_side_lp_limit: Uint128,
+    /// pool asset to join the pool with on its own (`JoinSwapExternAmountIn`),
+    /// refunding any payment of the other asset. if not set, both assets
+    /// are joined when paid and a single paid asset is joined on its own
+    pub single_asset_join_denom: Option<String>,
 }
 
 #[cw_serde]
 pub struct OutpostWithdrawLiquidityConfig {
     /// id of the pool we wish to withdraw liquidity from
     pub pool_id: Uint64,
+    /// guard against exiting a manipulated pool.
+    /// pool is exited at any price if not set
+    pub price_guard: Option<OutpostWithdrawPriceGuard>,
+    /// exit the pool entirely into a single pool asset (`ExitSwapShareAmountIn`).
+    /// shares are redeemed for both pool assets if not set
+    pub single_asset_exit: Option<OutpostSingleAssetExit>,
+}
+
+#[cw_serde]
+pub struct OutpostSingleAssetExit {
+    /// pool asset to receive the redeemed shares in
+    pub denom: String,
+    /// slippage tolerance applied to the amount estimated from
+    /// the exit simulation and the pool spot price
+    pub slippage_tolerance: Decimal,
+}
+
+#[cw_serde]
+pub struct OutpostProvideConcentratedLiquidityConfig {
+    /// id of the concentrated liquidity pool we wish to provide liquidity to
+    pub pool_id: Uint64,
+    /// tick range of the position
+    pub tick_range: TickRange,
+    /// the price (token0 / token1) which we expect to provide liquidity at
+    pub expected_spot_price: Decimal,
+    /// acceptable delta (both ways) of the expected price
+    pub acceptable_price_spread: Decimal,
+    /// existing position of the sender to add the liquidity to.
+    /// a new position is created if not set
+    pub position_id: Option<Uint64>,
+}
+
+#[cw_serde]
+pub struct OutpostWithdrawConcentratedLiquidityConfig {
+    /// id of the position we wish to withdraw liquidity from
+    pub position_id: Uint64,
+    /// share of the position liquidity to withdraw, in range (0, 1]
+    pub percentage: Decimal,
+    /// guard against exiting a manipulated pool.
+    /// only the `PriceRange` guard applies to concentrated liquidity positions
+    pub price_guard: Option<OutpostWithdrawPriceGuard>,
+}
+
+#[cw_serde]
+pub struct TickRange {
+    pub lower_tick: i64,
+    pub upper_tick: i64,
+}
+
+impl TickRange {
+    pub fn validate_bounds(&self) -> Result<(), ContractError> {
+        if self.lower_tick >= self.upper_tick {
+            return Err(ContractError::ConcentratedLiquidityError(format!(
+                "lower tick {} must be below upper tick {}",
+                self.lower_tick, self.upper_tick
+            )));
+        }
+        Ok(())
+    }
+
+    /// validates that the tick range is usable in a pool with the given tick spacing
+    pub fn validate(&self, tick_spacing: u64) -> Result<(), ContractError> {
+        self.validate_bounds()?;
+
+        let tick_spacing = i64::try_from(tick_spacing)
+            .map_err(|e| ContractError::ConcentratedLiquidityError(e.to_string()))?;
+        if tick_spacing == 0
+            || self.lower_tick % tick_spacing != 0
+            || self.upper_tick % tick_spacing != 0
+        {
+            return Err(ContractError::ConcentratedLiquidityError(format!(
+                "ticks must be multiples of the pool tick spacing {tick_spacing}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cw_serde]
+pub enum OutpostWithdrawPriceGuard {
+    /// pool spot price (asset 1 / asset 2) must be within the expected range
+    PriceRange {
+        expected_spot_price: Decimal,
+        acceptable_price_spread: Decimal,
+    },
+    /// minimum amounts of the pool assets to receive per redeemed share
+    MinAssetsPerShare { asset_1: Decimal, asset_2: Decimal },
 }
 
 #[cw_serde]
@@ -45,7 +151,11 @@ pub struct CallerContext {
     pub sender: String,
     pub pool_denom_1: String,
     pub pool_denom_2: String,
-    pub gamm_denom: String,
+    /// gamm shares denom, not set for concentrated liquidity positions
+    pub gamm_denom: Option<String>,
+    /// concentrated liquidity position that no longer exists
+    /// if the pool interaction succeeds
+    pub closed_position_id: Option<Uint64>,
 }
 
 #[cw_serde]
@@ -58,9 +168,81 @@ pub enum MigrateMsg {
 
 pub trait OsmosisPool {
     fn validate_pool_assets_length(&self) -> Result<(), ContractError>;
-    fn validate_pool_asset_weights(&self) -> Result<(), ContractError>;
     fn get_pool_cw_coins(&self) -> Result<Vec<Coin>, ContractError>;
     fn get_gamm_cw_coin(&self) -> Result<Coin, ContractError>;
+    /// spot price of the pool (asset 1 / asset 2)
+    fn get_spot_price(&self) -> Result<Decimal256, ContractError>;
+}
+
+/// gamm pools that the outpost can provide liquidity to
+pub enum GammPool {
+    Balancer(Pool),
+    Stableswap(StableswapPool),
+}
+
+impl GammPool {
+    pub fn get_id(&self) -> u64 {
+        match self {
+            GammPool::Balancer(pool) => pool.id,
+            GammPool::Stableswap(pool) => pool.id,
+        }
+    }
+}
+
+impl TryFrom<Option<Any>> for GammPool {
+    type Error = StdError;
+
+    fn try_from(pool: Option<Any>) -> Result<Self, Self::Error> {
+        match pool {
+            Some(any) if any.type_url == BALANCER_POOL_TYPE_URL => {
+                Pool::decode(any.value.as_slice())
+                    .map(GammPool::Balancer)
+                    .map_err(|e| StdError::parse_err("Pool", e.to_string()))
+            }
+            Some(any) if any.type_url == STABLESWAP_POOL_TYPE_URL => {
+                StableswapPool::decode(any.value.as_slice())
+                    .map(GammPool::Stableswap)
+                    .map_err(|e| StdError::parse_err("StableswapPool", e.to_string()))
+            }
+            Some(any) => Err(StdError::generic_err(format!(
+                "unsupported pool type: {}",
+                any.type_url
+            ))),
+            None => Err(StdError::NotFound {
+                kind: "shim not found".to_string(),
+            }),
+        }
+    }
+}
+
+impl OsmosisPool for GammPool {
+    fn validate_pool_assets_length(&self) -> Result<(), ContractError> {
+        match self {
+            GammPool::Balancer(pool) => pool.validate_pool_assets_length(),
+            GammPool::Stableswap(pool) => pool.validate_pool_assets_length(),
+        }
+    }
+
+    fn get_pool_cw_coins(&self) -> Result<Vec<Coin>, ContractError> {
+        match self {
+            GammPool::Balancer(pool) => pool.get_pool_cw_coins(),
+            GammPool::Stableswap(pool) => pool.get_pool_cw_coins(),
+        }
+    }
+
+    fn get_gamm_cw_coin(&self) -> Result<Coin, ContractError> {
+        match self {
+            GammPool::Balancer(pool) => pool.get_gamm_cw_coin(),
+            GammPool::Stableswap(pool) => pool.get_gamm_cw_coin(),
+        }
+    }
+
+    fn get_spot_price(&self) -> Result<Decimal256, ContractError> {
+        match self {
+            GammPool::Balancer(pool) => pool.get_spot_price(),
+            GammPool::Stableswap(pool) => pool.get_spot_price(),
+        }
+    }
 }
 
 impl OsmosisPool for Pool {
@@ -75,18 +257,6 @@ impl OsmosisPool for Pool {
         }
     }
 
-    /// only gamm 50:50 pools are supported (for now)
-    fn validate_pool_asset_weights(&self) -> Result<(), ContractError> {
-        if self.pool_assets[0].weight != self.pool_assets[1].weight {
-            Err(ContractError::PoolRatioError(format!(
-                "{:?}:{:?}",
-                self.pool_assets[0].weight, self.pool_assets[1].weight
-            )))
-        } else {
-            Ok(())
-        }
-    }
-
     /// collect the pool assets into cw coins
     fn get_pool_cw_coins(&self) -> Result<Vec<Coin>, ContractError> {
         let mut pool_assets: Vec<Coin> = vec![];
@@ -107,14 +277,74 @@ impl OsmosisPool for Pool {
     }
 
     fn get_gamm_cw_coin(&self) -> Result<Coin, ContractError> {
-        match &self.total_shares {
-            Some(coin) => Ok(Coin {
-                denom: coin.denom.to_string(),
-                amount: Uint128::from_str(&coin.amount)?,
-            }),
-            None => Err(ContractError::OsmosisPoolError(
-                "expected Some(total_shares), found None".to_string(),
+        get_total_shares_cw_coin(&self.total_shares)
+    }
+
+    /// weighted spot price `(reserve_1 / weight_1) / (reserve_2 / weight_2)`
+    fn get_spot_price(&self) -> Result<Decimal256, ContractError> {
+        self.validate_pool_assets_length()?;
+        let pool_assets = self.get_pool_cw_coins()?;
+        let weight_1 = Uint128::from_str(&self.pool_assets[0].weight)?;
+        let weight_2 = Uint128::from_str(&self.pool_assets[1].weight)?;
+
+        Decimal256::checked_from_ratio(
+            Uint256::from(pool_assets[0].amount).checked_mul(weight_2.into())?,
+            Uint256::from(pool_assets[1].amount).checked_mul(weight_1.into())?,
+        )
+        .map_err(|e| ContractError::OsmosisPoolError(e.to_string()))
+    }
+}
+
+impl OsmosisPool for StableswapPool {
+    /// validate that the pool we wish to provide liquidity
+    /// to is composed of two assets
+    fn validate_pool_assets_length(&self) -> Result<(), ContractError> {
+        match (self.pool_liquidity.len(), self.scaling_factors.len()) {
+            (2, 2) => Ok(()),
+            _ => Err(ContractError::OsmosisPoolError(
+                "pool must have 2 assets".to_string(),
             )),
         }
     }
+
+    /// collect the pool reserves into cw coins
+    fn get_pool_cw_coins(&self) -> Result<Vec<Coin>, ContractError> {
+        let mut pool_assets: Vec<Coin> = vec![];
+        for reserve in &self.pool_liquidity {
+            pool_assets.push(Coin {
+                denom: reserve.denom.to_string(),
+                amount: Uint128::from_str(&reserve.amount)?,
+            });
+        }
+        Ok(pool_assets)
+    }
+
+    fn get_gamm_cw_coin(&self) -> Result<Coin, ContractError> {
+        get_total_shares_cw_coin(&self.total_shares)
+    }
+
+    fn get_spot_price(&self) -> Result<Decimal256, ContractError> {
+        self.validate_pool_assets_length()?;
+        let pool_assets = self.get_pool_cw_coins()?;
+
+        get_stableswap_spot_price(
+            (pool_assets[0].amount.into(), pool_assets[1].amount.into()),
+            (
+                self.scaling_factors[0].into(),
+                self.scaling_factors[1].into(),
+            ),
+        )
+    }
+}
+
+fn get_total_shares_cw_coin(coin: &Option<ProtoCoin>) -> Result<Coin, ContractError> {
+    match coin {
+        Some(coin) => Ok(Coin {
+            denom: coin.denom.to_string(),
+            amount: Uint128::from_str(&coin.amount)?,
+        }),
+        None => Err(ContractError::OsmosisPoolError(
+            "expected Some(total_shares), found None".to_string(),
+        )),
+    }
 }
@@ -0,0 +1,61 @@
+//! osmosis stableswap pool type.
+//! only the fields relevant to the outpost are declared.
+
+use cosmwasm_std::{Decimal256, Uint256};
+use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+use prost::Message;
+
+use crate::error::ContractError;
+
+pub const STABLESWAP_POOL_TYPE_URL: &str = "/osmosis.gamm.poolmodels.stableswap.v1beta1.Pool";
+
+#[derive(Clone, PartialEq, Message)]
+pub struct StableswapPool {
+    #[prost(string, tag = "1")]
+    pub address: String,
+    #[prost(uint64, tag = "2")]
+    pub id: u64,
+    #[prost(message, optional, tag = "5")]
+    pub total_shares: Option<ProtoCoin>,
+    /// pool reserves, sorted by denom
+    #[prost(message, repeated, tag = "6")]
+    pub pool_liquidity: Vec<ProtoCoin>,
+    /// scaling factors of the reserves, in the same order
+    #[prost(uint64, repeated, packed = "true", tag = "7")]
+    pub scaling_factors: Vec<u64>,
+}
+
+/// spot price (asset 1 / asset 2) of the osmosis stableswap curve
+/// `xy(x^2 + y^2) = k`, where x and y are the scaled reserves.
+///
+/// with `q = y / x`, the marginal price of the scaled reserves is
+/// `(1 + 3q^2) / (q(3 + q^2))`, which is then unscaled by the
+/// ratio of the scaling factors.
+pub fn get_stableswap_spot_price(
+    reserves: (Uint256, Uint256),
+    scaling_factors: (Uint256, Uint256),
+) -> Result<Decimal256, ContractError> {
+    let (reserve_1, reserve_2) = reserves;
+    let (scaling_factor_1, scaling_factor_2) = scaling_factors;
+
+    let q = Decimal256::checked_from_ratio(
+        reserve_2.checked_mul(scaling_factor_1)?,
+        reserve_1.checked_mul(scaling_factor_2)?,
+    )
+    .map_err(|e| ContractError::OsmosisPoolError(e.to_string()))?;
+    let q_squared = q.checked_mul(q)?;
+    let three = Decimal256::from_ratio(3u128, 1u128);
+
+    let numerator = Decimal256::one().checked_add(three.checked_mul(q_squared)?)?;
+    let denominator = q.checked_mul(three.checked_add(q_squared)?)?;
+    let scaled_price = numerator
+        .checked_div(denominator)
+        .map_err(|e| ContractError::OsmosisPoolError(e.to_string()))?;
+
+    scaled_price
+        .checked_mul(
+            Decimal256::checked_from_ratio(scaling_factor_1, scaling_factor_2)
+                .map_err(|e| ContractError::OsmosisPoolError(e.to_string()))?,
+        )
+        .map_err(ContractError::OverflowError)
+}
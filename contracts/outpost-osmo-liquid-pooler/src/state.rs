@@ -1,4 +1,9 @@
 use crate::msg::CallerContext;
-use cw_storage_plus::Item;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
 
 pub const PENDING_REPLY: Item<CallerContext> = Item::new("pending_reply");
+
+/// concentrated liquidity positions are owned by the outpost.
+/// we keep track of the accounts on whose behalf they were created.
+pub const POSITION_OWNERS: Map<u64, Addr> = Map::new("position_owners");
@@ -8,12 +8,16 @@ use cosmwasm_std::{
 };
 use covenant_utils::op_mode::ContractOperationModeConfig;
 use covenant_utils::split::SplitConfig;
-use covenant_utils::{instantiate2_helper::get_instantiate2_salt_and_address, DestinationConfig};
+use covenant_utils::{
+    instantiate2_helper::get_instantiate2_salt_and_address, DestinationConfig, ReceiverConfig,
+};
 use cw2::set_contract_version;
 use valence_ibc_forwarder::msg::InstantiateMsg as IbcForwarderInstantiateMsg;
 use valence_interchain_router::msg::InstantiateMsg as RouterInstantiateMsg;
 use valence_remote_chain_splitter::msg::InstantiateMsg as SplitterInstantiateMsg;
-use valence_single_party_pol_holder::msg::InstantiateMsg as HolderInstantiateMsg;
+use valence_single_party_pol_holder::msg::{
+    EarlyWithdrawalConfig, InstantiateMsg as HolderInstantiateMsg,
+};
 use valence_stride_liquid_staker::msg::InstantiateMsg as LiquidStakerInstantiateMsg;
 
 use crate::msg::LiquidPoolerMigrateMsg;
@@ -142,10 +146,17 @@ pub fn instantiate(
 
     let holder_instantiate2_msg = HolderInstantiateMsg {
         withdrawer: msg.covenant_party_config.addr.to_string(),
-        withdraw_to: router_instantiate2_config.addr.to_string(),
-        emergency_committee_addr: msg.emergency_committee.clone(),
+        withdraw_to: msg.withdraw_to.clone().unwrap_or(ReceiverConfig::Native(
+            router_instantiate2_config.addr.to_string(),
+        )),
+        emergency_committee: msg.emergency_committee.clone(),
         lockup_period: msg.lockup_period,
         pooler_address: liquid_pooler_instantiate2_config.addr.to_string(),
+        unlock_schedule: msg.unlock_schedule.clone(),
+        early_withdrawal_config: msg
+            .early_withdrawal_config
+            .clone()
+            .unwrap_or(EarlyWithdrawalConfig::Disabled),
     }
     .to_instantiate2_msg(
         &holder_instantiate2_config,
@@ -401,7 +412,9 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
             if let Some(liquid_pooler_migrate_msg) = liquid_pooler {
                 let msg: Binary = match liquid_pooler_migrate_msg {
                     LiquidPoolerMigrateMsg::Astroport(msg) => to_json_binary(&msg)?,
+                    LiquidPoolerMigrateMsg::Duality(msg) => to_json_binary(&msg)?,
                     LiquidPoolerMigrateMsg::Osmosis(msg) => to_json_binary(&msg)?,
+                    LiquidPoolerMigrateMsg::Custom(msg) => msg,
                 };
                 resp = resp.add_attribute("liquid_pooler_migrate", msg.to_base64());
                 migrate_msgs.push(WasmMsg::Migrate {
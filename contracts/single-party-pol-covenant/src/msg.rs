@@ -3,13 +3,19 @@ use std::collections::BTreeMap;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Binary, Decimal, StdResult, Uint128, Uint64, WasmMsg};
 use covenant_utils::{
-    instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
-    CovenantParty, DestinationConfig, InterchainCovenantParty, NativeCovenantParty,
-    PacketForwardMiddlewareConfig, PoolPriceConfig, ReceiverConfig,
+    emergency_committee::EmergencyCommitteeConfig, instantiate2_helper::Instantiate2HelperConfig,
+    op_mode::ContractOperationModeConfig, CovenantParty, DestinationConfig,
+    InterchainCovenantParty, NativeCovenantParty, PacketForwardMiddlewareConfig, PoolPriceConfig,
+    ReceiverConfig,
 };
 use cw_utils::Expiration;
+use liquid_pooler_interface::{
+    CustomLiquidPoolerConfig, LiquidPoolerInstantiate, LiquidPoolerInstantiateFields,
+};
 use valence_astroport_liquid_pooler::msg::AstroportLiquidPoolerConfig;
+use valence_duality_liquid_pooler::msg::DualityLiquidPoolerConfig;
 use valence_osmo_liquid_pooler::msg::OsmosisLiquidPoolerConfig;
+use valence_single_party_pol_holder::msg::{EarlyWithdrawalConfig, UnlockSchedule};
 
 pub const DEFAULT_TIMEOUT: u64 = 60 * 60 * 5; // 5 hours
 
@@ -25,16 +31,28 @@ pub struct InstantiateMsg {
     pub lp_forwarder_config: CovenantPartyConfig,
     pub pool_price_config: PoolPriceConfig,
     pub remote_chain_splitter_config: RemoteChainSplitterConfig,
-    pub emergency_committee: Option<String>,
+    pub emergency_committee: Option<EmergencyCommitteeConfig>,
     pub covenant_party_config: InterchainCovenantParty,
     pub liquid_pooler_config: LiquidPoolerConfig,
     pub operation_mode: ContractOperationModeConfig,
+    /// optional schedule gradually unlocking the position
+    /// after the lockup period
+    pub unlock_schedule: Option<UnlockSchedule>,
+    /// config describing the early withdrawal dynamics.
+    /// disabled if not set.
+    pub early_withdrawal_config: Option<EarlyWithdrawalConfig>,
+    /// receiver of the withdrawn liquidity. if not set,
+    /// liquidity is withdrawn to the interchain router.
+    pub withdraw_to: Option<ReceiverConfig>,
 }
 
 #[cw_serde]
 pub enum LiquidPoolerConfig {
     Osmosis(Box<OsmosisLiquidPoolerConfig>),
     Astroport(AstroportLiquidPoolerConfig),
+    Duality(DualityLiquidPoolerConfig),
+    /// any other pooler implementing the liquid pooler interface
+    Custom(CustomLiquidPoolerConfig),
 }
 
 impl LiquidPoolerConfig {
@@ -47,14 +65,22 @@ impl LiquidPoolerConfig {
         holder_addr: String,
         pool_price_config: PoolPriceConfig,
     ) -> StdResult<WasmMsg> {
-        match self {
-            LiquidPoolerConfig::Osmosis(config) => Ok(config
-                .to_instantiate_msg(op_mode, holder_addr.to_string(), pool_price_config)
-                .to_instantiate2_msg(instantiate2_helper, admin, label)?),
-            LiquidPoolerConfig::Astroport(config) => Ok(config
-                .to_instantiate_msg(holder_addr.to_string(), pool_price_config, op_mode)
-                .to_instantiate2_msg(instantiate2_helper, admin, label)?),
-        }
+        let config: &dyn LiquidPoolerInstantiate = match self {
+            LiquidPoolerConfig::Osmosis(config) => config.as_ref(),
+            LiquidPoolerConfig::Astroport(config) => config,
+            LiquidPoolerConfig::Duality(config) => config,
+            LiquidPoolerConfig::Custom(config) => config,
+        };
+        config.to_instantiate2_msg(
+            instantiate2_helper,
+            admin,
+            label,
+            LiquidPoolerInstantiateFields {
+                holder_address: holder_addr,
+                op_mode_cfg: op_mode,
+                pool_price_config,
+            },
+        )
     }
 }
 
@@ -211,4 +237,7 @@ pub enum MigrateMsg {
 pub enum LiquidPoolerMigrateMsg {
     Osmosis(valence_osmo_liquid_pooler::msg::MigrateMsg),
     Astroport(valence_astroport_liquid_pooler::msg::MigrateMsg),
+    Duality(valence_duality_liquid_pooler::msg::MigrateMsg),
+    /// migrate msg of any other pooler implementing the liquid pooler interface
+    Custom(Binary),
 }
@@ -1,18 +1,36 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, to_json_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    ensure, to_json_binary, Attribute, Binary, BlockInfo, Coin, CosmosMsg, Decimal, Deps, DepsMut,
+    Env, Fraction, MessageInfo, Response, StdError, StdResult, Storage,
 };
-use covenant_utils::withdraw_lp_helper::{generate_withdraw_msg, EMERGENCY_COMMITTEE_ADDR};
+use covenant_utils::emergency_committee::{
+    approve_emergency_action, migrate_legacy_emergency_committee, propose_emergency_action,
+    query_pending_emergency_actions, save_emergency_committee, take_executable_emergency_action,
+    EmergencyAction, EMERGENCY_COMMITTEE,
+};
+use covenant_utils::history::{query_history, record_history, HistoryAction};
+use covenant_utils::ReceiverConfig;
 use cw2::set_contract_version;
+use liquid_pooler_interface::LiquidPoolerContract;
+use neutron_sdk::bindings::{msg::NeutronMsg, query::NeutronQuery};
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use crate::state::{LOCKUP_PERIOD, POOLER_ADDRESS, WITHDRAWER, WITHDRAW_STATE, WITHDRAW_TO};
+use crate::msg::{
+    ClaimStatus, EarlyWithdrawalConfig, EarlyWithdrawalTerms, ExecuteMsg, InstantiateMsg,
+    MigrateMsg, QueryMsg,
+};
+use crate::state::{
+    EARLY_WITHDRAWAL_CONFIG, EMERGENCY_WITHDRAW_TO, LEGACY_WITHDRAW_TO, LOCKUP_PERIOD,
+    PENDING_EARLY_WITHDRAWAL_PENALTY, PENDING_WITHDRAW_PERCENTAGE, POOLER_ADDRESS, UNLOCK_SCHEDULE,
+    WITHDRAWER, WITHDRAWN_PERCENTAGE, WITHDRAW_STATE, WITHDRAW_TO,
+};
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+type ExecuteDeps<'a> = DepsMut<'a, NeutronQuery>;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -23,11 +41,11 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     let withdrawer = deps.api.addr_validate(&msg.withdrawer)?;
-    let withdraw_to = deps.api.addr_validate(&msg.withdraw_to)?;
+    msg.withdraw_to.validate_receiver_address(deps.api)?;
     let liquidity_pooler_address = deps.api.addr_validate(&msg.pooler_address)?;
 
     WITHDRAWER.save(deps.storage, &withdrawer)?;
-    WITHDRAW_TO.save(deps.storage, &withdraw_to)?;
+    WITHDRAW_TO.save(deps.storage, &msg.withdraw_to)?;
     POOLER_ADDRESS.save(deps.storage, &liquidity_pooler_address)?;
 
     ensure!(
@@ -36,49 +54,143 @@ pub fn instantiate(
     );
     LOCKUP_PERIOD.save(deps.storage, &msg.lockup_period)?;
 
-    let resp = Response::default()
+    msg.early_withdrawal_config.validate(deps.api)?;
+    EARLY_WITHDRAWAL_CONFIG.save(deps.storage, &msg.early_withdrawal_config)?;
+
+    let mut resp = Response::default()
         .add_attribute("method", "instantiate")
         .add_attribute("pool_address", liquidity_pooler_address)
         .add_attribute("withdrawer", withdrawer)
-        .add_attribute("withdraw_to", withdraw_to);
+        .add_attribute("withdraw_to", format!("{:?}", msg.withdraw_to))
+        .add_attributes(msg.early_withdrawal_config.get_response_attributes());
 
-    if let Some(addr) = msg.emergency_committee_addr {
-        EMERGENCY_COMMITTEE_ADDR.save(deps.storage, &deps.api.addr_validate(&addr)?)?;
-        Ok(resp.add_attribute("emergency_committee", addr))
-    } else {
-        Ok(resp)
+    if let Some(unlock_schedule) = msg.unlock_schedule {
+        unlock_schedule.validate(&msg.lockup_period)?;
+        UNLOCK_SCHEDULE.save(deps.storage, &unlock_schedule)?;
+        resp = resp.add_attribute("unlock_schedule", format!("{unlock_schedule:?}"));
     }
+
+    if let Some(committee_config) = msg.emergency_committee {
+        let committee = committee_config.validate(deps.api)?;
+        save_emergency_committee(deps.storage, &committee)?;
+        resp = resp.add_attributes(committee.get_response_attributes());
+    }
+
+    Ok(resp)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Withdrawer {} => Ok(to_json_binary(&WITHDRAWER.may_load(deps.storage)?)?),
         QueryMsg::WithdrawTo {} => Ok(to_json_binary(&WITHDRAW_TO.may_load(deps.storage)?)?),
         QueryMsg::PoolerAddress {} => Ok(to_json_binary(&POOLER_ADDRESS.may_load(deps.storage)?)?),
-        QueryMsg::EmergencyCommitteeAddr {} => Ok(to_json_binary(
-            &EMERGENCY_COMMITTEE_ADDR.may_load(deps.storage)?,
+        QueryMsg::EmergencyCommittee {} => Ok(to_json_binary(
+            &EMERGENCY_COMMITTEE.may_load(deps.storage)?,
+        )?),
+        QueryMsg::PendingEmergencyActions {} => Ok(to_json_binary(
+            &query_pending_emergency_actions(deps.storage)?,
         )?),
         QueryMsg::LockupConfig {} => Ok(to_json_binary(&LOCKUP_PERIOD.load(deps.storage)?)?),
+        QueryMsg::UnlockSchedule {} => {
+            Ok(to_json_binary(&UNLOCK_SCHEDULE.may_load(deps.storage)?)?)
+        }
+        QueryMsg::ClaimStatus {} => Ok(to_json_binary(&query_claim_status(
+            deps.storage,
+            &env.block,
+        )?)?),
+        QueryMsg::EarlyWithdrawalConfig {} => Ok(to_json_binary(
+            &EARLY_WITHDRAWAL_CONFIG
+                .may_load(deps.storage)?
+                .unwrap_or(EarlyWithdrawalConfig::Disabled),
+        )?),
+        QueryMsg::EarlyWithdrawalPenalty {} => {
+            let penalty = match EARLY_WITHDRAWAL_CONFIG
+                .may_load(deps.storage)?
+                .unwrap_or(EarlyWithdrawalConfig::Disabled)
+            {
+                EarlyWithdrawalConfig::Disabled => None,
+                EarlyWithdrawalConfig::Enabled(terms) => Some(get_early_withdrawal_penalty(
+                    &query_claim_status(deps.storage, &env.block)?,
+                    &terms,
+                )?),
+            };
+            Ok(to_json_binary(&penalty)?)
+        }
+        QueryMsg::History { start_after, limit } => Ok(to_json_binary(&query_history(
+            deps.storage,
+            start_after,
+            limit,
+        )?)?),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    deps: ExecuteDeps,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
-) -> Result<Response, ContractError> {
+) -> Result<Response<NeutronMsg>, ContractError> {
     match msg {
-        ExecuteMsg::Claim {} => try_claim(deps, env, info),
-        ExecuteMsg::Distribute {} => try_distribute(deps, info),
+        ExecuteMsg::Claim { percentage } => try_claim(deps, env, info, percentage),
+        ExecuteMsg::EarlyWithdraw {} => try_early_withdraw(deps, env, info),
+        ExecuteMsg::Distribute {} => try_distribute(deps, env, info),
         ExecuteMsg::WithdrawFailed {} => try_withdraw_failed(deps, info),
-        ExecuteMsg::EmergencyWithdraw {} => try_emergency_withdraw(deps, info),
+        ExecuteMsg::ProposeEmergencyAction { action } => {
+            try_propose_emergency_action(deps, env, info, action)
+        }
+        ExecuteMsg::ApproveEmergencyAction { id } => try_approve_emergency_action(deps, info, id),
+        ExecuteMsg::ExecuteEmergencyAction { id } => {
+            try_execute_emergency_action(deps, env, info, id)
+        }
+    }
+}
+
+/// the unlocked share of the initial position, minus what was
+/// already withdrawn or is currently being withdrawn
+fn query_claim_status(storage: &dyn Storage, block: &BlockInfo) -> StdResult<ClaimStatus> {
+    let lockup_period = LOCKUP_PERIOD.load(storage)?;
+    let unlocked = match UNLOCK_SCHEDULE.may_load(storage)? {
+        Some(schedule) => schedule.get_unlocked_percentage(&lockup_period, block),
+        None if lockup_period.is_expired(block) => Decimal::one(),
+        None => Decimal::zero(),
+    };
+    let withdrawn = WITHDRAWN_PERCENTAGE.may_load(storage)?.unwrap_or_default()
+        + PENDING_WITHDRAW_PERCENTAGE
+            .may_load(storage)?
+            .unwrap_or_default();
+
+    Ok(ClaimStatus {
+        unlocked,
+        withdrawn,
+        claimable: unlocked.saturating_sub(withdrawn),
+    })
+}
+
+/// the liquid pooler withdraws a share of its current position,
+/// so the share of the initial position to withdraw is scaled
+/// by the share of the initial position that is left
+fn get_pooler_withdraw_percentage(
+    storage: &dyn Storage,
+    percentage: Decimal,
+) -> Result<Option<Decimal>, ContractError> {
+    let remaining =
+        Decimal::one().saturating_sub(WITHDRAWN_PERCENTAGE.may_load(storage)?.unwrap_or_default());
+    if percentage >= remaining {
+        return Ok(None);
     }
+    Ok(Some(percentage.checked_div(remaining).map_err(|e| {
+        ContractError::InvalidClaimPercentage(e.to_string())
+    })?))
 }
 
-fn try_claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+fn try_claim(
+    deps: ExecuteDeps,
+    env: Env,
+    info: MessageInfo,
+    percentage: Option<Decimal>,
+) -> Result<Response<NeutronMsg>, ContractError> {
     if WITHDRAW_STATE.load(deps.storage).is_ok() {
         return Err(ContractError::WithdrawAlreadyStarted {});
     }
@@ -92,67 +204,321 @@ fn try_claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Con
     let withdrawer = WITHDRAWER.load(deps.storage)?;
     ensure!(info.sender == withdrawer, ContractError::Unauthorized {});
 
+    let claim_status = query_claim_status(deps.storage, &env.block)?;
+    let percentage = percentage.unwrap_or(claim_status.claimable);
+    ensure!(
+        !percentage.is_zero() && percentage <= claim_status.claimable,
+        ContractError::InvalidClaimPercentage(claim_status.claimable.to_string())
+    );
+
     let pooler_address = POOLER_ADDRESS.load(deps.storage)?;
+    let pooler_withdraw_percentage = get_pooler_withdraw_percentage(deps.storage, percentage)?;
 
-    let withdraw_msg = generate_withdraw_msg(pooler_address.to_string(), None)?;
+    let withdraw_msg =
+        LiquidPoolerContract(pooler_address).withdraw_msg(pooler_withdraw_percentage)?;
 
     WITHDRAW_STATE.save(deps.storage, &true)?;
+    PENDING_WITHDRAW_PERCENTAGE.save(deps.storage, &percentage)?;
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Claim,
+        Some(withdrawer.to_string()),
+        vec![],
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_claim")
+        .add_attribute("claim_percentage", percentage.to_string())
+        .add_message(withdraw_msg))
+}
 
-    Ok(Response::default().add_message(withdraw_msg))
+/// the penalty is only charged on the locked part of the position,
+/// so it is scaled down to apply to the whole remaining position.
+/// the resulting penalty is clamped to 1.0 of the withdrawn funds.
+fn get_early_withdrawal_penalty(
+    claim_status: &ClaimStatus,
+    terms: &EarlyWithdrawalTerms,
+) -> StdResult<Decimal> {
+    let remaining = Decimal::one().saturating_sub(claim_status.withdrawn);
+    let locked = Decimal::one().saturating_sub(claim_status.unlocked);
+    if remaining.is_zero() {
+        return Ok(Decimal::zero());
+    }
+    let penalty = terms
+        .penalty
+        .checked_mul(locked)
+        .map_err(StdError::overflow)?
+        .checked_div(remaining)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    Ok(penalty.min(Decimal::one()))
 }
 
-fn try_emergency_withdraw(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
-    // Make sure we are not withdrawing already
+fn try_early_withdraw(
+    deps: ExecuteDeps,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    // first we error out if early withdrawal is disabled
+    let terms = match EARLY_WITHDRAWAL_CONFIG
+        .may_load(deps.storage)?
+        .unwrap_or(EarlyWithdrawalConfig::Disabled)
+    {
+        EarlyWithdrawalConfig::Disabled => return Err(ContractError::EarlyWithdrawalDisabled {}),
+        EarlyWithdrawalConfig::Enabled(terms) => terms,
+    };
+
     if WITHDRAW_STATE.load(deps.storage).is_ok() {
         return Err(ContractError::WithdrawAlreadyStarted {});
     }
 
-    let committee_addr = EMERGENCY_COMMITTEE_ADDR.load(deps.storage)?;
+    let withdrawer = WITHDRAWER.load(deps.storage)?;
+    ensure!(info.sender == withdrawer, ContractError::Unauthorized {});
+
+    // once everything is unlocked, the position is to be claimed
+    let claim_status = query_claim_status(deps.storage, &env.block)?;
     ensure!(
-        info.sender == committee_addr,
-        ContractError::Unauthorized {}
+        claim_status.unlocked < Decimal::one(),
+        ContractError::LockupPeriodIsExpired {}
     );
+    let penalty = get_early_withdrawal_penalty(&claim_status, &terms)?;
 
+    // early withdrawals exit whatever is left of the position
+    let remaining = Decimal::one().saturating_sub(claim_status.withdrawn);
     let pooler_address = POOLER_ADDRESS.load(deps.storage)?;
-    let withdraw_msg = generate_withdraw_msg(pooler_address.to_string(), None)?;
+    let withdraw_msg = LiquidPoolerContract(pooler_address).withdraw_msg(None)?;
 
     WITHDRAW_STATE.save(deps.storage, &true)?;
+    PENDING_WITHDRAW_PERCENTAGE.save(deps.storage, &remaining)?;
+    PENDING_EARLY_WITHDRAWAL_PENALTY.save(deps.storage, &penalty)?;
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::EarlyWithdrawal,
+        Some(withdrawer.to_string()),
+        vec![],
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_early_withdraw")
+        .add_attribute("early_withdrawal_penalty", penalty.to_string())
+        .add_message(withdraw_msg))
+}
 
-    Ok(Response::default().add_message(withdraw_msg))
+fn try_propose_emergency_action(
+    deps: ExecuteDeps,
+    env: Env,
+    info: MessageInfo,
+    action: EmergencyAction,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let pending_action =
+        propose_emergency_action(deps.storage, deps.api, &env.block, info.sender, action)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "propose_emergency_action")
+        .add_attribute("action_id", pending_action.id.to_string())
+        .add_attribute(
+            "executable_after",
+            pending_action.executable_after.to_string(),
+        ))
 }
 
-fn try_distribute(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+fn try_approve_emergency_action(
+    deps: ExecuteDeps,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let pending_action = approve_emergency_action(deps.storage, info.sender, id)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "approve_emergency_action")
+        .add_attribute("action_id", id.to_string())
+        .add_attribute("approvals", pending_action.approvals.len().to_string()))
+}
+
+fn try_execute_emergency_action(
+    deps: ExecuteDeps,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    // Make sure we are not withdrawing already
+    if WITHDRAW_STATE.load(deps.storage).is_ok() {
+        return Err(ContractError::WithdrawAlreadyStarted {});
+    }
+
+    // Withdrawing to the parties means withdrawing to the regular withdraw_to address,
+    // otherwise we store the receiver for the distribute callback
+    match take_executable_emergency_action(deps.storage, &env.block, info.sender, id)? {
+        EmergencyAction::WithdrawToParties {} => EMERGENCY_WITHDRAW_TO.remove(deps.storage),
+        EmergencyAction::WithdrawTo { receiver } => {
+            EMERGENCY_WITHDRAW_TO.save(deps.storage, &deps.api.addr_validate(&receiver)?)?
+        }
+    }
+
+    let pooler_address = POOLER_ADDRESS.load(deps.storage)?;
+    let withdraw_msg = LiquidPoolerContract(pooler_address).withdraw_msg(None)?;
+
+    // emergency withdrawals exit whatever is left of the position
+    let remaining = Decimal::one().saturating_sub(
+        WITHDRAWN_PERCENTAGE
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    );
+    WITHDRAW_STATE.save(deps.storage, &true)?;
+    PENDING_WITHDRAW_PERCENTAGE.save(deps.storage, &remaining)?;
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::EmergencyWithdrawal,
+        None,
+        vec![],
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "execute_emergency_action")
+        .add_attribute("action_id", id.to_string())
+        .add_message(withdraw_msg))
+}
+
+fn try_distribute(
+    deps: ExecuteDeps,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response<NeutronMsg>, ContractError> {
     let pooler_addr = POOLER_ADDRESS.load(deps.storage)?;
-    let withdraw_to_addr = WITHDRAW_TO.load(deps.storage)?;
+    // emergency withdrawals go to a local address, regular ones
+    // to the configured receiver
+    let withdraw_to = match EMERGENCY_WITHDRAW_TO.may_load(deps.storage)? {
+        Some(addr) => ReceiverConfig::Native(addr.to_string()),
+        None => WITHDRAW_TO.load(deps.storage)?,
+    };
 
     // only liquid pooler should call this method
     ensure!(info.sender == pooler_addr, ContractError::Unauthorized {});
-    ensure!(info.funds.len() == 2, ContractError::InvalidFunds {});
+    // one-sided withdrawals only return one of the pool denoms
+    ensure!(
+        !info.funds.is_empty() && info.funds.len() <= 2,
+        ContractError::InvalidFunds {}
+    );
 
-    // clear the pending withdraw state
+    // account for the completed withdrawal and clear the pending withdraw state
+    if let Some(pending) = PENDING_WITHDRAW_PERCENTAGE.may_load(deps.storage)? {
+        let withdrawn = WITHDRAWN_PERCENTAGE
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        WITHDRAWN_PERCENTAGE.save(deps.storage, &(withdrawn + pending).min(Decimal::one()))?;
+    }
+    let penalty = PENDING_EARLY_WITHDRAWAL_PENALTY.may_load(deps.storage)?;
     WITHDRAW_STATE.remove(deps.storage);
+    PENDING_WITHDRAW_PERCENTAGE.remove(deps.storage);
+    PENDING_EARLY_WITHDRAWAL_PENALTY.remove(deps.storage);
+    EMERGENCY_WITHDRAW_TO.remove(deps.storage);
+
+    // early withdrawals pay the penalty to its beneficiaries
+    let mut messages: Vec<CosmosMsg<NeutronMsg>> = vec![];
+    let mut withdraw_to_coins: Vec<Coin> = info.funds.clone();
+    if let Some(penalty) = penalty {
+        // penalty can never exceed the withdrawn funds
+        let penalty = penalty.min(Decimal::one());
+        if let EarlyWithdrawalConfig::Enabled(terms) = EARLY_WITHDRAWAL_CONFIG
+            .may_load(deps.storage)?
+            .unwrap_or(EarlyWithdrawalConfig::Disabled)
+        {
+            withdraw_to_coins = vec![];
+            for coin in info.funds.iter() {
+                let penalty_amount = coin
+                    .amount
+                    .checked_multiply_ratio(penalty.numerator(), penalty.denominator())
+                    .map_err(|e| StdError::generic_err(e.to_string()))?;
+                if !penalty_amount.is_zero() {
+                    // split transfers are plain bank sends
+                    for msg in terms.penalty_split.get_transfer_messages(
+                        penalty_amount,
+                        coin.denom.to_string(),
+                        None,
+                    )? {
+                        if let CosmosMsg::Bank(bank_msg) = msg {
+                            messages.push(bank_msg.into());
+                        }
+                    }
+                }
+                withdraw_to_coins.push(Coin {
+                    denom: coin.denom.to_string(),
+                    amount: coin
+                        .amount
+                        .checked_sub(penalty_amount)
+                        .map_err(StdError::overflow)?,
+                });
+            }
+        }
+    }
+    withdraw_to_coins.retain(|c| !c.amount.is_zero());
+
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Distribution,
+        Some(withdraw_to.validate_receiver_address(deps.api)?.to_string()),
+        withdraw_to_coins.clone(),
+    )?;
+
+    if !withdraw_to_coins.is_empty() {
+        messages.extend(withdraw_to.get_transfer_messages(
+            deps.querier,
+            &env,
+            withdraw_to_coins,
+        )?);
+    }
 
-    let send_msg = BankMsg::Send {
-        to_address: withdraw_to_addr.to_string(),
-        amount: info.funds,
-    };
-
-    Ok(Response::default().add_message(send_msg))
+    Ok(Response::default().add_messages(messages))
 }
 
 /// We don't need to do much if the withdraw failed.
 /// We just need to ensure the caller is the pooler, and remove the withdraw_state storage
-fn try_withdraw_failed(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+fn try_withdraw_failed(
+    deps: ExecuteDeps,
+    info: MessageInfo,
+) -> Result<Response<NeutronMsg>, ContractError> {
     let pooler_addr = POOLER_ADDRESS.load(deps.storage)?;
     ensure!(info.sender == pooler_addr, ContractError::Unauthorized {});
 
     WITHDRAW_STATE.remove(deps.storage);
+    PENDING_WITHDRAW_PERCENTAGE.remove(deps.storage);
+    PENDING_EARLY_WITHDRAWAL_PENALTY.remove(deps.storage);
+    EMERGENCY_WITHDRAW_TO.remove(deps.storage);
 
     Ok(Response::default())
 }
 
+/// previous versions stored the withdraw_to receiver as a plain address
+/// under the same key. such receivers are converted to native receivers.
+fn migrate_legacy_withdraw_to(storage: &mut dyn Storage) -> StdResult<Option<ReceiverConfig>> {
+    if WITHDRAW_TO.load(storage).is_ok() {
+        return Ok(None);
+    }
+    match LEGACY_WITHDRAW_TO.may_load(storage)? {
+        Some(addr) => {
+            let receiver = ReceiverConfig::Native(addr.to_string());
+            WITHDRAW_TO.save(storage, &receiver)?;
+            Ok(Some(receiver))
+        }
+        None => Ok(None),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    // the emergency committee address of the previous versions
+    // is carried over on any migration
+    let mut legacy_attributes = migrate_legacy_emergency_committee(deps.storage)?
+        .map(|committee| committee.get_response_attributes())
+        .unwrap_or_default();
+    // as is the plain address withdraw_to receiver
+    if let Some(receiver) = migrate_legacy_withdraw_to(deps.storage)? {
+        legacy_attributes.push(Attribute::new("withdraw_to", format!("{receiver:?}")));
+    }
+
     match msg {
         MigrateMsg::UpdateConfig {
             withdrawer,
@@ -160,22 +526,28 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, Con
             pooler_address,
             lockup_period,
             emergency_committee,
+            unlock_schedule,
+            early_withdrawal_config,
         } => {
-            let mut response = Response::default().add_attribute("method", "update_config");
+            let mut response = Response::default()
+                .add_attribute("method", "update_config")
+                .add_attributes(legacy_attributes);
 
             if let Some(addr) = withdrawer {
                 WITHDRAWER.save(deps.storage, &deps.api.addr_validate(&addr)?)?;
                 response = response.add_attribute("withdrawer", addr);
             }
 
-            if let Some(addr) = withdraw_to {
-                WITHDRAW_TO.save(deps.storage, &deps.api.addr_validate(&addr)?)?;
-                response = response.add_attribute("withdraw_to", addr);
+            if let Some(receiver) = withdraw_to {
+                receiver.validate_receiver_address(deps.api)?;
+                WITHDRAW_TO.save(deps.storage, &receiver)?;
+                response = response.add_attribute("withdraw_to", format!("{receiver:?}"));
             }
 
-            if let Some(addr) = emergency_committee {
-                EMERGENCY_COMMITTEE_ADDR.save(deps.storage, &deps.api.addr_validate(&addr)?)?;
-                response = response.add_attribute("emergency_committee", addr);
+            if let Some(committee_config) = emergency_committee {
+                let committee = committee_config.validate(deps.api)?;
+                save_emergency_committee(deps.storage, &committee)?;
+                response = response.add_attributes(committee.get_response_attributes());
             }
 
             if let Some(addr) = pooler_address {
@@ -193,13 +565,25 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, Con
                 response = response.add_attribute("lockup_period", expires.to_string());
             }
 
+            if let Some(schedule) = unlock_schedule {
+                schedule.validate(&LOCKUP_PERIOD.load(deps.storage)?)?;
+                UNLOCK_SCHEDULE.save(deps.storage, &schedule)?;
+                response = response.add_attribute("unlock_schedule", format!("{schedule:?}"));
+            }
+
+            if let Some(config) = early_withdrawal_config {
+                config.validate(deps.api)?;
+                EARLY_WITHDRAWAL_CONFIG.save(deps.storage, &config)?;
+                response = response.add_attributes(config.get_response_attributes());
+            }
+
             Ok(response)
         }
         MigrateMsg::UpdateCodeId { data: _ } => {
             // This is a migrate message to update code id,
             // Data is optional base64 that we can parse to any data we would like in the future
             // let data: SomeStruct = from_binary(&data)?;
-            Ok(Response::default())
+            Ok(Response::default().add_attributes(legacy_attributes))
         }
     }
 }
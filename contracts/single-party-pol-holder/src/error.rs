@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use covenant_utils::emergency_committee::EmergencyCommitteeError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,6 +7,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    EmergencyCommitteeError(#[from] EmergencyCommitteeError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -21,6 +25,21 @@ pub enum ContractError {
     #[error("The lockup period must be in the future")]
     MustBeFutureLockupPeriod,
 
-    #[error("We expect 2 denoms to be received from the liquidity pooler")]
+    #[error("We expect 1 or 2 denoms to be received from the liquidity pooler")]
     InvalidFunds,
+
+    #[error("Unlock percentage must be between 0 and 1")]
+    UnlockPercentageError {},
+
+    #[error("Unlock interval must be non-zero and of the same kind as the lockup period")]
+    UnlockIntervalError {},
+
+    #[error("Claim percentage must be positive and not exceed the claimable share: {0}")]
+    InvalidClaimPercentage(String),
+
+    #[error("Early withdrawal penalty must be in range of [0.0, 1.0)")]
+    EarlyWithdrawalPenaltyRangeError {},
+
+    #[error("Early withdrawal is disabled")]
+    EarlyWithdrawalDisabled {},
 }
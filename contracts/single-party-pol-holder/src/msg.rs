@@ -1,22 +1,38 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_binary, Binary, StdResult, WasmMsg};
+use cosmwasm_std::{
+    ensure, to_json_binary, Api, Attribute, Binary, BlockInfo, Decimal, StdResult, WasmMsg,
+};
 use covenant_macros::{covenant_holder_distribute, covenant_holder_emergency_withdraw};
-use covenant_utils::instantiate2_helper::Instantiate2HelperConfig;
-use cw_utils::Expiration;
+use covenant_utils::{
+    emergency_committee::{EmergencyAction, EmergencyCommitteeConfig},
+    instantiate2_helper::Instantiate2HelperConfig,
+    split::SplitConfig,
+    ReceiverConfig,
+};
+use cw_utils::{Duration, Expiration};
+
+use crate::error::ContractError;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     /// A withdrawer is the only authorized address that can withdraw
     /// from the contract.
     pub withdrawer: String,
-    /// Withdraw the funds to this address
-    pub withdraw_to: String,
-    /// The address that is allowed to do emergency pull out
-    pub emergency_committee_addr: Option<String>,
+    /// Withdraw the funds to this receiver, either a local address
+    /// or a remote chain address reached over ibc
+    pub withdraw_to: ReceiverConfig,
+    /// The committee that is allowed to do emergency pull out
+    pub emergency_committee: Option<EmergencyCommitteeConfig>,
     /// the neutron address of the liquid pooler
     pub pooler_address: String,
     /// The lockup period for the covenant
     pub lockup_period: Expiration,
+    /// Optional schedule gradually unlocking the position after
+    /// the lockup period. If not set, the whole position unlocks
+    /// once the lockup period expires.
+    pub unlock_schedule: Option<UnlockSchedule>,
+    /// config describing the early withdrawal dynamics
+    pub early_withdrawal_config: EarlyWithdrawalConfig,
 }
 
 impl InstantiateMsg {
@@ -37,12 +53,122 @@ impl InstantiateMsg {
     }
 }
 
+/// Unlocks `unlock_percentage` of the initial position when the lockup
+/// period expires, and another `unlock_percentage` after every `interval`.
+/// e.g. 25% per quarter after the lockup period.
+#[cw_serde]
+pub struct UnlockSchedule {
+    pub unlock_percentage: Decimal,
+    /// must be of the same kind (height or time) as the lockup period
+    pub interval: Duration,
+}
+
+impl UnlockSchedule {
+    pub fn validate(&self, lockup_period: &Expiration) -> Result<(), ContractError> {
+        ensure!(
+            self.unlock_percentage > Decimal::zero() && self.unlock_percentage <= Decimal::one(),
+            ContractError::UnlockPercentageError {}
+        );
+        match (lockup_period, self.interval) {
+            (Expiration::AtHeight(_), Duration::Height(interval))
+            | (Expiration::AtTime(_), Duration::Time(interval))
+                if interval > 0 =>
+            {
+                Ok(())
+            }
+            _ => Err(ContractError::UnlockIntervalError {}),
+        }
+    }
+
+    /// returns the share of the initial position unlocked at `block`
+    pub fn get_unlocked_percentage(
+        &self,
+        lockup_period: &Expiration,
+        block: &BlockInfo,
+    ) -> Decimal {
+        let elapsed_intervals = match (lockup_period, self.interval) {
+            (Expiration::AtHeight(height), Duration::Height(interval))
+                if block.height >= *height =>
+            {
+                (block.height - height) / interval
+            }
+            (Expiration::AtTime(time), Duration::Time(interval)) if block.time >= *time => {
+                (block.time.seconds() - time.seconds()) / interval
+            }
+            _ => return Decimal::zero(),
+        };
+
+        // the first tranche unlocks as soon as the lockup period expires
+        self.unlock_percentage
+            .checked_mul(Decimal::from_ratio(elapsed_intervals + 1, 1u64))
+            .unwrap_or(Decimal::one())
+            .min(Decimal::one())
+    }
+}
+
+#[cw_serde]
+pub enum EarlyWithdrawalConfig {
+    /// early withdrawal is disabled
+    Disabled,
+    /// early withdrawal is enabled with `EarlyWithdrawalTerms`
+    Enabled(EarlyWithdrawalTerms),
+}
+
+impl EarlyWithdrawalConfig {
+    pub fn get_response_attributes(&self) -> Vec<Attribute> {
+        match self {
+            EarlyWithdrawalConfig::Disabled => {
+                vec![Attribute::new("early_withdrawal_config", "disabled")]
+            }
+            EarlyWithdrawalConfig::Enabled(terms) => vec![
+                Attribute::new("early_withdrawal_config", "enabled"),
+                Attribute::new("early_withdrawal_penalty", terms.penalty.to_string()),
+                terms
+                    .penalty_split
+                    .get_response_attribute("early_withdrawal_penalty_split".to_string()),
+            ],
+        }
+    }
+
+    pub fn validate(&self, api: &dyn Api) -> Result<(), ContractError> {
+        match self {
+            EarlyWithdrawalConfig::Disabled => Ok(()),
+            EarlyWithdrawalConfig::Enabled(terms) => {
+                // validate the range: [0.00, 1.00)
+                ensure!(
+                    terms.penalty < Decimal::one(),
+                    ContractError::EarlyWithdrawalPenaltyRangeError {}
+                );
+                terms
+                    .penalty_split
+                    .validate_shares_and_receiver_addresses(api)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cw_serde]
+pub struct EarlyWithdrawalTerms {
+    /// decimal based penalty charged on the locked part of the position
+    /// when withdrawing early. Must be in the range of [0.00, 1.00).
+    pub penalty: Decimal,
+    /// split of the penalty between its beneficiaries
+    pub penalty_split: SplitConfig,
+}
+
 #[covenant_holder_distribute]
 #[covenant_holder_emergency_withdraw]
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// This is called by the withdrawer to start the withdraw process
-    Claim {},
+    /// This is called by the withdrawer to start the withdraw process.
+    /// `percentage` is the share of the initial position to claim,
+    /// defaulting to everything that is currently claimable.
+    Claim { percentage: Option<Decimal> },
+    /// This is called by the withdrawer to withdraw whatever is left
+    /// of the position before it is fully unlocked, paying the
+    /// early withdrawal penalty on its locked part
+    EarlyWithdraw {},
 }
 
 #[cw_serde]
@@ -51,25 +177,54 @@ pub enum QueryMsg {
     // Queries the withdrawer address
     #[returns(cosmwasm_std::Addr)]
     Withdrawer {},
-    #[returns(cosmwasm_std::Addr)]
+    #[returns(covenant_utils::ReceiverConfig)]
     WithdrawTo {},
     // Queries the pooler address
     #[returns(cosmwasm_std::Addr)]
     PoolerAddress {},
-    #[returns(cosmwasm_std::Addr)]
-    EmergencyCommitteeAddr {},
+    #[returns(Option<covenant_utils::emergency_committee::EmergencyCommittee>)]
+    EmergencyCommittee {},
+    #[returns(Vec<covenant_utils::emergency_committee::PendingEmergencyAction>)]
+    PendingEmergencyActions {},
     #[returns(Expiration)]
     LockupConfig {},
+    #[returns(Option<UnlockSchedule>)]
+    UnlockSchedule {},
+    /// unlocked, withdrawn and claimable shares of the initial position
+    #[returns(ClaimStatus)]
+    ClaimStatus {},
+    #[returns(EarlyWithdrawalConfig)]
+    EarlyWithdrawalConfig {},
+    /// penalty that an early withdrawal would currently incur,
+    /// relative to the remaining position. none if disabled.
+    #[returns(Option<Decimal>)]
+    EarlyWithdrawalPenalty {},
+    /// paginated log of the actions performed by the holder
+    #[returns(Vec<covenant_utils::history::HistoryEntry>)]
+    History {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct ClaimStatus {
+    pub unlocked: Decimal,
+    /// includes a claim that is currently being withdrawn
+    pub withdrawn: Decimal,
+    pub claimable: Decimal,
 }
 
 #[cw_serde]
 pub enum MigrateMsg {
     UpdateConfig {
         withdrawer: Option<String>,
-        withdraw_to: Option<String>,
-        emergency_committee: Option<String>,
+        withdraw_to: Option<ReceiverConfig>,
+        emergency_committee: Option<EmergencyCommitteeConfig>,
         pooler_address: Option<String>,
         lockup_period: Option<Expiration>,
+        unlock_schedule: Option<UnlockSchedule>,
+        early_withdrawal_config: Option<EarlyWithdrawalConfig>,
     },
     UpdateCodeId {
         data: Option<Binary>,
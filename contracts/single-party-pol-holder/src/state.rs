@@ -1,14 +1,33 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Decimal};
+use covenant_utils::ReceiverConfig;
 use cw_storage_plus::Item;
 use cw_utils::Expiration;
 
+use crate::msg::{EarlyWithdrawalConfig, UnlockSchedule};
+
 /// address authorized to withdraw liquidity and the underlying assets
 pub const WITHDRAWER: Item<Addr> = Item::new("withdrawer");
-/// Addr that we withdraw the liquidity to
-pub const WITHDRAW_TO: Item<Addr> = Item::new("withdraw_to");
+/// receiver that we withdraw the liquidity to
+pub const WITHDRAW_TO: Item<ReceiverConfig> = Item::new("withdraw_to");
+/// plain address receiver stored under the same key by the previous versions
+pub const LEGACY_WITHDRAW_TO: Item<Addr> = Item::new("withdraw_to");
 /// address of the pool we expect to withdraw assets from
 pub const POOLER_ADDRESS: Item<Addr> = Item::new("pool_address");
 /// The lockup period of the LP tokens
 pub const LOCKUP_PERIOD: Item<Expiration> = Item::new("lockup_period");
+/// Optional schedule unlocking the position gradually after the lockup period
+pub const UNLOCK_SCHEDULE: Item<UnlockSchedule> = Item::new("unlock_schedule");
+/// Share of the initial position withdrawn so far
+pub const WITHDRAWN_PERCENTAGE: Item<Decimal> = Item::new("withdrawn_percentage");
+/// Share of the initial position being withdrawn by the ongoing withdrawal
+pub const PENDING_WITHDRAW_PERCENTAGE: Item<Decimal> = Item::new("pending_withdraw_percentage");
 /// The state of the withdraw process
 pub const WITHDRAW_STATE: Item<bool> = Item::new("withdraw_state");
+/// Configuration of the early withdrawal dynamics
+pub const EARLY_WITHDRAWAL_CONFIG: Item<EarlyWithdrawalConfig> =
+    Item::new("early_withdrawal_config");
+/// Penalty applied to the funds of the ongoing early withdrawal
+pub const PENDING_EARLY_WITHDRAWAL_PENALTY: Item<Decimal> =
+    Item::new("pending_early_withdrawal_penalty");
+/// Receiver approved by the emergency committee for the ongoing withdrawal
+pub const EMERGENCY_WITHDRAW_TO: Item<Addr> = Item::new("emergency_withdraw_to");
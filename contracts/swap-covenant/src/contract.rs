@@ -3,12 +3,14 @@ use std::collections::BTreeSet;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, to_json_binary, to_json_string, Binary, Deps, DepsMut, Env, MessageInfo, Response,
-    StdError, StdResult, WasmMsg,
+    ensure, to_json_binary, to_json_string, Addr, Binary, Deps, DepsMut, Env, MessageInfo,
+    Response, StdError, StdResult, WasmMsg,
 };
 use covenant_utils::{
-    instantiate2_helper::get_instantiate2_salt_and_address, op_mode::ContractOperationModeConfig,
-    split::remap_splits, CovenantPartiesConfig, CovenantTerms, SwapCovenantTerms,
+    astroport::validate_astro_pool_denoms, instantiate2_helper::get_instantiate2_salt_and_address,
+    op_mode::ContractOperationModeConfig, split::remap_splits, CovenantPartiesConfig,
+    CovenantTerms, PartialFillSwapCovenantTerms, PriceReferencedSwapCovenantTerms, ReceiverConfig,
+    SwapCovenantTerms,
 };
 use cw2::set_contract_version;
 use valence_swap_holder::msg::RefundConfig;
@@ -19,7 +21,8 @@ use crate::{
     state::{
         CONTRACT_CODES, COVENANT_CLOCK_ADDR, COVENANT_INTERCHAIN_SPLITTER_ADDR,
         COVENANT_SWAP_HOLDER_ADDR, PARTY_A_IBC_FORWARDER_ADDR, PARTY_A_ROUTER_ADDR,
-        PARTY_B_IBC_FORWARDER_ADDR, PARTY_B_ROUTER_ADDR,
+        PARTY_A_VESTING_ESCROW_ADDR, PARTY_B_IBC_FORWARDER_ADDR, PARTY_B_ROUTER_ADDR,
+        PARTY_B_VESTING_ESCROW_ADDR,
     },
 };
 
@@ -33,6 +36,8 @@ pub(crate) const SPLITTER_SALT: &[u8] = b"splitter";
 pub(crate) const HOLDER_SALT: &[u8] = b"holder";
 pub(crate) const PARTY_A_FORWARDER_SALT: &[u8] = b"party_a_ibc_forwarder";
 pub(crate) const PARTY_B_FORWARDER_SALT: &[u8] = b"party_b_ibc_forwarder";
+pub(crate) const PARTY_A_VESTING_ESCROW_SALT: &[u8] = b"party_a_vesting_escrow";
+pub(crate) const PARTY_B_VESTING_ESCROW_SALT: &[u8] = b"party_b_vesting_escrow";
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -83,6 +88,7 @@ pub fn instantiate(
         &msg.contract_codes.to_covenant_codes_config(
             party_a_router_instantiate2_config.code,
             party_b_router_instantiate2_config.code,
+            msg.vesting.as_ref().map(|config| config.escrow_code),
         ),
     )?;
 
@@ -120,6 +126,71 @@ pub fn instantiate(
         party_b_router_instantiate2_config.clone(),
     )?;
 
+    // parties without a vesting schedule receive their side of the
+    // swap through their router. otherwise, it is routed through a
+    // vesting escrow that releases the funds to the party router.
+    let mut party_a_swap_receiver = party_a_router_instantiate2_config.addr.to_string();
+    let mut party_b_swap_receiver = party_b_router_instantiate2_config.addr.to_string();
+    let mut vesting_escrow_messages = vec![];
+    if let Some(vesting) = &msg.vesting {
+        if let Some(schedule) = &vesting.party_a {
+            let escrow_instantiate2_config = get_instantiate2_salt_and_address(
+                deps.as_ref(),
+                PARTY_A_VESTING_ESCROW_SALT,
+                &creator_address,
+                vesting.escrow_code,
+            )?;
+            vesting_escrow_messages.push(
+                valence_vesting_escrow::msg::InstantiateMsg {
+                    op_mode_cfg: op_mode_cfg.clone(),
+                    receiver_address: party_a_router_instantiate2_config.addr.to_string(),
+                    denoms: covenant_denoms.clone(),
+                    vesting_schedule: schedule.clone(),
+                }
+                .to_instantiate2_msg(
+                    &escrow_instantiate2_config,
+                    env.contract.address.to_string(),
+                    format!("{}_party_a_vesting_escrow", msg.label),
+                )?,
+            );
+            PARTY_A_VESTING_ESCROW_ADDR.save(deps.storage, &escrow_instantiate2_config.addr)?;
+            clock_initial_queue.push(escrow_instantiate2_config.addr.to_string());
+            party_a_swap_receiver = escrow_instantiate2_config.addr.to_string();
+            resp = resp.add_attribute(
+                "party_a_vesting_escrow_address",
+                escrow_instantiate2_config.addr.to_string(),
+            );
+        }
+        if let Some(schedule) = &vesting.party_b {
+            let escrow_instantiate2_config = get_instantiate2_salt_and_address(
+                deps.as_ref(),
+                PARTY_B_VESTING_ESCROW_SALT,
+                &creator_address,
+                vesting.escrow_code,
+            )?;
+            vesting_escrow_messages.push(
+                valence_vesting_escrow::msg::InstantiateMsg {
+                    op_mode_cfg: op_mode_cfg.clone(),
+                    receiver_address: party_b_router_instantiate2_config.addr.to_string(),
+                    denoms: covenant_denoms.clone(),
+                    vesting_schedule: schedule.clone(),
+                }
+                .to_instantiate2_msg(
+                    &escrow_instantiate2_config,
+                    env.contract.address.to_string(),
+                    format!("{}_party_b_vesting_escrow", msg.label),
+                )?,
+            );
+            PARTY_B_VESTING_ESCROW_ADDR.save(deps.storage, &escrow_instantiate2_config.addr)?;
+            clock_initial_queue.push(escrow_instantiate2_config.addr.to_string());
+            party_b_swap_receiver = escrow_instantiate2_config.addr.to_string();
+            resp = resp.add_attribute(
+                "party_b_vesting_escrow_address",
+                escrow_instantiate2_config.addr.to_string(),
+            );
+        }
+    }
+
     // we validate that denoms explicitly defined in splits are the
     // same denoms that parties are expected to contribute
     ensure!(
@@ -139,28 +210,31 @@ pub fn instantiate(
         )
     );
 
+    // fallback split receivers are remapped to the party routers
+    let fallback_split = match msg.fallback_split.clone() {
+        Some(config) => Some(config.remap_receivers_to_routers(
+            msg.party_a_config.get_final_receiver_address(),
+            party_a_router_instantiate2_config.addr.to_string(),
+            msg.party_b_config.get_final_receiver_address(),
+            party_b_router_instantiate2_config.addr.to_string(),
+        )?),
+        None => None,
+    };
+
     let splitter_instantiate2_msg = valence_native_splitter::msg::InstantiateMsg {
         op_mode_cfg: op_mode_cfg.clone(),
         splits: remap_splits(
             msg.splits.clone(),
             (
                 msg.party_a_config.get_final_receiver_address(),
-                party_a_router_instantiate2_config.addr.to_string(),
+                party_a_swap_receiver,
             ),
             (
                 msg.party_b_config.get_final_receiver_address(),
-                party_b_router_instantiate2_config.addr.to_string(),
+                party_b_swap_receiver,
             ),
         )?,
-        fallback_split: match msg.fallback_split.clone() {
-            Some(config) => Some(config.remap_receivers_to_routers(
-                msg.party_a_config.get_final_receiver_address(),
-                party_a_router_instantiate2_config.addr.to_string(),
-                msg.party_b_config.get_final_receiver_address(),
-                party_b_router_instantiate2_config.addr.to_string(),
-            )?),
-            None => None,
-        },
+        fallback_split: fallback_split.clone(),
     }
     .to_instantiate2_msg(
         &splitter_instantiate2_config,
@@ -168,22 +242,26 @@ pub fn instantiate(
         format!("{}_interchain_splitter", msg.label),
     )?;
 
+    let covenant_terms = get_covenant_terms(deps.as_ref(), &msg)?;
+
     let holder_instantiate2_msg = valence_swap_holder::msg::InstantiateMsg {
         lockup_config: msg.lockup_config,
         parties_config: CovenantPartiesConfig {
             party_a: msg.party_a_config.to_covenant_party(),
             party_b: msg.party_b_config.to_covenant_party(),
         },
-        covenant_terms: CovenantTerms::TokenSwap(SwapCovenantTerms {
-            party_a_amount: msg.party_a_config.get_contribution().amount,
-            party_b_amount: msg.party_b_config.get_contribution().amount,
-        }),
+        covenant_terms,
         op_mode_cfg: op_mode_cfg.clone(),
         next_contract: splitter_instantiate2_config.addr.to_string(),
-        refund_config: RefundConfig {
-            party_a_refund_address: party_a_router_instantiate2_config.addr.to_string(),
-            party_b_refund_address: party_b_router_instantiate2_config.addr.to_string(),
-        },
+        refund_config: msg.refund_config.clone().unwrap_or(RefundConfig {
+            party_a_refund_receiver: ReceiverConfig::Native(
+                party_a_router_instantiate2_config.addr.to_string(),
+            ),
+            party_b_refund_receiver: ReceiverConfig::Native(
+                party_b_router_instantiate2_config.addr.to_string(),
+            ),
+        }),
+        fallback_split,
     }
     .to_instantiate2_msg(
         &holder_instantiate2_config,
@@ -197,6 +275,7 @@ pub fn instantiate(
         party_b_router_instantiate2_msg,
         splitter_instantiate2_msg,
     ];
+    messages.extend(vesting_escrow_messages);
 
     // if party A is an interchain party, we include it in the
     // covenant flow. otherwise party is native, meaning that
@@ -325,6 +404,54 @@ pub fn instantiate(
         .add_messages(messages))
 }
 
+/// builds the swap holder terms out of the party contributions
+/// and the optional partial fill or price reference configuration
+fn get_covenant_terms(deps: Deps, msg: &InstantiateMsg) -> Result<CovenantTerms, ContractError> {
+    let party_a_amount = msg.party_a_config.get_contribution().amount;
+    let party_b_amount = msg.party_b_config.get_contribution().amount;
+
+    match (msg.min_fill_ratio, msg.price_reference.clone()) {
+        (Some(_), Some(_)) => Err(ContractError::PriceReferencedPartialFillError {}),
+        (None, Some(config)) => {
+            ensure!(
+                party_b_amount >= config.max_party_b_amount,
+                ContractError::PriceReferenceContributionError {}
+            );
+            // validate that the referenced pool trades both party denoms
+            deps.api.addr_validate(&config.pool_address)?;
+            validate_astro_pool_denoms(
+                deps.querier,
+                &config.pool_address,
+                &[
+                    msg.party_a_config.get_native_denom().as_str(),
+                    msg.party_b_config.get_native_denom().as_str(),
+                ],
+            )?;
+            Ok(CovenantTerms::PriceReferencedTokenSwap(
+                PriceReferencedSwapCovenantTerms {
+                    party_a_amount,
+                    pool_address: config.pool_address,
+                    price_source: config.price_source,
+                    spread: config.spread,
+                    min_party_b_amount: config.min_party_b_amount,
+                    max_party_b_amount: config.max_party_b_amount,
+                },
+            ))
+        }
+        (Some(min_fill_ratio), None) => Ok(CovenantTerms::PartialFillTokenSwap(
+            PartialFillSwapCovenantTerms {
+                party_a_amount,
+                party_b_amount,
+                min_fill_ratio,
+            },
+        )),
+        (None, None) => Ok(CovenantTerms::TokenSwap(SwapCovenantTerms {
+            party_a_amount,
+            party_b_amount,
+        })),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -383,10 +510,34 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             };
             Ok(to_json_binary(&resp)?)
         }
+        QueryMsg::VestingEscrowAddress { party } => {
+            Ok(to_json_binary(&query_party_vesting_escrow(deps, &party)?)?)
+        }
+        QueryMsg::VestingProgress { party } => {
+            let resp: Option<valence_vesting_escrow::msg::VestingProgress> =
+                match query_party_vesting_escrow(deps, &party)? {
+                    Some(addr) => Some(deps.querier.query_wasm_smart(
+                        addr,
+                        &valence_vesting_escrow::msg::QueryMsg::VestingProgress {},
+                    )?),
+                    None => None,
+                };
+            Ok(to_json_binary(&resp)?)
+        }
         QueryMsg::ContractCodes {} => Ok(to_json_binary(&CONTRACT_CODES.load(deps.storage)?)?),
     }
 }
 
+fn query_party_vesting_escrow(deps: Deps, party: &str) -> StdResult<Option<Addr>> {
+    if party == "party_a" {
+        PARTY_A_VESTING_ESCROW_ADDR.may_load(deps.storage)
+    } else if party == "party_b" {
+        PARTY_B_VESTING_ESCROW_ADDR.may_load(deps.storage)
+    } else {
+        Err(StdError::not_found("unknown party"))
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
     match msg {
@@ -399,6 +550,8 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
             party_b_router,
             party_a_forwarder,
             party_b_forwarder,
+            party_a_vesting_escrow,
+            party_b_vesting_escrow,
         } => {
             let mut migrate_msgs = vec![];
             let mut resp = Response::default().add_attribute("method", "migrate_contracts");
@@ -468,6 +621,31 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
                 });
             }
 
+            for (escrow_migrate_msg, escrow_addr, attr) in [
+                (
+                    party_a_vesting_escrow,
+                    &PARTY_A_VESTING_ESCROW_ADDR,
+                    "party_a_vesting_escrow_migrate",
+                ),
+                (
+                    party_b_vesting_escrow,
+                    &PARTY_B_VESTING_ESCROW_ADDR,
+                    "party_b_vesting_escrow_migrate",
+                ),
+            ] {
+                if let Some(escrow_migrate_msg) = escrow_migrate_msg {
+                    let msg = to_json_binary(&escrow_migrate_msg)?;
+                    resp = resp.add_attribute(attr, msg.to_base64());
+                    migrate_msgs.push(WasmMsg::Migrate {
+                        contract_addr: escrow_addr.load(deps.storage)?.to_string(),
+                        new_code_id: contract_codes
+                            .vesting_escrow
+                            .ok_or_else(|| StdError::not_found("vesting escrow code"))?,
+                        msg,
+                    });
+                }
+            }
+
             if let Some(holder) = holder {
                 let msg: Binary = to_json_binary(&holder)?;
                 resp = resp.add_attribute("holder_migrate", msg.to_base64());
@@ -30,4 +30,10 @@ pub enum ContractError {
 
     #[error("{0} contribution missing an explicit split configuration (got {1})")]
     DenomMisconfigurationError(String, String),
+
+    #[error("partial fills are not supported with price referenced terms")]
+    PriceReferencedPartialFillError {},
+
+    #[error("party b contribution must cover the max party b amount")]
+    PriceReferenceContributionError {},
 }
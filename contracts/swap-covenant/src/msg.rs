@@ -1,13 +1,15 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Coin, StdResult, Uint64, WasmMsg};
+use cosmwasm_std::{Binary, Coin, Decimal, StdResult, Uint128, Uint64, WasmMsg};
 use covenant_utils::{
     instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
     split::SplitConfig, CovenantParty, DestinationConfig, InterchainCovenantParty,
-    NativeCovenantParty, ReceiverConfig,
+    NativeCovenantParty, PriceSource, ReceiverConfig,
 };
 use cw_utils::Expiration;
+use valence_swap_holder::msg::RefundConfig;
+use valence_vesting_escrow::msg::VestingSchedule;
 
 pub const DEFAULT_TIMEOUT: u64 = 60 * 60 * 5; // 5 hours
 
@@ -24,6 +26,42 @@ pub struct InstantiateMsg {
     pub fallback_split: Option<SplitConfig>,
     pub fallback_address: Option<String>,
     pub operation_mode: ContractOperationModeConfig,
+    /// enables partial fills. if set, the swap settles pro rata at the
+    /// lockup expiration as long as at least this fraction of the terms
+    /// is matched, and the unmatched remainder is refunded.
+    pub min_fill_ratio: Option<Decimal>,
+    /// if set, the amount owed by party b is priced off an astroport
+    /// pool at settlement instead of being fixed to its contribution.
+    /// not compatible with partial fills.
+    pub price_reference: Option<PriceReferenceConfig>,
+    /// if set, parties with a vesting schedule receive their
+    /// side of the swap over time through a vesting escrow
+    pub vesting: Option<VestingConfig>,
+    /// receivers of the party refunds. if not set, refunds
+    /// are routed to the party routers.
+    pub refund_config: Option<RefundConfig>,
+}
+
+#[cw_serde]
+pub struct VestingConfig {
+    /// code id of the vesting escrow contract
+    pub escrow_code: u64,
+    /// vesting schedule of the funds received by party a
+    pub party_a: Option<VestingSchedule>,
+    /// vesting schedule of the funds received by party b
+    pub party_b: Option<VestingSchedule>,
+}
+
+#[cw_serde]
+pub struct PriceReferenceConfig {
+    /// astroport pool trading both party denoms
+    pub pool_address: String,
+    pub price_source: PriceSource,
+    /// premium applied on top of the referenced price
+    pub spread: Decimal,
+    pub min_party_b_amount: Uint128,
+    /// party b contribution must cover this amount
+    pub max_party_b_amount: Uint128,
 }
 
 #[cw_serde]
@@ -144,6 +182,7 @@ impl SwapCovenantContractCodeIds {
         &self,
         party_a_router_code: u64,
         party_b_router_code: u64,
+        vesting_escrow_code: Option<u64>,
     ) -> CovenantContractCodes {
         CovenantContractCodes {
             clock: self.clock_code,
@@ -153,6 +192,7 @@ impl SwapCovenantContractCodeIds {
             party_b_router: party_b_router_code,
             party_a_forwarder: self.ibc_forwarder_code,
             party_b_forwarder: self.ibc_forwarder_code,
+            vesting_escrow: vesting_escrow_code,
         }
     }
 }
@@ -192,6 +232,13 @@ pub enum QueryMsg {
     IbcForwarderAddress { party: String },
     #[returns(cosmwasm_std::Addr)]
     PartyDepositAddress { party: String },
+    /// `party` is either `party_a` or `party_b`
+    #[returns(Option<cosmwasm_std::Addr>)]
+    VestingEscrowAddress { party: String },
+    /// vesting progress of the funds received by the party,
+    /// if the party has a vesting schedule
+    #[returns(Option<valence_vesting_escrow::msg::VestingProgress>)]
+    VestingProgress { party: String },
     #[returns(CovenantContractCodes)]
     ContractCodes {},
 }
@@ -207,6 +254,8 @@ pub enum MigrateMsg {
         party_b_router: Option<RouterMigrateMsg>,
         party_a_forwarder: Box<Option<valence_ibc_forwarder::msg::MigrateMsg>>,
         party_b_forwarder: Box<Option<valence_ibc_forwarder::msg::MigrateMsg>>,
+        party_a_vesting_escrow: Option<valence_vesting_escrow::msg::MigrateMsg>,
+        party_b_vesting_escrow: Option<valence_vesting_escrow::msg::MigrateMsg>,
     },
     UpdateCodeId {
         data: Option<Binary>,
@@ -228,4 +277,5 @@ pub struct CovenantContractCodes {
     pub party_a_forwarder: u64,
     pub party_b_forwarder: u64,
     pub splitter: u64,
+    pub vesting_escrow: Option<u64>,
 }
@@ -11,5 +11,7 @@ pub const PARTY_A_IBC_FORWARDER_ADDR: Item<Addr> = Item::new("party_a_ibc_forwar
 pub const PARTY_B_IBC_FORWARDER_ADDR: Item<Addr> = Item::new("party_b_ibc_forwarder_addr");
 pub const PARTY_A_ROUTER_ADDR: Item<Addr> = Item::new("party_a_router_addr");
 pub const PARTY_B_ROUTER_ADDR: Item<Addr> = Item::new("party_b_router_addr");
+pub const PARTY_A_VESTING_ESCROW_ADDR: Item<Addr> = Item::new("party_a_vesting_escrow_addr");
+pub const PARTY_B_VESTING_ESCROW_ADDR: Item<Addr> = Item::new("party_b_vesting_escrow_addr");
 
 pub(crate) const CONTRACT_CODES: Item<CovenantContractCodes> = Item::new("contract_codes");
@@ -1,19 +1,28 @@
 use cosmwasm_std::{
-    ensure, to_json_binary, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
-    Response, StdError, StdResult, SubMsg, Uint128,
+    coin, ensure, to_json_binary, Attribute, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps,
+    DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Response, StdError, StdResult, Storage,
+    SubMsg, SubMsgResult, Uint128,
 };
 use covenant_utils::{
+    astroport::{
+        query_astro_pool_price_observation, query_astro_pool_spot_price, validate_astro_pool_denoms,
+    },
     clock::dequeue_msg,
+    history::{query_history, record_history, HistoryAction},
+    neutron::to_neutron_msg,
     op_mode::{verify_caller, ContractOperationMode},
-    CovenantTerms,
+    CovenantPartiesConfig, CovenantTerms, PartialFillSwapCovenantTerms,
+    PriceReferencedSwapCovenantTerms, PriceSource, ReceiverConfig,
 };
+use neutron_sdk::bindings::{msg::NeutronMsg, query::NeutronQuery};
 
 use crate::{
     error::ContractError,
-    msg::{ContractState, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    msg::{ContractState, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, RefundConfig},
     state::{
-        CONTRACT_OP_MODE, CONTRACT_STATE, COVENANT_TERMS, LOCKUP_CONFIG, NEXT_CONTRACT,
-        PARTIES_CONFIG, REFUND_CONFIG,
+        CONTRACT_OP_MODE, CONTRACT_STATE, COVENANT_TERMS, FALLBACK_SPLIT, FILL_RATIO,
+        LEGACY_REFUND_CONFIG, LOCKUP_CONFIG, NEXT_CONTRACT, PARTIES_CONFIG, PENDING_IBC_REFUNDS,
+        REFUND_CONFIG, TWAP_OBSERVATION,
     },
 };
 #[cfg(not(feature = "library"))]
@@ -23,9 +32,14 @@ use cw2::set_contract_version;
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const PARTY_A_IBC_REFUND_REPLY_ID: u64 = 1;
+const PARTY_B_IBC_REFUND_REPLY_ID: u64 = 2;
+
+type ExecuteDeps<'a> = DepsMut<'a, NeutronQuery>;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
@@ -36,15 +50,22 @@ pub fn instantiate(
     let op_mode = ContractOperationMode::try_init(deps.api, msg.op_mode_cfg.clone())?;
 
     msg.parties_config.validate_party_addresses(deps.api)?;
+    msg.covenant_terms.validate()?;
     ensure!(
         !msg.lockup_config.is_expired(&env.block),
         ContractError::LockupConfigValidationError {}
     );
 
-    deps.api
-        .addr_validate(&msg.refund_config.party_a_refund_address)?;
-    deps.api
-        .addr_validate(&msg.refund_config.party_b_refund_address)?;
+    msg.refund_config.validate_receiver_addresses(deps.api)?;
+
+    if let CovenantTerms::PriceReferencedTokenSwap(terms) = &msg.covenant_terms {
+        init_price_reference(deps.branch(), &env, &msg.parties_config, terms)?;
+    }
+
+    if let Some(split) = &msg.fallback_split {
+        split.validate_shares_and_receiver_addresses(deps.api)?;
+        FALLBACK_SPLIT.save(deps.storage, split)?;
+    }
 
     NEXT_CONTRACT.save(deps.storage, &next_contract)?;
     CONTRACT_OP_MODE.save(deps.storage, &op_mode)?;
@@ -59,87 +80,237 @@ pub fn instantiate(
         .add_attributes(msg.get_response_attributes()))
 }
 
+/// validates the pool referenced by the covenant terms and
+/// records the initial price observation for twap pricing
+fn init_price_reference(
+    deps: DepsMut,
+    env: &Env,
+    parties: &CovenantPartiesConfig,
+    terms: &PriceReferencedSwapCovenantTerms,
+) -> StdResult<()> {
+    let party_a_denom = parties.party_a.native_denom.as_str();
+    let party_b_denom = parties.party_b.native_denom.as_str();
+
+    deps.api.addr_validate(&terms.pool_address)?;
+    validate_astro_pool_denoms(
+        deps.querier,
+        &terms.pool_address,
+        &[party_a_denom, party_b_denom],
+    )?;
+
+    if terms.price_source == PriceSource::Twap {
+        let observation = query_astro_pool_price_observation(
+            deps.querier,
+            &terms.pool_address,
+            party_a_denom,
+            party_b_denom,
+            env.block.time,
+        )?;
+        TWAP_OBSERVATION.save(deps.storage, &observation)?;
+    }
+
+    Ok(())
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    deps: ExecuteDeps,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
-) -> Result<Response, ContractError> {
-    // Verify caller is the clock
-    verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+) -> Result<Response<NeutronMsg>, ContractError> {
+    if let ExecuteMsg::Tick {} = msg {
+        // Verify caller is the clock
+        verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+    }
 
     match (CONTRACT_STATE.load(deps.storage)?, msg) {
         // from instantiated state we attempt to forward the funds
         (ContractState::Instantiated, ExecuteMsg::Tick {}) => try_forward(deps, env),
         // from expired state we attempt to refund any available funds
-        (ContractState::Expired, ExecuteMsg::Tick {}) => try_refund(deps, env),
-        // completed state is terminal, noop
-        (ContractState::Complete, ExecuteMsg::Tick {}) => Ok(Response::default()
-            .add_attribute("contract_state", "complete")
-            .add_attribute("method", "try_tick")),
+        (ContractState::Expired, ExecuteMsg::Tick {}) => try_refund(deps, env, "expired"),
+        // completed state is terminal. any party denoms left in the holder
+        // are surpluses whose ibc refunds failed, and are refunded again.
+        (ContractState::Complete, ExecuteMsg::Tick {}) => try_refund(deps, env, "complete"),
+        // distributing fallback splits is state-independent
+        (_, ExecuteMsg::DistributeFallbackSplit { denoms }) => {
+            try_distribute_fallback_split(deps, env, denoms)
+        }
     }
 }
 
+/// distributes the denoms not contributed by the parties according to
+/// the fallback split. if no fallback split is configured, the denoms
+/// are split equally between the party refund receivers.
+fn try_distribute_fallback_split(
+    deps: ExecuteDeps,
+    env: Env,
+    denoms: Vec<String>,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let parties = PARTIES_CONFIG.load(deps.storage)?;
+    let fallback_split = FALLBACK_SPLIT.may_load(deps.storage)?;
+    let refund_config = REFUND_CONFIG.load(deps.storage)?;
+
+    let mut available_balances = Vec::with_capacity(denoms.len());
+    let mut fallback_distribution_messages: Vec<CosmosMsg<NeutronMsg>> = vec![];
+    for denom in denoms {
+        ensure!(
+            denom != parties.party_a.native_denom && denom != parties.party_b.native_denom,
+            ContractError::UnauthorizedDenomDistribution {}
+        );
+        let queried_coin = deps.querier.query_balance(&env.contract.address, denom)?;
+        if queried_coin.amount.is_zero() {
+            continue;
+        }
+        match &fallback_split {
+            Some(split) => {
+                for msg in split.get_transfer_messages(
+                    queried_coin.amount,
+                    queried_coin.denom.to_string(),
+                    None,
+                )? {
+                    fallback_distribution_messages.push(to_neutron_msg(msg)?);
+                }
+            }
+            None => {
+                let party_a_amount = queried_coin.amount / Uint128::new(2);
+                let party_b_amount = queried_coin.amount - party_a_amount;
+                for (receiver, amount) in [
+                    (&refund_config.party_a_refund_receiver, party_a_amount),
+                    (&refund_config.party_b_refund_receiver, party_b_amount),
+                ] {
+                    if amount.is_zero() {
+                        continue;
+                    }
+                    fallback_distribution_messages.extend(receiver.get_transfer_messages(
+                        deps.querier,
+                        &env,
+                        vec![coin(amount.u128(), &queried_coin.denom)],
+                    )?);
+                }
+            }
+        }
+        available_balances.push(queried_coin);
+    }
+
+    if !available_balances.is_empty() {
+        record_history(
+            deps.storage,
+            &env.block,
+            HistoryAction::Distribution,
+            None,
+            available_balances,
+        )?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("method", "try_distribute_fallback_split")
+        .add_messages(fallback_distribution_messages))
+}
+
 /// attempts to route any available covenant party contribution denoms to
 /// the parties that were responsible for contributing that denom.
-fn try_refund(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+fn try_refund(
+    deps: ExecuteDeps,
+    env: Env,
+    contract_state: &str,
+) -> Result<Response<NeutronMsg>, ContractError> {
     let parties = PARTIES_CONFIG.load(deps.storage)?;
-    let refund_config = REFUND_CONFIG.load(deps.storage)?;
-    let contract_addr = env.contract.address;
+    let contract_addr = &env.contract.address;
 
     // query holder balances
     let party_a_bal = deps
         .querier
-        .query_balance(&contract_addr, parties.party_a.native_denom)?;
+        .query_balance(contract_addr, parties.party_a.native_denom)?;
     let party_b_bal = deps
         .querier
-        .query_balance(&contract_addr, parties.party_b.native_denom)?;
-
-    let refund_messages: Vec<CosmosMsg> =
-        match (party_a_bal.amount.is_zero(), party_b_bal.amount.is_zero()) {
-            // both balances empty, nothing to refund
-            (true, true) => vec![],
-            // party A failed to deposit. refund party B
-            (true, false) => vec![BankMsg::Send {
-                to_address: refund_config.party_b_refund_address,
-                amount: vec![party_b_bal],
-            }
-            .into()],
-            // party B failed to deposit. refund party A
-            (false, true) => vec![BankMsg::Send {
-                to_address: refund_config.party_a_refund_address,
-                amount: vec![party_a_bal],
-            }
-            .into()],
-            // not enough balances to perform the covenant swap.
-            // refund denoms to both parties.
-            (false, false) => vec![
-                BankMsg::Send {
-                    to_address: refund_config.party_a_refund_address,
-                    amount: vec![party_a_bal],
-                }
-                .into(),
-                BankMsg::Send {
-                    to_address: refund_config.party_b_refund_address,
-                    amount: vec![party_b_bal],
-                }
-                .into(),
-            ],
-        };
+        .query_balance(contract_addr, parties.party_b.native_denom)?;
+
+    // refund any available party denoms to their refund receivers.
+    // if both balances are empty, there is nothing to refund.
+    let refund_messages =
+        get_refund_submsgs(deps.storage, deps.querier, &env, [party_a_bal, party_b_bal])?;
 
     Ok(Response::default()
-        .add_attribute("contract_state", "expired")
+        .add_attribute("contract_state", contract_state)
         .add_attribute("method", "try_refund")
-        .add_messages(refund_messages))
+        .add_submessages(refund_messages))
+}
+
+/// returns the submessages refunding the given coins to the party
+/// refund receivers. coins are expected in party a, party b order.
+fn get_refund_submsgs(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper<NeutronQuery>,
+    env: &Env,
+    refund_coins: [Coin; 2],
+) -> Result<Vec<SubMsg<NeutronMsg>>, ContractError> {
+    let parties = PARTIES_CONFIG.load(storage)?;
+    let refund_config = REFUND_CONFIG.load(storage)?;
+
+    let mut refund_messages: Vec<SubMsg<NeutronMsg>> = vec![];
+    for (refund_coin, (reply_id, party_addr, receiver)) in refund_coins.into_iter().zip([
+        (
+            PARTY_A_IBC_REFUND_REPLY_ID,
+            parties.party_a.addr,
+            refund_config.party_a_refund_receiver,
+        ),
+        (
+            PARTY_B_IBC_REFUND_REPLY_ID,
+            parties.party_b.addr,
+            refund_config.party_b_refund_receiver,
+        ),
+    ]) {
+        if refund_coin.amount.is_zero() {
+            continue;
+        }
+        let transfer_messages =
+            receiver.get_transfer_messages(querier, env, vec![refund_coin.clone()])?;
+        match receiver {
+            ReceiverConfig::Native(_) => {
+                record_history(
+                    storage,
+                    &env.block,
+                    HistoryAction::Refund,
+                    Some(party_addr),
+                    vec![refund_coin],
+                )?;
+                refund_messages.extend(transfer_messages.into_iter().map(SubMsg::new));
+            }
+            // ibc refunds pay their fees out of the holder balance and may
+            // fail independently. each one is dispatched in isolation so
+            // that it does not block the refund of the other party. failed
+            // refunds remain in the holder and are retried on the next tick.
+            ReceiverConfig::Ibc(_) => {
+                if transfer_messages.is_empty() {
+                    continue;
+                }
+                PENDING_IBC_REFUNDS.save(storage, reply_id, &(party_addr, refund_coin))?;
+                refund_messages.extend(
+                    transfer_messages
+                        .into_iter()
+                        .map(|msg| SubMsg::reply_always(msg, reply_id)),
+                );
+            }
+        }
+    }
+
+    Ok(refund_messages)
 }
 
-fn try_forward(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+fn try_forward(mut deps: ExecuteDeps, env: Env) -> Result<Response<NeutronMsg>, ContractError> {
     let lockup_config = LOCKUP_CONFIG.load(deps.storage)?;
-    let contract_addr = env.contract.address;
+    let covenant_terms = COVENANT_TERMS.load(deps.storage)?;
 
     // check if covenant is expired
     if lockup_config.is_expired(&env.block) {
+        // partial fill covenants attempt to settle the matched
+        // amounts before falling back to refunds
+        if let CovenantTerms::PartialFillTokenSwap(terms) = covenant_terms {
+            if let Some(resp) = try_settle_partial_fill(deps.branch(), &env, terms)? {
+                return Ok(resp);
+            }
+        }
         CONTRACT_STATE.save(deps.storage, &ContractState::Expired)?;
         return Ok(Response::default()
             .add_attribute("method", "try_forward")
@@ -147,29 +318,194 @@ fn try_forward(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
             .add_attribute("contract_state", "expired"));
     }
 
+    let (party_a_amount, party_b_amount) = match covenant_terms {
+        CovenantTerms::TokenSwap(terms) => (terms.party_a_amount, terms.party_b_amount),
+        CovenantTerms::PartialFillTokenSwap(terms) => (terms.party_a_amount, terms.party_b_amount),
+        CovenantTerms::PriceReferencedTokenSwap(terms) => {
+            return try_forward_price_referenced(deps, env, terms)
+        }
+    };
+
+    let parties = PARTIES_CONFIG.load(deps.storage)?;
+
+    let party_a_bal = deps
+        .querier
+        .query_balance(&env.contract.address, &parties.party_a.native_denom)?;
+    let party_b_bal = deps
+        .querier
+        .query_balance(&env.contract.address, &parties.party_b.native_denom)?;
+
+    // if either of the parties did not deposit its part,
+    // we are not ready for the swap yet
+    if party_a_bal.amount < party_a_amount || party_b_bal.amount < party_b_amount {
+        return Err(ContractError::InsufficientFunds {});
+    }
+
+    // otherwise we forward the agreed amounts to the next module
+    let resp = forward_to_next_contract(
+        deps.branch(),
+        &env,
+        coin(party_a_amount.u128(), &party_a_bal.denom),
+        coin(party_b_amount.u128(), &party_b_bal.denom),
+        Decimal::one(),
+    )?;
+
+    // and refund any over-deposits to the parties
+    let refund_messages = get_surplus_refund_msgs(
+        deps.storage,
+        deps.querier,
+        &env,
+        [(party_a_bal, party_a_amount), (party_b_bal, party_b_amount)],
+    )?;
+
+    Ok(resp.add_submessages(refund_messages))
+}
+
+/// settles the matched part of the swap terms if the fill ratio
+/// reaches the configured minimum and refunds the unmatched remainder.
+/// returns `None` if the swap can not be settled.
+fn try_settle_partial_fill(
+    mut deps: ExecuteDeps,
+    env: &Env,
+    terms: PartialFillSwapCovenantTerms,
+) -> Result<Option<Response<NeutronMsg>>, ContractError> {
     let parties = PARTIES_CONFIG.load(deps.storage)?;
-    let CovenantTerms::TokenSwap(covenant_terms) = COVENANT_TERMS.load(deps.storage)?;
 
-    let mut party_a_coin = deps
+    let party_a_bal = deps
         .querier
-        .query_balance(&contract_addr, parties.party_a.native_denom)?;
-    let mut party_b_coin = deps
+        .query_balance(&env.contract.address, &parties.party_a.native_denom)?;
+    let party_b_bal = deps
         .querier
-        .query_balance(&contract_addr, parties.party_b.native_denom)?;
+        .query_balance(&env.contract.address, &parties.party_b.native_denom)?;
 
-    if party_a_coin.amount < covenant_terms.party_a_amount {
-        party_a_coin.amount = Uint128::zero();
-    } else if party_b_coin.amount < covenant_terms.party_b_amount {
-        party_b_coin.amount = Uint128::zero();
+    let fill_ratio = terms.get_fill_ratio(party_a_bal.amount, party_b_bal.amount);
+    if fill_ratio < terms.min_fill_ratio {
+        return Ok(None);
     }
 
-    // if either of the coin amounts did not get updated to non-zero,
-    // we are not ready for the swap yet
-    if party_a_coin.amount.is_zero() || party_b_coin.amount.is_zero() {
+    let (party_a_matched, party_b_matched) = terms.get_matched_amounts(fill_ratio)?;
+
+    let resp = forward_to_next_contract(
+        deps.branch(),
+        env,
+        coin(party_a_matched.u128(), &party_a_bal.denom),
+        coin(party_b_matched.u128(), &party_b_bal.denom),
+        fill_ratio,
+    )?;
+
+    // refund whatever did not get matched
+    let refund_messages = get_surplus_refund_msgs(
+        deps.storage,
+        deps.querier,
+        env,
+        [
+            (party_a_bal, party_a_matched),
+            (party_b_bal, party_b_matched),
+        ],
+    )?;
+
+    Ok(Some(
+        resp.add_attribute("result", "partial_fill")
+            .add_submessages(refund_messages),
+    ))
+}
+
+/// forwards the party a amount and the party b amount priced off
+/// the referenced pool, and refunds any over-deposits
+fn try_forward_price_referenced(
+    mut deps: ExecuteDeps,
+    env: Env,
+    terms: PriceReferencedSwapCovenantTerms,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let parties = PARTIES_CONFIG.load(deps.storage)?;
+    let party_a_denom = parties.party_a.native_denom;
+    let party_b_denom = parties.party_b.native_denom;
+
+    let price = match terms.price_source {
+        PriceSource::Spot => query_astro_pool_spot_price(
+            deps.querier.into_empty(),
+            &terms.pool_address,
+            &party_a_denom,
+            &party_b_denom,
+        )?,
+        PriceSource::Twap => {
+            let latest_observation = query_astro_pool_price_observation(
+                deps.querier.into_empty(),
+                &terms.pool_address,
+                &party_a_denom,
+                &party_b_denom,
+                env.block.time,
+            )?;
+            TWAP_OBSERVATION
+                .load(deps.storage)?
+                .get_twap(&latest_observation)?
+        }
+    };
+    let party_b_amount = terms.get_party_b_amount(price)?;
+
+    let party_a_bal = deps
+        .querier
+        .query_balance(&env.contract.address, &party_a_denom)?;
+    let party_b_bal = deps
+        .querier
+        .query_balance(&env.contract.address, &party_b_denom)?;
+
+    if party_a_bal.amount < terms.party_a_amount || party_b_bal.amount < party_b_amount {
         return Err(ContractError::InsufficientFunds {});
     }
 
-    // otherwise we are ready to forward the funds to the next module
+    let resp = forward_to_next_contract(
+        deps.branch(),
+        &env,
+        coin(terms.party_a_amount.u128(), &party_a_denom),
+        coin(party_b_amount.u128(), &party_b_denom),
+        Decimal::one(),
+    )?;
+
+    let refund_messages = get_surplus_refund_msgs(
+        deps.storage,
+        deps.querier,
+        &env,
+        [
+            (party_a_bal, terms.party_a_amount),
+            (party_b_bal, party_b_amount),
+        ],
+    )?;
+
+    Ok(resp
+        .add_attribute("price", price.to_string())
+        .add_attribute("party_b_amount", party_b_amount)
+        .add_submessages(refund_messages))
+}
+
+/// returns the submessages refunding the party balances exceeding the
+/// forwarded amounts. balances are expected in party a, party b order.
+fn get_surplus_refund_msgs(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper<NeutronQuery>,
+    env: &Env,
+    balances: [(Coin, Uint128); 2],
+) -> Result<Vec<SubMsg<NeutronMsg>>, ContractError> {
+    let [party_a_surplus, party_b_surplus] = balances.map(|(bal, forwarded)| {
+        bal.amount
+            .checked_sub(forwarded)
+            .map(|surplus| coin(surplus.u128(), bal.denom))
+            .map_err(StdError::overflow)
+    });
+
+    get_refund_submsgs(storage, querier, env, [party_a_surplus?, party_b_surplus?])
+}
+
+/// sends the given party coins to the deposit address of the next
+/// contract, completes the covenant, and dequeues from the clock
+fn forward_to_next_contract(
+    mut deps: ExecuteDeps,
+    env: &Env,
+    party_a_coin: Coin,
+    party_b_coin: Coin,
+    fill_ratio: Decimal,
+) -> Result<Response<NeutronMsg>, ContractError> {
+    let parties = PARTIES_CONFIG.load(deps.storage)?;
 
     // first we query the deposit address of next module
     let next_contract = NEXT_CONTRACT.load(deps.storage)?;
@@ -187,13 +523,27 @@ fn try_forward(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
 
     // Transition contract state to complete
     ContractState::complete(deps.branch())?;
+    FILL_RATIO.save(deps.storage, &fill_ratio)?;
+
+    for (coin, party_addr) in [
+        (&party_a_coin, parties.party_a.addr),
+        (&party_b_coin, parties.party_b.addr),
+    ] {
+        record_history(
+            deps.storage,
+            &env.block,
+            HistoryAction::Deposit,
+            Some(party_addr),
+            vec![coin.clone()],
+        )?;
+    }
 
     let bank_msg = BankMsg::Send {
         to_address: deposit_address,
         amount: vec![party_a_coin, party_b_coin],
     };
 
-    let mut submsgs: Vec<SubMsg> = vec![];
+    let mut submsgs: Vec<SubMsg<NeutronMsg>> = vec![];
     let _ = CONTRACT_OP_MODE.load(deps.storage).map(|op_mode| {
         match op_mode {
             ContractOperationMode::Permissioned(privileged_accounts) => {
@@ -211,6 +561,7 @@ fn try_forward(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
     });
 
     Ok(Response::default()
+        .add_attribute("fill_ratio", fill_ratio.to_string())
         .add_message(bank_msg)
         .add_submessages(submsgs))
 }
@@ -231,11 +582,40 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         // the deposit address for swap-holder is the contract itself
         QueryMsg::DepositAddress {} => Ok(to_json_binary(&Some(env.contract.address))?),
         QueryMsg::RefundConfig {} => Ok(to_json_binary(&REFUND_CONFIG.may_load(deps.storage)?)?),
+        QueryMsg::FallbackSplit {} => Ok(to_json_binary(&FALLBACK_SPLIT.may_load(deps.storage)?)?),
+        QueryMsg::FillRatio {} => Ok(to_json_binary(&FILL_RATIO.may_load(deps.storage)?)?),
+        QueryMsg::History { start_after, limit } => Ok(to_json_binary(&query_history(
+            deps.storage,
+            start_after,
+            limit,
+        )?)?),
+    }
+}
+
+/// previous versions refunded the parties to neutron addresses only.
+/// such refund config is converted to native refund receivers.
+fn migrate_legacy_refund_config(storage: &mut dyn Storage) -> StdResult<Option<RefundConfig>> {
+    if REFUND_CONFIG.load(storage).is_ok() {
+        return Ok(None);
+    }
+    match LEGACY_REFUND_CONFIG.may_load(storage)? {
+        Some(legacy_config) => {
+            let config: RefundConfig = legacy_config.into();
+            REFUND_CONFIG.save(storage, &config)?;
+            Ok(Some(config))
+        }
+        None => Ok(None),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    // the refund addresses of the previous versions are
+    // carried over on any migration
+    let legacy_refund_attributes: Vec<Attribute> = migrate_legacy_refund_config(deps.storage)?
+        .map(|config| vec![Attribute::new("refund_config", format!("{config:?}"))])
+        .unwrap_or_default();
+
     match msg {
         MigrateMsg::UpdateConfig {
             op_mode,
@@ -244,8 +624,11 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response>
             parties_config,
             covenant_terms,
             refund_config,
+            fallback_split,
         } => {
-            let mut resp = Response::default().add_attribute("method", "update_config");
+            let mut resp = Response::default()
+                .add_attribute("method", "update_config")
+                .add_attributes(legacy_refund_attributes);
 
             if let Some(op_mode_cfg) = op_mode {
                 let updated_op_mode = ContractOperationMode::try_init(deps.api, op_mode_cfg)
@@ -275,35 +658,75 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response>
             }
 
             if let Some(covenant_terms) = covenant_terms {
+                covenant_terms.validate()?;
+                if let CovenantTerms::PriceReferencedTokenSwap(terms) = &covenant_terms {
+                    let parties = PARTIES_CONFIG.load(deps.storage)?;
+                    init_price_reference(deps.branch(), &env, &parties, terms)?;
+                }
                 COVENANT_TERMS.save(deps.storage, &covenant_terms)?;
                 resp = resp.add_attribute("covenant_terms", format!("{covenant_terms:?}"));
             }
 
             if let Some(config) = refund_config {
-                deps.api.addr_validate(&config.party_a_refund_address)?;
-                deps.api.addr_validate(&config.party_b_refund_address)?;
+                config.validate_receiver_addresses(deps.api)?;
                 REFUND_CONFIG.save(deps.storage, &config)?;
                 resp = resp.add_attribute("refund_config", format!("{config:?}"));
             }
 
+            if let Some(split) = fallback_split {
+                split.validate_shares_and_receiver_addresses(deps.api)?;
+                FALLBACK_SPLIT.save(deps.storage, &split)?;
+                resp = resp.add_attribute("fallback_split", format!("{split:?}"));
+            }
+
             Ok(resp)
         }
         MigrateMsg::UpdateCodeId { data: _ } => {
             // This is a migrate message to update code id,
             // Data is optional base64 that we can parse to any data we would like in the future
             // let data: SomeStruct = from_binary(&data)?;
-            Ok(Response::default())
+            Ok(Response::default().add_attributes(legacy_refund_attributes))
         }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
-    // if we get a reply with id u64::MAX, we can assume it is a dequeue message
-    if msg.id == u64::MAX {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        // if we get a reply with id u64::MAX, we can assume it is a dequeue message
         // Do nothing, whether it fails or not (dequeue messages are "fire & forget" style messages)
-        Ok(Response::default())
-    } else {
-        Err(ContractError::UnexpectedReplyId {})
+        u64::MAX => Ok(Response::default()),
+        PARTY_A_IBC_REFUND_REPLY_ID | PARTY_B_IBC_REFUND_REPLY_ID => {
+            handle_ibc_refund_reply(deps, env, msg)
+        }
+        _ => Err(ContractError::UnexpectedReplyId {}),
+    }
+}
+
+/// records the dispatched ibc refund in the history. failed refunds
+/// are not recorded and their funds are left in the holder.
+fn handle_ibc_refund_reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let (party_addr, refund_coin) = PENDING_IBC_REFUNDS.load(deps.storage, msg.id)?;
+    PENDING_IBC_REFUNDS.remove(deps.storage, msg.id);
+
+    let resp = Response::default()
+        .add_attribute("method", "handle_ibc_refund_reply")
+        .add_attribute("party", party_addr.to_string())
+        .add_attribute("refund", refund_coin.to_string());
+
+    match msg.result {
+        SubMsgResult::Ok(_) => {
+            record_history(
+                deps.storage,
+                &env.block,
+                HistoryAction::Refund,
+                Some(party_addr),
+                vec![refund_coin],
+            )?;
+            Ok(resp.add_attribute("status", "dispatched"))
+        }
+        SubMsgResult::Err(err) => Ok(resp
+            .add_attribute("status", "failed")
+            .add_attribute("error", err)),
     }
 }
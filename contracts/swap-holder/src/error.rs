@@ -25,4 +25,7 @@ pub enum ContractError {
 
     #[error("Lockup config must be in the future")]
     LockupConfigValidationError {},
+
+    #[error("unauthorized to distribute party contribution denom")]
+    UnauthorizedDenomDistribution {},
 }
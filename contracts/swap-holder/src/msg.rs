@@ -1,11 +1,12 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_binary, Attribute, Binary, DepsMut, StdError, StdResult, WasmMsg};
+use cosmwasm_std::{to_json_binary, Api, Attribute, Binary, DepsMut, StdError, StdResult, WasmMsg};
 use covenant_macros::{clocked, covenant_deposit_address};
 use covenant_utils::{
     instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
-    CovenantPartiesConfig, CovenantTerms,
+    split::SplitConfig, CovenantPartiesConfig, CovenantTerms, ReceiverConfig,
 };
 use cw_utils::Expiration;
+use neutron_sdk::bindings::query::NeutronQuery;
 
 use crate::state::CONTRACT_STATE;
 
@@ -26,8 +27,12 @@ pub struct InstantiateMsg {
     pub parties_config: CovenantPartiesConfig,
     /// terms of the covenant
     pub covenant_terms: CovenantTerms,
-    /// refund configuration containing party router adresses
+    /// refund configuration containing the party refund receivers
     pub refund_config: RefundConfig,
+    /// split for any denoms not contributed by the parties.
+    /// if not set, such denoms are split equally between
+    /// the party refund addresses.
+    pub fallback_split: Option<SplitConfig>,
 }
 
 impl InstantiateMsg {
@@ -57,19 +62,40 @@ impl InstantiateMsg {
         ];
         attrs.extend(self.parties_config.get_response_attributes());
         attrs.extend(self.covenant_terms.get_response_attributes());
+        attrs.push(match self.fallback_split.as_ref() {
+            Some(split) => split.get_response_attribute("fallback_split".to_string()),
+            None => Attribute::new("fallback_split", "none"),
+        });
         attrs
     }
 }
 
 #[cw_serde]
 pub struct RefundConfig {
-    pub party_a_refund_address: String,
-    pub party_b_refund_address: String,
+    /// receiver of party a refunds. either a neutron address
+    /// (usually the party router) or a remote chain destination
+    /// that gets refunded over ibc.
+    pub party_a_refund_receiver: ReceiverConfig,
+    /// receiver of party b refunds
+    pub party_b_refund_receiver: ReceiverConfig,
+}
+
+impl RefundConfig {
+    pub fn validate_receiver_addresses(&self, api: &dyn Api) -> StdResult<()> {
+        self.party_a_refund_receiver
+            .validate_receiver_address(api)?;
+        self.party_b_refund_receiver
+            .validate_receiver_address(api)?;
+        Ok(())
+    }
 }
 
 #[clocked]
 #[cw_serde]
-pub enum ExecuteMsg {}
+pub enum ExecuteMsg {
+    /// distribute any denoms not contributed by the parties
+    DistributeFallbackSplit { denoms: Vec<String> },
+}
 
 #[covenant_deposit_address]
 #[cw_serde]
@@ -87,8 +113,20 @@ pub enum QueryMsg {
     ContractState {},
     #[returns(RefundConfig)]
     RefundConfig {},
+    #[returns(Option<SplitConfig>)]
+    FallbackSplit {},
     #[returns(covenant_utils::op_mode::ContractOperationMode)]
     OperationMode {},
+    /// fraction of the covenant terms that got settled,
+    /// available once the swap is complete
+    #[returns(Option<cosmwasm_std::Decimal>)]
+    FillRatio {},
+    /// paginated log of the actions performed by the holder
+    #[returns(Vec<covenant_utils::history::HistoryEntry>)]
+    History {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -101,7 +139,7 @@ pub enum ContractState {
 }
 
 impl ContractState {
-    pub fn complete(deps: DepsMut) -> Result<(), StdError> {
+    pub fn complete(deps: DepsMut<NeutronQuery>) -> Result<(), StdError> {
         CONTRACT_STATE.save(deps.storage, &ContractState::Complete)
     }
 }
@@ -115,6 +153,7 @@ pub enum MigrateMsg {
         parties_config: Box<Option<CovenantPartiesConfig>>,
         covenant_terms: Option<CovenantTerms>,
         refund_config: Option<RefundConfig>,
+        fallback_split: Option<SplitConfig>,
     },
     UpdateCodeId {
         data: Option<Binary>,
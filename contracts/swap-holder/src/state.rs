@@ -1,6 +1,10 @@
-use cosmwasm_std::Addr;
-use covenant_utils::{op_mode::ContractOperationMode, CovenantPartiesConfig, CovenantTerms};
-use cw_storage_plus::Item;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Decimal};
+use covenant_utils::{
+    astroport::PriceObservation, op_mode::ContractOperationMode, split::SplitConfig,
+    CovenantPartiesConfig, CovenantTerms, ReceiverConfig,
+};
+use cw_storage_plus::{Item, Map};
 use cw_utils::Expiration;
 
 use crate::msg::{ContractState, RefundConfig};
@@ -12,3 +16,32 @@ pub const PARTIES_CONFIG: Item<CovenantPartiesConfig> = Item::new("parties_confi
 pub const LOCKUP_CONFIG: Item<Expiration> = Item::new("lockup_config");
 pub const COVENANT_TERMS: Item<CovenantTerms> = Item::new("covenant_terms");
 pub const REFUND_CONFIG: Item<RefundConfig> = Item::new("refund_config");
+/// refund configuration stored under the same key by the previous versions
+pub const LEGACY_REFUND_CONFIG: Item<LegacyRefundConfig> = Item::new("refund_config");
+/// fraction of the covenant terms that got settled
+pub const FILL_RATIO: Item<Decimal> = Item::new("fill_ratio");
+/// cumulative price observed at instantiation, used to price
+/// twap referenced swap terms
+pub const TWAP_OBSERVATION: Item<PriceObservation> = Item::new("twap_observation");
+/// split for any denoms not contributed by the parties
+pub const FALLBACK_SPLIT: Item<SplitConfig> = Item::new("fallback_split");
+/// ibc refunds in flight keyed by their reply id, holding the refunded
+/// party and coin. history is recorded once the transfer is dispatched.
+pub const PENDING_IBC_REFUNDS: Map<u64, (String, Coin)> = Map::new("pending_ibc_refunds");
+
+/// refund configuration of the previous versions, refunding
+/// the parties to neutron addresses only
+#[cw_serde]
+pub struct LegacyRefundConfig {
+    pub party_a_refund_address: String,
+    pub party_b_refund_address: String,
+}
+
+impl From<LegacyRefundConfig> for RefundConfig {
+    fn from(value: LegacyRefundConfig) -> Self {
+        RefundConfig {
+            party_a_refund_receiver: ReceiverConfig::Native(value.party_a_refund_address),
+            party_b_refund_receiver: ReceiverConfig::Native(value.party_b_refund_address),
+        }
+    }
+}
@@ -50,6 +50,13 @@ pub fn instantiate(
     let creator_address: CanonicalAddr =
         deps.api.addr_canonicalize(env.contract.address.as_str())?;
 
+    // every party must provide some liquidity
+    let pool_denoms = msg.liquid_pooler_config.get_pool_denoms();
+    msg.party_a_config
+        .validate_contribution(pool_denoms.as_deref())?;
+    msg.party_b_config
+        .validate_contribution(pool_denoms.as_deref())?;
+
     let covenant_denoms: BTreeSet<String> = msg.splits.keys().map(|k| k.to_string()).collect();
 
     let clock_instantiate2_config = get_instantiate2_salt_and_address(
@@ -140,7 +147,8 @@ pub fn instantiate(
             ),
             covenant_type: msg.covenant_type.clone(),
         },
-        emergency_committee_addr: msg.emergency_committee,
+        emergency_committee: msg.emergency_committee,
+        pool_denoms,
     }
     .to_instantiate2_msg(
         &holder_instantiate2_config,
@@ -444,7 +452,9 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response>
             if let Some(liquid_pooler_migrate_msg) = liquid_pooler {
                 let msg: Binary = match liquid_pooler_migrate_msg {
                     LiquidPoolerMigrateMsg::Astroport(msg) => to_json_binary(&msg)?,
+                    LiquidPoolerMigrateMsg::Duality(msg) => to_json_binary(&msg)?,
                     LiquidPoolerMigrateMsg::Osmosis(msg) => to_json_binary(&msg)?,
+                    LiquidPoolerMigrateMsg::Custom(msg) => msg,
                 };
 
                 resp = resp.add_attribute("liquid_pooler_migrate", msg.to_base64());
@@ -22,6 +22,12 @@ pub enum ContractError {
         err: ParseReplyError,
     },
 
+    #[error("party contribution basket cannot be empty")]
+    EmptyContributionBasket {},
+
+    #[error("party contribution basket must include a pool denom")]
+    PoolContributionDenomMissing {},
+
     #[error("{0}")]
     InstantiationError(#[from] Instantiate2AddressError),
 }
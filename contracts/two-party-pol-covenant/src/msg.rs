@@ -1,17 +1,23 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{coin, Addr, Binary, Decimal, StdResult, Uint64, WasmMsg};
+use cosmwasm_std::{coin, ensure, Addr, Binary, Coin, Decimal, StdResult, Uint64, WasmMsg};
 use covenant_utils::{
-    instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
-    split::SplitConfig, CovenantParty, DestinationConfig, InterchainCovenantParty,
-    NativeCovenantParty, PoolPriceConfig, ReceiverConfig,
+    emergency_committee::EmergencyCommitteeConfig, instantiate2_helper::Instantiate2HelperConfig,
+    op_mode::ContractOperationModeConfig, split::SplitConfig, CovenantParty, DestinationConfig,
+    InterchainCovenantParty, NativeCovenantParty, PoolPriceConfig, ReceiverConfig,
 };
 use cw_utils::Expiration;
+use liquid_pooler_interface::{
+    CustomLiquidPoolerConfig, LiquidPoolerInstantiate, LiquidPoolerInstantiateFields,
+};
 use valence_astroport_liquid_pooler::msg::AstroportLiquidPoolerConfig;
+use valence_duality_liquid_pooler::msg::DualityLiquidPoolerConfig;
 use valence_osmo_liquid_pooler::msg::OsmosisLiquidPoolerConfig;
 use valence_two_party_pol_holder::msg::{CovenantType, RagequitConfig, TwoPartyPolCovenantParty};
 
+use crate::error::ContractError;
+
 pub const DEFAULT_TIMEOUT: u64 = 60 * 60 * 5; // 5 hours
 
 #[cw_serde]
@@ -31,7 +37,7 @@ pub struct InstantiateMsg {
     pub pool_price_config: PoolPriceConfig,
     pub splits: BTreeMap<String, SplitConfig>,
     pub fallback_split: Option<SplitConfig>,
-    pub emergency_committee: Option<String>,
+    pub emergency_committee: Option<EmergencyCommitteeConfig>,
     pub liquid_pooler_config: LiquidPoolerConfig,
     pub fallback_address: Option<String>,
     pub operation_mode: ContractOperationModeConfig,
@@ -41,9 +47,31 @@ pub struct InstantiateMsg {
 pub enum LiquidPoolerConfig {
     Osmosis(Box<OsmosisLiquidPoolerConfig>),
     Astroport(AstroportLiquidPoolerConfig),
+    Duality(DualityLiquidPoolerConfig),
+    /// any other pooler implementing the liquid pooler interface
+    Custom(CustomLiquidPoolerConfig),
 }
 
 impl LiquidPoolerConfig {
+    /// neutron denoms of the pool assets, if known from the pooler config
+    pub fn get_pool_denoms(&self) -> Option<Vec<String>> {
+        match self {
+            LiquidPoolerConfig::Osmosis(config) => Some(vec![
+                config.party_1_denom_info.local_denom.to_string(),
+                config.party_2_denom_info.local_denom.to_string(),
+            ]),
+            LiquidPoolerConfig::Astroport(config) => Some(vec![
+                config.asset_a_denom.to_string(),
+                config.asset_b_denom.to_string(),
+            ]),
+            LiquidPoolerConfig::Duality(config) => Some(vec![
+                config.asset_a_denom.to_string(),
+                config.asset_b_denom.to_string(),
+            ]),
+            LiquidPoolerConfig::Custom(_) => None,
+        }
+    }
+
     pub fn to_instantiate2_msg(
         &self,
         instantiate2_helper: &Instantiate2HelperConfig,
@@ -53,14 +81,22 @@ impl LiquidPoolerConfig {
         holder_addr: String,
         pool_price_config: PoolPriceConfig,
     ) -> StdResult<WasmMsg> {
-        match self {
-            LiquidPoolerConfig::Osmosis(config) => Ok(config
-                .to_instantiate_msg(op_mode, holder_addr.to_string(), pool_price_config)
-                .to_instantiate2_msg(instantiate2_helper, admin, label)?),
-            LiquidPoolerConfig::Astroport(config) => Ok(config
-                .to_instantiate_msg(holder_addr.to_string(), pool_price_config, op_mode)
-                .to_instantiate2_msg(instantiate2_helper, admin, label)?),
-        }
+        let config: &dyn LiquidPoolerInstantiate = match self {
+            LiquidPoolerConfig::Osmosis(config) => config.as_ref(),
+            LiquidPoolerConfig::Astroport(config) => config,
+            LiquidPoolerConfig::Duality(config) => config,
+            LiquidPoolerConfig::Custom(config) => config,
+        };
+        config.to_instantiate2_msg(
+            instantiate2_helper,
+            admin,
+            label,
+            LiquidPoolerInstantiateFields {
+                holder_address: holder_addr,
+                op_mode_cfg: op_mode,
+                pool_price_config,
+            },
+        )
     }
 }
 
@@ -78,6 +114,9 @@ impl CovenantPartyConfig {
             CovenantPartyConfig::Native(config) => {
                 ReceiverConfig::Native(config.party_receiver_addr.to_string())
             }
+            CovenantPartyConfig::NativeBasket(config) => {
+                ReceiverConfig::Native(config.party_receiver_addr.to_string())
+            }
         }
     }
 
@@ -85,6 +124,7 @@ impl CovenantPartyConfig {
         match self {
             CovenantPartyConfig::Interchain(config) => config.party_receiver_addr.to_string(),
             CovenantPartyConfig::Native(config) => config.party_receiver_addr.to_string(),
+            CovenantPartyConfig::NativeBasket(config) => config.party_receiver_addr.to_string(),
         }
     }
 
@@ -100,6 +140,11 @@ impl CovenantPartyConfig {
                 native_denom: config.native_denom.to_string(),
                 receiver_config: self.to_receiver_config(),
             },
+            CovenantPartyConfig::NativeBasket(config) => CovenantParty {
+                addr: config.addr.to_string(),
+                native_denom: self.get_native_denom(),
+                receiver_config: self.to_receiver_config(),
+            },
         }
     }
 
@@ -110,16 +155,23 @@ impl CovenantPartyConfig {
     ) -> TwoPartyPolCovenantParty {
         match &self {
             CovenantPartyConfig::Interchain(config) => TwoPartyPolCovenantParty {
-                contribution: coin(
+                contribution: vec![coin(
                     config.contribution.amount.u128(),
                     config.native_denom.to_string(),
-                ),
+                )],
                 host_addr: config.addr.to_string(),
                 controller_addr: config.party_receiver_addr.to_string(),
                 allocation,
                 router,
             },
             CovenantPartyConfig::Native(config) => TwoPartyPolCovenantParty {
+                contribution: vec![config.contribution.clone()],
+                host_addr: config.addr.to_string(),
+                controller_addr: config.party_receiver_addr.to_string(),
+                allocation,
+                router,
+            },
+            CovenantPartyConfig::NativeBasket(config) => TwoPartyPolCovenantParty {
                 contribution: config.contribution.clone(),
                 host_addr: config.addr.to_string(),
                 controller_addr: config.party_receiver_addr.to_string(),
@@ -129,16 +181,60 @@ impl CovenantPartyConfig {
         }
     }
 
+    /// neutron denoms contributed by the party
+    pub fn get_contribution_denoms(&self) -> Vec<String> {
+        match self {
+            CovenantPartyConfig::Interchain(config) => vec![config.native_denom.to_string()],
+            CovenantPartyConfig::Native(config) => vec![config.native_denom.to_string()],
+            CovenantPartyConfig::NativeBasket(config) => config
+                .contribution
+                .iter()
+                .map(|c| c.denom.to_string())
+                .collect(),
+        }
+    }
+
+    /// validates that the party contributes at least one denom and, if the
+    /// pool denoms are known, that at least one of them is provided as
+    /// liquidity. the rest of the basket is distributed with its splits.
+    pub fn validate_contribution(
+        &self,
+        pool_denoms: Option<&[String]>,
+    ) -> Result<(), ContractError> {
+        let contribution_denoms = self.get_contribution_denoms();
+        ensure!(
+            !contribution_denoms.is_empty(),
+            ContractError::EmptyContributionBasket {}
+        );
+        if let Some(pool_denoms) = pool_denoms {
+            ensure!(
+                contribution_denoms
+                    .iter()
+                    .any(|denom| pool_denoms.contains(denom)),
+                ContractError::PoolContributionDenomMissing {}
+            );
+        }
+        Ok(())
+    }
+
     pub fn get_native_denom(&self) -> String {
         match self {
             CovenantPartyConfig::Interchain(config) => config.native_denom.to_string(),
             CovenantPartyConfig::Native(config) => config.native_denom.to_string(),
+            // basket parties are represented by their first contribution denom
+            CovenantPartyConfig::NativeBasket(config) => config
+                .contribution
+                .first()
+                .map(|c| c.denom.to_string())
+                .unwrap_or_default(),
         }
     }
 
     pub fn get_router_code_id(&self, contract_codes: &CovenantContractCodeIds) -> u64 {
         match self {
-            CovenantPartyConfig::Native(_) => contract_codes.native_router_code,
+            CovenantPartyConfig::Native(_) | CovenantPartyConfig::NativeBasket(_) => {
+                contract_codes.native_router_code
+            }
             CovenantPartyConfig::Interchain(_) => contract_codes.interchain_router_code,
         }
     }
@@ -179,6 +275,16 @@ impl CovenantPartyConfig {
                 };
                 Ok(instantiate_msg.to_instantiate2_msg(&instantiate2_helper, admin_addr, label)?)
             }
+            CovenantPartyConfig::NativeBasket(party) => {
+                let instantiate_msg = valence_native_router::msg::InstantiateMsg {
+                    op_mode_cfg: ContractOperationModeConfig::Permissioned(vec![
+                        clock_addr.to_string()
+                    ]),
+                    receiver_address: party.party_receiver_addr.to_string(),
+                    denoms,
+                };
+                Ok(instantiate_msg.to_instantiate2_msg(&instantiate2_helper, admin_addr, label)?)
+            }
         }
     }
 }
@@ -187,6 +293,18 @@ impl CovenantPartyConfig {
 pub enum CovenantPartyConfig {
     Interchain(InterchainCovenantParty),
     Native(NativeCovenantParty),
+    /// native party contributing a basket of denoms
+    NativeBasket(NativeBasketCovenantParty),
+}
+
+#[cw_serde]
+pub struct NativeBasketCovenantParty {
+    /// address of the receiver on destination chain
+    pub party_receiver_addr: String,
+    /// authorized address of the party on neutron
+    pub addr: String,
+    /// coins the party is expected to contribute, one per denom
+    pub contribution: Vec<Coin>,
 }
 
 #[cw_serde]
@@ -289,6 +407,9 @@ pub enum MigrateMsg {
 pub enum LiquidPoolerMigrateMsg {
     Osmosis(valence_osmo_liquid_pooler::msg::MigrateMsg),
     Astroport(valence_astroport_liquid_pooler::msg::MigrateMsg),
+    Duality(valence_duality_liquid_pooler::msg::MigrateMsg),
+    /// migrate msg of any other pooler implementing the liquid pooler interface
+    Custom(Binary),
 }
 
 #[cw_serde]
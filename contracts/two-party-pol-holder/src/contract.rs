@@ -2,29 +2,36 @@ use std::cmp::Ordering;
 use std::collections::BTreeMap;
 
 use cosmwasm_std::{
-    ensure, to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env,
-    MessageInfo, Reply, Response, StdError, StdResult, SubMsg,
+    ensure, to_json_binary, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps,
+    DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult, Storage, SubMsg,
 };
 
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 
+use covenant_utils::emergency_committee::{
+    approve_emergency_action, migrate_legacy_emergency_committee, propose_emergency_action,
+    query_pending_emergency_actions, save_emergency_committee, take_executable_emergency_action,
+    EmergencyAction, EMERGENCY_COMMITTEE,
+};
+use covenant_utils::history::{query_history, record_history, HistoryAction};
 use covenant_utils::op_mode::{verify_caller, ContractOperationMode};
 use covenant_utils::split::SplitConfig;
-use covenant_utils::withdraw_lp_helper::{generate_withdraw_msg, EMERGENCY_COMMITTEE_ADDR};
 use cw2::set_contract_version;
+use liquid_pooler_interface::LiquidPoolerContract;
 
 use crate::msg::CovenantType;
 use crate::state::{WithdrawState, CONTRACT_OP_MODE, LIQUID_POOLER_ADDRESS, WITHDRAW_STATE};
 use crate::{
     error::ContractError,
     msg::{
-        ContractState, DenomSplits, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
-        RagequitConfig, RagequitState, TwoPartyPolCovenantConfig, TwoPartyPolCovenantParty,
+        ContractState, DenomSplits, DepositsResponse, ExecuteMsg, InstantiateMsg, MigrateMsg,
+        PartyDeposit, QueryMsg, RagequitConfig, RagequitState, TwoPartyPolCovenantConfig,
+        TwoPartyPolCovenantParty,
     },
     state::{
-        CONTRACT_STATE, COVENANT_CONFIG, DENOM_SPLITS, DEPOSIT_DEADLINE, LOCKUP_CONFIG,
-        RAGEQUIT_CONFIG,
+        CONTRACT_STATE, COVENANT_CONFIG, DENOM_SPLITS, DEPOSIT_DEADLINE, LEGACY_COVENANT_CONFIG,
+        LEGACY_RAGEQUIT_CONFIG, LEGACY_WITHDRAW_STATE, LOCKUP_CONFIG, POOL_DENOMS, RAGEQUIT_CONFIG,
     },
 };
 
@@ -59,9 +66,12 @@ pub fn instantiate(
         None => return Err(ContractError::ExpirationValidationError {}),
     };
 
-    if let Some(addr) = &msg.emergency_committee_addr {
-        let committee_addr = deps.api.addr_validate(addr)?;
-        EMERGENCY_COMMITTEE_ADDR.save(deps.storage, &committee_addr)?;
+    let mut resp = Response::default().add_attribute("method", "two_party_pol_holder_instantiate");
+
+    if let Some(committee_config) = &msg.emergency_committee {
+        let committee = committee_config.validate(deps.api)?;
+        save_emergency_committee(deps.storage, &committee)?;
+        resp = resp.add_attributes(committee.get_response_attributes());
     }
 
     msg.covenant_config.validate(deps.api)?;
@@ -95,6 +105,36 @@ pub fn instantiate(
         })
         .transpose()?;
 
+    // every contributed denom must be distributable on claims
+    if msg.fallback_split.is_none() {
+        for coin in msg
+            .covenant_config
+            .party_a
+            .contribution
+            .iter()
+            .chain(msg.covenant_config.party_b.contribution.iter())
+        {
+            ensure!(
+                explicit_splits.contains_key(&coin.denom),
+                ContractError::ContributionDenomSplitMissing(coin.denom.to_string())
+            );
+        }
+    }
+
+    // every party must provide some liquidity
+    if let Some(pool_denoms) = &msg.pool_denoms {
+        for party in [&msg.covenant_config.party_a, &msg.covenant_config.party_b] {
+            ensure!(
+                party
+                    .contribution
+                    .iter()
+                    .any(|coin| pool_denoms.contains(&coin.denom)),
+                ContractError::PoolContributionDenomMissing(party.host_addr.to_string())
+            );
+        }
+        POOL_DENOMS.save(deps.storage, pool_denoms)?;
+    }
+
     DENOM_SPLITS.save(
         deps.storage,
         &DenomSplits {
@@ -110,9 +150,7 @@ pub fn instantiate(
     COVENANT_CONFIG.save(deps.storage, &msg.covenant_config)?;
     DEPOSIT_DEADLINE.save(deps.storage, &msg.deposit_deadline)?;
 
-    Ok(Response::default()
-        .add_attribute("method", "two_party_pol_holder_instantiate")
-        .add_attributes(msg.get_response_attributes()))
+    Ok(resp.add_attributes(msg.get_response_attributes()))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -139,15 +177,23 @@ pub fn execute(
         (ContractState::Complete, ExecuteMsg::Tick {}) => try_refund(deps, env, info),
         // ragequit is state-independent
         (current_state, ExecuteMsg::Ragequit {}) => try_ragequit(deps, env, info, current_state),
-        // emergency withdraw is state-independent
-        (_, ExecuteMsg::EmergencyWithdraw {}) => try_emergency_withdraw(deps, info),
+        // emergency actions are state-independent
+        (_, ExecuteMsg::ProposeEmergencyAction { action }) => {
+            try_propose_emergency_action(deps, env, info, action)
+        }
+        (_, ExecuteMsg::ApproveEmergencyAction { id }) => {
+            try_approve_emergency_action(deps, info, id)
+        }
+        (_, ExecuteMsg::ExecuteEmergencyAction { id }) => {
+            try_execute_emergency_action(deps, env, info, id)
+        }
         // claims can only be performed from ragequit or expired state
         (ContractState::Ragequit | ContractState::Expired, ExecuteMsg::Claim {}) => {
-            try_claim(deps, info)
+            try_claim(deps, env, info)
         }
         (_, ExecuteMsg::Claim {}) => Err(ContractError::ClaimError {}),
         // receiving distribute callback is state-independent
-        (_, ExecuteMsg::Distribute {}) => try_distribute(deps, info),
+        (_, ExecuteMsg::Distribute {}) => try_distribute(deps, env, info),
         // receiving withdraw failed callback is state-independent
         (_, ExecuteMsg::WithdrawFailed {}) => try_withdraw_failed(deps, info),
         // distributing fallback splits is state-independent
@@ -174,6 +220,14 @@ fn try_distribute_fallback_split(
         available_balances.push(queried_coin);
     }
 
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Distribution,
+        None,
+        available_balances.clone(),
+    )?;
+
     let fallback_distribution_messages =
         denom_splits.get_fallback_distribution_messages(available_balances);
 
@@ -183,7 +237,7 @@ fn try_distribute_fallback_split(
 }
 
 /// On claim, we should simply ask the LPer to withdraw the liquidity and execute a Distribute msg on the holder
-fn try_claim(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+fn try_claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
     ensure!(
         !WITHDRAW_STATE.exists(deps.storage),
         ContractError::WithdrawAlreadyStarted {}
@@ -202,6 +256,14 @@ fn try_claim(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError
             .add_submessages(dequeue_messages));
     }
 
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Claim,
+        Some(claim_party.host_addr.to_string()),
+        vec![],
+    )?;
+
     // set WithdrawState to include original data
     WITHDRAW_STATE.save(
         deps.storage,
@@ -218,31 +280,85 @@ fn try_claim(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError
     };
 
     let lper = LIQUID_POOLER_ADDRESS.load(deps.storage)?;
-    let withdraw_msg = generate_withdraw_msg(lper.to_string(), withdraw_percentage)?;
+    let withdraw_msg = LiquidPoolerContract(lper).withdraw_msg(withdraw_percentage)?;
 
     Ok(Response::default().add_message(withdraw_msg))
 }
 
-fn try_emergency_withdraw(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+fn try_propose_emergency_action(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: EmergencyAction,
+) -> Result<Response, ContractError> {
+    let pending_action =
+        propose_emergency_action(deps.storage, deps.api, &env.block, info.sender, action)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_propose_emergency_action")
+        .add_attribute("action_id", pending_action.id.to_string())
+        .add_attribute("action", format!("{:?}", pending_action.action))
+        .add_attribute(
+            "executable_after",
+            pending_action.executable_after.to_string(),
+        ))
+}
+
+fn try_approve_emergency_action(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let pending_action = approve_emergency_action(deps.storage, info.sender, id)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "try_approve_emergency_action")
+        .add_attribute("action_id", id.to_string())
+        .add_attribute("approvals", pending_action.approvals.len().to_string()))
+}
+
+/// Executing an emergency action withdraws all of the liquidity.
+/// Funds are then handled on the distribute callback according to the action.
+fn try_execute_emergency_action(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
     ensure!(
         !WITHDRAW_STATE.exists(deps.storage),
         ContractError::WithdrawAlreadyStarted {}
     );
 
-    ensure!(
-        info.sender == EMERGENCY_COMMITTEE_ADDR.load(deps.storage)?,
-        ContractError::Unauthorized {}
-    );
+    let receiver =
+        match take_executable_emergency_action(deps.storage, &env.block, info.sender, id)? {
+            EmergencyAction::WithdrawToParties {} => None,
+            EmergencyAction::WithdrawTo { receiver } => Some(deps.api.addr_validate(&receiver)?),
+        };
 
-    WITHDRAW_STATE.save(deps.storage, &WithdrawState::Emergency {})?;
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::EmergencyWithdrawal,
+        None,
+        vec![],
+    )?;
+    WITHDRAW_STATE.save(deps.storage, &WithdrawState::Emergency { receiver })?;
 
     let lper = LIQUID_POOLER_ADDRESS.load(deps.storage)?;
-    let withdraw_msg = generate_withdraw_msg(lper.to_string(), None)?;
+    let withdraw_msg = LiquidPoolerContract(lper).withdraw_msg(None)?;
 
-    Ok(Response::default().add_message(withdraw_msg))
+    Ok(Response::default()
+        .add_attribute("method", "try_execute_emergency_action")
+        .add_attribute("action_id", id.to_string())
+        .add_message(withdraw_msg))
 }
 
-fn try_distribute(mut deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+fn try_distribute(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
     // Only pooler can call this
     ensure!(
         info.sender == LIQUID_POOLER_ADDRESS.load(deps.storage)?,
@@ -253,6 +369,19 @@ fn try_distribute(mut deps: DepsMut, info: MessageInfo) -> Result<Response, Cont
         .load(deps.storage)
         .map_err(|_| ContractError::WithdrawStateNotStarted {})?;
 
+    let distribution_party = match &withdraw_state {
+        WithdrawState::Processing { claimer_addr }
+        | WithdrawState::ProcessingRagequit { claimer_addr, .. } => Some(claimer_addr.to_string()),
+        WithdrawState::Emergency { .. } => None,
+    };
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Distribution,
+        distribution_party,
+        info.funds.clone(),
+    )?;
+
     let covenant_config = COVENANT_CONFIG.load(deps.storage)?;
     let denom_splits = DENOM_SPLITS.load(deps.storage)?;
 
@@ -272,7 +401,10 @@ fn try_distribute(mut deps: DepsMut, info: MessageInfo) -> Result<Response, Cont
 
             (rq_party, counterparty, new_denom_split, true)
         }
-        WithdrawState::Emergency {} => {
+        WithdrawState::Emergency {
+            receiver: Some(receiver),
+        } => return try_emergency_withdraw_to(deps, receiver, info.funds, covenant_config),
+        WithdrawState::Emergency { receiver: None } => {
             return try_claim_side_based(
                 deps,
                 covenant_config.party_a.clone(),
@@ -318,6 +450,36 @@ fn try_distribute(mut deps: DepsMut, info: MessageInfo) -> Result<Response, Cont
     }
 }
 
+/// Sends all of the withdrawn funds to the receiver approved by the
+/// emergency committee and completes the covenant.
+fn try_emergency_withdraw_to(
+    deps: DepsMut,
+    receiver: Addr,
+    funds: Vec<Coin>,
+    mut covenant_config: TwoPartyPolCovenantConfig,
+) -> Result<Response, ContractError> {
+    let mut party_a = covenant_config.party_a.clone();
+    let mut party_b = covenant_config.party_b.clone();
+    party_a.allocation = Decimal::zero();
+    party_b.allocation = Decimal::zero();
+    covenant_config.update_parties(party_a, party_b);
+    COVENANT_CONFIG.save(deps.storage, &covenant_config)?;
+
+    let dequeue_messages = ContractState::complete_and_get_dequeue_msgs(deps)?;
+
+    let mut resp = Response::default()
+        .add_attribute("method", "emergency_withdraw_to")
+        .add_attribute("receiver", receiver.to_string())
+        .add_submessages(dequeue_messages);
+    if !funds.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: receiver.to_string(),
+            amount: funds,
+        });
+    }
+    Ok(resp)
+}
+
 /// We don't do much on failed withdraw, as nothing changed so far.
 /// We only change state on distribute msg.
 fn try_withdraw_failed(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
@@ -397,30 +559,37 @@ fn try_refund(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Co
     let config = COVENANT_CONFIG.load(deps.storage)?;
     let contract_addr = env.contract.address.to_string();
 
-    // assert the balances
-    let party_a_bal = deps
-        .querier
-        .query_balance(&contract_addr, config.party_a.contribution.denom)?;
-    let party_b_bal = deps
-        .querier
-        .query_balance(&contract_addr, config.party_b.contribution.denom)?;
-
-    let refund_messages: Vec<CosmosMsg> = [
-        (party_a_bal, config.party_a.router),
-        (party_b_bal, config.party_b.router),
-    ]
-    .into_iter()
-    // find the parties that need to be refunded
-    .filter(|(party_coin, _)| !party_coin.amount.is_zero())
-    // get the bank transfer of the party's contribution to the respective router
-    .map(|(party_coin, to_address)| {
-        BankMsg::Send {
-            to_address,
-            amount: vec![party_coin],
+    let mut refund_messages: Vec<CosmosMsg> = vec![];
+    for party in [config.party_a, config.party_b] {
+        // collect the balances of every denom in the party contribution
+        let mut refund_coins = vec![];
+        for contribution in party.contribution.iter() {
+            let bal = deps
+                .querier
+                .query_balance(&contract_addr, &contribution.denom)?;
+            if !bal.amount.is_zero() {
+                refund_coins.push(bal);
+            }
         }
-        .into()
-    })
-    .collect();
+
+        // get the bank transfer of the party's contribution to the respective router
+        if !refund_coins.is_empty() {
+            record_history(
+                deps.storage,
+                &env.block,
+                HistoryAction::Refund,
+                Some(party.host_addr),
+                refund_coins.clone(),
+            )?;
+            refund_messages.push(
+                BankMsg::Send {
+                    to_address: party.router,
+                    amount: refund_coins,
+                }
+                .into(),
+            );
+        }
+    }
 
     Ok(Response::default()
         .add_attribute("contract_state", "complete")
@@ -442,36 +611,120 @@ fn try_deposit(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, C
     }
 
     let config = COVENANT_CONFIG.load(deps.storage)?;
-    let contract_addr = env.contract.address.to_string();
-
-    // assert the balances
-    let party_a_bal = deps
-        .querier
-        .query_balance(&contract_addr, config.party_a.contribution.denom)?;
-    let party_b_bal = deps
-        .querier
-        .query_balance(&contract_addr, config.party_b.contribution.denom)?;
+    let deposits = query_deposits(deps.as_ref(), &env, &config)?;
 
     // if either party did not fulfill their deposit, we error out
     ensure!(
-        config.party_a.contribution.amount <= party_a_bal.amount
-            && config.party_b.contribution.amount <= party_b_bal.amount,
+        deposits.party_a.is_fulfilled() && deposits.party_b.is_fulfilled(),
         ContractError::InsufficientDeposits {}
     );
 
+    // anything deposited on top of the agreed contribution is
+    // refunded to the party router before we forward the funds
+    let mut resp = Response::default().add_attribute("method", "deposit_to_next_contract");
+    for (deposit, party) in [
+        (deposits.party_a, &config.party_a),
+        (deposits.party_b, &config.party_b),
+    ] {
+        let excess = deposit.get_excess();
+        if !excess.is_empty() {
+            record_history(
+                deps.storage,
+                &env.block,
+                HistoryAction::Refund,
+                Some(party.host_addr.to_string()),
+                excess.clone(),
+            )?;
+            let excess_str = excess
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+                .join(",");
+            resp = resp
+                .add_attribute("excess_refund", format!("{excess_str} -> {}", party.router))
+                .add_message(BankMsg::Send {
+                    to_address: party.router.to_string(),
+                    amount: excess,
+                });
+        }
+    }
+
+    for party in [&config.party_a, &config.party_b] {
+        record_history(
+            deps.storage,
+            &env.block,
+            HistoryAction::Deposit,
+            Some(party.host_addr.to_string()),
+            party.contribution.clone(),
+        )?;
+    }
+
+    // contribution denoms that the liquid pooler does not provide
+    // are distributed right away
+    let pool_denoms = POOL_DENOMS.may_load(deps.storage)?;
+    let (liquidity_coins, distributed_coins): (Vec<Coin>, Vec<Coin>) = config
+        .party_a
+        .contribution
+        .into_iter()
+        .chain(config.party_b.contribution)
+        .partition(|coin| match &pool_denoms {
+            Some(denoms) => denoms.contains(&coin.denom),
+            None => true,
+        });
+    if !distributed_coins.is_empty() {
+        record_history(
+            deps.storage,
+            &env.block,
+            HistoryAction::Distribution,
+            None,
+            distributed_coins.clone(),
+        )?;
+        let denom_splits = DENOM_SPLITS.load(deps.storage)?;
+        resp = resp
+            .add_messages(
+                denom_splits
+                    .clone()
+                    .get_shared_distribution_messages(distributed_coins.clone()),
+            )
+            .add_messages(denom_splits.get_fallback_distribution_messages(distributed_coins));
+    }
+
     // LiquidPooler is the next contract
     let liquid_pooler = LIQUID_POOLER_ADDRESS.load(deps.storage)?;
     let msg = BankMsg::Send {
         to_address: liquid_pooler.to_string(),
-        amount: vec![party_a_bal, party_b_bal],
+        amount: liquidity_coins,
     };
 
     // advance the state to Active
     CONTRACT_STATE.save(deps.storage, &ContractState::Active)?;
 
-    Ok(Response::default()
-        .add_attribute("method", "deposit_to_next_contract")
-        .add_message(msg))
+    Ok(resp.add_message(msg))
+}
+
+/// queries the holder balances of both party contribution denoms
+fn query_deposits(
+    deps: Deps,
+    env: &Env,
+    config: &TwoPartyPolCovenantConfig,
+) -> StdResult<DepositsResponse> {
+    let contract_addr = env.contract.address.to_string();
+    let query_party_deposit = |party: &TwoPartyPolCovenantParty| -> StdResult<PartyDeposit> {
+        let deposited = party
+            .contribution
+            .iter()
+            .map(|c| deps.querier.query_balance(&contract_addr, &c.denom))
+            .collect::<StdResult<Vec<Coin>>>()?;
+        Ok(PartyDeposit {
+            contribution: party.contribution.clone(),
+            deposited,
+        })
+    };
+
+    Ok(DepositsResponse {
+        party_a: query_party_deposit(&config.party_a)?,
+        party_b: query_party_deposit(&config.party_b)?,
+    })
 }
 
 fn check_expiration(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
@@ -537,6 +790,14 @@ fn try_ragequit(
         CovenantType::Side => None, // 100%
     };
 
+    record_history(
+        deps.storage,
+        &env.block,
+        HistoryAction::Ragequit,
+        Some(rq_party.host_addr.to_string()),
+        vec![],
+    )?;
+
     // set WithdrawState to include original data
     WITHDRAW_STATE.save(
         deps.storage,
@@ -546,7 +807,7 @@ fn try_ragequit(
         },
     )?;
 
-    let withdraw_msg = generate_withdraw_msg(lper.to_string(), withdraw_percentage)?;
+    let withdraw_msg = LiquidPoolerContract(lper).withdraw_msg(withdraw_percentage)?;
 
     Ok(Response::default().add_message(withdraw_msg))
 }
@@ -601,13 +862,64 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::DepositAddress {} => Ok(to_json_binary(&env.contract.address)?),
         QueryMsg::DenomSplits {} => Ok(to_json_binary(&DENOM_SPLITS.load(deps.storage)?)?),
         QueryMsg::EmergencyCommittee {} => Ok(to_json_binary(
-            &EMERGENCY_COMMITTEE_ADDR.may_load(deps.storage)?,
+            &EMERGENCY_COMMITTEE.may_load(deps.storage)?,
+        )?),
+        QueryMsg::PendingEmergencyActions {} => Ok(to_json_binary(
+            &query_pending_emergency_actions(deps.storage)?,
         )?),
+        QueryMsg::History { start_after, limit } => Ok(to_json_binary(&query_history(
+            deps.storage,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::Deposits {} => {
+            ensure!(
+                CONTRACT_STATE.load(deps.storage)? == ContractState::Instantiated,
+                StdError::generic_err("deposits are only tracked in instantiated state")
+            );
+            let config = COVENANT_CONFIG.load(deps.storage)?;
+            Ok(to_json_binary(&query_deposits(deps, &env, &config)?)?)
+        }
+    }
+}
+
+/// previous versions stored a single coin contribution per party. the
+/// covenant config, along with any ragequit party and pending ragequit
+/// withdrawal, is converted to single coin contribution baskets.
+fn migrate_legacy_contributions(storage: &mut dyn Storage) -> StdResult<Vec<Attribute>> {
+    let mut attributes = vec![];
+    if COVENANT_CONFIG.load(storage).is_err() {
+        if let Some(legacy_config) = LEGACY_COVENANT_CONFIG.may_load(storage)? {
+            let config: TwoPartyPolCovenantConfig = legacy_config.into();
+            COVENANT_CONFIG.save(storage, &config)?;
+            attributes.push(Attribute::new("covenant_config", format!("{:?}", config)));
+        }
+    }
+    if RAGEQUIT_CONFIG.load(storage).is_err() {
+        if let Some(legacy_config) = LEGACY_RAGEQUIT_CONFIG.may_load(storage)? {
+            let config: RagequitConfig = legacy_config.into();
+            RAGEQUIT_CONFIG.save(storage, &config)?;
+            attributes.extend(config.get_response_attributes());
+        }
     }
+    if WITHDRAW_STATE.may_load(storage).is_err() {
+        if let Some(legacy_state) = LEGACY_WITHDRAW_STATE.may_load(storage)? {
+            WITHDRAW_STATE.save(storage, &legacy_state.into())?;
+        }
+    }
+    Ok(attributes)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    // the emergency committee address of the previous versions
+    // is carried over on any migration
+    let mut legacy_committee_attributes = migrate_legacy_emergency_committee(deps.storage)?
+        .map(|committee| committee.get_response_attributes())
+        .unwrap_or_default();
+    // as are the single coin party contributions
+    legacy_committee_attributes.extend(migrate_legacy_contributions(deps.storage)?);
+
     match msg {
         MigrateMsg::UpdateConfig {
             op_mode,
@@ -620,7 +932,9 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response>
             fallback_split,
             emergency_committee,
         } => {
-            let mut resp = Response::default().add_attribute("method", "update_config");
+            let mut resp = Response::default()
+                .add_attribute("method", "update_config")
+                .add_attributes(legacy_committee_attributes);
 
             if let Some(op_mode_cfg) = op_mode {
                 let updated_op_mode = ContractOperationMode::try_init(deps.api, op_mode_cfg)
@@ -652,10 +966,10 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response>
                 resp = resp.add_attribute("deposit_deadline", expiry_config.to_string());
             }
 
-            if let Some(addr) = emergency_committee {
-                let committee_addr = deps.api.addr_validate(&addr)?;
-                EMERGENCY_COMMITTEE_ADDR.save(deps.storage, &committee_addr)?;
-                resp = resp.add_attribute("emergency_committee_addr", committee_addr);
+            if let Some(committee_config) = emergency_committee {
+                let committee = committee_config.validate(deps.api)?;
+                save_emergency_committee(deps.storage, &committee)?;
+                resp = resp.add_attributes(committee.get_response_attributes());
             }
 
             if let Some(config) = *ragequit_config {
@@ -694,7 +1008,7 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response>
             // This is a migrate message to update code id,
             // Data is optional base64 that we can parse to any data we would like in the future
             // let data: SomeStruct = from_binary(&data)?;
-            Ok(Response::default())
+            Ok(Response::default().add_attributes(legacy_committee_attributes))
         }
     }
 }
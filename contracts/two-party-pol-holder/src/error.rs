@@ -1,5 +1,7 @@
 use cosmwasm_std::StdError;
-use covenant_utils::op_mode::ContractOperationError;
+use covenant_utils::{
+    emergency_committee::EmergencyCommitteeError, op_mode::ContractOperationError,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,6 +12,9 @@ pub enum ContractError {
     #[error(transparent)]
     ContractOperationError(#[from] ContractOperationError),
 
+    #[error(transparent)]
+    EmergencyCommitteeError(#[from] EmergencyCommitteeError),
+
     #[error("party allocations must add up to 1.0")]
     AllocationValidationError {},
 
@@ -84,4 +89,13 @@ pub enum ContractError {
 
     #[error("Party contribution cannot be zero")]
     PartyContributionConfigError {},
+
+    #[error("Contribution denom {0} is provided more than once")]
+    DuplicateContributionDenom(String),
+
+    #[error("Party {0} does not contribute any pool denom")]
+    PoolContributionDenomMissing(String),
+
+    #[error("Contribution denom {0} has no split configured and no fallback split is provided")]
+    ContributionDenomSplitMissing(String),
 }
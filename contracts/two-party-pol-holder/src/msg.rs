@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, fmt};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
@@ -11,6 +14,7 @@ use covenant_macros::{
 };
 use covenant_utils::{
     clock::dequeue_msg,
+    emergency_committee::{EmergencyAction, EmergencyCommitteeConfig},
     instantiate2_helper::Instantiate2HelperConfig,
     op_mode::{ContractOperationMode, ContractOperationModeConfig},
     split::SplitConfig,
@@ -44,8 +48,13 @@ pub struct InstantiateMsg {
     /// a split for all denoms that are not covered in the
     /// regular `splits` list
     pub fallback_split: Option<SplitConfig>,
-    /// address of the emergency committee
-    pub emergency_committee_addr: Option<String>,
+    /// optional emergency committee allowed to withdraw the
+    /// liquidity with M-of-N approvals after a timelock
+    pub emergency_committee: Option<EmergencyCommitteeConfig>,
+    /// denoms provided as liquidity by the liquid pooler. contribution
+    /// denoms outside of it are distributed with their splits upon
+    /// deposit. every contribution denom goes to the liquid pooler if not set
+    pub pool_denoms: Option<Vec<String>>,
 }
 
 impl InstantiateMsg {
@@ -88,6 +97,9 @@ impl InstantiateMsg {
         attrs.extend(self.ragequit_config.get_response_attributes());
         attrs.extend(splits_attr);
         attrs.extend(self.covenant_config.get_response_attributes());
+        if let Some(pool_denoms) = &self.pool_denoms {
+            attrs.push(Attribute::new("pool_denoms", pool_denoms.join(",")));
+        }
         attrs
     }
 }
@@ -289,11 +301,21 @@ impl TwoPartyPolCovenantConfig {
         api.addr_validate(&self.party_a.host_addr)?;
         api.addr_validate(&self.party_b.host_addr)?;
 
-        ensure!(
-            !self.party_a.contribution.amount.is_zero()
-                && !self.party_b.contribution.amount.is_zero(),
-            ContractError::PartyContributionConfigError {}
-        );
+        let mut contribution_denoms = BTreeSet::new();
+        for party in [&self.party_a, &self.party_b] {
+            ensure!(
+                !party.contribution.is_empty()
+                    && party.contribution.iter().all(|c| !c.amount.is_zero()),
+                ContractError::PartyContributionConfigError {}
+            );
+            for coin in party.contribution.iter() {
+                // a denom can only be contributed once, by a single party
+                ensure!(
+                    contribution_denoms.insert(coin.denom.to_string()),
+                    ContractError::DuplicateContributionDenom(coin.denom.to_string())
+                );
+            }
+        }
 
         ensure!(
             self.party_a.allocation + self.party_b.allocation == Decimal::one(),
@@ -316,8 +338,9 @@ impl TwoPartyPolCovenantConfig {
 
 #[cw_serde]
 pub struct TwoPartyPolCovenantParty {
-    /// the `denom` and `amount` (`Uint128`) to be contributed by the party
-    pub contribution: Coin,
+    /// the coins to be contributed by the party. a party may contribute
+    /// a basket of denoms, each with its own amount.
+    pub contribution: Vec<Coin>,
     /// neutron address authorized by the party to perform claims/ragequits
     pub host_addr: String,
     /// address of the party on the controller chain (final receiver)
@@ -334,7 +357,14 @@ pub struct TwoPartyPolCovenantParty {
 impl TwoPartyPolCovenantParty {
     pub fn get_response_attributes(&self) -> Vec<Attribute> {
         vec![
-            Attribute::new("contribution", self.contribution.to_string()),
+            Attribute::new(
+                "contribution",
+                self.contribution
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join(","),
+            ),
             Attribute::new("host_addr", self.host_addr.to_string()),
             Attribute::new("controller_addr", self.controller_addr.to_string()),
             Attribute::new("allocation", self.allocation.to_string()),
@@ -386,7 +416,7 @@ pub enum MigrateMsg {
     UpdateConfig {
         op_mode: Option<ContractOperationModeConfig>,
         next_contract: Option<String>,
-        emergency_committee: Option<String>,
+        emergency_committee: Option<EmergencyCommitteeConfig>,
         lockup_config: Option<Expiration>,
         deposit_deadline: Option<Expiration>,
         ragequit_config: Box<Option<RagequitConfig>>,
@@ -471,10 +501,71 @@ pub enum QueryMsg {
     Config {},
     #[returns(DenomSplits)]
     DenomSplits {},
-    #[returns(Option<cosmwasm_std::Addr>)]
+    #[returns(Option<covenant_utils::emergency_committee::EmergencyCommittee>)]
     EmergencyCommittee {},
+    #[returns(Vec<covenant_utils::emergency_committee::PendingEmergencyAction>)]
+    PendingEmergencyActions {},
     #[returns(covenant_utils::op_mode::ContractOperationMode)]
     OperationMode {},
+    /// amounts funded by each party while awaiting deposits.
+    /// only available in `Instantiated` state.
+    #[returns(DepositsResponse)]
+    Deposits {},
+    /// paginated log of the actions performed by the holder
+    #[returns(Vec<covenant_utils::history::HistoryEntry>)]
+    History {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct DepositsResponse {
+    pub party_a: PartyDeposit,
+    pub party_b: PartyDeposit,
+}
+
+#[cw_serde]
+pub struct PartyDeposit {
+    /// coins the party agreed to contribute
+    pub contribution: Vec<Coin>,
+    /// amounts of the contribution denoms currently held by the holder,
+    /// in the same order as the contribution
+    pub deposited: Vec<Coin>,
+}
+
+impl PartyDeposit {
+    /// the deposit is fulfilled once every denom of the contribution
+    /// basket is held in at least the agreed amount
+    pub fn is_fulfilled(&self) -> bool {
+        self.contribution.iter().all(|contribution| {
+            self.deposited
+                .iter()
+                .any(|d| d.denom == contribution.denom && d.amount >= contribution.amount)
+        })
+    }
+
+    /// returns the amounts deposited on top of the agreed contribution
+    pub fn get_excess(&self) -> Vec<Coin> {
+        self.contribution
+            .iter()
+            .filter_map(|contribution| {
+                let deposited = self
+                    .deposited
+                    .iter()
+                    .find(|d| d.denom == contribution.denom)?;
+                let excess = deposited.amount.checked_sub(contribution.amount).ok()?;
+                if excess.is_zero() {
+                    None
+                } else {
+                    Some(Coin {
+                        denom: contribution.denom.to_string(),
+                        amount: excess,
+                    })
+                }
+            })
+            .collect()
+    }
 }
 
 #[cw_serde]
@@ -1,11 +1,12 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Coin, Decimal};
 use covenant_utils::op_mode::ContractOperationMode;
 use cw_storage_plus::Item;
 use cw_utils::Expiration;
 
 use crate::msg::{
-    ContractState, DenomSplits, RagequitConfig, RagequitTerms, TwoPartyPolCovenantConfig,
+    ContractState, CovenantType, DenomSplits, RagequitConfig, RagequitState, RagequitTerms,
+    TwoPartyPolCovenantConfig, TwoPartyPolCovenantParty,
 };
 
 pub const CONTRACT_STATE: Item<ContractState> = Item::new("contract_state");
@@ -29,6 +30,17 @@ pub const RAGEQUIT_CONFIG: Item<RagequitConfig> = Item::new("ragequit_config");
 
 /// configuration storing both parties information
 pub const COVENANT_CONFIG: Item<TwoPartyPolCovenantConfig> = Item::new("covenant_config");
+/// single coin contribution configuration stored under the same key by the previous versions
+pub const LEGACY_COVENANT_CONFIG: Item<LegacyTwoPartyPolCovenantConfig> =
+    Item::new("covenant_config");
+/// ragequit configuration of the previous versions, holding a single coin contribution party
+pub const LEGACY_RAGEQUIT_CONFIG: Item<LegacyRagequitConfig> = Item::new("ragequit_config");
+/// withdraw state of the previous versions, holding a single coin contribution party
+pub const LEGACY_WITHDRAW_STATE: Item<LegacyWithdrawState> = Item::new("withdraw_state");
+
+/// denoms provided as liquidity by the liquid pooler.
+/// every contribution denom is provided if not set
+pub const POOL_DENOMS: Item<Vec<String>> = Item::new("pool_denoms");
 
 /// stores the configuration describing how to distribute every denom
 pub const DENOM_SPLITS: Item<DenomSplits> = Item::new("denom_splits");
@@ -44,5 +56,118 @@ pub enum WithdrawState {
         claimer_addr: String,
         terms: RagequitTerms,
     },
-    Emergency {},
+    /// emergency withdrawal approved by the emergency committee.
+    /// if receiver is not set, funds are routed to the parties.
+    Emergency {
+        receiver: Option<Addr>,
+    },
+}
+
+#[cw_serde]
+pub struct LegacyTwoPartyPolCovenantConfig {
+    pub party_a: LegacyTwoPartyPolCovenantParty,
+    pub party_b: LegacyTwoPartyPolCovenantParty,
+    pub covenant_type: CovenantType,
+}
+
+impl From<LegacyTwoPartyPolCovenantConfig> for TwoPartyPolCovenantConfig {
+    fn from(config: LegacyTwoPartyPolCovenantConfig) -> Self {
+        TwoPartyPolCovenantConfig {
+            party_a: config.party_a.into(),
+            party_b: config.party_b.into(),
+            covenant_type: config.covenant_type,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct LegacyTwoPartyPolCovenantParty {
+    pub contribution: Coin,
+    pub host_addr: String,
+    pub controller_addr: String,
+    pub allocation: Decimal,
+    pub router: String,
+}
+
+impl From<LegacyTwoPartyPolCovenantParty> for TwoPartyPolCovenantParty {
+    fn from(party: LegacyTwoPartyPolCovenantParty) -> Self {
+        TwoPartyPolCovenantParty {
+            contribution: vec![party.contribution],
+            host_addr: party.host_addr,
+            controller_addr: party.controller_addr,
+            allocation: party.allocation,
+            router: party.router,
+        }
+    }
+}
+
+#[cw_serde]
+pub enum LegacyRagequitConfig {
+    Disabled,
+    Enabled(LegacyRagequitTerms),
+}
+
+impl From<LegacyRagequitConfig> for RagequitConfig {
+    fn from(config: LegacyRagequitConfig) -> Self {
+        match config {
+            LegacyRagequitConfig::Disabled => RagequitConfig::Disabled,
+            LegacyRagequitConfig::Enabled(terms) => RagequitConfig::Enabled(terms.into()),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct LegacyRagequitTerms {
+    pub penalty: Decimal,
+    pub state: Option<LegacyRagequitState>,
+}
+
+impl From<LegacyRagequitTerms> for RagequitTerms {
+    fn from(terms: LegacyRagequitTerms) -> Self {
+        RagequitTerms {
+            penalty: terms.penalty,
+            state: terms.state.map(|state| RagequitState {
+                coins: state.coins,
+                rq_party: state.rq_party.into(),
+            }),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct LegacyRagequitState {
+    pub coins: Vec<Coin>,
+    pub rq_party: LegacyTwoPartyPolCovenantParty,
+}
+
+#[cw_serde]
+pub enum LegacyWithdrawState {
+    Processing {
+        claimer_addr: String,
+    },
+    ProcessingRagequit {
+        claimer_addr: String,
+        terms: LegacyRagequitTerms,
+    },
+    Emergency {
+        receiver: Option<Addr>,
+    },
+}
+
+impl From<LegacyWithdrawState> for WithdrawState {
+    fn from(state: LegacyWithdrawState) -> Self {
+        match state {
+            LegacyWithdrawState::Processing { claimer_addr } => {
+                WithdrawState::Processing { claimer_addr }
+            }
+            LegacyWithdrawState::ProcessingRagequit {
+                claimer_addr,
+                terms,
+            } => WithdrawState::ProcessingRagequit {
+                claimer_addr,
+                terms: terms.into(),
+            },
+            LegacyWithdrawState::Emergency { receiver } => WithdrawState::Emergency { receiver },
+        }
+    }
 }
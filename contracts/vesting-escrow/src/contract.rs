@@ -0,0 +1,257 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    coin, to_json_binary, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Env, Fraction,
+    MessageInfo, Response, StdError, StdResult, Timestamp,
+};
+use covenant_utils::op_mode::{verify_caller, ContractOperationMode};
+use cw2::set_contract_version;
+
+use crate::{
+    error::ContractError,
+    msg::{
+        DenomVestingProgress, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, VestingProgress,
+    },
+    state::{
+        CONTRACT_OP_MODE, RECEIVER_ADDRESS, RELEASED_AMOUNTS, VESTING_DENOMS, VESTING_SCHEDULE,
+        VESTING_START,
+    },
+};
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let op_mode = ContractOperationMode::try_init(deps.api, msg.op_mode_cfg.clone())?;
+    let receiver_addr = deps.api.addr_validate(&msg.receiver_address)?;
+    msg.vesting_schedule.validate()?;
+
+    CONTRACT_OP_MODE.save(deps.storage, &op_mode)?;
+    RECEIVER_ADDRESS.save(deps.storage, &receiver_addr)?;
+    VESTING_DENOMS.save(deps.storage, &msg.denoms)?;
+    VESTING_SCHEDULE.save(deps.storage, &msg.vesting_schedule)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "vesting_escrow_instantiate")
+        .add_attributes(msg.get_response_attributes()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Tick {} => {
+            verify_caller(&info.sender, &CONTRACT_OP_MODE.load(deps.storage)?)?;
+            try_release(deps, env)
+        }
+        ExecuteMsg::DistributeFallback { denoms } => try_distribute_fallback(deps, env, denoms),
+    }
+}
+
+/// releases the vested part of the escrowed funds to the receiver.
+/// vesting starts on the first tick that finds any vesting funds.
+fn try_release(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let balances = query_vesting_balances(deps.as_ref(), &env)?;
+
+    let start = match VESTING_START.may_load(deps.storage)? {
+        Some(start) => start,
+        None => {
+            // nothing to vest yet
+            if balances.iter().all(|c| c.amount.is_zero()) {
+                return Ok(Response::default()
+                    .add_attribute("method", "try_release")
+                    .add_attribute("result", "vesting_not_started"));
+            }
+            VESTING_START.save(deps.storage, &env.block.time)?;
+            env.block.time
+        }
+    };
+
+    let vested_ratio = VESTING_SCHEDULE
+        .load(deps.storage)?
+        .get_vested_ratio(start, env.block.time);
+
+    let mut release_coins: Vec<Coin> = vec![];
+    for bal in balances {
+        let progress = get_denom_vesting_progress(deps.as_ref(), bal, vested_ratio)?;
+        let releasable = progress.vested.saturating_sub(progress.released);
+        if releasable.is_zero() {
+            continue;
+        }
+        RELEASED_AMOUNTS.save(
+            deps.storage,
+            progress.denom.to_string(),
+            &(progress.released + releasable),
+        )?;
+        release_coins.push(coin(releasable.u128(), progress.denom));
+    }
+
+    let resp = Response::default()
+        .add_attribute("method", "try_release")
+        .add_attribute("vesting_start", start.to_string())
+        .add_attribute("vested_ratio", vested_ratio.to_string());
+
+    if release_coins.is_empty() {
+        return Ok(resp);
+    }
+
+    Ok(resp.add_message(BankMsg::Send {
+        to_address: RECEIVER_ADDRESS.load(deps.storage)?.to_string(),
+        amount: release_coins,
+    }))
+}
+
+/// sends any denoms not subject to vesting straight to the receiver
+fn try_distribute_fallback(
+    deps: DepsMut,
+    env: Env,
+    denoms: Vec<String>,
+) -> Result<Response, ContractError> {
+    let vesting_denoms = VESTING_DENOMS.load(deps.storage)?;
+    let receiver_address = RECEIVER_ADDRESS.load(deps.storage)?;
+
+    let mut available_balances = Vec::with_capacity(denoms.len());
+    for denom in denoms {
+        if vesting_denoms.contains(&denom) {
+            return Err(ContractError::UnauthorizedDenomDistribution {});
+        }
+        let queried_coin = deps
+            .querier
+            .query_balance(env.contract.address.to_string(), denom)?;
+        if !queried_coin.amount.is_zero() {
+            available_balances.push(queried_coin);
+        }
+    }
+    available_balances.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+    let mut resp = Response::default().add_attribute("method", "try_distribute_fallback");
+    if !available_balances.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: receiver_address.to_string(),
+            amount: available_balances,
+        });
+    }
+    Ok(resp)
+}
+
+/// queries the escrow balances of the vesting denoms
+fn query_vesting_balances(deps: Deps, env: &Env) -> StdResult<Vec<Coin>> {
+    VESTING_DENOMS
+        .load(deps.storage)?
+        .into_iter()
+        .map(|denom| deps.querier.query_balance(&env.contract.address, denom))
+        .collect()
+}
+
+/// the total amount of a denom is what was already released
+/// plus what is still held by the escrow
+fn get_denom_vesting_progress(
+    deps: Deps,
+    balance: Coin,
+    vested_ratio: Decimal,
+) -> StdResult<DenomVestingProgress> {
+    let released = RELEASED_AMOUNTS
+        .may_load(deps.storage, balance.denom.to_string())?
+        .unwrap_or_default();
+    let total = released.checked_add(balance.amount)?;
+    let vested = total
+        .checked_multiply_ratio(vested_ratio.numerator(), vested_ratio.denominator())
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    Ok(DenomVestingProgress {
+        denom: balance.denom,
+        total,
+        vested,
+        released,
+    })
+}
+
+fn query_vesting_progress(deps: Deps, env: Env) -> StdResult<VestingProgress> {
+    let start: Option<Timestamp> = VESTING_START.may_load(deps.storage)?;
+    let vested_ratio = match start {
+        Some(start) => VESTING_SCHEDULE
+            .load(deps.storage)?
+            .get_vested_ratio(start, env.block.time),
+        None => Decimal::zero(),
+    };
+
+    let mut denoms = vec![];
+    for bal in query_vesting_balances(deps, &env)? {
+        denoms.push(get_denom_vesting_progress(deps, bal, vested_ratio)?);
+    }
+
+    Ok(VestingProgress {
+        start,
+        vested_ratio,
+        denoms,
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::ReceiverAddress {} => {
+            Ok(to_json_binary(&RECEIVER_ADDRESS.may_load(deps.storage)?)?)
+        }
+        QueryMsg::VestingDenoms {} => Ok(to_json_binary(&VESTING_DENOMS.may_load(deps.storage)?)?),
+        QueryMsg::VestingSchedule {} => {
+            Ok(to_json_binary(&VESTING_SCHEDULE.may_load(deps.storage)?)?)
+        }
+        QueryMsg::VestingProgress {} => Ok(to_json_binary(&query_vesting_progress(deps, env)?)?),
+        QueryMsg::OperationMode {} => {
+            Ok(to_json_binary(&CONTRACT_OP_MODE.may_load(deps.storage)?)?)
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    match msg {
+        MigrateMsg::UpdateConfig {
+            op_mode,
+            receiver_address,
+            vesting_schedule,
+        } => {
+            let mut response = Response::default().add_attribute("method", "update_config");
+
+            if let Some(op_mode_cfg) = op_mode {
+                let updated_op_mode = ContractOperationMode::try_init(deps.api, op_mode_cfg)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+                CONTRACT_OP_MODE.save(deps.storage, &updated_op_mode)?;
+                response = response.add_attribute("op_mode", format!("{:?}", updated_op_mode));
+            }
+
+            if let Some(addr) = receiver_address {
+                RECEIVER_ADDRESS.save(deps.storage, &deps.api.addr_validate(&addr)?)?;
+                response = response.add_attribute("receiver_address", addr);
+            }
+
+            if let Some(schedule) = vesting_schedule {
+                schedule.validate()?;
+                VESTING_SCHEDULE.save(deps.storage, &schedule)?;
+                response = response.add_attribute("vesting_schedule", format!("{schedule:?}"));
+            }
+
+            Ok(response)
+        }
+        MigrateMsg::UpdateCodeId { data: _ } => {
+            // This is a migrate message to update code id,
+            // Data is optional base64 that we can parse to any data we would like in the future
+            // let data: SomeStruct = from_binary(&data)?;
+            Ok(Response::default())
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use cosmwasm_std::StdError;
+use covenant_utils::op_mode::ContractOperationError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    ContractOperationError(#[from] ContractOperationError),
+
+    #[error("vesting duration cannot be zero")]
+    VestingDurationError {},
+
+    #[error("vesting cliff cannot exceed the vesting duration")]
+    VestingCliffError {},
+
+    #[error("unauthorized to distribute vesting denom")]
+    UnauthorizedDenomDistribution {},
+}
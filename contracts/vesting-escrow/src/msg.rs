@@ -0,0 +1,146 @@
+use std::collections::BTreeSet;
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Attribute, Binary, Decimal, StdResult, Timestamp, Uint128, Uint64, WasmMsg,
+};
+use covenant_macros::clocked;
+use covenant_utils::{
+    instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
+};
+
+use crate::error::ContractError;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    // Contract Operation Mode.
+    // The contract operation (the Tick function mostly) can either be a permissionless
+    // (aka non-privileged) operation, or a permissioned operation, that is,
+    // restricted to being executed by one of the configured privileged accounts.
+    pub op_mode_cfg: ContractOperationModeConfig,
+    /// address receiving the vested funds.
+    /// usually expected to be the party router.
+    pub receiver_address: String,
+    /// denoms released according to the vesting schedule
+    pub denoms: BTreeSet<String>,
+    pub vesting_schedule: VestingSchedule,
+}
+
+impl InstantiateMsg {
+    pub fn to_instantiate2_msg(
+        &self,
+        instantiate2_helper: &Instantiate2HelperConfig,
+        admin: String,
+        label: String,
+    ) -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Instantiate2 {
+            admin: Some(admin),
+            code_id: instantiate2_helper.code,
+            label,
+            msg: to_json_binary(self)?,
+            funds: vec![],
+            salt: instantiate2_helper.salt.clone(),
+        })
+    }
+
+    pub fn get_response_attributes(self) -> Vec<Attribute> {
+        let denoms: Vec<String> = self.denoms.into_iter().collect();
+        vec![
+            Attribute::new("op_mode", format!("{:?}", self.op_mode_cfg)),
+            Attribute::new("receiver_address", self.receiver_address),
+            Attribute::new("denoms", denoms.join(",")),
+            Attribute::new("vesting_cliff", self.vesting_schedule.cliff),
+            Attribute::new("vesting_duration", self.vesting_schedule.duration),
+        ]
+    }
+}
+
+/// vesting schedule relative to the time the escrow first receives funds
+#[cw_serde]
+pub struct VestingSchedule {
+    /// seconds after the vesting start during which nothing is released
+    pub cliff: Uint64,
+    /// seconds after the vesting start until everything is released.
+    /// funds are released linearly after the cliff.
+    pub duration: Uint64,
+}
+
+impl VestingSchedule {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.duration.is_zero() {
+            return Err(ContractError::VestingDurationError {});
+        }
+        if self.cliff > self.duration {
+            return Err(ContractError::VestingCliffError {});
+        }
+        Ok(())
+    }
+
+    /// returns the fraction of the funds vested at `now`
+    /// for a vesting that started at `start`
+    pub fn get_vested_ratio(&self, start: Timestamp, now: Timestamp) -> Decimal {
+        let elapsed = now.seconds().saturating_sub(start.seconds());
+        if elapsed < self.cliff.u64() {
+            Decimal::zero()
+        } else if elapsed >= self.duration.u64() {
+            Decimal::one()
+        } else {
+            Decimal::from_ratio(elapsed, self.duration.u64())
+        }
+    }
+}
+
+#[clocked]
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// distribute any denoms not subject to vesting to the receiver
+    DistributeFallback { denoms: Vec<String> },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(cosmwasm_std::Addr)]
+    ReceiverAddress {},
+    #[returns(BTreeSet<String>)]
+    VestingDenoms {},
+    #[returns(VestingSchedule)]
+    VestingSchedule {},
+    /// vesting progress as of the current block
+    #[returns(VestingProgress)]
+    VestingProgress {},
+    #[returns(covenant_utils::op_mode::ContractOperationMode)]
+    OperationMode {},
+}
+
+#[cw_serde]
+pub struct VestingProgress {
+    /// time at which the vesting started, if any funds were received
+    pub start: Option<Timestamp>,
+    /// fraction of the received funds that is vested
+    pub vested_ratio: Decimal,
+    pub denoms: Vec<DenomVestingProgress>,
+}
+
+#[cw_serde]
+pub struct DenomVestingProgress {
+    pub denom: String,
+    /// total amount received by the escrow
+    pub total: Uint128,
+    /// amount vested so far
+    pub vested: Uint128,
+    /// amount released to the receiver so far
+    pub released: Uint128,
+}
+
+#[cw_serde]
+pub enum MigrateMsg {
+    UpdateConfig {
+        op_mode: Option<ContractOperationModeConfig>,
+        receiver_address: Option<String>,
+        vesting_schedule: Option<VestingSchedule>,
+    },
+    UpdateCodeId {
+        data: Option<Binary>,
+    },
+}
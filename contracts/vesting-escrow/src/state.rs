@@ -0,0 +1,16 @@
+use std::collections::BTreeSet;
+
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use covenant_utils::op_mode::ContractOperationMode;
+use cw_storage_plus::{Item, Map};
+
+use crate::msg::VestingSchedule;
+
+pub const CONTRACT_OP_MODE: Item<ContractOperationMode> = Item::new("contract_op_mode");
+pub const RECEIVER_ADDRESS: Item<Addr> = Item::new("receiver_address");
+pub const VESTING_DENOMS: Item<BTreeSet<String>> = Item::new("vesting_denoms");
+pub const VESTING_SCHEDULE: Item<VestingSchedule> = Item::new("vesting_schedule");
+/// time at which the first vesting funds were received
+pub const VESTING_START: Item<Timestamp> = Item::new("vesting_start");
+/// amounts already released to the receiver, keyed by denom
+pub const RELEASED_AMOUNTS: Map<String, Uint128> = Map::new("released_amounts");
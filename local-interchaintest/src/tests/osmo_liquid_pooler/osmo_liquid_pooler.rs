@@ -6,7 +6,9 @@ use crate::helpers::{
     },
 };
 use cosmwasm_std::{Coin, Decimal, Uint128, Uint64};
-use covenant_utils::{op_mode::ContractOperationModeConfig, PoolPriceConfig, SingleSideLpLimits};
+use covenant_utils::{
+    op_mode::ContractOperationModeConfig, PoolPriceConfig, ReceiverConfig, SingleSideLpLimits,
+};
 use cw_utils::{Duration as CwDuration, Expiration};
 use localic_std::{errors::LocalError, modules::cosmwasm::CosmWasm, relayer::Relayer};
 use localic_utils::{
@@ -137,10 +139,13 @@ fn make_holder(
         .with_msg(
             serde_json::to_value(&valence_single_party_pol_holder::msg::InstantiateMsg {
                 withdrawer: ACC1_ADDRESS_NEUTRON.to_owned(),
-                withdraw_to: ACC1_ADDRESS_NEUTRON.to_owned(),
-                emergency_committee_addr: None,
+                withdraw_to: ReceiverConfig::Native(ACC1_ADDRESS_NEUTRON.to_owned()),
+                emergency_committee: None,
                 pooler_address,
                 lockup_period: Expiration::Never {},
+                unlock_schedule: None,
+                early_withdrawal_config:
+                    valence_single_party_pol_holder::msg::EarlyWithdrawalConfig::Disabled,
             })
             .unwrap(),
         )
@@ -314,6 +319,10 @@ fn make_pooler(
                     asset_a_limit: Default::default(),
                     asset_b_limit: Default::default(),
                 },
+                withdraw_price_guard: None,
+                tick_range: None,
+                state_timeouts: None,
+                single_asset_exit: None,
                 slippage_tolerance: None,
                 pool_price_config: PoolPriceConfig {
                     expected_spot_price: Decimal::one(),
@@ -543,8 +543,15 @@ pub fn test_single_party_pol_stride(test_ctx: &mut TestContext) -> Result<(), Lo
                 asset_a_limit: Uint128::new(1000000),
                 asset_b_limit: Uint128::new(1000000),
             },
+            lp_staking_config: None,
+            zap_config: None,
+            twap_window_seconds: None,
+            withdraw_price_guard: None,
         }),
         operation_mode: covenant_utils::op_mode::ContractOperationModeConfig::Permissioned(vec![]),
+        unlock_schedule: None,
+        early_withdrawal_config: None,
+        withdraw_to: None,
     };
 
     let covenant_contract = contract_instantiate(
@@ -922,8 +929,10 @@ pub fn test_single_party_pol_stride(test_ctx: &mut TestContext) -> Result<(), Lo
                 .get_request_builder(NEUTRON_CHAIN_NAME),
             &holder_address,
             ACC_1_KEY,
-            &serde_json::to_string(&valence_single_party_pol_holder::msg::ExecuteMsg::Claim {})
-                .unwrap(),
+            &serde_json::to_string(&valence_single_party_pol_holder::msg::ExecuteMsg::Claim {
+                percentage: None,
+            })
+            .unwrap(),
             EXECUTE_FLAGS,
         ) {
             Ok(_) => break,
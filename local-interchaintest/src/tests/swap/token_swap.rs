@@ -176,6 +176,10 @@ pub fn test_token_swap(test_ctx: &mut TestContext) -> Result<(), LocalError> {
         fallback_split: None,
         fallback_address: None,
         operation_mode: ContractOperationModeConfig::Permissioned(vec![]),
+        min_fill_ratio: None,
+        price_reference: None,
+        vesting: None,
+        refund_config: None,
     };
 
     let covenant_contract = contract_instantiate(
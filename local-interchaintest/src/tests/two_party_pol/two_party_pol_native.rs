@@ -407,6 +407,10 @@ pub fn test_two_party_pol_native(test_ctx: &mut TestContext) -> Result<(), Local
                     asset_a_limit: Uint128::new(100000),
                     asset_b_limit: Uint128::new(100000),
                 },
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         ),
         fallback_address: None,
@@ -923,6 +927,10 @@ pub fn test_two_party_pol_native(test_ctx: &mut TestContext) -> Result<(), Local
                     asset_a_limit: Uint128::new(100000),
                     asset_b_limit: Uint128::new(100000),
                 },
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         ),
         fallback_address: None,
@@ -1316,6 +1324,10 @@ pub fn test_two_party_pol_native(test_ctx: &mut TestContext) -> Result<(), Local
                     asset_a_limit: Uint128::new(100000),
                     asset_b_limit: Uint128::new(100000),
                 },
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         ),
         fallback_address: None,
@@ -1706,6 +1718,10 @@ pub fn test_two_party_pol_native(test_ctx: &mut TestContext) -> Result<(), Local
                     asset_a_limit: Uint128::new(100000),
                     asset_b_limit: Uint128::new(100000),
                 },
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         ),
         fallback_address: None,
@@ -485,6 +485,10 @@ pub fn test_two_party_pol(test_ctx: &mut TestContext) -> Result<(), LocalError>
                     asset_a_limit: Uint128::new(100000),
                     asset_b_limit: Uint128::new(1000000),
                 },
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         ),
         fallback_address: None,
@@ -981,6 +985,10 @@ pub fn test_two_party_pol(test_ctx: &mut TestContext) -> Result<(), LocalError>
                     asset_a_limit: Uint128::new(100000),
                     asset_b_limit: Uint128::new(100000),
                 },
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         ),
         fallback_address: None,
@@ -1409,6 +1417,10 @@ pub fn test_two_party_pol(test_ctx: &mut TestContext) -> Result<(), LocalError>
                     asset_a_limit: Uint128::new(1000000),
                     asset_b_limit: Uint128::new(1000000),
                 },
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         ),
         fallback_address: None,
@@ -1814,6 +1826,10 @@ pub fn test_two_party_pol(test_ctx: &mut TestContext) -> Result<(), LocalError>
                     asset_a_limit: Uint128::new(1000000),
                     asset_b_limit: Uint128::new(10000000),
                 },
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         ),
         fallback_address: None,
@@ -3,8 +3,11 @@ use std::{collections::BTreeMap, env, path::Path, str::FromStr, thread, time::Du
 use anyhow::Error;
 use cosmwasm_std::{Coin, Decimal, Uint128, Uint64};
 use covenant_utils::{
-    op_mode::ContractOperationModeConfig, split::SplitConfig, ForwardMetadata,
-    InterchainCovenantParty, PacketForwardMiddlewareConfig, PoolPriceConfig, SingleSideLpLimits,
+    emergency_committee::{EmergencyAction, EmergencyCommitteeConfig},
+    op_mode::ContractOperationModeConfig,
+    split::SplitConfig,
+    ForwardMetadata, InterchainCovenantParty, PacketForwardMiddlewareConfig, PoolPriceConfig,
+    SingleSideLpLimits,
 };
 use cw_utils::Expiration;
 use localic_std::{
@@ -414,7 +417,12 @@ pub fn test_two_party_pol_osmo(test_ctx: &mut TestContext) -> Result<(), Error>
             ),
         ]),
         fallback_split: None,
-        emergency_committee: Some(NEUTRON_CHAIN_ADMIN_ADDR.to_string()),
+        emergency_committee: Some(EmergencyCommitteeConfig {
+            members: vec![NEUTRON_CHAIN_ADMIN_ADDR.to_string()],
+            threshold: 1,
+            timelock: cw_utils::Duration::Height(0),
+            safe_mode: true,
+        }),
         liquid_pooler_config: valence_covenant_two_party_pol::msg::LiquidPoolerConfig::Osmosis(
             Box::new(OsmosisLiquidPoolerConfig {
                 note_address: polytone_note_contract.address.clone(),
@@ -492,6 +500,10 @@ pub fn test_two_party_pol_osmo(test_ctx: &mut TestContext) -> Result<(), Error>
                     asset_a_limit: Uint128::new(10000),
                     asset_b_limit: Uint128::new(975000004),
                 },
+                withdraw_price_guard: None,
+                tick_range: None,
+                state_timeouts: None,
+                single_asset_exit: None,
             }),
         ),
         fallback_address: None,
@@ -768,7 +780,24 @@ pub fn test_two_party_pol_osmo(test_ctx: &mut TestContext) -> Result<(), Error>
         &holder_address,
         DEFAULT_KEY,
         &serde_json::to_string(
-            &valence_two_party_pol_holder::msg::ExecuteMsg::EmergencyWithdraw {},
+            &valence_two_party_pol_holder::msg::ExecuteMsg::ProposeEmergencyAction {
+                action: EmergencyAction::WithdrawToParties {},
+            },
+        )
+        .unwrap(),
+        EXECUTE_FLAGS,
+    )
+    .unwrap();
+    thread::sleep(Duration::from_secs(3));
+
+    contract_execute(
+        test_ctx
+            .get_request_builder()
+            .get_request_builder(NEUTRON_CHAIN_NAME),
+        &holder_address,
+        DEFAULT_KEY,
+        &serde_json::to_string(
+            &valence_two_party_pol_holder::msg::ExecuteMsg::ExecuteEmergencyAction { id: 0 },
         )
         .unwrap(),
         EXECUTE_FLAGS,
@@ -1024,7 +1053,12 @@ pub fn test_two_party_pol_osmo(test_ctx: &mut TestContext) -> Result<(), Error>
             ),
         ]),
         fallback_split: None,
-        emergency_committee: Some(NEUTRON_CHAIN_ADMIN_ADDR.to_string()),
+        emergency_committee: Some(EmergencyCommitteeConfig {
+            members: vec![NEUTRON_CHAIN_ADMIN_ADDR.to_string()],
+            threshold: 1,
+            timelock: cw_utils::Duration::Height(0),
+            safe_mode: true,
+        }),
         liquid_pooler_config: valence_covenant_two_party_pol::msg::LiquidPoolerConfig::Osmosis(
             Box::new(OsmosisLiquidPoolerConfig {
                 note_address: polytone_note_contract.address,
@@ -1102,6 +1136,10 @@ pub fn test_two_party_pol_osmo(test_ctx: &mut TestContext) -> Result<(), Error>
                     asset_a_limit: Uint128::new(10000),
                     asset_b_limit: Uint128::new(975000004),
                 },
+                withdraw_price_guard: None,
+                tick_range: None,
+                state_timeouts: None,
+                single_asset_exit: None,
             }),
         ),
         fallback_address: None,
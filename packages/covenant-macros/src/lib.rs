@@ -179,8 +179,14 @@ pub fn covenant_holder_emergency_withdraw(
         input,
         quote!(
             enum EmergencyWithdrawMsgs {
-                /// Allows for the emergency committee to withdraw the funds on case of an emergency
-                EmergencyWithdraw {},
+                /// Allows an emergency committee member to propose an emergency action.
+                /// Proposing counts as the proposer's approval.
+                ProposeEmergencyAction { action: EmergencyAction },
+                /// Allows an emergency committee member to approve a pending emergency action
+                ApproveEmergencyAction { id: u64 },
+                /// Executes a pending emergency action once enough committee members
+                /// approved it and its timelock is over
+                ExecuteEmergencyAction { id: u64 },
             }
         )
         .into(),
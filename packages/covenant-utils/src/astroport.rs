@@ -1,6 +1,9 @@
-use astroport::asset::PairInfo;
+use astroport::{
+    asset::{AssetInfo, PairInfo},
+    pair::{CumulativePricesResponse, PoolResponse, TWAP_PRECISION},
+};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{QuerierWrapper, StdError, Uint128};
+use cosmwasm_std::{Decimal, QuerierWrapper, StdError, StdResult, Timestamp, Uint128};
 use cw20::BalanceResponse;
 
 /// queries the liquidity token balance of given address
@@ -52,3 +55,117 @@ pub struct AstroportPoolTokenResponse {
     pub pair_info: PairInfo,
     pub balance_response: BalanceResponse,
 }
+
+/// validates that the given pool trades the given native denoms
+pub fn validate_astro_pool_denoms(
+    querier: QuerierWrapper,
+    pool: &str,
+    denoms: &[&str],
+) -> StdResult<()> {
+    let pair_info: PairInfo =
+        querier.query_wasm_smart(pool, &astroport::pair::QueryMsg::Pair {})?;
+
+    for denom in denoms {
+        let native_asset = AssetInfo::NativeToken {
+            denom: denom.to_string(),
+        };
+        if !pair_info.asset_infos.contains(&native_asset) {
+            return Err(StdError::generic_err(format!(
+                "pool {pool} does not trade {denom}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// queries the spot price of `base_denom` denominated in `quote_denom`,
+/// derived from the pool reserves
+pub fn query_astro_pool_spot_price(
+    querier: QuerierWrapper,
+    pool: &str,
+    base_denom: &str,
+    quote_denom: &str,
+) -> StdResult<Decimal> {
+    let pool_response: PoolResponse =
+        querier.query_wasm_smart(pool, &astroport::pair::QueryMsg::Pool {})?;
+
+    let (mut base_bal, mut quote_bal) = (Uint128::zero(), Uint128::zero());
+    for asset in pool_response.assets {
+        let coin = asset.to_coin()?;
+        if coin.denom == base_denom {
+            base_bal = coin.amount;
+        } else if coin.denom == quote_denom {
+            quote_bal = coin.amount;
+        }
+    }
+
+    if base_bal.is_zero() || quote_bal.is_zero() {
+        return Err(StdError::generic_err("all pool assets must be non-zero"));
+    }
+
+    Decimal::checked_from_ratio(quote_bal, base_bal)
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+/// snapshot of the cumulative price of a pool asset at a point in time
+#[cw_serde]
+pub struct PriceObservation {
+    pub cumulative_price: Uint128,
+    pub timestamp: Timestamp,
+}
+
+impl PriceObservation {
+    /// returns the time weighted average price between this
+    /// observation and a later one
+    pub fn get_twap(&self, latest: &PriceObservation) -> StdResult<Decimal> {
+        let elapsed_seconds = latest
+            .timestamp
+            .seconds()
+            .saturating_sub(self.timestamp.seconds());
+        if elapsed_seconds == 0 {
+            return Err(StdError::generic_err(
+                "no time elapsed since the price observation",
+            ));
+        }
+
+        // cumulative prices are accumulated with `TWAP_PRECISION` decimals
+        let denominator = Uint128::from(elapsed_seconds)
+            .checked_mul(Uint128::from(10u128.pow(TWAP_PRECISION.into())))?;
+        Decimal::checked_from_ratio(
+            latest.cumulative_price.wrapping_sub(self.cumulative_price),
+            denominator,
+        )
+        .map_err(|e| StdError::generic_err(e.to_string()))
+    }
+}
+
+/// observes the cumulative price of `base_denom` denominated in `quote_denom`
+pub fn query_astro_pool_price_observation(
+    querier: QuerierWrapper,
+    pool: &str,
+    base_denom: &str,
+    quote_denom: &str,
+    timestamp: Timestamp,
+) -> StdResult<PriceObservation> {
+    let response: CumulativePricesResponse =
+        querier.query_wasm_smart(pool, &astroport::pair::QueryMsg::CumulativePrices {})?;
+
+    let base_info = AssetInfo::NativeToken {
+        denom: base_denom.to_string(),
+    };
+    let quote_info = AssetInfo::NativeToken {
+        denom: quote_denom.to_string(),
+    };
+
+    let (_, _, cumulative_price) = response
+        .cumulative_prices
+        .into_iter()
+        .find(|(from, to, _)| from == &base_info && to == &quote_info)
+        .ok_or_else(|| StdError::not_found("cumulative price"))?;
+
+    Ok(PriceObservation {
+        cumulative_price,
+        timestamp,
+    })
+}
@@ -0,0 +1,301 @@
+use std::collections::HashSet;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Addr, Api, BlockInfo, Order, StdError, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+use thiserror::Error;
+
+/// configured emergency committee, if any
+pub const EMERGENCY_COMMITTEE: Item<EmergencyCommittee> = Item::new("e_c");
+/// emergency actions that were proposed but not yet executed
+pub const PENDING_EMERGENCY_ACTIONS: Map<u64, PendingEmergencyAction> = Map::new("e_c_pending");
+/// id to be assigned to the next proposed emergency action
+pub const NEXT_EMERGENCY_ACTION_ID: Item<u64> = Item::new("e_c_next_id");
+/// single emergency committee address stored by the previous contract versions
+const LEGACY_EMERGENCY_COMMITTEE_ADDR: Item<Addr> = Item::new("e_c_a");
+
+#[derive(Error, Debug, PartialEq)]
+pub enum EmergencyCommitteeError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("emergency committee is not configured")]
+    NotConfigured {},
+
+    #[error("only emergency committee members can perform this action")]
+    Unauthorized {},
+
+    #[error("emergency committee in safe mode can only route funds to the covenant parties")]
+    SafeModeViolation {},
+
+    #[error("emergency action {id} not found")]
+    ActionNotFound { id: u64 },
+
+    #[error("emergency action already approved by this member")]
+    AlreadyApproved {},
+
+    #[error("emergency action has {approvals} out of {threshold} required approvals")]
+    ThresholdNotReached { approvals: u64, threshold: u64 },
+
+    #[error("emergency action timelock is not over, executable after: {0}")]
+    TimelockNotExpired(Expiration),
+}
+
+#[cw_serde]
+pub struct EmergencyCommitteeConfig {
+    /// addresses of the committee members
+    pub members: Vec<String>,
+    /// number of member approvals needed to execute an action
+    pub threshold: u64,
+    /// delay between proposing an action and being able to execute it
+    pub timelock: Duration,
+    /// if enabled, the committee can only route the funds
+    /// back to the covenant parties
+    pub safe_mode: bool,
+}
+
+#[cw_serde]
+pub struct EmergencyCommittee {
+    pub members: Vec<Addr>,
+    pub threshold: u64,
+    pub timelock: Duration,
+    pub safe_mode: bool,
+}
+
+#[cw_serde]
+pub enum EmergencyAction {
+    /// withdraw the liquidity and route it to the covenant parties
+    /// the same way a regular withdrawal would
+    WithdrawToParties {},
+    /// withdraw the liquidity and send all of it to the receiver.
+    /// not available in safe mode.
+    WithdrawTo { receiver: String },
+}
+
+#[cw_serde]
+pub struct PendingEmergencyAction {
+    pub id: u64,
+    pub action: EmergencyAction,
+    pub proposer: Addr,
+    pub approvals: Vec<Addr>,
+    pub executable_after: Expiration,
+}
+
+impl EmergencyCommitteeConfig {
+    pub fn validate(&self, api: &dyn Api) -> StdResult<EmergencyCommittee> {
+        ensure!(
+            !self.members.is_empty(),
+            StdError::generic_err("emergency committee members cannot be empty")
+        );
+
+        let members = self
+            .members
+            .iter()
+            .map(|member| api.addr_validate(member))
+            .collect::<StdResult<Vec<Addr>>>()?;
+
+        let unique_members: HashSet<&Addr> = members.iter().collect();
+        ensure!(
+            unique_members.len() == members.len(),
+            StdError::generic_err("emergency committee members must be unique")
+        );
+
+        ensure!(
+            self.threshold > 0 && self.threshold <= members.len() as u64,
+            StdError::generic_err(
+                "emergency committee threshold must be in range of [1, members count]"
+            )
+        );
+
+        Ok(EmergencyCommittee {
+            members,
+            threshold: self.threshold,
+            timelock: self.timelock,
+            safe_mode: self.safe_mode,
+        })
+    }
+}
+
+impl EmergencyCommittee {
+    pub fn is_member(&self, addr: &Addr) -> bool {
+        self.members.contains(addr)
+    }
+
+    pub fn get_response_attributes(&self) -> Vec<(String, String)> {
+        vec![
+            (
+                "emergency_committee_members".to_string(),
+                self.members
+                    .iter()
+                    .map(|member| member.to_string())
+                    .collect::<Vec<String>>()
+                    .join(","),
+            ),
+            (
+                "emergency_committee_threshold".to_string(),
+                self.threshold.to_string(),
+            ),
+            (
+                "emergency_committee_timelock".to_string(),
+                self.timelock.to_string(),
+            ),
+            (
+                "emergency_committee_safe_mode".to_string(),
+                self.safe_mode.to_string(),
+            ),
+        ]
+    }
+}
+
+/// stores the emergency committee and discards any actions that were
+/// pending under the previous configuration
+pub fn save_emergency_committee(
+    storage: &mut dyn Storage,
+    committee: &EmergencyCommittee,
+) -> StdResult<()> {
+    PENDING_EMERGENCY_ACTIONS.clear(storage);
+    EMERGENCY_COMMITTEE.save(storage, committee)
+}
+
+/// moves the single emergency committee address stored by the previous
+/// contract versions into a committee of that sole member. the migrated
+/// committee acts right away and, as before, can only route the funds
+/// to the covenant parties. returns the migrated committee, if any.
+pub fn migrate_legacy_emergency_committee(
+    storage: &mut dyn Storage,
+) -> StdResult<Option<EmergencyCommittee>> {
+    let legacy_addr = match LEGACY_EMERGENCY_COMMITTEE_ADDR.may_load(storage)? {
+        Some(addr) => addr,
+        None => return Ok(None),
+    };
+    LEGACY_EMERGENCY_COMMITTEE_ADDR.remove(storage);
+
+    // a committee configured by this version takes precedence
+    if EMERGENCY_COMMITTEE.may_load(storage)?.is_some() {
+        return Ok(None);
+    }
+
+    let committee = EmergencyCommittee {
+        members: vec![legacy_addr],
+        threshold: 1,
+        timelock: Duration::Time(0),
+        safe_mode: true,
+    };
+    save_emergency_committee(storage, &committee)?;
+
+    Ok(Some(committee))
+}
+
+fn load_committee_and_authorize(
+    storage: &dyn Storage,
+    sender: &Addr,
+) -> Result<EmergencyCommittee, EmergencyCommitteeError> {
+    let committee = EMERGENCY_COMMITTEE
+        .may_load(storage)?
+        .ok_or(EmergencyCommitteeError::NotConfigured {})?;
+    ensure!(
+        committee.is_member(sender),
+        EmergencyCommitteeError::Unauthorized {}
+    );
+    Ok(committee)
+}
+
+/// registers a new emergency action. proposing counts as the
+/// proposer's approval.
+pub fn propose_emergency_action(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    block: &BlockInfo,
+    sender: Addr,
+    action: EmergencyAction,
+) -> Result<PendingEmergencyAction, EmergencyCommitteeError> {
+    let committee = load_committee_and_authorize(storage, &sender)?;
+
+    if let EmergencyAction::WithdrawTo { receiver } = &action {
+        ensure!(
+            !committee.safe_mode,
+            EmergencyCommitteeError::SafeModeViolation {}
+        );
+        api.addr_validate(receiver)?;
+    }
+
+    let id = NEXT_EMERGENCY_ACTION_ID
+        .may_load(storage)?
+        .unwrap_or_default();
+    NEXT_EMERGENCY_ACTION_ID.save(storage, &(id + 1))?;
+
+    let pending_action = PendingEmergencyAction {
+        id,
+        action,
+        proposer: sender.clone(),
+        approvals: vec![sender],
+        executable_after: committee.timelock.after(block),
+    };
+    PENDING_EMERGENCY_ACTIONS.save(storage, id, &pending_action)?;
+
+    Ok(pending_action)
+}
+
+pub fn approve_emergency_action(
+    storage: &mut dyn Storage,
+    sender: Addr,
+    id: u64,
+) -> Result<PendingEmergencyAction, EmergencyCommitteeError> {
+    load_committee_and_authorize(storage, &sender)?;
+
+    let mut pending_action = PENDING_EMERGENCY_ACTIONS
+        .may_load(storage, id)?
+        .ok_or(EmergencyCommitteeError::ActionNotFound { id })?;
+    ensure!(
+        !pending_action.approvals.contains(&sender),
+        EmergencyCommitteeError::AlreadyApproved {}
+    );
+
+    pending_action.approvals.push(sender);
+    PENDING_EMERGENCY_ACTIONS.save(storage, id, &pending_action)?;
+
+    Ok(pending_action)
+}
+
+/// validates that the action is approved by enough members and that its
+/// timelock is over. on success, the action is removed from the pending
+/// actions and returned for the caller to perform.
+pub fn take_executable_emergency_action(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    sender: Addr,
+    id: u64,
+) -> Result<EmergencyAction, EmergencyCommitteeError> {
+    let committee = load_committee_and_authorize(storage, &sender)?;
+
+    let pending_action = PENDING_EMERGENCY_ACTIONS
+        .may_load(storage, id)?
+        .ok_or(EmergencyCommitteeError::ActionNotFound { id })?;
+
+    let approvals = pending_action.approvals.len() as u64;
+    ensure!(
+        approvals >= committee.threshold,
+        EmergencyCommitteeError::ThresholdNotReached {
+            approvals,
+            threshold: committee.threshold,
+        }
+    );
+    ensure!(
+        pending_action.executable_after.is_expired(block),
+        EmergencyCommitteeError::TimelockNotExpired(pending_action.executable_after)
+    );
+
+    PENDING_EMERGENCY_ACTIONS.remove(storage, id);
+
+    Ok(pending_action.action)
+}
+
+pub fn query_pending_emergency_actions(
+    storage: &dyn Storage,
+) -> StdResult<Vec<PendingEmergencyAction>> {
+    PENDING_EMERGENCY_ACTIONS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, action)| action))
+        .collect()
+}
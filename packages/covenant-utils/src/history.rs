@@ -0,0 +1,84 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{BlockInfo, Coin, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Item, Map};
+
+/// append-only log of the actions performed by a holder
+pub const HISTORY: Map<u64, HistoryEntry> = Map::new("history");
+/// id to be assigned to the next history entry
+pub const NEXT_HISTORY_ENTRY_ID: Item<u64> = Item::new("history_next_id");
+
+const DEFAULT_HISTORY_LIMIT: u32 = 10;
+const MAX_HISTORY_LIMIT: u32 = 30;
+
+#[cw_serde]
+pub enum HistoryAction {
+    /// party funds were accepted and forwarded to the next contract
+    Deposit,
+    /// party funds were returned to the party
+    Refund,
+    /// party initiated a claim
+    Claim,
+    /// party initiated a ragequit
+    Ragequit,
+    /// party withdrew before the position was unlocked
+    EarlyWithdrawal,
+    /// withdrawn funds were distributed
+    Distribution,
+    /// emergency committee withdrew the funds
+    EmergencyWithdrawal,
+}
+
+#[cw_serde]
+pub struct HistoryEntry {
+    pub id: u64,
+    pub height: u64,
+    pub time: Timestamp,
+    pub action: HistoryAction,
+    /// party associated with the action, if any
+    pub party: Option<String>,
+    /// coins moved by the action
+    pub coins: Vec<Coin>,
+}
+
+/// appends a new entry to the history log
+pub fn record_history(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    action: HistoryAction,
+    party: Option<String>,
+    coins: Vec<Coin>,
+) -> StdResult<HistoryEntry> {
+    let id = NEXT_HISTORY_ENTRY_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_HISTORY_ENTRY_ID.save(storage, &(id + 1))?;
+
+    let entry = HistoryEntry {
+        id,
+        height: block.height,
+        time: block.time,
+        action,
+        party,
+        coins,
+    };
+    HISTORY.save(storage, id, &entry)?;
+
+    Ok(entry)
+}
+
+/// returns the history entries in the order they were recorded,
+/// starting after the `start_after` entry id
+pub fn query_history(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<HistoryEntry>> {
+    let limit = limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .min(MAX_HISTORY_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    HISTORY
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, entry)| entry))
+        .collect()
+}
@@ -2,26 +2,29 @@ use std::collections::BTreeMap;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_string, Addr, Api, Attribute, Coin, CosmosMsg, Decimal, StdError, StdResult, Timestamp,
-    Uint128, Uint64,
+    to_json_string, Addr, Api, Attribute, BankMsg, Coin, CosmosMsg, Decimal, Env, Fraction,
+    QuerierWrapper, StdError, StdResult, Timestamp, Uint128, Uint64,
 };
-use neutron::flatten_ibc_fee_total_amount;
+use neutron::{flatten_ibc_fee_total_amount, query_ibc_fee};
 use neutron_sdk::{
-    bindings::msg::{IbcFee, NeutronMsg},
+    bindings::{
+        msg::{IbcFee, NeutronMsg},
+        query::NeutronQuery,
+    },
     sudo::msg::RequestPacketTimeoutHeight,
 };
 
 pub mod astroport;
 pub mod clock;
 pub mod deadline;
+pub mod emergency_committee;
+pub mod history;
 pub mod ica;
 pub mod instantiate2_helper;
-pub mod liquid_pooler_withdraw;
 pub mod neutron;
 pub mod op_mode;
 pub mod polytone;
 pub mod split;
-pub mod withdraw_lp_helper;
 
 #[cw_serde]
 pub struct InterchainCovenantParty {
@@ -85,21 +88,9 @@ impl ReceiverConfig {
                 .collect(),
         }
     }
-}
-
-#[cw_serde]
-pub struct CovenantParty {
-    /// authorized address of the party
-    pub addr: String,
-    /// denom provided by the party
-    pub native_denom: String,
-    /// information about receiver address
-    pub receiver_config: ReceiverConfig,
-}
 
-impl CovenantParty {
     pub fn validate_receiver_address(&self, api: &dyn Api) -> StdResult<Addr> {
-        match &self.receiver_config {
+        match self {
             ReceiverConfig::Native(addr) => api.addr_validate(addr),
             ReceiverConfig::Ibc(destination_config) => {
                 match soft_validate_remote_chain_addr(
@@ -114,6 +105,48 @@ impl CovenantParty {
             }
         }
     }
+
+    /// returns the messages delivering the coins to the receiver.
+    /// native receivers get a bank send, while ibc receivers get
+    /// the coins transferred (and unwound via pfm, if configured)
+    /// with the ibc fees paid out of the sender balance.
+    pub fn get_transfer_messages(
+        &self,
+        querier: QuerierWrapper<NeutronQuery>,
+        env: &Env,
+        coins: Vec<Coin>,
+    ) -> StdResult<Vec<CosmosMsg<NeutronMsg>>> {
+        match self {
+            ReceiverConfig::Native(addr) => Ok(vec![BankMsg::Send {
+                to_address: addr.to_string(),
+                amount: coins,
+            }
+            .into()]),
+            ReceiverConfig::Ibc(destination_config) => destination_config
+                .get_ibc_transfer_messages_for_coins(
+                    coins,
+                    env.block.time,
+                    env.contract.address.to_string(),
+                    query_ibc_fee(querier)?.ibc_fee,
+                ),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct CovenantParty {
+    /// authorized address of the party
+    pub addr: String,
+    /// denom provided by the party
+    pub native_denom: String,
+    /// information about receiver address
+    pub receiver_config: ReceiverConfig,
+}
+
+impl CovenantParty {
+    pub fn validate_receiver_address(&self, api: &dyn Api) -> StdResult<Addr> {
+        self.receiver_config.validate_receiver_address(api)
+    }
 }
 
 #[cw_serde]
@@ -165,6 +198,12 @@ impl CovenantPartiesConfig {
 #[cw_serde]
 pub enum CovenantTerms {
     TokenSwap(SwapCovenantTerms),
+    /// token swap that settles the matched amounts pro rata if one
+    /// of the parties falls short by the lockup expiration
+    PartialFillTokenSwap(PartialFillSwapCovenantTerms),
+    /// token swap where the amount owed by party b is priced
+    /// at settlement from an astroport pool
+    PriceReferencedTokenSwap(PriceReferencedSwapCovenantTerms),
 }
 
 #[cw_serde]
@@ -173,13 +212,122 @@ pub struct SwapCovenantTerms {
     pub party_b_amount: Uint128,
 }
 
+#[cw_serde]
+pub struct PartialFillSwapCovenantTerms {
+    pub party_a_amount: Uint128,
+    pub party_b_amount: Uint128,
+    /// minimum fraction of the terms that needs to be matched
+    /// in order to settle the swap. must be in range (0.0, 1.0]
+    pub min_fill_ratio: Decimal,
+}
+
+impl PartialFillSwapCovenantTerms {
+    /// returns the fraction of the terms that can be matched
+    /// with the given party balances
+    pub fn get_fill_ratio(&self, party_a_balance: Uint128, party_b_balance: Uint128) -> Decimal {
+        let party_a_ratio = Decimal::from_ratio(
+            party_a_balance.min(self.party_a_amount),
+            self.party_a_amount,
+        );
+        let party_b_ratio = Decimal::from_ratio(
+            party_b_balance.min(self.party_b_amount),
+            self.party_b_amount,
+        );
+        party_a_ratio.min(party_b_ratio)
+    }
+
+    /// returns the party amounts matched at the given fill ratio
+    pub fn get_matched_amounts(&self, fill_ratio: Decimal) -> StdResult<(Uint128, Uint128)> {
+        let party_a_matched = self
+            .party_a_amount
+            .checked_multiply_ratio(fill_ratio.numerator(), fill_ratio.denominator())
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+        let party_b_matched = self
+            .party_b_amount
+            .checked_multiply_ratio(fill_ratio.numerator(), fill_ratio.denominator())
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+        Ok((party_a_matched, party_b_matched))
+    }
+}
+
 #[cw_serde]
 pub struct PolCovenantTerms {
     pub party_a_amount: Uint128,
     pub party_b_amount: Uint128,
 }
 
+#[cw_serde]
+pub enum PriceSource {
+    /// price derived from the current pool reserves
+    Spot,
+    /// time weighted average price since the covenant was instantiated
+    Twap,
+}
+
+#[cw_serde]
+pub struct PriceReferencedSwapCovenantTerms {
+    pub party_a_amount: Uint128,
+    /// astroport pool used to price party a denom in party b denom
+    pub pool_address: String,
+    pub price_source: PriceSource,
+    /// premium applied on top of the referenced price
+    pub spread: Decimal,
+    /// lower bound of the amount owed by party b
+    pub min_party_b_amount: Uint128,
+    /// upper bound of the amount owed by party b
+    pub max_party_b_amount: Uint128,
+}
+
+impl PriceReferencedSwapCovenantTerms {
+    /// returns the amount owed by party b at the given price of party a denom,
+    /// clamped to the configured bounds
+    pub fn get_party_b_amount(&self, price: Decimal) -> StdResult<Uint128> {
+        let price_with_spread = price.checked_mul(Decimal::one().checked_add(self.spread)?)?;
+        let party_b_amount = self
+            .party_a_amount
+            .checked_multiply_ratio(
+                price_with_spread.numerator(),
+                price_with_spread.denominator(),
+            )
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+        Ok(party_b_amount.clamp(self.min_party_b_amount, self.max_party_b_amount))
+    }
+}
+
 impl CovenantTerms {
+    pub fn validate(&self) -> StdResult<()> {
+        match self {
+            CovenantTerms::TokenSwap(_) => Ok(()),
+            CovenantTerms::PartialFillTokenSwap(terms) => {
+                if terms.party_a_amount.is_zero() || terms.party_b_amount.is_zero() {
+                    return Err(StdError::generic_err(
+                        "swap amounts must be non-zero".to_string(),
+                    ));
+                }
+                if terms.min_fill_ratio.is_zero() || terms.min_fill_ratio > Decimal::one() {
+                    return Err(StdError::generic_err(
+                        "min fill ratio must be in range (0.0, 1.0]".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+            CovenantTerms::PriceReferencedTokenSwap(terms) => {
+                if terms.party_a_amount.is_zero() || terms.min_party_b_amount.is_zero() {
+                    return Err(StdError::generic_err(
+                        "swap amounts must be non-zero".to_string(),
+                    ));
+                }
+                if terms.min_party_b_amount > terms.max_party_b_amount {
+                    return Err(StdError::generic_err(
+                        "min party b amount must not exceed the max party b amount".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
     pub fn get_response_attributes(self) -> Vec<Attribute> {
         match self {
             CovenantTerms::TokenSwap(terms) => {
@@ -190,6 +338,21 @@ impl CovenantTerms {
                 ];
                 attrs
             }
+            CovenantTerms::PartialFillTokenSwap(terms) => vec![
+                Attribute::new("covenant_terms", "partial_fill_token_swap"),
+                Attribute::new("party_a_amount", terms.party_a_amount),
+                Attribute::new("party_b_amount", terms.party_b_amount),
+                Attribute::new("min_fill_ratio", terms.min_fill_ratio.to_string()),
+            ],
+            CovenantTerms::PriceReferencedTokenSwap(terms) => vec![
+                Attribute::new("covenant_terms", "price_referenced_token_swap"),
+                Attribute::new("party_a_amount", terms.party_a_amount),
+                Attribute::new("pool_address", terms.pool_address),
+                Attribute::new("price_source", format!("{:?}", terms.price_source)),
+                Attribute::new("spread", terms.spread.to_string()),
+                Attribute::new("min_party_b_amount", terms.min_party_b_amount),
+                Attribute::new("max_party_b_amount", terms.max_party_b_amount),
+            ],
         }
     }
 }
@@ -361,6 +524,16 @@ pub struct PoolPriceConfig {
     pub acceptable_price_spread: Decimal,
 }
 
+/// guard against exiting a manipulated pool upon liquidity withdrawal.
+/// asset a and b refer to the same assets as in `SingleSideLpLimits`.
+#[cw_serde]
+pub enum WithdrawPriceGuard {
+    /// pool spot price (a/b) must be within the configured range
+    PriceRange(PoolPriceConfig),
+    /// minimum amounts of both assets to receive per withdrawn lp share
+    MinAssetsPerShare { asset_a: Decimal, asset_b: Decimal },
+}
+
 /// soft validation for addresses on remote chains.
 /// skips the bech32 prefix and variant checks.
 pub fn soft_validate_remote_chain_addr(api: &dyn Api, addr: &str) -> StdResult<()> {
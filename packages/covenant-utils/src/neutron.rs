@@ -1,10 +1,14 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
-    Attribute, Binary, MessageInfo, QuerierWrapper, StdError, StdResult, Uint128, Uint64,
+    Attribute, Binary, CosmosMsg, MessageInfo, QuerierWrapper, StdError, StdResult, Uint128, Uint64,
 };
 use cw_utils::must_pay;
 use neutron_sdk::{
-    bindings::{msg::IbcFee, query::NeutronQuery, types::ProtobufAny},
+    bindings::{
+        msg::{IbcFee, NeutronMsg},
+        query::NeutronQuery,
+        types::ProtobufAny,
+    },
     query::min_ibc_fee::MinIbcFeeResponse,
     NeutronResult,
 };
@@ -137,6 +141,20 @@ pub struct MinIbcFeeConfig {
     pub total_ntrn_fee: Uint128,
 }
 
+/// converts a message without a custom payload into a message
+/// that can be returned from a neutron contract response
+pub fn to_neutron_msg(msg: CosmosMsg) -> StdResult<CosmosMsg<NeutronMsg>> {
+    match msg {
+        CosmosMsg::Bank(msg) => Ok(CosmosMsg::Bank(msg)),
+        CosmosMsg::Wasm(msg) => Ok(CosmosMsg::Wasm(msg)),
+        CosmosMsg::Ibc(msg) => Ok(CosmosMsg::Ibc(msg)),
+        CosmosMsg::Stargate { type_url, value } => Ok(CosmosMsg::Stargate { type_url, value }),
+        msg => Err(StdError::generic_err(format!(
+            "unsupported message type: {msg:?}"
+        ))),
+    }
+}
+
 pub fn query_ibc_fee(querier: QuerierWrapper<'_, NeutronQuery>) -> StdResult<MinIbcFeeConfig> {
     let min_fee_query_response: MinIbcFeeResponse =
         querier.query(&NeutronQuery::MinIbcFee {}.into())?;
@@ -0,0 +1,248 @@
+//! Venue agnostic interface of the covenant liquid poolers.
+//!
+//! Liquid poolers expose (at least) the execute and query messages
+//! defined here, regardless of the venue they provide liquidity to.
+//! Holders talk to the poolers through these messages, and covenant
+//! factories instantiate any pooler implementing `LiquidPoolerInstantiate`.
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Coin, CustomQuery, Decimal, QuerierWrapper, StdError, StdResult,
+    Uint128, WasmMsg,
+};
+use covenant_utils::{
+    instantiate2_helper::Instantiate2HelperConfig, op_mode::ContractOperationModeConfig,
+    PoolPriceConfig,
+};
+use serde_json::{Map, Value};
+
+/// execute messages every liquid pooler accepts
+#[cw_serde]
+pub enum LiquidPoolerExecuteMsg {
+    /// Wakes the pooler up to provide the available liquidity
+    Tick {},
+    /// Tells the LPer to withdraw the given share of its position
+    /// (all of it if not set) and send the withdrawn funds to the holder.
+    /// Should only be called by the holder of the covenant
+    Withdraw { percentage: Option<Decimal> },
+}
+
+/// queries every liquid pooler responds to
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum LiquidPoolerQueryMsg {
+    /// Returns the address the pooler expects to receive funds to
+    #[returns(Option<String>)]
+    DepositAddress {},
+    /// Returns the amounts of both assets provided to the pool
+    #[returns(ProvidedLiquidityInfo)]
+    ProvidedLiquidityInfo {},
+    /// Returns the lp tokens currently held by the pooler
+    #[returns(LiquidityPosition)]
+    LiquidityPosition {},
+}
+
+/// messages the liquid pooler sends back to the holder
+/// once a withdrawal is handled
+#[cw_serde]
+pub enum LiquidPoolerHolderMsg {
+    /// Carries the withdrawn funds to the holder to be distributed
+    Distribute {},
+    /// Notifies the holder that an async withdrawal failed
+    WithdrawFailed {},
+}
+
+/// keeps track of provided asset liquidities in `Uint128`.
+#[cw_serde]
+pub struct ProvidedLiquidityInfo {
+    pub provided_coin_a: Coin,
+    pub provided_coin_b: Coin,
+}
+
+/// lp position held by the liquid pooler
+#[cw_serde]
+pub struct LiquidityPosition {
+    /// denom or contract address of the lp token
+    pub lp_token: String,
+    pub amount: Uint128,
+}
+
+/// wraps the address of a liquid pooler to build its messages and queries
+#[cw_serde]
+pub struct LiquidPoolerContract(pub Addr);
+
+impl LiquidPoolerContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn withdraw_msg(&self, percentage: Option<Decimal>) -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_json_binary(&LiquidPoolerExecuteMsg::Withdraw { percentage })?,
+            funds: vec![],
+        })
+    }
+
+    pub fn query_deposit_address<C: CustomQuery>(
+        &self,
+        querier: QuerierWrapper<C>,
+    ) -> StdResult<Option<String>> {
+        querier.query_wasm_smart(&self.0, &LiquidPoolerQueryMsg::DepositAddress {})
+    }
+
+    pub fn query_provided_liquidity_info<C: CustomQuery>(
+        &self,
+        querier: QuerierWrapper<C>,
+    ) -> StdResult<ProvidedLiquidityInfo> {
+        querier.query_wasm_smart(&self.0, &LiquidPoolerQueryMsg::ProvidedLiquidityInfo {})
+    }
+
+    pub fn query_liquidity_position<C: CustomQuery>(
+        &self,
+        querier: QuerierWrapper<C>,
+    ) -> StdResult<LiquidityPosition> {
+        querier.query_wasm_smart(&self.0, &LiquidPoolerQueryMsg::LiquidityPosition {})
+    }
+}
+
+/// instantiate msg fields filled in by the covenant factory
+/// that every liquid pooler is expected to accept
+#[cw_serde]
+pub struct LiquidPoolerInstantiateFields {
+    pub holder_address: String,
+    pub op_mode_cfg: ContractOperationModeConfig,
+    pub pool_price_config: PoolPriceConfig,
+}
+
+/// implemented by the liquid pooler configs that covenant factories
+/// accept, so that any pooler can be instantiated the same way
+pub trait LiquidPoolerInstantiate {
+    fn to_instantiate_binary(&self, fields: LiquidPoolerInstantiateFields) -> StdResult<Binary>;
+
+    fn to_instantiate2_msg(
+        &self,
+        instantiate2_helper: &Instantiate2HelperConfig,
+        admin: String,
+        label: String,
+        fields: LiquidPoolerInstantiateFields,
+    ) -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Instantiate2 {
+            admin: Some(admin),
+            code_id: instantiate2_helper.code,
+            label,
+            msg: self.to_instantiate_binary(fields)?,
+            funds: vec![],
+            salt: instantiate2_helper.salt.clone(),
+        })
+    }
+}
+
+/// config of any other pooler implementing the interface.
+/// `instantiate_msg` is the json object of the venue specific fields,
+/// which gets merged with the fields filled in by the covenant factory.
+#[cw_serde]
+pub struct CustomLiquidPoolerConfig {
+    pub instantiate_msg: Binary,
+}
+
+impl LiquidPoolerInstantiate for CustomLiquidPoolerConfig {
+    fn to_instantiate_binary(&self, fields: LiquidPoolerInstantiateFields) -> StdResult<Binary> {
+        let venue_fields: Map<String, Value> =
+            serde_json::from_slice(self.instantiate_msg.as_slice()).map_err(|_| {
+                StdError::generic_err("custom liquid pooler instantiate msg must be a json object")
+            })?;
+
+        let mut merged = match serde_json::to_value(&fields)
+            .map_err(|e| StdError::generic_err(e.to_string()))?
+        {
+            Value::Object(factory_fields) => factory_fields,
+            _ => {
+                return Err(StdError::generic_err(
+                    "factory fields must be a json object",
+                ))
+            }
+        };
+
+        // fields filled in by the covenant factory cannot be overridden
+        for (key, value) in venue_fields {
+            if merged.contains_key(&key) {
+                return Err(StdError::generic_err(format!(
+                    "custom liquid pooler instantiate msg cannot set factory field {key}"
+                )));
+            }
+            merged.insert(key, value);
+        }
+
+        serde_json::to_vec(&merged)
+            .map(Binary::from)
+            .map_err(|e| StdError::generic_err(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{from_json, to_json_binary, Binary, Decimal, Uint64};
+    use covenant_utils::{op_mode::ContractOperationModeConfig, PoolPriceConfig};
+
+    use super::{CustomLiquidPoolerConfig, LiquidPoolerInstantiate, LiquidPoolerInstantiateFields};
+
+    #[cw_serde]
+    struct VenueInstantiateMsg {
+        holder_address: String,
+        op_mode_cfg: ContractOperationModeConfig,
+        pool_price_config: PoolPriceConfig,
+        pool_id: Uint64,
+    }
+
+    #[cw_serde]
+    struct VenueFields {
+        pool_id: Uint64,
+    }
+
+    fn fields() -> LiquidPoolerInstantiateFields {
+        LiquidPoolerInstantiateFields {
+            holder_address: "holder".to_string(),
+            op_mode_cfg: ContractOperationModeConfig::Permissionless,
+            pool_price_config: PoolPriceConfig {
+                expected_spot_price: Decimal::one(),
+                acceptable_price_spread: Decimal::percent(10),
+            },
+        }
+    }
+
+    #[test]
+    fn test_custom_config_merges_instantiate_fields() {
+        let config = CustomLiquidPoolerConfig {
+            instantiate_msg: to_json_binary(&VenueFields {
+                pool_id: Uint64::new(1),
+            })
+            .unwrap(),
+        };
+
+        let msg: VenueInstantiateMsg =
+            from_json(config.to_instantiate_binary(fields()).unwrap()).unwrap();
+        assert_eq!(msg.holder_address, "holder");
+        assert_eq!(msg.pool_id, Uint64::new(1));
+    }
+
+    #[test]
+    fn test_custom_config_rejects_factory_field_collisions() {
+        let config = CustomLiquidPoolerConfig {
+            instantiate_msg: Binary::from(br#"{"holder_address":"attacker","pool_id":"1"}"#),
+        };
+        let err = config.to_instantiate_binary(fields()).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("cannot set factory field holder_address"));
+    }
+
+    #[test]
+    fn test_custom_config_rejects_non_object_msg() {
+        let config = CustomLiquidPoolerConfig {
+            instantiate_msg: to_json_binary(&"pool").unwrap(),
+        };
+        config.to_instantiate_binary(fields()).unwrap_err();
+    }
+}
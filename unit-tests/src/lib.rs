@@ -7,14 +7,26 @@ pub mod setup;
 #[cfg(test)]
 pub mod test_astroport_liquid_pooler;
 #[cfg(test)]
+pub mod test_duality_liquid_pooler;
+#[cfg(test)]
 pub mod test_ibc_forwarder;
 #[cfg(test)]
 pub mod test_interchain_router;
 #[cfg(test)]
+pub mod test_multi_party_covenant;
+#[cfg(test)]
+pub mod test_multi_party_pol_holder;
+#[cfg(test)]
+pub mod test_multi_party_swap_covenant;
+#[cfg(test)]
+pub mod test_multi_party_swap_holder;
+#[cfg(test)]
 pub mod test_native_router;
 #[cfg(test)]
 pub mod test_native_splitter;
 #[cfg(test)]
+pub mod test_osmo_liquid_pooler;
+#[cfg(test)]
 pub mod test_osmo_lp_outpost;
 #[cfg(test)]
 pub mod test_remote_chain_splitter;
@@ -30,3 +42,5 @@ pub mod test_swap_holder;
 pub mod test_two_party_covenant;
 #[cfg(test)]
 pub mod test_two_party_pol_holder;
+#[cfg(test)]
+pub mod test_vesting_escrow;
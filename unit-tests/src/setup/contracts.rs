@@ -235,6 +235,48 @@ pub fn native_router_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
     Box::new(contract)
 }
 
+pub fn vesting_escrow_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    let exec = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_vesting_escrow::msg::ExecuteMsg| {
+        execute_into_neutron(valence_vesting_escrow::contract::execute(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let init = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_vesting_escrow::msg::InstantiateMsg| {
+        execute_into_neutron(valence_vesting_escrow::contract::instantiate(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let query = |deps: Deps<NeutronQuery>, env: Env, msg: valence_vesting_escrow::msg::QueryMsg| {
+        valence_vesting_escrow::contract::query(get_empty_deps(deps), env, msg)
+    };
+
+    let migrate =
+        |deps: DepsMut<NeutronQuery>, env: Env, msg: valence_vesting_escrow::msg::MigrateMsg| {
+            execute_into_neutron(valence_vesting_escrow::contract::migrate(
+                get_empty_depsmut(deps),
+                env,
+                msg,
+            ))
+        };
+
+    let contract = ContractWrapper::new(exec, init, query).with_migrate(migrate);
+    Box::new(contract)
+}
+
 pub fn native_splitter_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
     let exec = |deps: DepsMut<NeutronQuery>,
                 env: Env,
@@ -320,18 +362,6 @@ pub fn single_party_covenant_contract() -> Box<dyn Contract<NeutronMsg, NeutronQ
 }
 
 pub fn single_party_holder_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
-    let exec = |deps: DepsMut<NeutronQuery>,
-                env: Env,
-                info: MessageInfo,
-                msg: valence_single_party_pol_holder::msg::ExecuteMsg| {
-        execute_into_neutron(valence_single_party_pol_holder::contract::execute(
-            get_empty_depsmut(deps),
-            env,
-            info,
-            msg,
-        ))
-    };
-
     let init = |deps: DepsMut<NeutronQuery>,
                 env: Env,
                 info: MessageInfo,
@@ -360,7 +390,12 @@ pub fn single_party_holder_contract() -> Box<dyn Contract<NeutronMsg, NeutronQue
         ))
     };
 
-    let contract = ContractWrapper::new(exec, init, query).with_migrate(migrate);
+    let contract = ContractWrapper::new(
+        valence_single_party_pol_holder::contract::execute,
+        init,
+        query,
+    )
+    .with_migrate(migrate);
     Box::new(contract)
 }
 
@@ -415,18 +450,6 @@ pub fn swap_covenant_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
 }
 
 pub fn swap_holder_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
-    let exec = |deps: DepsMut<NeutronQuery>,
-                env: Env,
-                info: MessageInfo,
-                msg: valence_swap_holder::msg::ExecuteMsg| {
-        execute_into_neutron(valence_swap_holder::contract::execute(
-            get_empty_depsmut(deps),
-            env,
-            info,
-            msg,
-        ))
-    };
-
     let init = |deps: DepsMut<NeutronQuery>,
                 env: Env,
                 info: MessageInfo,
@@ -452,7 +475,8 @@ pub fn swap_holder_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
             ))
         };
 
-    let contract = ContractWrapper::new(exec, init, query).with_migrate(migrate);
+    let contract = ContractWrapper::new(valence_swap_holder::contract::execute, init, query)
+        .with_migrate(migrate);
     Box::new(contract)
 }
 
@@ -550,6 +574,197 @@ pub fn two_party_holder_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>
     Box::new(contract)
 }
 
+pub fn multi_party_covenant_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    let exec = |_deps: DepsMut<NeutronQuery>,
+                _env: Env,
+                _info: MessageInfo,
+                _msg: Empty|
+     -> Result<Response<NeutronMsg>, StdError> {
+        Err(StdError::generic_err("Execute msg is not implemented"))
+    };
+
+    let init = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_covenant_multi_party_pol::msg::InstantiateMsg| {
+        execute_into_neutron(valence_covenant_multi_party_pol::contract::instantiate(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let query = |deps: Deps<NeutronQuery>,
+                 env: Env,
+                 msg: valence_covenant_multi_party_pol::msg::QueryMsg| {
+        valence_covenant_multi_party_pol::contract::query(get_empty_deps(deps), env, msg)
+    };
+
+    let migrate = |deps: DepsMut<NeutronQuery>,
+                   env: Env,
+                   msg: valence_covenant_multi_party_pol::msg::MigrateMsg| {
+        execute_into_neutron(valence_covenant_multi_party_pol::contract::migrate(
+            get_empty_depsmut(deps),
+            env,
+            msg,
+        ))
+    };
+
+    let contract = ContractWrapper::new(exec, init, query).with_migrate(migrate);
+    Box::new(contract)
+}
+
+pub fn multi_party_holder_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    let exec = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_multi_party_pol_holder::msg::ExecuteMsg| {
+        execute_into_neutron(valence_multi_party_pol_holder::contract::execute(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let init = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_multi_party_pol_holder::msg::InstantiateMsg| {
+        execute_into_neutron(valence_multi_party_pol_holder::contract::instantiate(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let query =
+        |deps: Deps<NeutronQuery>, env: Env, msg: valence_multi_party_pol_holder::msg::QueryMsg| {
+            valence_multi_party_pol_holder::contract::query(get_empty_deps(deps), env, msg)
+        };
+
+    let migrate = |deps: DepsMut<NeutronQuery>,
+                   env: Env,
+                   msg: valence_multi_party_pol_holder::msg::MigrateMsg| {
+        execute_into_neutron(valence_multi_party_pol_holder::contract::migrate(
+            get_empty_depsmut(deps),
+            env,
+            msg,
+        ))
+    };
+
+    let reply = |deps: DepsMut<NeutronQuery>, env: Env, reply: Reply| {
+        execute_into_neutron(valence_multi_party_pol_holder::contract::reply(
+            get_empty_depsmut(deps),
+            env,
+            reply,
+        ))
+    };
+
+    let contract = ContractWrapper::new(exec, init, query)
+        .with_migrate(migrate)
+        .with_reply(reply);
+    Box::new(contract)
+}
+
+pub fn multi_party_swap_covenant_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    let exec = |_deps: DepsMut<NeutronQuery>,
+                _env: Env,
+                _info: MessageInfo,
+                _msg: Empty|
+     -> Result<Response<NeutronMsg>, StdError> {
+        Err(StdError::generic_err("Execute msg is not implemented"))
+    };
+
+    let init = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_covenant_multi_party_swap::msg::InstantiateMsg| {
+        execute_into_neutron(valence_covenant_multi_party_swap::contract::instantiate(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let query = |deps: Deps<NeutronQuery>,
+                 env: Env,
+                 msg: valence_covenant_multi_party_swap::msg::QueryMsg| {
+        valence_covenant_multi_party_swap::contract::query(get_empty_deps(deps), env, msg)
+    };
+
+    let migrate = |deps: DepsMut<NeutronQuery>,
+                   env: Env,
+                   msg: valence_covenant_multi_party_swap::msg::MigrateMsg| {
+        execute_into_neutron(valence_covenant_multi_party_swap::contract::migrate(
+            get_empty_depsmut(deps),
+            env,
+            msg,
+        ))
+    };
+
+    let contract = ContractWrapper::new(exec, init, query).with_migrate(migrate);
+    Box::new(contract)
+}
+
+pub fn multi_party_swap_holder_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    let exec = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_multi_party_swap_holder::msg::ExecuteMsg| {
+        execute_into_neutron(valence_multi_party_swap_holder::contract::execute(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let init = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_multi_party_swap_holder::msg::InstantiateMsg| {
+        execute_into_neutron(valence_multi_party_swap_holder::contract::instantiate(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let query = |deps: Deps<NeutronQuery>,
+                 env: Env,
+                 msg: valence_multi_party_swap_holder::msg::QueryMsg| {
+        valence_multi_party_swap_holder::contract::query(get_empty_deps(deps), env, msg)
+    };
+
+    let migrate = |deps: DepsMut<NeutronQuery>,
+                   env: Env,
+                   msg: valence_multi_party_swap_holder::msg::MigrateMsg| {
+        execute_into_neutron(valence_multi_party_swap_holder::contract::migrate(
+            get_empty_depsmut(deps),
+            env,
+            msg,
+        ))
+    };
+
+    let reply = |deps: DepsMut<NeutronQuery>, env: Env, reply: Reply| {
+        execute_into_neutron(valence_multi_party_swap_holder::contract::reply(
+            get_empty_depsmut(deps),
+            env,
+            reply,
+        ))
+    };
+
+    let contract = ContractWrapper::new(exec, init, query)
+        .with_migrate(migrate)
+        .with_reply(reply);
+    Box::new(contract)
+}
+
 pub fn astroport_pooler_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
     let exec = |deps: DepsMut<NeutronQuery>,
                 env: Env,
@@ -604,3 +819,84 @@ pub fn astroport_pooler_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>
         .with_migrate(migrate);
     Box::new(contract)
 }
+
+pub fn osmo_pooler_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    let init = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_osmo_liquid_pooler::msg::InstantiateMsg| {
+        execute_into_neutron(valence_osmo_liquid_pooler::contract::instantiate(
+            deps, env, info, msg,
+        ))
+    };
+
+    let migrate = |deps: DepsMut<NeutronQuery>,
+                   env: Env,
+                   msg: valence_osmo_liquid_pooler::msg::MigrateMsg| {
+        execute_into_neutron(valence_osmo_liquid_pooler::contract::migrate(
+            deps, env, msg,
+        ))
+    };
+
+    let contract = ContractWrapper::new(
+        valence_osmo_liquid_pooler::contract::execute,
+        init,
+        valence_osmo_liquid_pooler::contract::query,
+    )
+    .with_migrate(migrate);
+    Box::new(contract)
+}
+
+pub fn duality_pooler_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    let exec = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_duality_liquid_pooler::msg::ExecuteMsg| {
+        execute_into_neutron(valence_duality_liquid_pooler::contract::execute(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let init = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: valence_duality_liquid_pooler::msg::InstantiateMsg| {
+        execute_into_neutron(valence_duality_liquid_pooler::contract::instantiate(
+            get_empty_depsmut(deps),
+            env,
+            info,
+            msg,
+        ))
+    };
+
+    let query =
+        |deps: Deps<NeutronQuery>, env: Env, msg: valence_duality_liquid_pooler::msg::QueryMsg| {
+            valence_duality_liquid_pooler::contract::query(get_empty_deps(deps), env, msg)
+        };
+
+    let reply = |deps: DepsMut<NeutronQuery>, env: Env, reply: Reply| {
+        execute_into_neutron(valence_duality_liquid_pooler::contract::reply(
+            get_empty_depsmut(deps),
+            env,
+            reply,
+        ))
+    };
+
+    let migrate = |deps: DepsMut<NeutronQuery>,
+                   env: Env,
+                   msg: valence_duality_liquid_pooler::msg::MigrateMsg| {
+        execute_into_neutron(valence_duality_liquid_pooler::contract::migrate(
+            get_empty_depsmut(deps),
+            env,
+            msg,
+        ))
+    };
+
+    let contract = ContractWrapper::new(exec, init, query)
+        .with_reply(reply)
+        .with_migrate(migrate);
+    Box::new(contract)
+}
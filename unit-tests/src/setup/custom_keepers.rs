@@ -1,25 +1,53 @@
+use cosmwasm_schema::cw_serde;
 use cosmwasm_schema::serde::de::DeserializeOwned;
 use cosmwasm_schema::serde::Serialize;
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Api, Binary, BlockInfo, CustomMsg, CustomQuery, Querier,
-    Storage,
+    coin, coins, from_json, to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, CustomMsg,
+    CustomQuery, Decimal256, Querier, Storage, Uint128, Uint256, Uint64,
 };
 use covenant_utils::ica::{Params, QueryParamsResponse};
 use cw_multi_test::error::{AnyError, AnyResult};
-use cw_multi_test::{AppResponse, CosmosRouter, Module, StargateQuery};
+use cw_multi_test::{
+    AppResponse, BankSudo, CosmosRouter, Module, StargateMsg, StargateQuery, SudoMsg,
+};
+use cw_storage_plus::{Item, Map};
 use osmosis_std::types::cosmos::base::v1beta1::Coin;
 use osmosis_std::types::osmosis::gamm::v1beta1::{
-    PoolAsset, QueryCalcExitPoolCoinsFromSharesResponse, QueryCalcJoinPoolNoSwapSharesResponse,
-    QueryCalcJoinPoolSharesResponse, QueryPoolResponse,
+    PoolAsset, QueryCalcExitPoolCoinsFromSharesRequest, QueryCalcExitPoolCoinsFromSharesResponse,
+    QueryCalcJoinPoolNoSwapSharesResponse, QueryCalcJoinPoolSharesResponse, QueryPoolRequest,
+    QueryPoolResponse,
 };
 use prost::Message;
+use valence_duality_liquid_pooler::dex::{
+    self, get_pair_id, MsgDeposit, MsgWithdrawal, MSG_DEPOSIT_TYPE_URL, MSG_WITHDRAWAL_TYPE_URL,
+    POOL_SHARES_DENOM_PREFIX,
+};
+use valence_outpost_osmo_liquid_pooler::concentrated_liquidity::{
+    ConcentratedLiquidityPool, EstimateSwapExactAmountInRequest, EstimateSwapExactAmountInResponse,
+    FullPositionBreakdown, MsgAddToPosition, MsgCreatePosition, MsgPositionResponse,
+    MsgWithdrawPosition, Position, QueryPoolRequest as PoolManagerQueryPoolRequest,
+    QueryPoolResponse as PoolManagerQueryPoolResponse, QueryPositionByIdRequest,
+    QueryPositionByIdResponse, CONCENTRATED_LIQUIDITY_POOL_TYPE_URL, MSG_ADD_TO_POSITION_TYPE_URL,
+    MSG_CREATE_POSITION_TYPE_URL, MSG_WITHDRAW_POSITION_TYPE_URL,
+    QUERY_ESTIMATE_SWAP_EXACT_AMOUNT_IN_PATH, QUERY_POOL_PATH as POOL_MANAGER_QUERY_POOL_PATH,
+    QUERY_POSITION_BY_ID_PATH,
+};
+use valence_outpost_osmo_liquid_pooler::stableswap::{StableswapPool, STABLESWAP_POOL_TYPE_URL};
 
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::str::FromStr;
 
 use crate::setup::DENOM_LS_ATOM_ON_NTRN;
 
-use super::{DENOM_ATOM, DENOM_FALLBACK};
+use super::{DENOM_ATOM, DENOM_FALLBACK, DENOM_OSMO};
+
+/// gamm pool ids served by the stand-in pools query.
+/// any other id returns a 50:50 balancer pool
+pub const WEIGHTED_POOL_ID: u64 = 3;
+pub const STABLESWAP_POOL_ID: u64 = 4;
+/// reserve of each asset of the 50:50 stand-in pools
+pub const GAMM_POOL_ASSET_RESERVE: u128 = 100;
 
 pub struct CustomStargateKeeper<ExecT, QueryT, SudoT>(
     PhantomData<(ExecT, QueryT, SudoT)>,
@@ -46,18 +74,61 @@ where
 
     fn execute<ExecC, QueryC>(
         &self,
-        _api: &dyn Api,
-        _storage: &mut dyn Storage,
-        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
-        _block: &BlockInfo,
-        _sender: Addr,
-        _msg: Self::ExecT,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
     ) -> AnyResult<AppResponse>
     where
         ExecC: CustomMsg + DeserializeOwned + 'static,
         QueryC: CustomQuery + DeserializeOwned + 'static,
     {
-        Ok(AppResponse::default())
+        let msg: StargateMsg = from_json(to_json_binary(&msg)?)?;
+        match msg.type_url.as_str() {
+            MSG_DEPOSIT_TYPE_URL => duality_deposit(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                MsgDeposit::decode(msg.value.as_slice())?,
+            ),
+            MSG_WITHDRAWAL_TYPE_URL => duality_withdraw(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                MsgWithdrawal::decode(msg.value.as_slice())?,
+            ),
+            MSG_CREATE_POSITION_TYPE_URL => create_cl_position(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                MsgCreatePosition::decode(msg.value.as_slice())?,
+            ),
+            MSG_ADD_TO_POSITION_TYPE_URL => add_to_cl_position(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                MsgAddToPosition::decode(msg.value.as_slice())?,
+            ),
+            MSG_WITHDRAW_POSITION_TYPE_URL => withdraw_cl_position(
+                api,
+                storage,
+                router,
+                block,
+                sender,
+                MsgWithdrawPosition::decode(msg.value.as_slice())?,
+            ),
+            _ => Ok(AppResponse::default()),
+        }
     }
 
     fn sudo<ExecC, QueryC>(
@@ -78,7 +149,7 @@ where
     fn query(
         &self,
         _api: &dyn Api,
-        _storage: &dyn Storage,
+        storage: &dyn Storage,
         _querier: &dyn Querier,
         _block: &BlockInfo,
         request: QueryT,
@@ -100,9 +171,50 @@ where
         }
 
         if query.path == "/osmosis.gamm.v1beta1.Query/Pool" {
+            let request = QueryPoolRequest::decode(query.data.as_slice())?;
+            if request.pool_id == STABLESWAP_POOL_ID {
+                let pool = StableswapPool {
+                    address: "address".to_string(),
+                    id: STABLESWAP_POOL_ID,
+                    total_shares: Some(Coin {
+                        amount: "101010".to_string(),
+                        denom: DENOM_FALLBACK.to_string(),
+                    }),
+                    pool_liquidity: vec![
+                        Coin {
+                            amount: "100".to_string(),
+                            denom: DENOM_ATOM.to_string(),
+                        },
+                        Coin {
+                            amount: "300000".to_string(),
+                            denom: DENOM_LS_ATOM_ON_NTRN.to_string(),
+                        },
+                    ],
+                    scaling_factors: vec![1, 1000],
+                };
+
+                let response = QueryPoolResponse {
+                    pool: Some(osmosis_std::shim::Any {
+                        type_url: STABLESWAP_POOL_TYPE_URL.to_string(),
+                        value: pool.encode_to_vec(),
+                    }),
+                };
+
+                return Ok(to_json_binary(&response).unwrap());
+            }
+
+            // weighted pool reserves are scaled to keep the pool priced at 1
+            let ((reserve_1, weight_1), (reserve_2, weight_2)) = match request.pool_id {
+                WEIGHTED_POOL_ID => ((400, "80"), (100, "20")),
+                _ => (
+                    (GAMM_POOL_ASSET_RESERVE, "50"),
+                    (GAMM_POOL_ASSET_RESERVE, "50"),
+                ),
+            };
+
             let pool = osmosis_std::types::osmosis::gamm::v1beta1::Pool {
                 address: "address".to_string(),
-                id: 1,
+                id: request.pool_id,
                 pool_params: None,
                 future_pool_governor: "governor".to_string(),
                 total_shares: Some(Coin {
@@ -112,17 +224,17 @@ where
                 pool_assets: vec![
                     PoolAsset {
                         token: Some(Coin {
-                            amount: "100".to_string(),
+                            amount: reserve_1.to_string(),
                             denom: DENOM_ATOM.to_string(),
                         }),
-                        weight: "50".to_string(),
+                        weight: weight_1.to_string(),
                     },
                     PoolAsset {
                         token: Some(Coin {
-                            amount: "100".to_string(),
+                            amount: reserve_2.to_string(),
                             denom: DENOM_LS_ATOM_ON_NTRN.to_string(),
                         }),
-                        weight: "50".to_string(),
+                        weight: weight_2.to_string(),
                     },
                 ],
                 total_weight: "123123".to_string(),
@@ -140,14 +252,16 @@ where
             return Ok(to_json_binary(&response).unwrap());
         }
 
+        // every share redeems one of each pool asset
         if query.path == "/osmosis.gamm.v1beta1.Query/CalcExitPoolCoinsFromShares" {
+            let request = QueryCalcExitPoolCoinsFromSharesRequest::decode(query.data.as_slice())?;
             let tokens_out = vec![
                 Coin {
-                    amount: "1".to_string(),
+                    amount: request.share_in_amount.to_string(),
                     denom: DENOM_ATOM.to_string(),
                 },
                 Coin {
-                    amount: "1".to_string(),
+                    amount: request.share_in_amount,
                     denom: DENOM_LS_ATOM_ON_NTRN.to_string(),
                 },
             ];
@@ -156,6 +270,25 @@ where
             return Ok(to_json_binary(&response).unwrap());
         }
 
+        // swaps are estimated as constant product swaps
+        // against the 50:50 stand-in pool reserves
+        if query.path == QUERY_ESTIMATE_SWAP_EXACT_AMOUNT_IN_PATH {
+            let request = EstimateSwapExactAmountInRequest::decode(query.data.as_slice())?;
+            let amount_len = request
+                .token_in
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(request.token_in.len());
+            let amount_in = Uint128::from_str(&request.token_in[..amount_len])?;
+            let reserve = Uint128::new(GAMM_POOL_ASSET_RESERVE);
+            let token_out_amount =
+                amount_in.multiply_ratio(reserve, reserve.checked_add(amount_in)?);
+            let response = EstimateSwapExactAmountInResponse {
+                token_out_amount: token_out_amount.to_string(),
+            };
+
+            return Ok(to_json_binary(&response).unwrap());
+        }
+
         if query.path == "/osmosis.gamm.v1beta1.Query/CalcJoinPoolShares" {
             let tokens_out = vec![
                 Coin {
@@ -194,6 +327,489 @@ where
             return Ok(to_json_binary(&response).unwrap());
         }
 
+        if query.path == POOL_MANAGER_QUERY_POOL_PATH {
+            let request = PoolManagerQueryPoolRequest::decode(query.data.as_slice())?;
+            let pool = ConcentratedLiquidityPool {
+                address: "address".to_string(),
+                id: request.pool_id,
+                token0: CL_POOL_TOKEN_0.to_string(),
+                token1: CL_POOL_TOKEN_1.to_string(),
+                // sqrt price of 1 (BigDec atomics)
+                current_sqrt_price: format!("1{}", "0".repeat(36)),
+                current_tick: 0,
+                tick_spacing: CL_POOL_TICK_SPACING,
+            };
+
+            let response = PoolManagerQueryPoolResponse {
+                pool: Some(osmosis_std::shim::Any {
+                    type_url: CONCENTRATED_LIQUIDITY_POOL_TYPE_URL.to_string(),
+                    value: pool.encode_to_vec(),
+                }),
+            };
+
+            return Ok(to_json_binary(&response).unwrap());
+        }
+
+        if query.path == QUERY_POSITION_BY_ID_PATH {
+            let request = QueryPositionByIdRequest::decode(query.data.as_slice())?;
+            let position = CL_POSITIONS
+                .load(storage, request.position_id)
+                .map_err(|_| AnyError::msg("position not found"))?;
+
+            let response = QueryPositionByIdResponse {
+                position: FullPositionBreakdown {
+                    position: Position {
+                        position_id: Uint64::new(request.position_id),
+                        pool_id: Uint64::new(position.pool_id),
+                        liquidity: position.liquidity,
+                    },
+                },
+            };
+
+            return Ok(to_json_binary(&response).unwrap());
+        }
+
+        if query.path == dex::QUERY_POOL_PATH {
+            let request = dex::QueryPoolRequest::decode(query.data.as_slice())?;
+            let pool = DUALITY_POOLS
+                .load(
+                    storage,
+                    get_duality_pool_key(&request.pair_id, request.tick_index, request.fee),
+                )
+                .map_err(|_| AnyError::msg("pool not found"))?;
+
+            let response = dex::QueryPoolResponse {
+                pool: dex::Pool {
+                    id: Uint64::new(pool.id),
+                    lower_tick0: Some(dex::PoolReserves {
+                        reserves_maker_denom: pool.reserves_0,
+                    }),
+                    upper_tick1: Some(dex::PoolReserves {
+                        reserves_maker_denom: pool.reserves_1,
+                    }),
+                },
+            };
+
+            return Ok(to_json_binary(&response).unwrap());
+        }
+
         Err(AnyError::msg(self.2))
     }
 }
+
+/// stand-in of the neutron dex (duality) pools. pools are keyed by their
+/// pair id, tick index (from token0 to token1) and fee. pool prices are not
+/// modelled, shares are minted 1:1 with the deposited token amounts.
+const DUALITY_POOLS: Map<String, DualityPool> = Map::new("duality_pools");
+const DUALITY_POOL_COUNT: Item<u64> = Item::new("duality_pool_count");
+
+#[cw_serde]
+struct DualityPool {
+    id: u64,
+    reserves_0: Uint128,
+    reserves_1: Uint128,
+    total_shares: Uint128,
+}
+
+impl DualityPool {
+    fn shares_denom(&self) -> String {
+        format!("{POOL_SHARES_DENOM_PREFIX}{}", self.id)
+    }
+}
+
+fn get_duality_pool_key(pair_id: &str, tick_index: i64, fee: u64) -> String {
+    format!("{pair_id}/{tick_index}/{fee}")
+}
+
+/// returns the pool key along with the (token0, token1) ordered values
+fn get_duality_pool_key_and_order<T>(
+    token_a: &str,
+    token_b: &str,
+    tick_index_a_to_b: i64,
+    fee: u64,
+    values: (T, T),
+) -> (String, (T, T)) {
+    let pair_id = get_pair_id(token_a, token_b);
+    if token_a < token_b {
+        (
+            get_duality_pool_key(&pair_id, tick_index_a_to_b, fee),
+            values,
+        )
+    } else {
+        (
+            get_duality_pool_key(&pair_id, -tick_index_a_to_b, fee),
+            (values.1, values.0),
+        )
+    }
+}
+
+/// simulates swaps fully crossing the tick of a duality pool:
+/// every `token_b` reserve gets traded 1:1 for `token_a`
+pub fn cross_duality_pool_tick(
+    storage: &mut dyn Storage,
+    token_a: &str,
+    token_b: &str,
+    tick_index_a_to_b: i64,
+    fee: u64,
+) -> AnyResult<()> {
+    let (key, (is_token_a_0, _)) =
+        get_duality_pool_key_and_order(token_a, token_b, tick_index_a_to_b, fee, (true, false));
+    let mut pool = DUALITY_POOLS.load(storage, key.to_string())?;
+    if is_token_a_0 {
+        pool.reserves_0 += pool.reserves_1;
+        pool.reserves_1 = Uint128::zero();
+    } else {
+        pool.reserves_1 += pool.reserves_0;
+        pool.reserves_0 = Uint128::zero();
+    }
+    DUALITY_POOLS.save(storage, key, &pool)?;
+    Ok(())
+}
+
+fn duality_deposit<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    sender: Addr,
+    msg: MsgDeposit,
+) -> AnyResult<AppResponse>
+where
+    ExecC: CustomMsg + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let (key, ((denom_0, amount_0), (denom_1, amount_1))) = get_duality_pool_key_and_order(
+        &msg.token_a,
+        &msg.token_b,
+        msg.tick_indexes_a_to_b[0],
+        msg.fees[0],
+        (
+            (
+                msg.token_a.to_string(),
+                Uint128::from_str(&msg.amounts_a[0])?,
+            ),
+            (
+                msg.token_b.to_string(),
+                Uint128::from_str(&msg.amounts_b[0])?,
+            ),
+        ),
+    );
+
+    let mut pool = match DUALITY_POOLS.may_load(storage, key.to_string())? {
+        Some(pool) => pool,
+        None => {
+            let id = DUALITY_POOL_COUNT.may_load(storage)?.unwrap_or_default();
+            DUALITY_POOL_COUNT.save(storage, &(id + 1))?;
+            DualityPool {
+                id,
+                reserves_0: Uint128::zero(),
+                reserves_1: Uint128::zero(),
+                total_shares: Uint128::zero(),
+            }
+        }
+    };
+
+    // without autoswap, deposits into existing pools keep the reserves ratio
+    let autoswap = !msg.options.first().is_some_and(|o| o.disable_autoswap);
+    let (deposit_0, deposit_1) = if pool.total_shares.is_zero() || autoswap {
+        (amount_0, amount_1)
+    } else if pool.reserves_1.is_zero() {
+        (amount_0, Uint128::zero())
+    } else if pool.reserves_0.is_zero() {
+        (Uint128::zero(), amount_1)
+    } else {
+        let max_amount_0 = amount_1.multiply_ratio(pool.reserves_0, pool.reserves_1);
+        if amount_0 <= max_amount_0 {
+            (
+                amount_0,
+                amount_0.multiply_ratio(pool.reserves_1, pool.reserves_0),
+            )
+        } else {
+            (max_amount_0, amount_1)
+        }
+    };
+
+    let deposit = deposit_0 + deposit_1;
+    let shares = if pool.total_shares.is_zero() {
+        deposit
+    } else {
+        deposit.multiply_ratio(pool.total_shares, pool.reserves_0 + pool.reserves_1)
+    };
+
+    pool.reserves_0 += deposit_0;
+    pool.reserves_1 += deposit_1;
+    pool.total_shares += shares;
+    DUALITY_POOLS.save(storage, key, &pool)?;
+
+    let burn_amount: Vec<_> = [
+        coin(deposit_0.u128(), denom_0),
+        coin(deposit_1.u128(), denom_1),
+    ]
+    .into_iter()
+    .filter(|c| !c.amount.is_zero())
+    .collect();
+    router.execute(
+        api,
+        storage,
+        block,
+        sender,
+        BankMsg::Burn {
+            amount: burn_amount,
+        }
+        .into(),
+    )?;
+    router.sudo(
+        api,
+        storage,
+        block,
+        SudoMsg::Bank(BankSudo::Mint {
+            to_address: msg.receiver,
+            amount: coins(shares.u128(), pool.shares_denom()),
+        }),
+    )?;
+
+    Ok(AppResponse::default())
+}
+
+fn duality_withdraw<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    sender: Addr,
+    msg: MsgWithdrawal,
+) -> AnyResult<AppResponse>
+where
+    ExecC: CustomMsg + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let (key, (denom_0, denom_1)) = get_duality_pool_key_and_order(
+        &msg.token_a,
+        &msg.token_b,
+        msg.tick_indexes_a_to_b[0],
+        msg.fees[0],
+        (msg.token_a.to_string(), msg.token_b.to_string()),
+    );
+    let mut pool = DUALITY_POOLS.load(storage, key.to_string())?;
+    let shares = Uint128::from_str(&msg.shares_to_remove[0])?;
+
+    let withdrawn_0 = pool.reserves_0.multiply_ratio(shares, pool.total_shares);
+    let withdrawn_1 = pool.reserves_1.multiply_ratio(shares, pool.total_shares);
+
+    pool.reserves_0 -= withdrawn_0;
+    pool.reserves_1 -= withdrawn_1;
+    pool.total_shares -= shares;
+    DUALITY_POOLS.save(storage, key, &pool)?;
+
+    router.execute(
+        api,
+        storage,
+        block,
+        sender,
+        BankMsg::Burn {
+            amount: coins(shares.u128(), pool.shares_denom()),
+        }
+        .into(),
+    )?;
+    router.sudo(
+        api,
+        storage,
+        block,
+        SudoMsg::Bank(BankSudo::Mint {
+            to_address: msg.receiver,
+            amount: [
+                coin(withdrawn_0.u128(), denom_0),
+                coin(withdrawn_1.u128(), denom_1),
+            ]
+            .into_iter()
+            .filter(|c| !c.amount.is_zero())
+            .collect(),
+        }),
+    )?;
+
+    Ok(AppResponse::default())
+}
+
+/// stand-in of the osmosis concentrated liquidity pools. every pool is
+/// priced at 1 and the position liquidity equals the sum of its amounts.
+pub const CL_POOL_TOKEN_0: &str = DENOM_ATOM;
+pub const CL_POOL_TOKEN_1: &str = DENOM_LS_ATOM_ON_NTRN;
+pub const CL_POOL_TICK_SPACING: u64 = 100;
+/// incentives claimed by every position withdrawal, paid in a non-pool denom
+pub const CL_POSITION_INCENTIVES: u128 = 10;
+pub const CL_POSITION_INCENTIVES_DENOM: &str = DENOM_OSMO;
+const CL_POSITIONS: Map<u64, ConcentratedLiquidityPosition> = Map::new("cl_positions");
+const CL_POSITION_COUNT: Item<u64> = Item::new("cl_position_count");
+
+#[cw_serde]
+struct ConcentratedLiquidityPosition {
+    pool_id: u64,
+    amount_0: Uint128,
+    amount_1: Uint128,
+    liquidity: Decimal256,
+}
+
+/// stores a new position and returns the `MsgCreatePosition`-like response
+fn save_cl_position(
+    storage: &mut dyn Storage,
+    pool_id: u64,
+    amount_0: Uint128,
+    amount_1: Uint128,
+) -> AnyResult<AppResponse> {
+    let position_id = CL_POSITION_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    CL_POSITION_COUNT.save(storage, &position_id)?;
+    CL_POSITIONS.save(
+        storage,
+        position_id,
+        &ConcentratedLiquidityPosition {
+            pool_id,
+            amount_0,
+            amount_1,
+            liquidity: Decimal256::from_ratio(amount_0 + amount_1, 1u128),
+        },
+    )?;
+
+    Ok(AppResponse {
+        events: vec![],
+        data: Some(Binary(MsgPositionResponse { position_id }.encode_to_vec())),
+    })
+}
+
+fn create_cl_position<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    sender: Addr,
+    msg: MsgCreatePosition,
+) -> AnyResult<AppResponse>
+where
+    ExecC: CustomMsg + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let mut amount_0 = Uint128::zero();
+    let mut amount_1 = Uint128::zero();
+    let mut burn_amount = vec![];
+    for token in msg.tokens_provided {
+        let amount = Uint128::from_str(&token.amount)?;
+        match token.denom.as_str() {
+            CL_POOL_TOKEN_0 => amount_0 += amount,
+            CL_POOL_TOKEN_1 => amount_1 += amount,
+            _ => return Err(AnyError::msg("denom not in pool")),
+        }
+        burn_amount.push(coin(amount.u128(), token.denom));
+    }
+
+    router.execute(
+        api,
+        storage,
+        block,
+        sender,
+        BankMsg::Burn {
+            amount: burn_amount,
+        }
+        .into(),
+    )?;
+
+    save_cl_position(storage, msg.pool_id, amount_0, amount_1)
+}
+
+fn add_to_cl_position<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    sender: Addr,
+    msg: MsgAddToPosition,
+) -> AnyResult<AppResponse>
+where
+    ExecC: CustomMsg + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let position = CL_POSITIONS.load(storage, msg.position_id)?;
+    let amount_0 = Uint128::from_str(&msg.amount0)?;
+    let amount_1 = Uint128::from_str(&msg.amount1)?;
+
+    router.execute(
+        api,
+        storage,
+        block,
+        sender,
+        BankMsg::Burn {
+            amount: [
+                coin(amount_0.u128(), CL_POOL_TOKEN_0),
+                coin(amount_1.u128(), CL_POOL_TOKEN_1),
+            ]
+            .into_iter()
+            .filter(|c| !c.amount.is_zero())
+            .collect(),
+        }
+        .into(),
+    )?;
+
+    // the existing position is replaced with a new one
+    CL_POSITIONS.remove(storage, msg.position_id);
+    save_cl_position(
+        storage,
+        position.pool_id,
+        position.amount_0 + amount_0,
+        position.amount_1 + amount_1,
+    )
+}
+
+fn withdraw_cl_position<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    sender: Addr,
+    msg: MsgWithdrawPosition,
+) -> AnyResult<AppResponse>
+where
+    ExecC: CustomMsg + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let mut position = CL_POSITIONS.load(storage, msg.position_id)?;
+    let liquidity_amount = Decimal256::new(Uint256::from_str(&msg.liquidity_amount)?);
+    if liquidity_amount > position.liquidity {
+        return Err(AnyError::msg("insufficient liquidity"));
+    }
+
+    let withdrawn_0 = Uint128::try_from(
+        Uint256::from(position.amount_0)
+            .multiply_ratio(liquidity_amount.atomics(), position.liquidity.atomics()),
+    )?;
+    let withdrawn_1 = Uint128::try_from(
+        Uint256::from(position.amount_1)
+            .multiply_ratio(liquidity_amount.atomics(), position.liquidity.atomics()),
+    )?;
+
+    // withdrawing the entire liquidity closes the position
+    if liquidity_amount == position.liquidity {
+        CL_POSITIONS.remove(storage, msg.position_id);
+    } else {
+        position.amount_0 -= withdrawn_0;
+        position.amount_1 -= withdrawn_1;
+        position.liquidity -= liquidity_amount;
+        CL_POSITIONS.save(storage, msg.position_id, &position)?;
+    }
+
+    router.sudo(
+        api,
+        storage,
+        block,
+        SudoMsg::Bank(BankSudo::Mint {
+            to_address: sender.to_string(),
+            amount: [
+                coin(withdrawn_0.u128(), CL_POOL_TOKEN_0),
+                coin(withdrawn_1.u128(), CL_POOL_TOKEN_1),
+                coin(CL_POSITION_INCENTIVES, CL_POSITION_INCENTIVES_DENOM),
+            ]
+            .into_iter()
+            .filter(|c| !c.amount.is_zero())
+            .collect(),
+        }),
+    )?;
+
+    Ok(AppResponse::default())
+}
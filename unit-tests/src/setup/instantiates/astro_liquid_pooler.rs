@@ -1,6 +1,8 @@
 use astroport::factory::PairType;
 use cosmwasm_std::{Decimal, Uint128};
-use covenant_utils::{op_mode::ContractOperationModeConfig, PoolPriceConfig, SingleSideLpLimits};
+use covenant_utils::{
+    op_mode::ContractOperationModeConfig, PoolPriceConfig, SingleSideLpLimits, WithdrawPriceGuard,
+};
 
 use crate::setup::{DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN};
 
@@ -25,6 +27,10 @@ impl AstroLiquidPoolerInstantiate {
         pool_price_config: PoolPriceConfig,
         pair_type: PairType,
         holder_address: String,
+        lp_staking_config: Option<valence_astroport_liquid_pooler::msg::LpStakingConfig>,
+        zap_config: Option<valence_astroport_liquid_pooler::msg::ZapConfig>,
+        twap_window_seconds: Option<u64>,
+        withdraw_price_guard: Option<WithdrawPriceGuard>,
     ) -> Self {
         Self {
             msg: valence_astroport_liquid_pooler::msg::InstantiateMsg {
@@ -36,6 +42,10 @@ impl AstroLiquidPoolerInstantiate {
                 pool_price_config,
                 pair_type,
                 holder_address,
+                lp_staking_config,
+                zap_config,
+                twap_window_seconds,
+                withdraw_price_guard,
             },
         }
     }
@@ -85,6 +95,35 @@ impl AstroLiquidPoolerInstantiate {
         self.msg.holder_address = holder_address;
         self
     }
+
+    pub fn with_lp_staking_config(
+        &mut self,
+        lp_staking_config: Option<valence_astroport_liquid_pooler::msg::LpStakingConfig>,
+    ) -> &mut Self {
+        self.msg.lp_staking_config = lp_staking_config;
+        self
+    }
+
+    pub fn with_zap_config(
+        &mut self,
+        zap_config: Option<valence_astroport_liquid_pooler::msg::ZapConfig>,
+    ) -> &mut Self {
+        self.msg.zap_config = zap_config;
+        self
+    }
+
+    pub fn with_twap_window_seconds(&mut self, twap_window_seconds: Option<u64>) -> &mut Self {
+        self.msg.twap_window_seconds = twap_window_seconds;
+        self
+    }
+
+    pub fn with_withdraw_price_guard(
+        &mut self,
+        withdraw_price_guard: Option<WithdrawPriceGuard>,
+    ) -> &mut Self {
+        self.msg.withdraw_price_guard = withdraw_price_guard;
+        self
+    }
 }
 
 impl AstroLiquidPoolerInstantiate {
@@ -112,6 +151,10 @@ impl AstroLiquidPoolerInstantiate {
                 },
                 pair_type: PairType::Stable {},
                 holder_address,
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         }
     }
@@ -0,0 +1,84 @@
+use cosmwasm_std::{Decimal, Uint128};
+use covenant_utils::{op_mode::ContractOperationModeConfig, PoolPriceConfig};
+use valence_duality_liquid_pooler::msg::{AssetData, TickRange};
+
+use crate::setup::{DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN};
+
+#[derive(Clone)]
+pub struct DualityLiquidPoolerInstantiate {
+    pub msg: valence_duality_liquid_pooler::msg::InstantiateMsg,
+}
+
+impl From<DualityLiquidPoolerInstantiate> for valence_duality_liquid_pooler::msg::InstantiateMsg {
+    fn from(value: DualityLiquidPoolerInstantiate) -> Self {
+        value.msg
+    }
+}
+
+impl DualityLiquidPoolerInstantiate {
+    pub fn new(
+        assets: AssetData,
+        tick_range: TickRange,
+        pool_price_config: PoolPriceConfig,
+        holder_address: String,
+        op_mode_cfg: ContractOperationModeConfig,
+    ) -> Self {
+        Self {
+            msg: valence_duality_liquid_pooler::msg::InstantiateMsg {
+                assets,
+                tick_range,
+                pool_price_config,
+                holder_address,
+                op_mode_cfg,
+            },
+        }
+    }
+
+    pub fn with_assets(&mut self, assets: AssetData) -> &mut Self {
+        self.msg.assets = assets;
+        self
+    }
+
+    pub fn with_tick_range(&mut self, tick_range: TickRange) -> &mut Self {
+        self.msg.tick_range = tick_range;
+        self
+    }
+
+    pub fn with_pool_price_config(&mut self, pool_price_config: PoolPriceConfig) -> &mut Self {
+        self.msg.pool_price_config = pool_price_config;
+        self
+    }
+
+    pub fn with_holder_address(&mut self, holder_address: String) -> &mut Self {
+        self.msg.holder_address = holder_address;
+        self
+    }
+
+    pub fn with_op_mode(&mut self, op_mode: ContractOperationModeConfig) -> &mut Self {
+        self.msg.op_mode_cfg = op_mode;
+        self
+    }
+}
+
+impl DualityLiquidPoolerInstantiate {
+    pub fn default(op_mode: ContractOperationModeConfig, holder_address: String) -> Self {
+        Self {
+            msg: valence_duality_liquid_pooler::msg::InstantiateMsg {
+                assets: AssetData {
+                    asset_a_denom: DENOM_ATOM_ON_NTRN.to_string(),
+                    asset_b_denom: DENOM_LS_ATOM_ON_NTRN.to_string(),
+                },
+                tick_range: TickRange {
+                    tick_index: 0,
+                    fee: 1,
+                },
+                pool_price_config: PoolPriceConfig {
+                    expected_spot_price: Decimal::one(),
+                    acceptable_price_spread: Decimal::from_ratio(Uint128::one(), Uint128::new(2)),
+                },
+                holder_address,
+                op_mode_cfg: op_mode,
+            },
+        }
+    }
+}
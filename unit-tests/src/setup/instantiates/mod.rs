@@ -1,9 +1,15 @@
 pub mod astro_liquid_pooler;
 pub mod clock;
+pub mod duality_liquid_pooler;
 pub mod ibc_forwarder;
 pub mod interchain_router;
+pub mod multi_party_covenant;
+pub mod multi_party_pol_holder;
+pub mod multi_party_swap_covenant;
+pub mod multi_party_swap_holder;
 pub mod native_router;
 pub mod native_splitter;
+pub mod osmo_liquid_pooler;
 pub mod osmo_lp_outpost;
 pub mod remote_chain_splitter;
 pub mod single_party_covenant;
@@ -12,3 +18,4 @@ pub mod swap_covenant;
 pub mod swap_holder;
 pub mod two_party_covenant;
 pub mod two_party_pol_holder;
+pub mod vesting_escrow;
@@ -0,0 +1,121 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{coin, Addr, Decimal, Uint128, Uint64};
+use covenant_utils::{NativeCovenantParty, PoolPriceConfig, SingleSideLpLimits};
+use cw_utils::Expiration;
+use valence_astroport_liquid_pooler::msg::AstroportLiquidPoolerConfig;
+use valence_covenant_multi_party_pol::msg::MultiPartyCovenantPartyConfig;
+use valence_covenant_two_party_pol::msg::{CovenantPartyConfig, Timeouts};
+
+use crate::setup::{suite_builder::SuiteBuilder, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN};
+
+#[derive(Clone)]
+pub struct MultiPartyCovenantInstantiate {
+    pub msg: valence_covenant_multi_party_pol::msg::InstantiateMsg,
+}
+
+impl From<MultiPartyCovenantInstantiate> for valence_covenant_multi_party_pol::msg::InstantiateMsg {
+    fn from(value: MultiPartyCovenantInstantiate) -> Self {
+        value.msg
+    }
+}
+
+impl MultiPartyCovenantInstantiate {
+    pub fn with_parties(&mut self, parties: Vec<MultiPartyCovenantPartyConfig>) -> &mut Self {
+        self.msg.parties = parties;
+        self
+    }
+
+    pub fn with_ragequit_config(
+        &mut self,
+        ragequit_config: Option<valence_multi_party_pol_holder::msg::RagequitConfig>,
+    ) -> &mut Self {
+        self.msg.ragequit_config = ragequit_config;
+        self
+    }
+
+    pub fn with_fallback_split(
+        &mut self,
+        fallback_split: Option<covenant_utils::split::SplitConfig>,
+    ) -> &mut Self {
+        self.msg.fallback_split = fallback_split;
+        self
+    }
+}
+
+impl MultiPartyCovenantInstantiate {
+    pub fn default(builder: &SuiteBuilder, party_addrs: [Addr; 3], pool_address: Addr) -> Self {
+        let contract_codes = valence_covenant_multi_party_pol::msg::CovenantContractCodeIds {
+            ibc_forwarder_code: builder.ibc_forwarder_code_id,
+            interchain_router_code: builder.interchain_router_code_id,
+            holder_code: builder.multi_party_holder_code_id,
+            clock_code: builder.clock_code_id,
+            liquid_pooler_code: builder.astro_pooler_code_id,
+            native_router_code: builder.native_router_code_id,
+        };
+
+        let contributions = [
+            (coin(10_000, DENOM_ATOM_ON_NTRN), "0.5"),
+            (coin(5_000, DENOM_LS_ATOM_ON_NTRN), "0.25"),
+            (coin(5_000, DENOM_LS_ATOM_ON_NTRN), "0.25"),
+        ];
+        let parties = party_addrs
+            .iter()
+            .zip(contributions)
+            .map(
+                |(addr, (contribution, allocation))| MultiPartyCovenantPartyConfig {
+                    config: CovenantPartyConfig::Native(NativeCovenantParty {
+                        party_receiver_addr: addr.to_string(),
+                        native_denom: contribution.denom.to_string(),
+                        addr: addr.to_string(),
+                        contribution,
+                    }),
+                    allocation: Decimal::from_str(allocation).unwrap(),
+                },
+            )
+            .collect();
+
+        Self {
+            msg: valence_covenant_multi_party_pol::msg::InstantiateMsg {
+                label: "valence_covenant_multi_party_pol".to_string(),
+                timeouts: Timeouts {
+                    ica_timeout: Uint64::new(100),
+                    ibc_transfer_timeout: Uint64::new(100),
+                },
+                contract_codes,
+                clock_tick_max_gas: None,
+                lockup_config: Expiration::AtHeight(200000),
+                parties,
+                ragequit_config: None,
+                deposit_deadline: Expiration::AtHeight(100000),
+                pool_price_config: PoolPriceConfig {
+                    expected_spot_price: Decimal::from_str("1.0").unwrap(),
+                    acceptable_price_spread: Decimal::from_str("0.1").unwrap(),
+                },
+                fallback_split: None,
+                emergency_committee: None,
+                liquid_pooler_config:
+                    valence_covenant_two_party_pol::msg::LiquidPoolerConfig::Astroport(
+                        AstroportLiquidPoolerConfig {
+                            pool_pair_type: astroport::factory::PairType::Stable {},
+                            pool_address: pool_address.to_string(),
+                            asset_a_denom: DENOM_ATOM_ON_NTRN.to_string(),
+                            asset_b_denom: DENOM_LS_ATOM_ON_NTRN.to_string(),
+                            single_side_lp_limits: SingleSideLpLimits {
+                                asset_a_limit: Uint128::new(10_000),
+                                asset_b_limit: Uint128::new(10_000),
+                            },
+                            lp_staking_config: None,
+                            zap_config: None,
+                            twap_window_seconds: None,
+                            withdraw_price_guard: None,
+                        },
+                    ),
+                fallback_address: None,
+                operation_mode: covenant_utils::op_mode::ContractOperationModeConfig::Permissioned(
+                    vec![],
+                ),
+            },
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{coin, Addr, Decimal};
+use covenant_utils::{
+    emergency_committee::EmergencyCommitteeConfig, op_mode::ContractOperationModeConfig,
+    split::SplitConfig,
+};
+use cw_utils::Expiration;
+use valence_multi_party_pol_holder::msg::{
+    MultiPartyPolCovenantConfig, MultiPartyPolCovenantParty, RagequitConfig,
+};
+
+use crate::setup::{DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN};
+
+#[derive(Clone)]
+pub struct MultiPartyHolderInstantiate {
+    pub msg: valence_multi_party_pol_holder::msg::InstantiateMsg,
+}
+
+impl From<MultiPartyHolderInstantiate> for valence_multi_party_pol_holder::msg::InstantiateMsg {
+    fn from(value: MultiPartyHolderInstantiate) -> Self {
+        value.msg
+    }
+}
+
+impl MultiPartyHolderInstantiate {
+    /* Change functions */
+    pub fn with_op_mode(&mut self, op_mode: &ContractOperationModeConfig) -> &mut Self {
+        self.msg.op_mode_cfg = op_mode.clone();
+        self
+    }
+
+    pub fn with_lockup_config(&mut self, config: Expiration) -> &mut Self {
+        self.msg.lockup_config = config;
+        self
+    }
+
+    pub fn with_ragequit_config(&mut self, config: RagequitConfig) -> &mut Self {
+        self.msg.ragequit_config = config;
+        self
+    }
+
+    pub fn with_deposit_deadline(&mut self, config: Expiration) -> &mut Self {
+        self.msg.deposit_deadline = config;
+        self
+    }
+
+    pub fn with_covenant_config(&mut self, config: MultiPartyPolCovenantConfig) -> &mut Self {
+        self.msg.covenant_config = config;
+        self
+    }
+
+    pub fn with_fallback_split(&mut self, split: SplitConfig) -> &mut Self {
+        self.msg.fallback_split = Some(split);
+        self
+    }
+
+    pub fn with_emergency_committee(&mut self, config: EmergencyCommitteeConfig) -> &mut Self {
+        self.msg.emergency_committee = Some(config);
+        self
+    }
+}
+
+impl MultiPartyHolderInstantiate {
+    /// three parties where the first one contributes atom and the
+    /// other two share the ls_atom side of the position
+    pub fn default(
+        op_mode_cfg: ContractOperationModeConfig,
+        next_contract: String,
+        party_addrs: [Addr; 3],
+    ) -> Self {
+        let contributions = [
+            (coin(10_000, DENOM_ATOM_ON_NTRN), "0.5"),
+            (coin(5_000, DENOM_LS_ATOM_ON_NTRN), "0.25"),
+            (coin(5_000, DENOM_LS_ATOM_ON_NTRN), "0.25"),
+        ];
+
+        let parties = party_addrs
+            .iter()
+            .zip(contributions)
+            .map(
+                |(addr, (contribution, allocation))| MultiPartyPolCovenantParty {
+                    contribution: vec![contribution],
+                    host_addr: addr.to_string(),
+                    controller_addr: addr.to_string(),
+                    allocation: Decimal::from_str(allocation).unwrap(),
+                    router: addr.to_string(),
+                },
+            )
+            .collect();
+
+        Self {
+            msg: valence_multi_party_pol_holder::msg::InstantiateMsg {
+                op_mode_cfg,
+                next_contract,
+                lockup_config: Expiration::AtHeight(200000),
+                ragequit_config: RagequitConfig::Disabled {},
+                deposit_deadline: Expiration::AtHeight(100000),
+                covenant_config: MultiPartyPolCovenantConfig { parties },
+                fallback_split: None,
+                emergency_committee: None,
+            },
+        }
+    }
+}
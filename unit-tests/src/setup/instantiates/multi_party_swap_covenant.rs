@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{coin, Addr, Decimal, Uint64};
+use covenant_utils::{split::SplitConfig, NativeCovenantParty};
+use cw_utils::Expiration;
+use valence_covenant_swap::msg::{CovenantPartyConfig, Timeouts};
+
+use crate::setup::{
+    suite_builder::SuiteBuilder, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, DENOM_NTRN,
+};
+
+#[derive(Clone)]
+pub struct MultiPartySwapCovenantInstantiate {
+    pub msg: valence_covenant_multi_party_swap::msg::InstantiateMsg,
+}
+
+impl From<MultiPartySwapCovenantInstantiate>
+    for valence_covenant_multi_party_swap::msg::InstantiateMsg
+{
+    fn from(value: MultiPartySwapCovenantInstantiate) -> Self {
+        value.msg
+    }
+}
+
+impl MultiPartySwapCovenantInstantiate {
+    pub fn with_parties(&mut self, parties: Vec<CovenantPartyConfig>) -> &mut Self {
+        self.msg.parties = parties;
+        self
+    }
+
+    pub fn with_splits(&mut self, splits: BTreeMap<String, SplitConfig>) -> &mut Self {
+        self.msg.splits = splits;
+        self
+    }
+
+    pub fn with_fallback_split(&mut self, fallback_split: Option<SplitConfig>) -> &mut Self {
+        self.msg.fallback_split = fallback_split;
+        self
+    }
+}
+
+impl MultiPartySwapCovenantInstantiate {
+    /// three native parties contributing atom, ls_atom and ntrn
+    /// respectively, where every party receives the contribution
+    /// of the party preceding it
+    pub fn default(builder: &SuiteBuilder, party_addrs: [Addr; 3]) -> Self {
+        let contract_codes = valence_covenant_multi_party_swap::msg::CovenantContractCodeIds {
+            ibc_forwarder_code: builder.ibc_forwarder_code_id,
+            interchain_router_code: builder.interchain_router_code_id,
+            native_router_code: builder.native_router_code_id,
+            splitter_code: builder.native_splitter_code_id,
+            holder_code: builder.multi_party_swap_holder_code_id,
+            clock_code: builder.clock_code_id,
+        };
+
+        let contributions = [
+            coin(100_000, DENOM_ATOM_ON_NTRN),
+            coin(200_000, DENOM_LS_ATOM_ON_NTRN),
+            coin(300_000, DENOM_NTRN),
+        ];
+
+        let mut parties = Vec::with_capacity(party_addrs.len());
+        let mut splits = BTreeMap::new();
+        for (i, (addr, contribution)) in party_addrs.iter().zip(contributions).enumerate() {
+            let receiver = &party_addrs[(i + 1) % party_addrs.len()];
+            splits.insert(
+                contribution.denom.to_string(),
+                SplitConfig {
+                    receivers: BTreeMap::from_iter(vec![(receiver.to_string(), Decimal::one())]),
+                },
+            );
+            parties.push(CovenantPartyConfig::Native(NativeCovenantParty {
+                party_receiver_addr: addr.to_string(),
+                native_denom: contribution.denom.to_string(),
+                addr: addr.to_string(),
+                contribution,
+            }));
+        }
+
+        Self {
+            msg: valence_covenant_multi_party_swap::msg::InstantiateMsg {
+                label: "valence_covenant_multi_party_swap".to_string(),
+                timeouts: Timeouts {
+                    ica_timeout: Uint64::new(100),
+                    ibc_transfer_timeout: Uint64::new(100),
+                },
+                contract_codes,
+                clock_tick_max_gas: None,
+                lockup_config: Expiration::AtHeight(1000000),
+                parties,
+                splits,
+                fallback_split: None,
+                fallback_address: None,
+                operation_mode: covenant_utils::op_mode::ContractOperationModeConfig::Permissioned(
+                    vec![],
+                ),
+            },
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use cosmwasm_std::{coin, Addr};
+use covenant_utils::op_mode::ContractOperationModeConfig;
+use cw_utils::Expiration;
+use valence_multi_party_swap_holder::msg::SwapParty;
+
+use crate::setup::{DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, DENOM_NTRN};
+
+#[derive(Clone)]
+pub struct MultiPartySwapHolderInstantiate {
+    pub msg: valence_multi_party_swap_holder::msg::InstantiateMsg,
+}
+
+impl From<MultiPartySwapHolderInstantiate>
+    for valence_multi_party_swap_holder::msg::InstantiateMsg
+{
+    fn from(value: MultiPartySwapHolderInstantiate) -> Self {
+        value.msg
+    }
+}
+
+impl MultiPartySwapHolderInstantiate {
+    /* Change functions */
+    pub fn with_op_mode(&mut self, op_mode: ContractOperationModeConfig) -> &mut Self {
+        self.msg.op_mode_cfg = op_mode;
+        self
+    }
+
+    pub fn with_next_contract(&mut self, addr: &str) -> &mut Self {
+        self.msg.next_contract = addr.to_string();
+        self
+    }
+
+    pub fn with_lockup_config(&mut self, config: Expiration) -> &mut Self {
+        self.msg.lockup_config = config;
+        self
+    }
+
+    pub fn with_parties(&mut self, parties: Vec<SwapParty>) -> &mut Self {
+        self.msg.parties = parties;
+        self
+    }
+}
+
+impl MultiPartySwapHolderInstantiate {
+    /// three parties contributing atom, ls_atom and ntrn respectively
+    pub fn default(
+        op_mode_cfg: ContractOperationModeConfig,
+        next_contract: String,
+        party_addrs: [Addr; 3],
+        refund_addrs: [Addr; 3],
+    ) -> Self {
+        let contributions = [
+            coin(100_000, DENOM_ATOM_ON_NTRN),
+            coin(200_000, DENOM_LS_ATOM_ON_NTRN),
+            coin(300_000, DENOM_NTRN),
+        ];
+
+        let parties = party_addrs
+            .iter()
+            .zip(refund_addrs.iter())
+            .zip(contributions)
+            .map(|((addr, refund_addr), contribution)| SwapParty {
+                addr: addr.to_string(),
+                contribution,
+                refund_address: refund_addr.to_string(),
+            })
+            .collect();
+
+        Self {
+            msg: valence_multi_party_swap_holder::msg::InstantiateMsg {
+                op_mode_cfg,
+                next_contract,
+                lockup_config: Expiration::AtHeight(1000000),
+                parties,
+            },
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use cosmwasm_std::{coin, Decimal, Uint128, Uint64};
+use covenant_utils::{
+    op_mode::ContractOperationModeConfig, PoolPriceConfig, SingleSideLpLimits, WithdrawPriceGuard,
+};
+use valence_osmo_liquid_pooler::msg::{PartyChainInfo, PartyDenomInfo, StateTimeouts};
+use valence_outpost_osmo_liquid_pooler::msg::{OutpostSingleAssetExit, TickRange};
+
+use crate::setup::{
+    DENOM_ATOM, DENOM_ATOM_ON_NTRN, DENOM_OSMO, DENOM_OSMO_ON_NTRN, NTRN_HUB_CHANNEL,
+    NTRN_OSMO_CHANNEL,
+};
+
+#[derive(Clone)]
+pub struct OsmoLiquidPoolerInstantiate {
+    pub msg: valence_osmo_liquid_pooler::msg::InstantiateMsg,
+}
+
+impl From<OsmoLiquidPoolerInstantiate> for valence_osmo_liquid_pooler::msg::InstantiateMsg {
+    fn from(value: OsmoLiquidPoolerInstantiate) -> Self {
+        value.msg
+    }
+}
+
+impl OsmoLiquidPoolerInstantiate {
+    pub fn with_holder_address(&mut self, holder_address: String) -> &mut Self {
+        self.msg.holder_address = holder_address;
+        self
+    }
+
+    pub fn with_op_mode(&mut self, op_mode: ContractOperationModeConfig) -> &mut Self {
+        self.msg.op_mode_cfg = op_mode;
+        self
+    }
+
+    pub fn with_state_timeouts(&mut self, state_timeouts: Option<StateTimeouts>) -> &mut Self {
+        self.msg.state_timeouts = state_timeouts;
+        self
+    }
+
+    pub fn with_withdraw_price_guard(
+        &mut self,
+        withdraw_price_guard: Option<WithdrawPriceGuard>,
+    ) -> &mut Self {
+        self.msg.withdraw_price_guard = withdraw_price_guard;
+        self
+    }
+
+    pub fn with_tick_range(&mut self, tick_range: Option<TickRange>) -> &mut Self {
+        self.msg.tick_range = tick_range;
+        self
+    }
+
+    pub fn with_single_asset_exit(
+        &mut self,
+        single_asset_exit: Option<OutpostSingleAssetExit>,
+    ) -> &mut Self {
+        self.msg.single_asset_exit = single_asset_exit;
+        self
+    }
+}
+
+impl OsmoLiquidPoolerInstantiate {
+    pub fn default(
+        op_mode: ContractOperationModeConfig,
+        holder_address: String,
+        note_address: String,
+        osmo_outpost: String,
+    ) -> Self {
+        Self {
+            msg: valence_osmo_liquid_pooler::msg::InstantiateMsg {
+                op_mode_cfg: op_mode,
+                holder_address,
+                note_address,
+                pool_id: Uint64::one(),
+                osmo_ibc_timeout: Uint64::new(300),
+                party_1_chain_info: PartyChainInfo {
+                    neutron_to_party_chain_channel: NTRN_HUB_CHANNEL.0.to_string(),
+                    party_chain_to_neutron_channel: NTRN_HUB_CHANNEL.1.to_string(),
+                    outwards_pfm: None,
+                    inwards_pfm: None,
+                    ibc_timeout: Uint64::new(300),
+                },
+                party_2_chain_info: PartyChainInfo {
+                    neutron_to_party_chain_channel: NTRN_OSMO_CHANNEL.0.to_string(),
+                    party_chain_to_neutron_channel: NTRN_OSMO_CHANNEL.1.to_string(),
+                    outwards_pfm: None,
+                    inwards_pfm: None,
+                    ibc_timeout: Uint64::new(300),
+                },
+                osmo_to_neutron_channel_id: NTRN_OSMO_CHANNEL.1.to_string(),
+                party_1_denom_info: PartyDenomInfo {
+                    osmosis_coin: coin(100_000, DENOM_ATOM),
+                    local_denom: DENOM_ATOM_ON_NTRN.to_string(),
+                },
+                party_2_denom_info: PartyDenomInfo {
+                    osmosis_coin: coin(100_000, DENOM_OSMO),
+                    local_denom: DENOM_OSMO_ON_NTRN.to_string(),
+                },
+                osmo_outpost,
+                lp_token_denom: "gamm/pool/1".to_string(),
+                slippage_tolerance: None,
+                pool_price_config: PoolPriceConfig {
+                    expected_spot_price: Decimal::one(),
+                    acceptable_price_spread: Decimal::from_ratio(Uint128::one(), Uint128::new(2)),
+                },
+                funding_duration: cw_utils::Duration::Time(400),
+                single_side_lp_limits: SingleSideLpLimits {
+                    asset_a_limit: Uint128::new(10_000),
+                    asset_b_limit: Uint128::new(10_000),
+                },
+                withdraw_price_guard: None,
+                tick_range: None,
+                state_timeouts: None,
+                single_asset_exit: None,
+            },
+        }
+    }
+}
@@ -66,6 +66,8 @@ impl SinglePartyCovenantInstantiate {
             covenant_party,
             pooler_config,
             op_mode,
+            None,
+            None,
         )
     }
 
@@ -164,6 +166,10 @@ impl SinglePartyCovenantInstantiate {
                 asset_a_denom: denom_a.into(),
                 asset_b_denom: denom_b.into(),
                 single_side_lp_limits,
+                lp_staking_config: None,
+                zap_config: None,
+                twap_window_seconds: None,
+                withdraw_price_guard: None,
             },
         )
     }
@@ -192,10 +198,14 @@ impl SinglePartyCovenantInstantiate {
         lp_forwarder_config: valence_covenant_single_party_pol::msg::CovenantPartyConfig,
         pool_price_config: covenant_utils::PoolPriceConfig,
         remote_chain_splitter_config: valence_covenant_single_party_pol::msg::RemoteChainSplitterConfig,
-        emergency_committee: Option<String>,
+        emergency_committee: Option<covenant_utils::emergency_committee::EmergencyCommitteeConfig>,
         covenant_party_config: covenant_utils::InterchainCovenantParty,
         liquid_pooler_config: valence_covenant_single_party_pol::msg::LiquidPoolerConfig,
         operation_mode: ContractOperationModeConfig,
+        unlock_schedule: Option<valence_single_party_pol_holder::msg::UnlockSchedule>,
+        early_withdrawal_config: Option<
+            valence_single_party_pol_holder::msg::EarlyWithdrawalConfig,
+        >,
     ) -> Self {
         Self {
             msg: valence_covenant_single_party_pol::msg::InstantiateMsg {
@@ -213,6 +223,9 @@ impl SinglePartyCovenantInstantiate {
                 covenant_party_config,
                 liquid_pooler_config,
                 operation_mode,
+                unlock_schedule,
+                early_withdrawal_config,
+                withdraw_to: None,
             },
         }
     }
@@ -255,9 +268,30 @@ impl SinglePartyCovenantInstantiate {
 
     pub fn with_emergency_committee(
         &mut self,
-        emergency_committee: impl Into<String>,
+        emergency_committee: covenant_utils::emergency_committee::EmergencyCommitteeConfig,
+    ) -> &mut Self {
+        self.msg.emergency_committee = Some(emergency_committee);
+        self
+    }
+
+    pub fn with_unlock_schedule(
+        &mut self,
+        unlock_schedule: valence_single_party_pol_holder::msg::UnlockSchedule,
     ) -> &mut Self {
-        self.msg.emergency_committee = Some(emergency_committee.into());
+        self.msg.unlock_schedule = Some(unlock_schedule);
+        self
+    }
+
+    pub fn with_early_withdrawal_config(
+        &mut self,
+        config: valence_single_party_pol_holder::msg::EarlyWithdrawalConfig,
+    ) -> &mut Self {
+        self.msg.early_withdrawal_config = Some(config);
+        self
+    }
+
+    pub fn with_withdraw_to(&mut self, receiver: covenant_utils::ReceiverConfig) -> &mut Self {
+        self.msg.withdraw_to = Some(receiver);
         self
     }
 }
@@ -1,4 +1,6 @@
-use cw_utils::Expiration;
+use covenant_utils::{emergency_committee::EmergencyCommitteeConfig, ReceiverConfig};
+use cw_utils::{Duration, Expiration};
+use valence_single_party_pol_holder::msg::{EarlyWithdrawalConfig, UnlockSchedule};
 
 pub struct SinglePartyHolderInstantiate {
     pub msg: valence_single_party_pol_holder::msg::InstantiateMsg,
@@ -13,18 +15,22 @@ impl From<SinglePartyHolderInstantiate> for valence_single_party_pol_holder::msg
 impl SinglePartyHolderInstantiate {
     pub fn new(
         withdrawer: String,
-        withdraw_to: String,
-        emergency_committee_addr: Option<String>,
+        withdraw_to: ReceiverConfig,
+        emergency_committee: Option<EmergencyCommitteeConfig>,
         pooler_address: String,
         lockup_period: Expiration,
+        unlock_schedule: Option<UnlockSchedule>,
+        early_withdrawal_config: EarlyWithdrawalConfig,
     ) -> Self {
         Self {
             msg: valence_single_party_pol_holder::msg::InstantiateMsg {
                 withdrawer,
                 withdraw_to,
-                emergency_committee_addr,
+                emergency_committee,
                 pooler_address,
                 lockup_period,
+                unlock_schedule,
+                early_withdrawal_config,
             },
         }
     }
@@ -34,13 +40,16 @@ impl SinglePartyHolderInstantiate {
         self
     }
 
-    pub fn with_withdraw_to(&mut self, addr: String) -> &mut Self {
-        self.msg.withdraw_to = addr;
+    pub fn with_withdraw_to(&mut self, receiver: ReceiverConfig) -> &mut Self {
+        self.msg.withdraw_to = receiver;
         self
     }
 
-    pub fn with_emergency_committee_addr(&mut self, addr: Option<String>) -> &mut Self {
-        self.msg.emergency_committee_addr = addr;
+    pub fn with_emergency_committee(
+        &mut self,
+        config: Option<EmergencyCommitteeConfig>,
+    ) -> &mut Self {
+        self.msg.emergency_committee = config;
         self
     }
 
@@ -53,6 +62,16 @@ impl SinglePartyHolderInstantiate {
         self.msg.lockup_period = period;
         self
     }
+
+    pub fn with_unlock_schedule(&mut self, schedule: Option<UnlockSchedule>) -> &mut Self {
+        self.msg.unlock_schedule = schedule;
+        self
+    }
+
+    pub fn with_early_withdrawal_config(&mut self, config: EarlyWithdrawalConfig) -> &mut Self {
+        self.msg.early_withdrawal_config = config;
+        self
+    }
 }
 
 impl SinglePartyHolderInstantiate {
@@ -60,10 +79,17 @@ impl SinglePartyHolderInstantiate {
         Self {
             msg: valence_single_party_pol_holder::msg::InstantiateMsg {
                 withdrawer: pooler_address.to_string(),
-                withdraw_to: pooler_address.to_string(),
-                emergency_committee_addr: Some(pooler_address.to_string()),
+                withdraw_to: ReceiverConfig::Native(pooler_address.to_string()),
+                emergency_committee: Some(EmergencyCommitteeConfig {
+                    members: vec![pooler_address.to_string()],
+                    threshold: 1,
+                    timelock: Duration::Height(0),
+                    safe_mode: false,
+                }),
                 pooler_address,
                 lockup_period: Expiration::AtHeight(100000),
+                unlock_schedule: None,
+                early_withdrawal_config: EarlyWithdrawalConfig::Disabled,
             },
         }
     }
@@ -48,6 +48,9 @@ impl SwapCovenantInstantiate {
             None,
             None,
             ContractOperationModeConfig::Permissionless,
+            None,
+            None,
+            None,
         )
     }
 
@@ -126,6 +129,9 @@ impl SwapCovenantInstantiate {
         fallback_split: Option<covenant_utils::split::SplitConfig>,
         fallback_address: Option<String>,
         operation_mode: ContractOperationModeConfig,
+        min_fill_ratio: Option<Decimal>,
+        price_reference: Option<valence_covenant_swap::msg::PriceReferenceConfig>,
+        vesting: Option<valence_covenant_swap::msg::VestingConfig>,
     ) -> Self {
         Self {
             msg: valence_covenant_swap::msg::InstantiateMsg {
@@ -140,6 +146,10 @@ impl SwapCovenantInstantiate {
                 fallback_split,
                 fallback_address,
                 operation_mode,
+                min_fill_ratio,
+                price_reference,
+                vesting,
+                refund_config: None,
             },
         }
     }
@@ -218,4 +228,33 @@ impl SwapCovenantInstantiate {
         self.msg.fallback_address = Some(addr);
         self
     }
+
+    pub fn with_min_fill_ratio(&mut self, min_fill_ratio: Decimal) -> &mut Self {
+        self.msg.min_fill_ratio = Some(min_fill_ratio);
+        self
+    }
+
+    pub fn with_price_reference(
+        &mut self,
+        price_reference: valence_covenant_swap::msg::PriceReferenceConfig,
+    ) -> &mut Self {
+        self.msg.price_reference = Some(price_reference);
+        self
+    }
+
+    pub fn with_vesting(
+        &mut self,
+        vesting: valence_covenant_swap::msg::VestingConfig,
+    ) -> &mut Self {
+        self.msg.vesting = Some(vesting);
+        self
+    }
+
+    pub fn with_refund_config(
+        &mut self,
+        refund_config: valence_swap_holder::msg::RefundConfig,
+    ) -> &mut Self {
+        self.msg.refund_config = Some(refund_config);
+        self
+    }
 }
@@ -1,7 +1,7 @@
 use cosmwasm_std::{Addr, Uint128};
 use covenant_utils::{
-    op_mode::ContractOperationModeConfig, CovenantPartiesConfig, CovenantParty, CovenantTerms,
-    ReceiverConfig,
+    op_mode::ContractOperationModeConfig, split::SplitConfig, CovenantPartiesConfig, CovenantParty,
+    CovenantTerms, ReceiverConfig,
 };
 use cw_utils::Expiration;
 use valence_swap_holder::msg::RefundConfig;
@@ -26,6 +26,7 @@ impl SwapHolderInstantiate {
         covenant_terms: CovenantTerms,
         parties_config: CovenantPartiesConfig,
         refund_config: RefundConfig,
+        fallback_split: Option<SplitConfig>,
     ) -> Self {
         Self {
             msg: valence_swap_holder::msg::InstantiateMsg {
@@ -35,6 +36,7 @@ impl SwapHolderInstantiate {
                 covenant_terms,
                 parties_config,
                 refund_config,
+                fallback_split,
             },
         }
     }
@@ -68,6 +70,11 @@ impl SwapHolderInstantiate {
         self.msg.refund_config = config;
         self
     }
+
+    pub fn with_fallback_split(&mut self, split: Option<SplitConfig>) -> &mut Self {
+        self.msg.fallback_split = split;
+        self
+    }
 }
 
 impl SwapHolderInstantiate {
@@ -101,9 +108,10 @@ impl SwapHolderInstantiate {
                     },
                 },
                 refund_config: RefundConfig {
-                    party_a_refund_address,
-                    party_b_refund_address,
+                    party_a_refund_receiver: ReceiverConfig::Native(party_a_refund_address),
+                    party_b_refund_receiver: ReceiverConfig::Native(party_b_refund_address),
                 },
+                fallback_split: None,
             },
         }
     }
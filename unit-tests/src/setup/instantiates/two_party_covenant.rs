@@ -107,7 +107,10 @@ impl TwoPartyCovenantInstantiate {
         self
     }
 
-    pub fn with_emergency_committee(&mut self, emergency_committee: Option<String>) -> &mut Self {
+    pub fn with_emergency_committee(
+        &mut self,
+        emergency_committee: Option<covenant_utils::emergency_committee::EmergencyCommitteeConfig>,
+    ) -> &mut Self {
         self.msg.emergency_committee = emergency_committee;
         self
     }
@@ -191,6 +194,10 @@ impl TwoPartyCovenantInstantiate {
                                 asset_a_limit: Uint128::new(10_000),
                                 asset_b_limit: Uint128::new(10_000),
                             },
+                            lp_staking_config: None,
+                            zap_config: None,
+                            twap_window_seconds: None,
+                            withdraw_price_guard: None,
                         },
                     ),
                 fallback_address: None,
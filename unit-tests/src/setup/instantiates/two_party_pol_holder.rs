@@ -1,7 +1,10 @@
 use std::{collections::BTreeMap, str::FromStr};
 
 use cosmwasm_std::{coin, Addr, Decimal};
-use covenant_utils::{op_mode::ContractOperationModeConfig, split::SplitConfig};
+use covenant_utils::{
+    emergency_committee::EmergencyCommitteeConfig, op_mode::ContractOperationModeConfig,
+    split::SplitConfig,
+};
 use cw_utils::Expiration;
 
 use crate::setup::{DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN};
@@ -28,7 +31,7 @@ impl TwoPartyHolderInstantiate {
         covenant_config: valence_two_party_pol_holder::msg::TwoPartyPolCovenantConfig,
         splits: BTreeMap<String, SplitConfig>,
         fallback_split: Option<SplitConfig>,
-        emergency_committee_addr: Option<String>,
+        emergency_committee: Option<EmergencyCommitteeConfig>,
     ) -> Self {
         Self {
             msg: valence_two_party_pol_holder::msg::InstantiateMsg {
@@ -40,7 +43,8 @@ impl TwoPartyHolderInstantiate {
                 covenant_config,
                 splits,
                 fallback_split,
-                emergency_committee_addr,
+                emergency_committee,
+                pool_denoms: None,
             },
         }
     }
@@ -92,8 +96,13 @@ impl TwoPartyHolderInstantiate {
         self
     }
 
-    pub fn with_emergency_committee(&mut self, addr: &str) -> &mut Self {
-        self.msg.emergency_committee_addr = Some(addr.to_string());
+    pub fn with_emergency_committee(&mut self, config: EmergencyCommitteeConfig) -> &mut Self {
+        self.msg.emergency_committee = Some(config);
+        self
+    }
+
+    pub fn with_pool_denoms(&mut self, pool_denoms: Vec<String>) -> &mut Self {
+        self.msg.pool_denoms = Some(pool_denoms);
         self
     }
 }
@@ -123,14 +132,14 @@ impl TwoPartyHolderInstantiate {
                 deposit_deadline: Expiration::AtHeight(100000),
                 covenant_config: valence_two_party_pol_holder::msg::TwoPartyPolCovenantConfig {
                     party_a: valence_two_party_pol_holder::msg::TwoPartyPolCovenantParty {
-                        contribution: coin(10_000, DENOM_ATOM_ON_NTRN),
+                        contribution: vec![coin(10_000, DENOM_ATOM_ON_NTRN)],
                         host_addr: party_a_addr.to_string(),
                         controller_addr: party_a_addr.to_string(),
                         allocation: Decimal::from_str("0.5").unwrap(),
                         router: party_a_addr.to_string(),
                     },
                     party_b: valence_two_party_pol_holder::msg::TwoPartyPolCovenantParty {
-                        contribution: coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+                        contribution: vec![coin(10_000, DENOM_LS_ATOM_ON_NTRN)],
                         host_addr: party_b_addr.to_string(),
                         controller_addr: party_b_addr.to_string(),
                         allocation: Decimal::from_str("0.5").unwrap(),
@@ -140,7 +149,8 @@ impl TwoPartyHolderInstantiate {
                 },
                 splits: denom_to_split_config_map,
                 fallback_split: None,
-                emergency_committee_addr: None,
+                emergency_committee: None,
+                pool_denoms: None,
             },
         }
     }
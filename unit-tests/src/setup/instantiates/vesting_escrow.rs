@@ -0,0 +1,67 @@
+use std::collections::BTreeSet;
+
+use cosmwasm_std::{Addr, Uint64};
+use covenant_utils::op_mode::ContractOperationModeConfig;
+use valence_vesting_escrow::msg::VestingSchedule;
+
+use crate::setup::DENOM_ATOM_ON_NTRN;
+
+pub struct VestingEscrowInstantiate {
+    pub msg: valence_vesting_escrow::msg::InstantiateMsg,
+}
+
+impl From<VestingEscrowInstantiate> for valence_vesting_escrow::msg::InstantiateMsg {
+    fn from(value: VestingEscrowInstantiate) -> Self {
+        value.msg
+    }
+}
+
+impl VestingEscrowInstantiate {
+    pub fn new(
+        op_mode_cfg: ContractOperationModeConfig,
+        receiver_address: Addr,
+        denoms: BTreeSet<String>,
+        vesting_schedule: VestingSchedule,
+    ) -> Self {
+        Self {
+            msg: valence_vesting_escrow::msg::InstantiateMsg {
+                op_mode_cfg,
+                receiver_address: receiver_address.to_string(),
+                denoms,
+                vesting_schedule,
+            },
+        }
+    }
+
+    pub fn with_op_mode(&mut self, op_mode: ContractOperationModeConfig) -> &mut Self {
+        self.msg.op_mode_cfg = op_mode;
+        self
+    }
+
+    pub fn with_receiver_address(&mut self, addr: String) -> &mut Self {
+        self.msg.receiver_address = addr;
+        self
+    }
+
+    pub fn with_denoms(&mut self, denoms: BTreeSet<String>) -> &mut Self {
+        self.msg.denoms = denoms;
+        self
+    }
+
+    pub fn with_vesting_schedule(&mut self, vesting_schedule: VestingSchedule) -> &mut Self {
+        self.msg.vesting_schedule = vesting_schedule;
+        self
+    }
+}
+
+impl VestingEscrowInstantiate {
+    pub fn default(op_mode: ContractOperationModeConfig, receiver_address: Addr) -> Self {
+        let denoms = BTreeSet::from_iter(vec![DENOM_ATOM_ON_NTRN.to_string()]);
+        let vesting_schedule = VestingSchedule {
+            cliff: Uint64::new(100),
+            duration: Uint64::new(1000),
+        };
+
+        Self::new(op_mode, receiver_address, denoms, vesting_schedule)
+    }
+}
@@ -0,0 +1,174 @@
+use astroport::{
+    asset::AssetInfo,
+    generator::{Config, PendingTokenResponse},
+};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    coins, to_json_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Storage, Uint128, Uint64, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::{Item, Map};
+use neutron_sdk::bindings::{msg::NeutronMsg, query::NeutronQuery};
+
+/// stand-in of an astroport generator. every user with staked lp tokens
+/// accrues a fixed amount of native rewards per block, paid out of the
+/// generator balance whenever the user deposits, withdraws or claims.
+const REWARD_DENOM: Item<String> = Item::new("reward_denom");
+const REWARDS_PER_BLOCK: Item<Uint128> = Item::new("rewards_per_block");
+/// staked amounts keyed by (lp token, user)
+const DEPOSITS: Map<(&str, &str), Uint128> = Map::new("deposits");
+/// heights of the last reward payouts keyed by (lp token, user)
+const LAST_CLAIMS: Map<(&str, &str), u64> = Map::new("last_claims");
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub reward_denom: String,
+    pub rewards_per_block: Uint128,
+}
+
+/// subset of the generator messages used by the liquid pooler
+#[cw_serde]
+pub enum ExecuteMsg {
+    Receive(Cw20ReceiveMsg),
+    ClaimRewards { lp_tokens: Vec<String> },
+    Withdraw { lp_token: String, amount: Uint128 },
+}
+
+/// subset of the generator queries used by the liquid pooler
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Uint128)]
+    Deposit { lp_token: String, user: String },
+    #[returns(PendingTokenResponse)]
+    PendingToken { lp_token: String, user: String },
+    #[returns(Config)]
+    Config {},
+}
+
+fn query_pending(
+    storage: &dyn Storage,
+    env: &Env,
+    lp_token: &str,
+    user: &str,
+) -> StdResult<Uint128> {
+    let deposit = DEPOSITS
+        .may_load(storage, (lp_token, user))?
+        .unwrap_or_default();
+    if deposit.is_zero() {
+        return Ok(Uint128::zero());
+    }
+    let last_claim = LAST_CLAIMS
+        .may_load(storage, (lp_token, user))?
+        .unwrap_or(env.block.height);
+    Ok(REWARDS_PER_BLOCK.load(storage)? * Uint128::from(env.block.height - last_claim))
+}
+
+/// pays out the pending rewards of the user and resets their accrual
+fn claim(
+    storage: &mut dyn Storage,
+    env: &Env,
+    lp_token: &str,
+    user: &str,
+) -> StdResult<Vec<BankMsg>> {
+    let pending = query_pending(storage, env, lp_token, user)?;
+    LAST_CLAIMS.save(storage, (lp_token, user), &env.block.height)?;
+    if pending.is_zero() {
+        return Ok(vec![]);
+    }
+    Ok(vec![BankMsg::Send {
+        to_address: user.to_string(),
+        amount: coins(pending.u128(), REWARD_DENOM.load(storage)?),
+    }])
+}
+
+pub fn mock_astro_generator_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    let exec = |deps: DepsMut<NeutronQuery>,
+                env: Env,
+                info: MessageInfo,
+                msg: ExecuteMsg|
+     -> StdResult<Response<NeutronMsg>> {
+        match msg {
+            // any cw20 hook is treated as a deposit
+            ExecuteMsg::Receive(cw20_msg) => {
+                let key = (info.sender.as_str(), cw20_msg.sender.as_str());
+                let payout = claim(deps.storage, &env, key.0, key.1)?;
+                DEPOSITS.update(deps.storage, key, |deposit| -> StdResult<_> {
+                    Ok(deposit.unwrap_or_default().checked_add(cw20_msg.amount)?)
+                })?;
+                Ok(Response::default().add_messages(payout))
+            }
+            ExecuteMsg::ClaimRewards { lp_tokens } => {
+                let mut payouts = vec![];
+                for lp_token in lp_tokens {
+                    payouts.extend(claim(deps.storage, &env, &lp_token, info.sender.as_str())?);
+                }
+                Ok(Response::default().add_messages(payouts))
+            }
+            ExecuteMsg::Withdraw { lp_token, amount } => {
+                let key = (lp_token.as_str(), info.sender.as_str());
+                let payout = claim(deps.storage, &env, key.0, key.1)?;
+                DEPOSITS.update(deps.storage, key, |deposit| -> StdResult<_> {
+                    Ok(deposit.unwrap_or_default().checked_sub(amount)?)
+                })?;
+                Ok(Response::default()
+                    .add_messages(payout)
+                    .add_message(WasmMsg::Execute {
+                        contract_addr: lp_token.to_string(),
+                        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                            recipient: info.sender.to_string(),
+                            amount,
+                        })?,
+                        funds: vec![],
+                    }))
+            }
+        }
+    };
+
+    let init = |deps: DepsMut<NeutronQuery>,
+                _env: Env,
+                _info: MessageInfo,
+                msg: InstantiateMsg|
+     -> StdResult<Response<NeutronMsg>> {
+        REWARD_DENOM.save(deps.storage, &msg.reward_denom)?;
+        REWARDS_PER_BLOCK.save(deps.storage, &msg.rewards_per_block)?;
+        Ok(Response::default())
+    };
+
+    let query = |deps: Deps<NeutronQuery>, env: Env, msg: QueryMsg| -> StdResult<Binary> {
+        match msg {
+            QueryMsg::Deposit { lp_token, user } => to_json_binary(
+                &DEPOSITS
+                    .may_load(deps.storage, (lp_token.as_str(), user.as_str()))?
+                    .unwrap_or_default(),
+            ),
+            QueryMsg::PendingToken { lp_token, user } => to_json_binary(&PendingTokenResponse {
+                pending: query_pending(deps.storage, &env, &lp_token, &user)?,
+                pending_on_proxy: None,
+            }),
+            QueryMsg::Config {} => to_json_binary(&Config {
+                owner: env.contract.address.clone(),
+                factory: env.contract.address.clone(),
+                generator_controller: None,
+                voting_escrow: None,
+                voting_escrow_delegation: None,
+                astro_token: AssetInfo::NativeToken {
+                    denom: REWARD_DENOM.load(deps.storage)?,
+                },
+                tokens_per_block: REWARDS_PER_BLOCK.load(deps.storage)?,
+                total_alloc_point: Uint128::zero(),
+                start_block: Uint64::zero(),
+                allowed_reward_proxies: vec![],
+                vesting_contract: env.contract.address,
+                active_pools: vec![],
+                blocked_tokens_list: vec![],
+                guardian: None,
+                checkpoint_generator_limit: None,
+            }),
+        }
+    };
+
+    Box::new(ContractWrapper::new(exec, init, query))
+}
@@ -0,0 +1,62 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use covenant_utils::polytone::PolytoneExecuteMsg;
+use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::Item;
+use neutron_sdk::bindings::{msg::NeutronMsg, query::NeutronQuery};
+
+/// stand-in of a polytone note. it records the messages it is asked to
+/// relay instead of relaying them, leaving it up to the tests to deliver
+/// (or lose) the callbacks.
+const PROXY_ADDRESS: Item<String> = Item::new("proxy_address");
+const REQUESTS: Item<Vec<PolytoneExecuteMsg>> = Item::new("requests");
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// remote address reported for any local address
+    pub proxy_address: String,
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Option<String>)]
+    RemoteAddress { local_address: String },
+    /// all the messages relayed so far, in order
+    #[returns(Vec<PolytoneExecuteMsg>)]
+    Requests {},
+}
+
+pub fn mock_polytone_note_contract() -> Box<dyn Contract<NeutronMsg, NeutronQuery>> {
+    let exec = |deps: DepsMut<NeutronQuery>,
+                _env: Env,
+                _info: MessageInfo,
+                msg: PolytoneExecuteMsg|
+     -> StdResult<Response<NeutronMsg>> {
+        let mut requests = REQUESTS.load(deps.storage)?;
+        requests.push(msg);
+        REQUESTS.save(deps.storage, &requests)?;
+        Ok(Response::default())
+    };
+
+    let init = |deps: DepsMut<NeutronQuery>,
+                _env: Env,
+                _info: MessageInfo,
+                msg: InstantiateMsg|
+     -> StdResult<Response<NeutronMsg>> {
+        PROXY_ADDRESS.save(deps.storage, &msg.proxy_address)?;
+        REQUESTS.save(deps.storage, &vec![])?;
+        Ok(Response::default())
+    };
+
+    let query = |deps: Deps<NeutronQuery>, _env: Env, msg: QueryMsg| -> StdResult<Binary> {
+        match msg {
+            QueryMsg::RemoteAddress { local_address: _ } => {
+                to_json_binary(&PROXY_ADDRESS.may_load(deps.storage)?)
+            }
+            QueryMsg::Requests {} => to_json_binary(&REQUESTS.load(deps.storage)?),
+        }
+    };
+
+    Box::new(ContractWrapper::new(exec, init, query))
+}
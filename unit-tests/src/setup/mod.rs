@@ -1,5 +1,5 @@
 use const_format::concatcp;
-use cosmwasm_std::{Empty, MemoryStorage};
+use cosmwasm_std::{Addr, Empty, MemoryStorage};
 use cw_multi_test::{
     addons::MockApiBech32, App, BankKeeper, DistributionKeeper, GovFailingModule, IbcFailingModule,
     StakeKeeper, StargateMsg, StargateQuery, WasmKeeper,
@@ -14,6 +14,8 @@ pub mod contracts;
 pub mod custom_keepers;
 pub mod custom_module;
 pub mod instantiates;
+pub mod mock_astro_generator;
+pub mod mock_polytone_note;
 pub mod suite_builder;
 
 pub type CustomApp = App<
@@ -29,6 +31,18 @@ pub type CustomApp = App<
     CustomStargateKeeper<StargateMsg, StargateQuery, Empty>,
 >;
 
+/// raw storage key of a contract state item, as laid out by the multi-test wasm keeper
+pub fn get_contract_item_key(contract: &Addr, key: &str) -> Vec<u8> {
+    let namespace = format!("contract_data/{contract}");
+    [
+        b"\0\x04wasm".as_slice(),
+        &[0, namespace.len() as u8],
+        namespace.as_bytes(),
+        key.as_bytes(),
+    ]
+    .concat()
+}
+
 // TODO: Notes
 // 1. The ls/lp forwader config in the single party is really confusing, because you only use like half of the fields in the actual contract, I think a special config
 //    that is needed.
@@ -64,15 +78,24 @@ pub const CLOCK_SALT: &str = "clock";
 pub const SWAP_COVENANT_SALT: &str = "swap_covenant";
 pub const SINGLE_PARTY_COVENANT_SALT: &str = "single_party_covenant";
 pub const TWO_PARTY_COVENANT_SALT: &str = "two_party_covenant";
+pub const MULTI_PARTY_COVENANT_SALT: &str = "multi_party_covenant";
+pub const MULTI_PARTY_SWAP_COVENANT_SALT: &str = "multi_party_swap_covenant";
 pub const SWAP_HOLDER_SALT: &str = "swap_holder";
 pub const TWO_PARTY_HOLDER_SALT: &str = "two_party_holder";
+pub const MULTI_PARTY_HOLDER_SALT: &str = "multi_party_holder";
+pub const MULTI_PARTY_SWAP_HOLDER_SALT: &str = "multi_party_swap_holder";
 pub const SINGLE_PARTY_HOLDER_SALT: &str = "single_party_holder";
 pub const ASTRO_LIQUID_POOLER_SALT: &str = "astro_liquid_pooler";
+pub const ASTRO_GENERATOR_SALT: &str = "astro_generator";
+pub const DUALITY_LIQUID_POOLER_SALT: &str = "duality_liquid_pooler";
+pub const OSMO_LIQUID_POOLER_SALT: &str = "osmo_liquid_pooler";
+pub const POLYTONE_NOTE_SALT: &str = "polytone_note";
 pub const NATIVE_SPLITTER_SALT: &str = "native_splitter";
 pub const REMOTE_CHAIN_SPLITTER_SALT: &str = "remote_chain_splitter";
 pub const INTERCHAIN_ROUTER_SALT: &str = "interchain_router";
 pub const NATIVE_ROUTER_SALT: &str = "native_router";
 pub const IBC_FORWARDER_SALT: &str = "ibc_forwarder";
+pub const VESTING_ESCROW_SALT: &str = "vesting_escrow";
 
 // Channels between the chains
 pub const NTRN_HUB_CHANNEL: (&str, &str) = ("channel-1", "channel-100");
@@ -3,14 +3,15 @@ use std::str::FromStr;
 use astroport::pair_concentrated::ConcentratedPoolParams;
 use cosmwasm_schema::serde::Serialize;
 use cosmwasm_std::{
-    coin, coins, instantiate2_address, to_json_binary, Addr, Api, CodeInfoResponse, Coin, Decimal,
-    Empty,
+    coin, coins, instantiate2_address, to_json_binary, Addr, Api, Binary, CodeInfoResponse, Coin,
+    CosmosMsg, Decimal, Empty,
 };
 use cw_multi_test::{
     addons::{MockAddressGenerator, MockApiBech32},
     BasicAppBuilder, Executor, Stargate, StargateMsg, StargateQuery, WasmKeeper,
 };
 
+use prost::Message;
 use sha2::{Digest, Sha256};
 
 use super::{
@@ -20,14 +21,19 @@ use super::{
         astro_pair_xyk_contract, astro_token_contract, astro_whitelist_contract,
     },
     contracts::{
-        astroport_pooler_contract, clock_contract, ibc_forwarder_contract,
-        interchain_router_contract, native_router_contract, native_splitter_contract,
-        osmo_lp_outpost_contract, remote_splitter_contract, single_party_covenant_contract,
+        astroport_pooler_contract, clock_contract, duality_pooler_contract, ibc_forwarder_contract,
+        interchain_router_contract, multi_party_covenant_contract, multi_party_holder_contract,
+        multi_party_swap_covenant_contract, multi_party_swap_holder_contract,
+        native_router_contract, native_splitter_contract, osmo_lp_outpost_contract,
+        osmo_pooler_contract, remote_splitter_contract, single_party_covenant_contract,
         single_party_holder_contract, stride_lser_contract, swap_covenant_contract,
         swap_holder_contract, two_party_covenant_contract, two_party_holder_contract,
+        vesting_escrow_contract,
     },
     custom_keepers::CustomStargateKeeper,
     custom_module::{NeutronKeeper, CHAIN_PREFIX},
+    mock_astro_generator::mock_astro_generator_contract,
+    mock_polytone_note::mock_polytone_note_contract,
     CustomApp, ADMIN, ALL_DENOMS, DENOM_NTRN, FAUCET, HUB_OSMO_CHANNEL, HUB_STRIDE_CHANNEL,
     NTRN_HUB_CHANNEL, NTRN_OSMO_CHANNEL, NTRN_STRIDE_CHANNEL,
 };
@@ -47,6 +53,8 @@ pub struct SuiteBuilder {
     pub swap_covenant_code_id: u64,
     pub single_party_covenant_code_id: u64,
     pub two_party_covenant_code_id: u64,
+    pub multi_party_covenant_code_id: u64,
+    pub multi_party_swap_covenant_code_id: u64,
 
     // Modules code ids
     pub clock_code_id: u64,
@@ -58,9 +66,15 @@ pub struct SuiteBuilder {
     pub remote_splitter_code_id: u64,
     pub native_splitter_code_id: u64,
     pub astro_pooler_code_id: u64,
+    pub duality_pooler_code_id: u64,
+    pub osmo_pooler_code_id: u64,
     pub stride_staker_code_id: u64,
     pub two_party_holder_code_id: u64,
+    pub multi_party_holder_code_id: u64,
+    pub multi_party_swap_holder_code_id: u64,
     pub osmo_lp_outpost_code_id: u64,
+    pub vesting_escrow_code_id: u64,
+    pub polytone_note_code_id: u64,
 
     // astro contracts
     pub astro_token_code_id: u64,
@@ -70,6 +84,7 @@ pub struct SuiteBuilder {
     pub astro_pair_xyk_code_id: u64,
     pub astro_coin_registry_code_id: u64,
     pub astro_pair_concentrated_code_id: u64,
+    pub astro_generator_code_id: u64,
 }
 impl Default for SuiteBuilder {
     fn default() -> Self {
@@ -129,9 +144,15 @@ impl SuiteBuilder {
         let native_router_code_id = app.store_code(native_router_contract());
         let ibc_forwarder_code_id = app.store_code(ibc_forwarder_contract());
         let astro_pooler_code_id = app.store_code(astroport_pooler_contract());
+        let duality_pooler_code_id = app.store_code(duality_pooler_contract());
+        let osmo_pooler_code_id = app.store_code(osmo_pooler_contract());
         let stride_staker_code_id = app.store_code(stride_lser_contract());
         let two_party_holder_code_id = app.store_code(two_party_holder_contract());
+        let multi_party_holder_code_id = app.store_code(multi_party_holder_contract());
+        let multi_party_swap_holder_code_id = app.store_code(multi_party_swap_holder_contract());
         let osmo_lp_outpost_code_id = app.store_code(osmo_lp_outpost_contract());
+        let vesting_escrow_code_id = app.store_code(vesting_escrow_contract());
+        let polytone_note_code_id = app.store_code(mock_polytone_note_contract());
 
         let astro_token_code_id = app.store_code(astro_token_contract());
         let astro_whitelist_code_id = app.store_code(astro_whitelist_contract());
@@ -141,8 +162,12 @@ impl SuiteBuilder {
         let astro_pair_concentrated_code_id =
             app.store_code(astro_pair_custom_concentrated_contract());
         let astro_coin_registry_code_id = app.store_code(astro_coin_registry_contract());
+        let astro_generator_code_id = app.store_code(mock_astro_generator_contract());
 
         let two_party_covenant_code_id = app.store_code(two_party_covenant_contract());
+        let multi_party_covenant_code_id = app.store_code(multi_party_covenant_contract());
+        let multi_party_swap_covenant_code_id =
+            app.store_code(multi_party_swap_covenant_contract());
 
         Self {
             faucet: app.api().addr_make(FAUCET),
@@ -154,6 +179,8 @@ impl SuiteBuilder {
             swap_covenant_code_id,
             single_party_covenant_code_id,
             two_party_covenant_code_id,
+            multi_party_covenant_code_id,
+            multi_party_swap_covenant_code_id,
 
             clock_code_id,
             swap_holder_code_id,
@@ -164,9 +191,15 @@ impl SuiteBuilder {
             remote_splitter_code_id,
             native_splitter_code_id,
             astro_pooler_code_id,
+            duality_pooler_code_id,
+            osmo_pooler_code_id,
             stride_staker_code_id,
             two_party_holder_code_id,
+            multi_party_holder_code_id,
+            multi_party_swap_holder_code_id,
             osmo_lp_outpost_code_id,
+            vesting_escrow_code_id,
+            polytone_note_code_id,
 
             astro_token_code_id,
             astro_whitelist_code_id,
@@ -175,6 +208,7 @@ impl SuiteBuilder {
             astro_pair_xyk_code_id,
             astro_coin_registry_code_id,
             astro_pair_concentrated_code_id,
+            astro_generator_code_id,
         }
     }
 
@@ -184,6 +218,17 @@ impl SuiteBuilder {
         pair_type: astroport::factory::PairType,
         coin_a: Coin,
         coin_b: Coin,
+    ) -> (Addr, Addr) {
+        self.init_astro_pool_with_decimals(pair_type, coin_a, coin_b, (6, 6))
+    }
+
+    // Init pool with the given coin a and b decimals and return the addr
+    pub fn init_astro_pool_with_decimals(
+        &mut self,
+        pair_type: astroport::factory::PairType,
+        coin_a: Coin,
+        coin_b: Coin,
+        decimals: (u8, u8),
     ) -> (Addr, Addr) {
         let registery_init = astroport::native_coin_registry::InstantiateMsg {
             owner: self.admin.to_string(),
@@ -207,7 +252,10 @@ impl SuiteBuilder {
                 self.admin.clone(),
                 coin_registry_addr.clone(),
                 &astroport::native_coin_registry::ExecuteMsg::Add {
-                    native_coins: vec![(coin_a.denom.clone(), 6), (coin_b.denom.clone(), 6)],
+                    native_coins: vec![
+                        (coin_a.denom.clone(), decimals.0),
+                        (coin_b.denom.clone(), decimals.1),
+                    ],
                 },
                 &[],
             )
@@ -361,6 +409,35 @@ impl SuiteBuilder {
 
         (pool_info.contract_addr, pool_info.liquidity_token)
     }
+
+    /// seeds a duality pool at the given tick range by depositing from the faucet
+    pub fn init_duality_pool(
+        &mut self,
+        coin_a: Coin,
+        coin_b: Coin,
+        tick_range: valence_duality_liquid_pooler::msg::TickRange,
+    ) {
+        let msg = valence_duality_liquid_pooler::dex::MsgDeposit {
+            creator: self.faucet.to_string(),
+            receiver: self.faucet.to_string(),
+            token_a: coin_a.denom,
+            token_b: coin_b.denom,
+            amounts_a: vec![coin_a.amount.to_string()],
+            amounts_b: vec![coin_b.amount.to_string()],
+            tick_indexes_a_to_b: vec![tick_range.tick_index],
+            fees: vec![tick_range.fee],
+            options: vec![],
+        };
+        self.app
+            .execute(
+                self.faucet.clone(),
+                CosmosMsg::Stargate {
+                    type_url: valence_duality_liquid_pooler::dex::MSG_DEPOSIT_TYPE_URL.to_string(),
+                    value: Binary(msg.encode_to_vec()),
+                },
+            )
+            .unwrap();
+    }
     /// Add IBC channels for the neutron module
     pub fn add_channels(
         &mut self,
@@ -1,21 +1,28 @@
 use astroport::factory::PairType;
-use cosmwasm_std::{coin, Addr, Coin, Decimal};
+use cosmwasm_std::{coin, coins, Addr, Coin, Decimal, Uint128};
 use covenant_utils::{
     op_mode::{ContractOperationMode, ContractOperationModeConfig},
-    PoolPriceConfig, SingleSideLpLimits,
+    PoolPriceConfig, SingleSideLpLimits, WithdrawPriceGuard,
 };
 use cw_multi_test::{AppResponse, Executor};
 use cw_utils::Expiration;
-use valence_astroport_liquid_pooler::msg::{LpConfig, ProvidedLiquidityInfo, QueryMsg};
+use liquid_pooler_interface::{LiquidPoolerContract, LiquidityPosition};
+use valence_astroport_liquid_pooler::msg::{
+    LpConfig, LpStakingConfig, ProvidedLiquidityInfo, QueryMsg, RewardsDestination,
+    StakedLiquidityInfo, ZapConfig,
+};
 
 use crate::setup::{
     base_suite::{BaseSuite, BaseSuiteMut},
     instantiates::astro_liquid_pooler::AstroLiquidPoolerInstantiate,
+    mock_astro_generator,
     suite_builder::SuiteBuilder,
-    CustomApp, ASTRO_LIQUID_POOLER_SALT, CLOCK_SALT, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN,
-    SINGLE_PARTY_HOLDER_SALT,
+    CustomApp, ASTRO_GENERATOR_SALT, ASTRO_LIQUID_POOLER_SALT, CLOCK_SALT, DENOM_ATOM_ON_NTRN,
+    DENOM_LS_ATOM_ON_NTRN, DENOM_NTRN, SINGLE_PARTY_HOLDER_SALT,
 };
 
+pub const MOCK_GENERATOR_REWARDS_PER_BLOCK: u128 = 1_000;
+
 pub struct AstroLiquidPoolerBuilder {
     pub builder: SuiteBuilder,
     pub instantiate_msg: AstroLiquidPoolerInstantiate,
@@ -44,10 +51,13 @@ impl Default for AstroLiquidPoolerBuilder {
 
         let holder_instantiate_msg = valence_single_party_pol_holder::msg::InstantiateMsg {
             withdrawer: clock_addr.to_string(),
-            withdraw_to: holder_addr.to_string(),
-            emergency_committee_addr: None,
+            withdraw_to: covenant_utils::ReceiverConfig::Native(holder_addr.to_string()),
+            emergency_committee: None,
             pooler_address: liquid_pooler_addr.to_string(),
             lockup_period: cw_utils::Expiration::AtHeight(123665),
+            unlock_schedule: None,
+            early_withdrawal_config:
+                valence_single_party_pol_holder::msg::EarlyWithdrawalConfig::Disabled,
         };
 
         let clock_instantiate_msg = valence_clock::msg::InstantiateMsg {
@@ -97,6 +107,21 @@ impl AstroLiquidPoolerBuilder {
         self
     }
 
+    pub fn with_custom_astroport_pool_decimals(
+        mut self,
+        pair_type: PairType,
+        coin_a: Coin,
+        coin_b: Coin,
+        decimals: (u8, u8),
+    ) -> Self {
+        let (pool_addr, _lp_token_addr) = self
+            .builder
+            .init_astro_pool_with_decimals(pair_type, coin_a, coin_b, decimals);
+        self.instantiate_msg
+            .with_pool_address(pool_addr.to_string());
+        self
+    }
+
     pub fn with_pool_address(mut self, pool_address: String) -> Self {
         self.instantiate_msg.with_pool_address(pool_address);
         self
@@ -140,6 +165,59 @@ impl AstroLiquidPoolerBuilder {
         self
     }
 
+    pub fn with_twap_window_seconds(mut self, twap_window_seconds: Option<u64>) -> Self {
+        self.instantiate_msg
+            .with_twap_window_seconds(twap_window_seconds);
+        self
+    }
+
+    pub fn with_withdraw_price_guard(
+        mut self,
+        withdraw_price_guard: Option<WithdrawPriceGuard>,
+    ) -> Self {
+        self.instantiate_msg
+            .with_withdraw_price_guard(withdraw_price_guard);
+        self
+    }
+
+    pub fn with_zap_config(mut self, zap_config: Option<ZapConfig>) -> Self {
+        self.instantiate_msg.with_zap_config(zap_config);
+        self
+    }
+
+    pub fn with_lp_staking_config(mut self, lp_staking_config: Option<LpStakingConfig>) -> Self {
+        self.instantiate_msg
+            .with_lp_staking_config(lp_staking_config);
+        self
+    }
+
+    /// stakes the lp tokens in a mock generator paying out
+    /// `MOCK_GENERATOR_REWARDS_PER_BLOCK` untrn per block
+    pub fn with_mock_generator(mut self, rewards_destination: RewardsDestination) -> Self {
+        let generator_addr = self.builder.contract_init2(
+            self.builder.astro_generator_code_id,
+            ASTRO_GENERATOR_SALT,
+            &mock_astro_generator::InstantiateMsg {
+                reward_denom: DENOM_NTRN.to_string(),
+                rewards_per_block: Uint128::new(MOCK_GENERATOR_REWARDS_PER_BLOCK),
+            },
+            &[],
+        );
+        self.builder
+            .app
+            .send_tokens(
+                self.builder.faucet.clone(),
+                generator_addr.clone(),
+                &coins(1_000_000_000_000, DENOM_NTRN),
+            )
+            .unwrap();
+
+        self.with_lp_staking_config(Some(LpStakingConfig {
+            generator_address: generator_addr.to_string(),
+            rewards_destination,
+        }))
+    }
+
     pub fn build(mut self) -> Suite {
         let liquid_pooler_address = self.builder.contract_init2(
             self.builder.astro_pooler_code_id,
@@ -224,12 +302,36 @@ impl Suite {
         app.execute_contract(
             sender.clone(),
             holder,
-            &valence_single_party_pol_holder::msg::ExecuteMsg::Claim {},
+            &valence_single_party_pol_holder::msg::ExecuteMsg::Claim { percentage: None },
             &[],
         )
         .unwrap()
     }
 
+    pub(crate) fn swap_in_pool(&mut self, offer_coin: Coin) -> AppResponse {
+        let faucet = self.faucet.clone();
+        let pool = self.lp_config.pool_address.clone();
+        let app = self.get_app();
+        app.execute_contract(
+            faucet,
+            pool,
+            &astroport::pair::ExecuteMsg::Swap {
+                offer_asset: astroport::asset::Asset {
+                    info: astroport::asset::AssetInfo::NativeToken {
+                        denom: offer_coin.denom.to_string(),
+                    },
+                    amount: offer_coin.amount,
+                },
+                ask_asset_info: None,
+                belief_price: None,
+                max_spread: Some(Decimal::percent(20)),
+                to: None,
+            },
+            &[offer_coin],
+        )
+        .unwrap()
+    }
+
     pub(crate) fn expire_lockup(&mut self) {
         let holder = self.holder_addr.clone();
         let expiration: Expiration = self
@@ -258,6 +360,44 @@ impl Suite {
             .unwrap()
     }
 
+    /// queries the pooler through the venue agnostic interface
+    pub(crate) fn query_liquidity_position(&self) -> LiquidityPosition {
+        LiquidPoolerContract(self.liquid_pooler_addr.clone())
+            .query_liquidity_position(self.get_app().wrap())
+            .unwrap()
+    }
+
+    pub(crate) fn query_twap(&self) -> Option<Decimal> {
+        self.get_app()
+            .wrap()
+            .query_wasm_smart(self.liquid_pooler_addr.clone(), &QueryMsg::Twap {})
+            .unwrap()
+    }
+
+    /// provides liquidity on the first tick and stakes the
+    /// obtained lp tokens on the second one
+    pub(crate) fn provide_and_stake_liquidity(&mut self) -> StakedLiquidityInfo {
+        let liquid_pooler = self.liquid_pooler_addr.clone();
+        self.fund_contract(&coins(500_001, DENOM_ATOM_ON_NTRN), liquid_pooler.clone());
+        self.fund_contract(
+            &coins(500_001, DENOM_LS_ATOM_ON_NTRN),
+            liquid_pooler.clone(),
+        );
+        self.tick_contract(liquid_pooler.clone());
+        self.tick_contract(liquid_pooler);
+        self.query_staked_liquidity().unwrap()
+    }
+
+    pub(crate) fn query_staked_liquidity(&self) -> Option<StakedLiquidityInfo> {
+        self.get_app()
+            .wrap()
+            .query_wasm_smart(
+                self.liquid_pooler_addr.clone(),
+                &QueryMsg::StakedLiquidity {},
+            )
+            .unwrap()
+    }
+
     pub(crate) fn query_contract_state(
         &self,
     ) -> valence_astroport_liquid_pooler::msg::ContractState {
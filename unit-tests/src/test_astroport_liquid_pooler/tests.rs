@@ -1,19 +1,22 @@
 use std::str::FromStr;
 
+use astroport::asset::{Asset, AssetInfo, PairInfo};
 use cosmwasm_std::{coin, coins, Addr, Decimal, Event, Uint128};
 use covenant_utils::{
     op_mode::{ContractOperationMode, ContractOperationModeConfig},
-    PoolPriceConfig,
+    PoolPriceConfig, WithdrawPriceGuard,
 };
 use cw_multi_test::Executor;
-use valence_astroport_liquid_pooler::msg::{AssetData, ProvidedLiquidityInfo};
+use valence_astroport_liquid_pooler::msg::{
+    AssetData, LpStakingConfig, ProvidedLiquidityInfo, RewardsDestination, ZapConfig,
+};
 
 use crate::setup::{
     base_suite::{BaseSuite, BaseSuiteMut},
-    ADMIN, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN,
+    ADMIN, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, DENOM_NTRN,
 };
 
-use super::suite::AstroLiquidPoolerBuilder;
+use super::suite::{AstroLiquidPoolerBuilder, MOCK_GENERATOR_REWARDS_PER_BLOCK};
 
 #[test]
 fn test_instantiate_with_valid_op_mode() {
@@ -78,6 +81,33 @@ fn test_instantiate_validates_holder_address() {
         .build();
 }
 
+#[test]
+#[should_panic]
+fn test_instantiate_validates_lp_staking_generator_address() {
+    AstroLiquidPoolerBuilder::default()
+        .with_lp_staking_config(Some(LpStakingConfig {
+            generator_address: "not a generator".to_string(),
+            rewards_destination: RewardsDestination::Holder {},
+        }))
+        .build();
+}
+
+#[test]
+#[should_panic]
+fn test_instantiate_validates_lp_staking_swap_pairs() {
+    let builder = AstroLiquidPoolerBuilder::default();
+    let generator = builder.builder.admin.to_string();
+
+    builder
+        .with_lp_staking_config(Some(LpStakingConfig {
+            generator_address: generator,
+            rewards_destination: RewardsDestination::Compound {
+                swap_pairs: vec!["not a pair".to_string()],
+            },
+        }))
+        .build();
+}
+
 #[test]
 #[should_panic(expected = "Cannot Sub with 1 and 2")]
 fn test_instantiate_validates_pool_price_config_upper_bound() {
@@ -247,6 +277,311 @@ fn test_withdraw_no_percentage_defaults_to_full_position() {
     suite.assert_balance(&holder, coin(500_000, DENOM_LS_ATOM_ON_NTRN));
 }
 
+#[test]
+fn test_withdraw_within_withdraw_price_guard() {
+    let mut suite = AstroLiquidPoolerBuilder::default()
+        .with_withdraw_price_guard(Some(WithdrawPriceGuard::PriceRange(PoolPriceConfig {
+            expected_spot_price: Decimal::one(),
+            acceptable_price_spread: Decimal::from_str("0.1").unwrap(),
+        })))
+        .build();
+    let withdrawer = suite.clock_addr.clone();
+    let holder = suite.holder_addr.clone();
+
+    suite.fund_contract(
+        &coins(500_001, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_001, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+
+    suite.assert_balance(&holder, coin(500_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&holder, coin(500_000, DENOM_LS_ATOM_ON_NTRN));
+}
+
+#[test]
+#[should_panic(expected = "Price range error")]
+fn test_withdraw_validates_withdraw_price_guard_price_range() {
+    let mut suite = AstroLiquidPoolerBuilder::default()
+        .with_withdraw_price_guard(Some(WithdrawPriceGuard::PriceRange(PoolPriceConfig {
+            expected_spot_price: Decimal::from_str("2.0").unwrap(),
+            acceptable_price_spread: Decimal::from_str("0.5").unwrap(),
+        })))
+        .build();
+    let withdrawer = suite.clock_addr.clone();
+
+    suite.fund_contract(
+        &coins(500_001, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_001, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+}
+
+#[test]
+#[should_panic(expected = "Withdrawal below the minimum amount")]
+fn test_withdraw_validates_withdraw_price_guard_min_assets_per_share() {
+    let mut suite = AstroLiquidPoolerBuilder::default()
+        .with_withdraw_price_guard(Some(WithdrawPriceGuard::MinAssetsPerShare {
+            asset_a: Decimal::from_str("1000.0").unwrap(),
+            asset_b: Decimal::from_str("1000.0").unwrap(),
+        }))
+        .build();
+    let withdrawer = suite.clock_addr.clone();
+
+    suite.fund_contract(
+        &coins(500_001, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_001, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+}
+
+#[test]
+fn test_query_liquidity_position() {
+    let mut suite = AstroLiquidPoolerBuilder::default().build();
+    let withdrawer = suite.clock_addr.clone();
+
+    let position = suite.query_liquidity_position();
+    assert!(position.amount.is_zero());
+
+    suite.fund_contract(
+        &coins(500_001, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_001, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    let pair_info: PairInfo = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            suite.lp_config.pool_address.clone(),
+            &astroport::pair::QueryMsg::Pair {},
+        )
+        .unwrap();
+    let position = suite.query_liquidity_position();
+    assert!(!position.amount.is_zero());
+    assert_eq!(position.lp_token, pair_info.liquidity_token.to_string());
+
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+
+    let position = suite.query_liquidity_position();
+    assert!(position.amount.is_zero());
+}
+
+#[test]
+fn test_tick_without_lp_staking_keeps_lp_tokens() {
+    let mut suite = AstroLiquidPoolerBuilder::default().build();
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+    let position = suite.query_liquidity_position();
+
+    // further ticks leave the lp tokens with the liquid pooler
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    assert_eq!(suite.query_liquidity_position(), position);
+    assert_eq!(suite.query_staked_liquidity(), None);
+}
+
+#[test]
+#[should_panic(expected = "Single party holder cannot distribute staking rewards")]
+fn test_instantiate_rejects_holder_rewards_for_single_party_holder() {
+    AstroLiquidPoolerBuilder::default()
+        .with_mock_generator(RewardsDestination::Holder {})
+        .build();
+}
+
+#[test]
+fn test_tick_stakes_lp_tokens() {
+    let builder = AstroLiquidPoolerBuilder::default();
+    let receiver = builder.builder.app.api().addr_make("rewards_receiver");
+    let mut suite = builder
+        .with_mock_generator(RewardsDestination::Receiver {
+            address: receiver.to_string(),
+        })
+        .build();
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    // lp tokens obtained during a tick get staked on the next one
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+    let position = suite.query_liquidity_position();
+    assert!(!position.amount.is_zero());
+    assert!(suite
+        .query_staked_liquidity()
+        .unwrap()
+        .staked_amount
+        .is_zero());
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+    let staked_liquidity = suite.query_staked_liquidity().unwrap();
+    assert_eq!(staked_liquidity.staked_amount, position.amount);
+    assert_eq!(staked_liquidity.lp_token, position.lp_token);
+    assert_eq!(suite.query_liquidity_position(), position);
+}
+
+#[test]
+fn test_tick_claims_staking_rewards() {
+    let builder = AstroLiquidPoolerBuilder::default();
+    let receiver = builder.builder.app.api().addr_make("rewards_receiver");
+    let mut suite = builder
+        .with_mock_generator(RewardsDestination::Receiver {
+            address: receiver.to_string(),
+        })
+        .build();
+    suite.provide_and_stake_liquidity();
+
+    suite.app.update_block(|b| b.height += 10);
+    let expected_rewards = 10 * MOCK_GENERATOR_REWARDS_PER_BLOCK;
+    assert_eq!(
+        suite.query_staked_liquidity().unwrap().pending_rewards,
+        vec![Asset {
+            info: AssetInfo::NativeToken {
+                denom: DENOM_NTRN.to_string(),
+            },
+            amount: Uint128::new(expected_rewards),
+        }]
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    suite.assert_balance(&receiver, coin(expected_rewards, DENOM_NTRN));
+    suite.assert_balance(suite.liquid_pooler_addr.clone(), coin(0, DENOM_NTRN));
+    assert!(suite
+        .query_staked_liquidity()
+        .unwrap()
+        .pending_rewards
+        .is_empty());
+}
+
+#[test]
+fn test_tick_compounds_staking_rewards() {
+    let mut builder = AstroLiquidPoolerBuilder::default();
+    let (swap_pair, _) = builder.builder.init_astro_pool(
+        astroport::factory::PairType::Xyk {},
+        coin(1_000_000_000_000, DENOM_NTRN),
+        coin(1_000_000_000_000, DENOM_ATOM_ON_NTRN),
+    );
+    let mut suite = builder
+        .with_mock_generator(RewardsDestination::Compound {
+            swap_pairs: vec![swap_pair.to_string()],
+        })
+        .build();
+    suite.provide_and_stake_liquidity();
+    let provided_liquidity = suite.query_provided_liquidity_info();
+
+    // rewards get claimed and swapped into the pool asset
+    suite.app.update_block(|b| b.height += 10);
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    suite.assert_balance(suite.liquid_pooler_addr.clone(), coin(0, DENOM_NTRN));
+    let compounded = suite.query_balance(&suite.liquid_pooler_addr, DENOM_ATOM_ON_NTRN);
+    assert!(!compounded.amount.is_zero());
+
+    // and provided on the following tick
+    suite
+        .tick_contract(suite.liquid_pooler_addr.clone())
+        .assert_event(&Event::new("wasm").add_attribute("method", "single_side_lp"));
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_ATOM_ON_NTRN),
+    );
+    assert_eq!(
+        suite.query_provided_liquidity_info().provided_coin_a.amount,
+        provided_liquidity.provided_coin_a.amount + compounded.amount
+    );
+}
+
+#[test]
+#[should_panic(expected = "Single side LP limit exceeded")]
+fn test_tick_fails_on_failing_provision_with_staking() {
+    let builder = AstroLiquidPoolerBuilder::default();
+    let receiver = builder.builder.app.api().addr_make("rewards_receiver");
+    let mut suite = builder
+        .with_mock_generator(RewardsDestination::Receiver {
+            address: receiver.to_string(),
+        })
+        .build();
+    suite.provide_and_stake_liquidity();
+
+    // single side balance exceeding the limit with no zap config fails to be provided
+    suite.fund_contract(
+        &coins(200_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.app.update_block(|b| b.height += 10);
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+}
+
+#[test]
+fn test_withdraw_unstakes_lp_tokens() {
+    let builder = AstroLiquidPoolerBuilder::default();
+    let receiver = builder.builder.app.api().addr_make("rewards_receiver");
+    let mut suite = builder
+        .with_mock_generator(RewardsDestination::Receiver {
+            address: receiver.to_string(),
+        })
+        .build();
+    let withdrawer = suite.clock_addr.clone();
+    let holder = suite.holder_addr.clone();
+    let staked_liquidity = suite.provide_and_stake_liquidity();
+    assert!(!staked_liquidity.staked_amount.is_zero());
+
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+
+    suite.assert_balance(&holder, coin(500_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&holder, coin(500_000, DENOM_LS_ATOM_ON_NTRN));
+    assert!(suite
+        .query_staked_liquidity()
+        .unwrap()
+        .staked_amount
+        .is_zero());
+    assert!(suite.query_liquidity_position().amount.is_zero());
+
+    // unstaking pays out the pending rewards
+    assert!(!suite.query_balance(&receiver, DENOM_NTRN).amount.is_zero());
+}
+
 #[test]
 #[should_panic(expected = "Contract operation unauthorized")]
 fn test_tick_unauthorized() {
@@ -447,6 +782,129 @@ fn test_provide_custom_concentrated_liquidity_single_side_asset_a() {
     );
 }
 
+#[test]
+fn test_provide_concentrated_liquidity_double_side_at_price_scale() {
+    let concentrated_pair_type = astroport::factory::PairType::Custom("concentrated".to_string());
+    let mut suite = AstroLiquidPoolerBuilder::default()
+        .with_custom_astroport_pool(
+            concentrated_pair_type.clone(),
+            coin(1_000_000_000, DENOM_ATOM_ON_NTRN),
+            coin(1_000_000_000, DENOM_LS_ATOM_ON_NTRN),
+        )
+        .with_pair_type(concentrated_pair_type)
+        .with_pool_price_config(PoolPriceConfig {
+            expected_spot_price: Decimal::one(),
+            acceptable_price_spread: Decimal::from_str("0.1").unwrap(),
+        })
+        .build();
+
+    // unbalance the pool reserves beyond the acceptable price spread.
+    // the price scale of the pool remains unchanged.
+    suite.swap_in_pool(coin(100_000_000, DENOM_ATOM_ON_NTRN));
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite
+        .tick_contract(suite.liquid_pooler_addr.clone())
+        .assert_event(&Event::new("wasm").add_attribute("method", "double_side_lp"));
+
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_LS_ATOM_ON_NTRN),
+    );
+    assert_eq!(
+        suite.query_provided_liquidity_info(),
+        ProvidedLiquidityInfo {
+            provided_coin_a: coin(500_000, DENOM_ATOM_ON_NTRN),
+            provided_coin_b: coin(500_000, DENOM_LS_ATOM_ON_NTRN)
+        }
+    );
+}
+
+#[test]
+fn test_provide_concentrated_liquidity_normalizes_price_scale_by_decimals() {
+    let concentrated_pair_type = astroport::factory::PairType::Custom("concentrated".to_string());
+    // price scale of 1 between a 6 decimal and an 8 decimal token
+    // means that 1 raw unit of a is worth 100 raw units of b
+    let mut suite = AstroLiquidPoolerBuilder::default()
+        .with_custom_astroport_pool_decimals(
+            concentrated_pair_type.clone(),
+            coin(1_000_000_000, DENOM_ATOM_ON_NTRN),
+            coin(100_000_000_000, DENOM_LS_ATOM_ON_NTRN),
+            (6, 8),
+        )
+        .with_pair_type(concentrated_pair_type)
+        .with_pool_price_config(PoolPriceConfig {
+            expected_spot_price: Decimal::from_str("0.01").unwrap(),
+            acceptable_price_spread: Decimal::from_str("0.001").unwrap(),
+        })
+        .build();
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(50_000_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite
+        .tick_contract(suite.liquid_pooler_addr.clone())
+        .assert_event(&Event::new("wasm").add_attribute("method", "double_side_lp"));
+
+    assert_eq!(
+        suite.query_provided_liquidity_info(),
+        ProvidedLiquidityInfo {
+            provided_coin_a: coin(500_000, DENOM_ATOM_ON_NTRN),
+            provided_coin_b: coin(50_000_000, DENOM_LS_ATOM_ON_NTRN)
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Price range error")]
+fn test_provide_concentrated_liquidity_validates_price_scale() {
+    let concentrated_pair_type = astroport::factory::PairType::Custom("concentrated".to_string());
+    let mut suite = AstroLiquidPoolerBuilder::default()
+        .with_custom_astroport_pool(
+            concentrated_pair_type.clone(),
+            coin(1_000_000_000, DENOM_ATOM_ON_NTRN),
+            coin(1_000_000_000, DENOM_LS_ATOM_ON_NTRN),
+        )
+        .with_pair_type(concentrated_pair_type)
+        .with_pool_price_config(PoolPriceConfig {
+            expected_spot_price: Decimal::from_str("1.25").unwrap(),
+            acceptable_price_spread: Decimal::from_str("0.1").unwrap(),
+        })
+        .build();
+
+    // pool reserves move within the expected price range, but the price scale does not
+    suite.swap_in_pool(coin(100_000_000, DENOM_ATOM_ON_NTRN));
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+}
+
 #[test]
 fn test_provide_xyk_liquidity_single_side_asset_a() {
     let mut suite = AstroLiquidPoolerBuilder::default()
@@ -764,6 +1222,145 @@ fn test_provide_liquidity_single_side_asset_b_exceeds_limits() {
     );
 }
 
+#[test]
+fn test_provide_liquidity_zaps_balance_exceeding_single_side_limits() {
+    let mut suite = AstroLiquidPoolerBuilder::default()
+        .with_zap_config(Some(ZapConfig {
+            router_address: None,
+        }))
+        .build();
+
+    suite.fund_contract(
+        &coins(1_000_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    // the remaining 500_000 exceed the single side limit of 100_000,
+    // so only the limit gets swapped and provided along with the swap proceeds
+    suite
+        .tick_contract(suite.liquid_pooler_addr.clone())
+        .assert_event(&Event::new("wasm").add_attribute("method", "zap_lp"));
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(300_000, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_LS_ATOM_ON_NTRN),
+    );
+    let provided_liquidity_info = suite.query_provided_liquidity_info();
+    assert_eq!(
+        provided_liquidity_info.provided_coin_a,
+        coin(600_000, DENOM_ATOM_ON_NTRN)
+    );
+    assert!(provided_liquidity_info.provided_coin_b.amount > Uint128::new(500_000));
+
+    // zapping continues until the balance is within single side limits
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(100_000, DENOM_ATOM_ON_NTRN),
+    );
+    suite
+        .tick_contract(suite.liquid_pooler_addr.clone())
+        .assert_event(&Event::new("wasm").add_attribute("method", "single_side_lp"));
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_ATOM_ON_NTRN),
+    );
+    assert_eq!(
+        suite.query_provided_liquidity_info().provided_coin_a,
+        coin(800_000, DENOM_ATOM_ON_NTRN)
+    );
+}
+
+#[test]
+#[should_panic(expected = "TWAP window must be non-zero")]
+fn test_instantiate_validates_twap_window() {
+    AstroLiquidPoolerBuilder::default()
+        .with_twap_window_seconds(Some(0))
+        .build();
+}
+
+#[test]
+fn test_provide_liquidity_awaits_twap_window() {
+    let mut suite = AstroLiquidPoolerBuilder::default()
+        .with_twap_window_seconds(Some(100))
+        .build();
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite
+        .tick_contract(suite.liquid_pooler_addr.clone())
+        .assert_event(&Event::new("wasm").add_attribute("status", "awaiting twap window"));
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(500_000, DENOM_ATOM_ON_NTRN),
+    );
+    assert_eq!(suite.query_twap(), None);
+
+    suite
+        .app
+        .update_block(|b| b.time = b.time.plus_seconds(100));
+
+    let twap = suite.query_twap().unwrap();
+    assert!(twap > Decimal::from_str("0.99").unwrap() && twap < Decimal::from_str("1.01").unwrap());
+
+    suite
+        .tick_contract(suite.liquid_pooler_addr.clone())
+        .assert_event(&Event::new("wasm").add_attribute("method", "double_side_lp"));
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_LS_ATOM_ON_NTRN),
+    );
+}
+
+#[test]
+fn test_provide_liquidity_records_twap_observation_out_of_range() {
+    let mut suite = AstroLiquidPoolerBuilder::default()
+        .with_custom_astroport_pool(
+            astroport::factory::PairType::Xyk {},
+            coin(1_000_000_000, DENOM_ATOM_ON_NTRN),
+            coin(1_000_000_000, DENOM_LS_ATOM_ON_NTRN),
+        )
+        .with_pair_type(astroport::factory::PairType::Xyk {})
+        .with_twap_window_seconds(Some(100))
+        .build();
+
+    // move the spot price above the expected range of [0.5, 1.5]
+    suite
+        .app
+        .update_block(|b| b.time = b.time.plus_seconds(100));
+    suite.swap_in_pool(coin(230_000_000, DENOM_ATOM_ON_NTRN));
+
+    suite
+        .tick_contract(suite.liquid_pooler_addr.clone())
+        .assert_event(&Event::new("wasm").add_attribute("status", "spot price out of range"));
+
+    // the twap averages from the observation recorded on the tick above,
+    // and thus only reflects the out of range price
+    suite
+        .app
+        .update_block(|b| b.time = b.time.plus_seconds(100));
+    assert!(suite.query_twap().unwrap() > Decimal::from_str("1.5").unwrap());
+}
+
 #[test]
 fn test_provide_liquidity_double_side_excess_a_denom() {
     let mut suite = AstroLiquidPoolerBuilder::default().build();
@@ -849,6 +1446,7 @@ fn test_migrate_update_config() {
                 ])),
                 holder_address: Some(clock.to_string()),
                 lp_config: Some(Box::new(lp_config)),
+                lp_staking_config: None,
             },
             11,
         )
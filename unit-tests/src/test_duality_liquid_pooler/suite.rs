@@ -0,0 +1,280 @@
+use cosmwasm_std::{coin, Addr, Coin, Decimal};
+use covenant_utils::{
+    op_mode::{ContractOperationMode, ContractOperationModeConfig},
+    PoolPriceConfig,
+};
+use cw_multi_test::{AppResponse, Executor};
+use cw_utils::Expiration;
+use liquid_pooler_interface::{LiquidPoolerContract, LiquidityPosition};
+use valence_duality_liquid_pooler::msg::{
+    AssetData, ContractState, LpConfig, ProvidedLiquidityInfo, QueryMsg, TickRange,
+};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    custom_keepers::cross_duality_pool_tick,
+    instantiates::duality_liquid_pooler::DualityLiquidPoolerInstantiate,
+    suite_builder::SuiteBuilder,
+    CustomApp, CLOCK_SALT, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, DUALITY_LIQUID_POOLER_SALT,
+    SINGLE_PARTY_HOLDER_SALT,
+};
+
+pub struct DualityLiquidPoolerBuilder {
+    pub builder: SuiteBuilder,
+    pub instantiate_msg: DualityLiquidPoolerInstantiate,
+    pub clock_addr: Addr,
+}
+
+impl Default for DualityLiquidPoolerBuilder {
+    fn default() -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        let clock_addr = builder.get_contract_addr(builder.clock_code_id, CLOCK_SALT);
+        let liquid_pooler_addr =
+            builder.get_contract_addr(builder.duality_pooler_code_id, DUALITY_LIQUID_POOLER_SALT);
+
+        let holder_addr = builder.get_contract_addr(
+            builder.single_party_holder_code_id,
+            SINGLE_PARTY_HOLDER_SALT,
+        );
+
+        let holder_instantiate_msg = valence_single_party_pol_holder::msg::InstantiateMsg {
+            withdrawer: clock_addr.to_string(),
+            withdraw_to: covenant_utils::ReceiverConfig::Native(holder_addr.to_string()),
+            emergency_committee: None,
+            pooler_address: liquid_pooler_addr.to_string(),
+            lockup_period: cw_utils::Expiration::AtHeight(123665),
+            unlock_schedule: None,
+            early_withdrawal_config:
+                valence_single_party_pol_holder::msg::EarlyWithdrawalConfig::Disabled,
+        };
+
+        let clock_instantiate_msg = valence_clock::msg::InstantiateMsg {
+            tick_max_gas: None,
+            whitelist: vec![],
+            initial_queue: vec![liquid_pooler_addr.to_string()],
+        };
+
+        builder.contract_init2(
+            builder.clock_code_id,
+            CLOCK_SALT,
+            &clock_instantiate_msg,
+            &[],
+        );
+        builder.contract_init2(
+            builder.single_party_holder_code_id,
+            SINGLE_PARTY_HOLDER_SALT,
+            &holder_instantiate_msg,
+            &[],
+        );
+
+        let liquid_pooler_instantiate = DualityLiquidPoolerInstantiate::default(
+            ContractOperationModeConfig::Permissioned(vec![clock_addr.to_string()]),
+            holder_addr.to_string(),
+        );
+
+        DualityLiquidPoolerBuilder {
+            builder,
+            instantiate_msg: liquid_pooler_instantiate,
+            clock_addr,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl DualityLiquidPoolerBuilder {
+    /// seeds the pool of the configured tick range
+    pub fn with_duality_pool(mut self, coin_a: Coin, coin_b: Coin) -> Self {
+        let tick_range = self.instantiate_msg.msg.tick_range.clone();
+        self.builder.init_duality_pool(coin_a, coin_b, tick_range);
+        self
+    }
+
+    pub fn with_default_duality_pool(self) -> Self {
+        self.with_duality_pool(
+            coin(1_000_000_000, DENOM_ATOM_ON_NTRN),
+            coin(1_000_000_000, DENOM_LS_ATOM_ON_NTRN),
+        )
+    }
+
+    pub fn with_op_mode(mut self, op_mode_cfg: ContractOperationModeConfig) -> Self {
+        self.instantiate_msg.with_op_mode(op_mode_cfg);
+        self
+    }
+
+    pub fn with_assets(mut self, assets: AssetData) -> Self {
+        self.instantiate_msg.with_assets(assets);
+        self
+    }
+
+    pub fn with_tick_range(mut self, tick_range: TickRange) -> Self {
+        self.instantiate_msg.with_tick_range(tick_range);
+        self
+    }
+
+    pub fn with_pool_price_config(mut self, pool_price_config: PoolPriceConfig) -> Self {
+        self.instantiate_msg
+            .with_pool_price_config(pool_price_config);
+        self
+    }
+
+    pub fn with_holder_address(mut self, holder_address: String) -> Self {
+        self.instantiate_msg.with_holder_address(holder_address);
+        self
+    }
+
+    pub fn build(mut self) -> Suite {
+        let liquid_pooler_address = self.builder.contract_init2(
+            self.builder.duality_pooler_code_id,
+            DUALITY_LIQUID_POOLER_SALT,
+            &self.instantiate_msg.msg,
+            &[],
+        );
+
+        let holder_addr: Addr = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                liquid_pooler_address.to_string(),
+                &QueryMsg::HolderAddress {},
+            )
+            .unwrap();
+
+        let lp_config: LpConfig = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(liquid_pooler_address.to_string(), &QueryMsg::LpConfig {})
+            .unwrap();
+
+        let faucet = self.builder.faucet.clone();
+        let admin = self.builder.admin.clone();
+
+        Suite {
+            faucet,
+            admin,
+            liquid_pooler_addr: liquid_pooler_address,
+            clock_addr: self.clock_addr,
+            holder_addr,
+            lp_config,
+            app: self.builder.build(),
+        }
+    }
+}
+
+pub struct Suite {
+    pub app: CustomApp,
+
+    pub faucet: Addr,
+    pub admin: Addr,
+
+    pub liquid_pooler_addr: Addr,
+    pub clock_addr: Addr,
+    pub holder_addr: Addr,
+    pub lp_config: LpConfig,
+}
+
+#[allow(dead_code)]
+impl Suite {
+    pub(crate) fn withdraw(&mut self, sender: &Addr, percentage: Option<Decimal>) -> AppResponse {
+        let holder = self.holder_addr.clone();
+        let app = self.get_app();
+        app.execute_contract(
+            sender.clone(),
+            holder,
+            &valence_single_party_pol_holder::msg::ExecuteMsg::Claim { percentage },
+            &[],
+        )
+        .unwrap()
+    }
+
+    pub(crate) fn expire_lockup(&mut self) {
+        let holder = self.holder_addr.clone();
+        let expiration: Expiration = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                holder.to_string(),
+                &valence_single_party_pol_holder::msg::QueryMsg::LockupConfig {},
+            )
+            .unwrap();
+        let app = self.get_app();
+        app.update_block(|b| match expiration {
+            Expiration::AtHeight(h) => b.height = h + 1,
+            Expiration::AtTime(t) => b.time = t,
+            Expiration::Never {} => (),
+        })
+    }
+
+    /// trades all the asset b reserves of the configured tick for asset a
+    pub(crate) fn cross_pool_tick(&mut self) {
+        let lp_config = self.lp_config.clone();
+        cross_duality_pool_tick(
+            self.app.storage_mut(),
+            &lp_config.asset_data.asset_a_denom,
+            &lp_config.asset_data.asset_b_denom,
+            lp_config.tick_range.tick_index,
+            lp_config.tick_range.fee,
+        )
+        .unwrap();
+    }
+
+    pub(crate) fn query_provided_liquidity_info(&self) -> ProvidedLiquidityInfo {
+        self.get_app()
+            .wrap()
+            .query_wasm_smart(
+                self.liquid_pooler_addr.clone(),
+                &QueryMsg::ProvidedLiquidityInfo {},
+            )
+            .unwrap()
+    }
+
+    /// queries the pooler through the venue agnostic interface
+    pub(crate) fn query_liquidity_position(&self) -> LiquidityPosition {
+        LiquidPoolerContract(self.liquid_pooler_addr.clone())
+            .query_liquidity_position(self.get_app().wrap())
+            .unwrap()
+    }
+
+    pub(crate) fn query_contract_state(&self) -> ContractState {
+        self.get_app()
+            .wrap()
+            .query_wasm_smart(self.liquid_pooler_addr.clone(), &QueryMsg::ContractState {})
+            .unwrap()
+    }
+
+    pub(crate) fn query_op_mode(&self) -> ContractOperationMode {
+        self.get_app()
+            .wrap()
+            .query_wasm_smart(self.liquid_pooler_addr.clone(), &QueryMsg::OperationMode {})
+            .unwrap()
+    }
+
+    pub(crate) fn query_lp_config(&self) -> LpConfig {
+        self.get_app()
+            .wrap()
+            .query_wasm_smart(self.liquid_pooler_addr.clone(), &QueryMsg::LpConfig {})
+            .unwrap()
+    }
+}
+
+impl BaseSuiteMut for Suite {
+    fn get_app(&mut self) -> &mut CustomApp {
+        &mut self.app
+    }
+
+    fn get_clock_addr(&mut self) -> Addr {
+        self.clock_addr.clone()
+    }
+
+    fn get_faucet_addr(&mut self) -> Addr {
+        self.faucet.clone()
+    }
+}
+
+impl BaseSuite for Suite {
+    fn get_app(&self) -> &CustomApp {
+        &self.app
+    }
+}
@@ -0,0 +1,461 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{coin, coins, Addr, Decimal, Uint128};
+use covenant_utils::{op_mode::ContractOperationModeConfig, PoolPriceConfig};
+use cw_multi_test::Executor;
+use valence_duality_liquid_pooler::msg::{
+    AssetData, ContractState, ProvidedLiquidityInfo, TickRange,
+};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN,
+};
+
+use super::suite::DualityLiquidPoolerBuilder;
+
+#[test]
+fn test_instantiate_with_valid_config() {
+    let suite = DualityLiquidPoolerBuilder::default().build();
+
+    assert_eq!(suite.query_contract_state(), ContractState::Instantiated);
+    assert_eq!(
+        suite.query_provided_liquidity_info(),
+        ProvidedLiquidityInfo {
+            provided_coin_a: coin(0, DENOM_ATOM_ON_NTRN),
+            provided_coin_b: coin(0, DENOM_LS_ATOM_ON_NTRN),
+        }
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_instantiate_validates_empty_privileged_accounts() {
+    DualityLiquidPoolerBuilder::default()
+        .with_op_mode(ContractOperationModeConfig::Permissioned(vec![]))
+        .build();
+}
+
+#[test]
+#[should_panic]
+fn test_instantiate_validates_holder_address() {
+    DualityLiquidPoolerBuilder::default()
+        .with_holder_address("not a holder".to_string())
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Asset denoms must differ")]
+fn test_instantiate_validates_asset_denoms() {
+    DualityLiquidPoolerBuilder::default()
+        .with_assets(AssetData {
+            asset_a_denom: DENOM_ATOM_ON_NTRN.to_string(),
+            asset_b_denom: DENOM_ATOM_ON_NTRN.to_string(),
+        })
+        .build();
+}
+
+#[test]
+#[should_panic]
+fn test_instantiate_validates_pool_price_config() {
+    DualityLiquidPoolerBuilder::default()
+        .with_pool_price_config(PoolPriceConfig {
+            expected_spot_price: Decimal::one(),
+            acceptable_price_spread: Decimal::from_str("1.5").unwrap(),
+        })
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Contract operation unauthorized")]
+fn test_tick_rejects_unprivileged_account() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+    let admin_addr = suite.admin.clone();
+    let liquid_pooler_addr = suite.liquid_pooler_addr.clone();
+    suite
+        .app
+        .execute_contract(
+            admin_addr,
+            liquid_pooler_addr,
+            &valence_duality_liquid_pooler::msg::ExecuteMsg::Tick {},
+            &[],
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Price range error")]
+fn test_instantiate_validates_tick_price() {
+    // 1.0001^5000 ~= 1.65
+    DualityLiquidPoolerBuilder::default()
+        .with_tick_range(TickRange {
+            tick_index: 5_000,
+            fee: 1,
+        })
+        .build();
+}
+
+#[test]
+fn test_instantiate_with_negative_tick_price() {
+    // 1.0001^-5000 ~= 0.61
+    let suite = DualityLiquidPoolerBuilder::default()
+        .with_tick_range(TickRange {
+            tick_index: -5_000,
+            fee: 1,
+        })
+        .build();
+
+    assert_eq!(suite.query_lp_config().tick_range.tick_index, -5_000);
+}
+
+#[test]
+fn test_tick_seeds_empty_pool() {
+    let mut suite = DualityLiquidPoolerBuilder::default().build();
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(300_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    // the pool is created with all the available balances
+    assert_eq!(
+        suite.query_provided_liquidity_info(),
+        ProvidedLiquidityInfo {
+            provided_coin_a: coin(500_000, DENOM_ATOM_ON_NTRN),
+            provided_coin_b: coin(300_000, DENOM_LS_ATOM_ON_NTRN),
+        }
+    );
+    let position = suite.query_liquidity_position();
+    assert_eq!(position.lp_token, "neutron/pool/0");
+    assert_eq!(position.amount, Uint128::new(800_000));
+}
+
+#[test]
+fn test_tick_deposits_into_configured_pool_only() {
+    let mut builder = DualityLiquidPoolerBuilder::default();
+    // pool of the same pair at another fee tier
+    builder.builder.init_duality_pool(
+        coin(1_000_000_000, DENOM_ATOM_ON_NTRN),
+        coin(1_000_000_000, DENOM_LS_ATOM_ON_NTRN),
+        TickRange {
+            tick_index: 0,
+            fee: 5,
+        },
+    );
+    let mut suite = builder.build();
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    let position = suite.query_liquidity_position();
+    assert_eq!(position.lp_token, "neutron/pool/1");
+    assert_eq!(position.amount, Uint128::new(1_000_000));
+}
+
+#[test]
+fn test_tick_deposits_single_sided_pool_asset() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_duality_pool(
+            coin(1_000_000_000, DENOM_ATOM_ON_NTRN),
+            coin(0, DENOM_LS_ATOM_ON_NTRN),
+        )
+        .build();
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(500_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    // only the asset held by the pool gets deposited
+    assert_eq!(
+        suite.query_provided_liquidity_info(),
+        ProvidedLiquidityInfo {
+            provided_coin_a: coin(500_000, DENOM_ATOM_ON_NTRN),
+            provided_coin_b: coin(0, DENOM_LS_ATOM_ON_NTRN),
+        }
+    );
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(500_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+}
+
+#[test]
+fn test_tick_awaits_both_assets() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(500_000, DENOM_ATOM_ON_NTRN),
+    );
+    assert_eq!(suite.query_liquidity_position().amount, Uint128::zero());
+}
+
+#[test]
+fn test_tick_deposits_at_pool_ratio() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(300_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    // only the amounts matching the pool ratio get deposited
+    assert_eq!(
+        suite.query_provided_liquidity_info(),
+        ProvidedLiquidityInfo {
+            provided_coin_a: coin(300_000, DENOM_ATOM_ON_NTRN),
+            provided_coin_b: coin(300_000, DENOM_LS_ATOM_ON_NTRN),
+        }
+    );
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(200_000, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_LS_ATOM_ON_NTRN),
+    );
+
+    let position = suite.query_liquidity_position();
+    assert_eq!(position.lp_token, "neutron/pool/0");
+    assert_eq!(position.amount, Uint128::new(600_000));
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(600_000, "neutron/pool/0"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Only holder can withdraw the position")]
+fn test_withdraw_validates_holder() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+    let not_the_holder = suite.faucet.clone();
+
+    suite
+        .app
+        .execute_contract(
+            not_the_holder,
+            suite.liquid_pooler_addr.clone(),
+            &valence_duality_liquid_pooler::msg::ExecuteMsg::Withdraw { percentage: None },
+            &[],
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Withdraw percentage range must belong to range (0.0, 1.0]")]
+fn test_withdraw_validates_percentage_range() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+    let holder: Addr = suite.holder_addr.clone();
+
+    suite
+        .app
+        .execute_contract(
+            holder,
+            suite.liquid_pooler_addr.clone(),
+            &valence_duality_liquid_pooler::msg::ExecuteMsg::Withdraw {
+                percentage: Some(Decimal::zero()),
+            },
+            &[],
+        )
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "no covenant denom or lp tokens available")]
+fn test_withdraw_no_shares_or_covenant_denoms() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+    let withdrawer = suite.clock_addr.clone();
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+}
+
+#[test]
+fn test_withdraw_no_shares_withdraws_covenant_assets() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+
+    let withdrawer = suite.clock_addr.clone();
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+
+    suite.assert_balance(suite.holder_addr.clone(), coin(500_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_ATOM_ON_NTRN),
+    );
+}
+
+#[test]
+fn test_withdraw_full_position() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+    suite.fund_contract(
+        &coins(300_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(300_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    let withdrawer = suite.clock_addr.clone();
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+
+    suite.assert_balance(suite.holder_addr.clone(), coin(300_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(
+        suite.holder_addr.clone(),
+        coin(300_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    assert_eq!(suite.query_liquidity_position().amount, Uint128::zero());
+}
+
+#[test]
+fn test_withdraw_full_position_includes_leftover_balances() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+    suite.fund_contract(
+        &coins(500_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(300_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    let withdrawer = suite.clock_addr.clone();
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+
+    suite.assert_balance(suite.holder_addr.clone(), coin(500_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(
+        suite.holder_addr.clone(),
+        coin(300_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.liquid_pooler_addr.clone(),
+        coin(0, DENOM_ATOM_ON_NTRN),
+    );
+}
+
+#[test]
+fn test_withdraw_one_sided_position() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+    suite.fund_contract(
+        &coins(300_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(300_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    // the pool ends up holding asset a only
+    suite.cross_pool_tick();
+
+    let withdrawer = suite.clock_addr.clone();
+    suite.expire_lockup();
+    suite.withdraw(&withdrawer, None);
+
+    suite.assert_balance(suite.holder_addr.clone(), coin(600_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(suite.holder_addr.clone(), coin(0, DENOM_LS_ATOM_ON_NTRN));
+    assert_eq!(suite.query_liquidity_position().amount, Uint128::zero());
+}
+
+#[test]
+fn test_withdraw_percentage_of_position() {
+    let mut suite = DualityLiquidPoolerBuilder::default()
+        .with_default_duality_pool()
+        .build();
+    suite.fund_contract(
+        &coins(300_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(300_000, DENOM_LS_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.tick_contract(suite.liquid_pooler_addr.clone());
+
+    let holder = suite.holder_addr.clone();
+    suite
+        .app
+        .execute_contract(
+            holder,
+            suite.liquid_pooler_addr.clone(),
+            &valence_duality_liquid_pooler::msg::ExecuteMsg::Withdraw {
+                percentage: Some(Decimal::percent(50)),
+            },
+            &[],
+        )
+        .unwrap();
+
+    suite.assert_balance(suite.holder_addr.clone(), coin(150_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(
+        suite.holder_addr.clone(),
+        coin(150_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    assert_eq!(
+        suite.query_liquidity_position().amount,
+        Uint128::new(300_000)
+    );
+}
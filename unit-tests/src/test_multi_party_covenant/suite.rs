@@ -0,0 +1,195 @@
+use cosmwasm_std::{coin, Addr};
+use valence_covenant_multi_party_pol::msg::{CovenantContractCodes, MultiPartyCovenantPartyConfig};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    instantiates::multi_party_covenant::MultiPartyCovenantInstantiate,
+    suite_builder::SuiteBuilder,
+    CustomApp, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, MULTI_PARTY_COVENANT_SALT,
+};
+
+pub struct MultiPartyCovenantBuilder {
+    pub builder: SuiteBuilder,
+    pub instantiate_msg: MultiPartyCovenantInstantiate,
+}
+
+impl Default for MultiPartyCovenantBuilder {
+    fn default() -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        // init astro pools
+        let (pool_addr, _lp_token_addr) = builder.init_astro_pool(
+            astroport::factory::PairType::Stable {},
+            coin(10_000_000_000_000, DENOM_ATOM_ON_NTRN),
+            coin(10_000_000_000_000, DENOM_LS_ATOM_ON_NTRN),
+        );
+
+        let party_addrs = [
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+        ];
+
+        let instantiate_msg =
+            MultiPartyCovenantInstantiate::default(&builder, party_addrs, pool_addr);
+
+        Self {
+            builder,
+            instantiate_msg,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl MultiPartyCovenantBuilder {
+    pub fn with_parties(mut self, parties: Vec<MultiPartyCovenantPartyConfig>) -> Self {
+        self.instantiate_msg.with_parties(parties);
+        self
+    }
+
+    pub fn with_ragequit_config(
+        mut self,
+        ragequit_config: Option<valence_multi_party_pol_holder::msg::RagequitConfig>,
+    ) -> Self {
+        self.instantiate_msg.with_ragequit_config(ragequit_config);
+        self
+    }
+
+    pub fn with_fallback_split(
+        mut self,
+        fallback_split: Option<covenant_utils::split::SplitConfig>,
+    ) -> Self {
+        self.instantiate_msg.with_fallback_split(fallback_split);
+        self
+    }
+
+    pub fn build(mut self) -> Suite {
+        let covenant_addr = self.builder.contract_init2(
+            self.builder.multi_party_covenant_code_id,
+            MULTI_PARTY_COVENANT_SALT,
+            &self.instantiate_msg.msg,
+            &[],
+        );
+
+        let clock_addr = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                covenant_addr.clone(),
+                &valence_covenant_multi_party_pol::msg::QueryMsg::ClockAddress {},
+            )
+            .unwrap();
+
+        let holder_addr = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                covenant_addr.clone(),
+                &valence_covenant_multi_party_pol::msg::QueryMsg::HolderAddress {},
+            )
+            .unwrap();
+
+        Suite {
+            faucet: self.builder.faucet.clone(),
+            admin: self.builder.admin.clone(),
+            covenant_addr,
+            app: self.builder.build(),
+            clock_addr,
+            holder_addr,
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub struct Suite {
+    pub app: CustomApp,
+
+    pub faucet: Addr,
+    pub admin: Addr,
+
+    pub covenant_addr: Addr,
+    pub clock_addr: Addr,
+    pub holder_addr: Addr,
+}
+
+impl Suite {
+    pub fn query_ibc_forwarder_address(&self, party: &str) -> Option<Addr> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_multi_party_pol::msg::QueryMsg::IbcForwarderAddress {
+                    party: party.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_router_address(&self, party: &str) -> Addr {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_multi_party_pol::msg::QueryMsg::InterchainRouterAddress {
+                    party: party.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_party_deposit_address(&self, party: &str) -> Option<String> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_multi_party_pol::msg::QueryMsg::PartyDepositAddress {
+                    party: party.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_contract_codes(&self) -> CovenantContractCodes {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_multi_party_pol::msg::QueryMsg::ContractCodes {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_holder_config(
+        &self,
+    ) -> valence_multi_party_pol_holder::msg::MultiPartyPolCovenantConfig {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::Config {},
+            )
+            .unwrap()
+    }
+}
+
+impl BaseSuiteMut for Suite {
+    fn get_app(&mut self) -> &mut CustomApp {
+        &mut self.app
+    }
+
+    fn get_clock_addr(&mut self) -> Addr {
+        self.clock_addr.clone()
+    }
+
+    fn get_faucet_addr(&mut self) -> Addr {
+        self.faucet.clone()
+    }
+}
+
+impl BaseSuite for Suite {
+    fn get_app(&self) -> &CustomApp {
+        &self.app
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{coin, Coin, Uint64};
+use valence_covenant_two_party_pol::msg::CovenantPartyConfig;
+
+use crate::setup::{
+    DENOM_ATOM, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_STRIDE,
+    NTRN_HUB_CHANNEL,
+};
+
+use super::suite::MultiPartyCovenantBuilder;
+
+#[test]
+fn test_instantiate_native_parties_astroport() {
+    let suite = MultiPartyCovenantBuilder::default().build();
+
+    let holder_config = suite.query_holder_config();
+    assert_eq!(holder_config.parties.len(), 3);
+
+    for party in holder_config.parties {
+        let router = suite.query_router_address(&party.host_addr);
+        assert_eq!(party.router, router.to_string());
+        assert_eq!(suite.query_ibc_forwarder_address(&party.host_addr), None);
+        assert_eq!(
+            suite.query_party_deposit_address(&party.host_addr),
+            Some(suite.holder_addr.to_string())
+        );
+    }
+}
+
+fn get_interchain_party_config(
+    party_address: &str,
+    remote_chain_denom: &str,
+    contribution: Coin,
+) -> CovenantPartyConfig {
+    CovenantPartyConfig::Interchain(covenant_utils::InterchainCovenantParty {
+        party_receiver_addr: party_address.to_string(),
+        party_chain_connection_id: "connection-0".to_string(),
+        ibc_transfer_timeout: Uint64::new(100),
+        party_to_host_chain_channel_id: NTRN_HUB_CHANNEL.0.to_string(),
+        host_to_party_chain_channel_id: NTRN_HUB_CHANNEL.1.to_string(),
+        remote_chain_denom: remote_chain_denom.to_string(),
+        addr: party_address.to_string(),
+        native_denom: contribution.denom.to_string(),
+        contribution,
+        denom_to_pfm_map: BTreeMap::new(),
+        fallback_address: None,
+    })
+}
+
+#[test]
+fn test_instantiate_interchain_party() {
+    let builder = MultiPartyCovenantBuilder::default();
+    let mut parties = builder.instantiate_msg.msg.parties.clone();
+    let party_address = parties[0].get_host_address();
+    parties[0].config =
+        get_interchain_party_config(&party_address, DENOM_ATOM, coin(10_000, DENOM_ATOM_ON_NTRN));
+    let suite = builder.with_parties(parties).build();
+
+    assert!(suite.query_ibc_forwarder_address(&party_address).is_some());
+    let native_party = suite.query_holder_config().parties[1].host_addr.to_string();
+    assert_eq!(suite.query_ibc_forwarder_address(&native_party), None);
+}
+
+#[test]
+#[should_panic(expected = "which is shared with other parties")]
+fn test_instantiate_validates_interchain_party_shared_denom() {
+    let builder = MultiPartyCovenantBuilder::default();
+    let mut parties = builder.instantiate_msg.msg.parties.clone();
+    let party_address = parties[1].get_host_address();
+    parties[1].config = get_interchain_party_config(
+        &party_address,
+        DENOM_LS_ATOM_ON_STRIDE,
+        coin(5_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    builder.with_parties(parties).build();
+}
+
+#[test]
+#[should_panic(expected = "not found")]
+fn test_query_party_deposit_address_validates_party() {
+    let suite = MultiPartyCovenantBuilder::default().build();
+    let faucet = suite.faucet.to_string();
+    suite.query_party_deposit_address(&faucet);
+}
+
+#[test]
+fn test_query_contract_codes() {
+    let builder = MultiPartyCovenantBuilder::default();
+    let holder_code = builder.builder.multi_party_holder_code_id;
+    let suite = builder.build();
+
+    assert_eq!(suite.query_contract_codes().holder, holder_code);
+}
@@ -0,0 +1,405 @@
+use astroport::factory::PairType;
+use cosmwasm_std::{coin, Addr, Coin, Decimal, Uint128};
+use covenant_utils::{
+    emergency_committee::{EmergencyAction, EmergencyCommitteeConfig},
+    history::HistoryEntry,
+    op_mode::{ContractOperationMode, ContractOperationModeConfig},
+    split::SplitConfig,
+    PoolPriceConfig, SingleSideLpLimits,
+};
+use cw_multi_test::{AppResponse, Executor};
+use cw_utils::Expiration;
+use valence_multi_party_pol_holder::msg::{
+    ContractState, DepositsResponse, MultiPartyPolCovenantConfig, RagequitConfig,
+};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    instantiates::multi_party_pol_holder::MultiPartyHolderInstantiate,
+    suite_builder::SuiteBuilder,
+    CustomApp, ASTRO_LIQUID_POOLER_SALT, CLOCK_SALT, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN,
+    MULTI_PARTY_HOLDER_SALT,
+};
+
+pub struct MultiPartyHolderBuilder {
+    pub builder: SuiteBuilder,
+    pub instantiate_msg: MultiPartyHolderInstantiate,
+}
+
+impl Default for MultiPartyHolderBuilder {
+    fn default() -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        let holder_addr =
+            builder.get_contract_addr(builder.multi_party_holder_code_id, MULTI_PARTY_HOLDER_SALT);
+        let clock_addr = builder.get_contract_addr(builder.clock_code_id, CLOCK_SALT);
+        let liquid_pooler_addr =
+            builder.get_contract_addr(builder.astro_pooler_code_id, ASTRO_LIQUID_POOLER_SALT);
+
+        // init astro pools
+        let (pool_addr, _lp_token_addr) = builder.init_astro_pool(
+            astroport::factory::PairType::Stable {},
+            coin(10_000_000_000_000, DENOM_ATOM_ON_NTRN),
+            coin(10_000_000_000_000, DENOM_LS_ATOM_ON_NTRN),
+        );
+
+        let clock_instantiate_msg = valence_clock::msg::InstantiateMsg {
+            tick_max_gas: None,
+            whitelist: vec![holder_addr.to_string()],
+            initial_queue: vec![liquid_pooler_addr.to_string()],
+        };
+        builder.contract_init2(
+            builder.clock_code_id,
+            CLOCK_SALT,
+            &clock_instantiate_msg,
+            &[],
+        );
+
+        let liquid_pooler_instantiate_msg = valence_astroport_liquid_pooler::msg::InstantiateMsg {
+            pool_address: pool_addr.to_string(),
+            op_mode_cfg: ContractOperationModeConfig::Permissioned(vec![clock_addr.to_string()]),
+            slippage_tolerance: None,
+            assets: valence_astroport_liquid_pooler::msg::AssetData {
+                asset_a_denom: DENOM_ATOM_ON_NTRN.to_string(),
+                asset_b_denom: DENOM_LS_ATOM_ON_NTRN.to_string(),
+            },
+            single_side_lp_limits: SingleSideLpLimits {
+                asset_a_limit: Uint128::new(100000),
+                asset_b_limit: Uint128::new(100000),
+            },
+            pool_price_config: PoolPriceConfig {
+                expected_spot_price: Decimal::one(),
+                acceptable_price_spread: Decimal::from_ratio(Uint128::one(), Uint128::new(2)),
+            },
+            pair_type: PairType::Stable {},
+            holder_address: holder_addr.to_string(),
+            lp_staking_config: None,
+            zap_config: None,
+            twap_window_seconds: None,
+            withdraw_price_guard: None,
+        };
+
+        builder.contract_init2(
+            builder.astro_pooler_code_id,
+            ASTRO_LIQUID_POOLER_SALT,
+            &liquid_pooler_instantiate_msg,
+            &[],
+        );
+
+        let party_addrs = [
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+        ];
+
+        let holder_instantiate_msg = MultiPartyHolderInstantiate::default(
+            ContractOperationModeConfig::Permissioned(vec![clock_addr.to_string()]),
+            liquid_pooler_addr.to_string(),
+            party_addrs,
+        );
+
+        Self {
+            builder,
+            instantiate_msg: holder_instantiate_msg,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl MultiPartyHolderBuilder {
+    pub fn with_lockup_config(mut self, config: Expiration) -> Self {
+        self.instantiate_msg.with_lockup_config(config);
+        self
+    }
+
+    pub fn with_ragequit_config(mut self, config: RagequitConfig) -> Self {
+        self.instantiate_msg.with_ragequit_config(config);
+        self
+    }
+
+    pub fn with_deposit_deadline(mut self, config: Expiration) -> Self {
+        self.instantiate_msg.with_deposit_deadline(config);
+        self
+    }
+
+    pub fn with_covenant_config(mut self, config: MultiPartyPolCovenantConfig) -> Self {
+        self.instantiate_msg.with_covenant_config(config);
+        self
+    }
+
+    pub fn with_fallback_split(mut self, split: SplitConfig) -> Self {
+        self.instantiate_msg.with_fallback_split(split);
+        self
+    }
+
+    pub fn with_emergency_committee(mut self, config: EmergencyCommitteeConfig) -> Self {
+        self.instantiate_msg.with_emergency_committee(config);
+        self
+    }
+
+    pub fn build(mut self) -> Suite {
+        let holder_addr = self.builder.contract_init2(
+            self.builder.multi_party_holder_code_id,
+            MULTI_PARTY_HOLDER_SALT,
+            &self.instantiate_msg.msg,
+            &[],
+        );
+
+        let contract_op_mode: ContractOperationMode = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::OperationMode {},
+            )
+            .unwrap();
+
+        let clock_addr = match contract_op_mode {
+            ContractOperationMode::Permissionless => Addr::unchecked(""),
+            ContractOperationMode::Permissioned(addrs) => addrs.to_vec()[0].clone(),
+        };
+
+        let lockup_config = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::LockupConfig {},
+            )
+            .unwrap();
+
+        let deposit_deadline = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::DepositDeadline {},
+            )
+            .unwrap();
+
+        let covenant_config = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::Config {},
+            )
+            .unwrap();
+
+        let next_contract = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::NextContract {},
+            )
+            .unwrap();
+
+        Suite {
+            faucet: self.builder.faucet.clone(),
+            admin: self.builder.admin.clone(),
+            holder_addr,
+            clock_addr,
+            next_contract,
+            lockup_config,
+            deposit_deadline,
+            covenant_config,
+            app: self.builder.build(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub struct Suite {
+    pub app: CustomApp,
+
+    pub faucet: Addr,
+    pub admin: Addr,
+
+    pub holder_addr: Addr,
+
+    pub clock_addr: Addr,
+    pub next_contract: Addr,
+    pub lockup_config: Expiration,
+    pub deposit_deadline: Expiration,
+    pub covenant_config: MultiPartyPolCovenantConfig,
+}
+
+impl Suite {
+    pub fn expire_deposit_deadline(&mut self) {
+        let expiration = self.deposit_deadline;
+        self.get_app().update_block(|b| match expiration {
+            Expiration::AtHeight(h) => b.height = h,
+            Expiration::AtTime(t) => b.time = t,
+            Expiration::Never {} => (),
+        });
+    }
+
+    pub fn expire_lockup_config(&mut self) {
+        let expiration = self.lockup_config;
+        self.get_app().update_block(|b| match expiration {
+            Expiration::AtHeight(h) => b.height = h,
+            Expiration::AtTime(t) => b.time = t,
+            Expiration::Never {} => (),
+        });
+    }
+
+    /// deposits the funds to the holder on behalf of the party
+    pub fn party_deposit(&mut self, sender: &str, funds: &[Coin]) -> AppResponse {
+        self.fund_contract(funds, Addr::unchecked(sender));
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::ExecuteMsg::Deposit {},
+                funds,
+            )
+            .unwrap()
+    }
+
+    /// deposits every party contribution to the holder and
+    /// enters the LP position
+    pub fn deposit_and_provide_liquidity(&mut self) {
+        let contributions: Vec<_> = self
+            .covenant_config
+            .parties
+            .iter()
+            .map(|party| (party.host_addr.to_string(), party.contribution.clone()))
+            .collect();
+        for (host_addr, contribution) in contributions {
+            self.party_deposit(&host_addr, &contribution);
+        }
+        self.tick_contract(self.holder_addr.clone());
+        self.tick_contract(self.next_contract.clone());
+    }
+
+    pub fn ragequit(&mut self, sender: &str) -> AppResponse {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::ExecuteMsg::Ragequit {},
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn claim(&mut self, sender: &str) -> AppResponse {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::ExecuteMsg::Claim {},
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn propose_emergency_action(
+        &mut self,
+        sender: &str,
+        action: EmergencyAction,
+    ) -> AppResponse {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::ExecuteMsg::ProposeEmergencyAction { action },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn execute_emergency_action(&mut self, sender: &str, id: u64) -> AppResponse {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::ExecuteMsg::ExecuteEmergencyAction { id },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn distribute_fallback_split(&mut self, sender: &str, denoms: Vec<String>) -> AppResponse {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::ExecuteMsg::DistributeFallbackSplit {
+                    denoms,
+                },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn query_covenant_config(&mut self) -> MultiPartyPolCovenantConfig {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::Config {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_contract_state(&mut self) -> ContractState {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::ContractState {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_deposits(&mut self) -> DepositsResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::Deposits {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_history(
+        &mut self,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Vec<HistoryEntry> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_multi_party_pol_holder::msg::QueryMsg::History { start_after, limit },
+            )
+            .unwrap()
+    }
+}
+
+impl BaseSuiteMut for Suite {
+    fn get_app(&mut self) -> &mut CustomApp {
+        &mut self.app
+    }
+
+    fn get_clock_addr(&mut self) -> Addr {
+        self.clock_addr.clone()
+    }
+
+    fn get_faucet_addr(&mut self) -> Addr {
+        self.faucet.clone()
+    }
+}
+
+impl BaseSuite for Suite {
+    fn get_app(&self) -> &CustomApp {
+        &self.app
+    }
+}
@@ -0,0 +1,415 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{coin, Addr, Decimal};
+use covenant_utils::{
+    emergency_committee::{EmergencyAction, EmergencyCommitteeConfig},
+    history::HistoryAction,
+    split::SplitConfig,
+};
+use cw_utils::Duration;
+use valence_multi_party_pol_holder::msg::{ContractState, RagequitConfig, RagequitTerms};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN,
+};
+
+use super::suite::MultiPartyHolderBuilder;
+
+#[test]
+#[should_panic(expected = "covenant must have at least two parties")]
+fn test_instantiate_validates_party_count() {
+    let builder = MultiPartyHolderBuilder::default();
+    let mut covenant_config = builder.instantiate_msg.msg.covenant_config.clone();
+    covenant_config.parties.truncate(1);
+    covenant_config.parties[0].allocation = Decimal::one();
+    builder.with_covenant_config(covenant_config).build();
+}
+
+#[test]
+#[should_panic(expected = "party allocations must be positive and add up to 1.0")]
+fn test_instantiate_validates_allocations() {
+    let builder = MultiPartyHolderBuilder::default();
+    let mut covenant_config = builder.instantiate_msg.msg.covenant_config.clone();
+    covenant_config.parties[2].allocation = Decimal::from_str("0.3").unwrap();
+    builder.with_covenant_config(covenant_config).build();
+}
+
+#[test]
+#[should_panic(expected = "party allocations must be positive and add up to 1.0")]
+fn test_instantiate_validates_zero_allocation() {
+    let builder = MultiPartyHolderBuilder::default();
+    let mut covenant_config = builder.instantiate_msg.msg.covenant_config.clone();
+    covenant_config.parties[1].allocation = Decimal::from_str("0.5").unwrap();
+    covenant_config.parties[2].allocation = Decimal::zero();
+    builder.with_covenant_config(covenant_config).build();
+}
+
+#[test]
+#[should_panic(expected = "is provided more than once")]
+fn test_instantiate_validates_duplicate_parties() {
+    let builder = MultiPartyHolderBuilder::default();
+    let mut covenant_config = builder.instantiate_msg.msg.covenant_config.clone();
+    covenant_config.parties[2].host_addr = covenant_config.parties[1].host_addr.to_string();
+    builder.with_covenant_config(covenant_config).build();
+}
+
+#[test]
+#[should_panic(expected = "Ragequit penalty exceeds party allocation")]
+fn test_instantiate_validates_ragequit_penalty_against_every_party() {
+    MultiPartyHolderBuilder::default()
+        .with_ragequit_config(RagequitConfig::Enabled(RagequitTerms {
+            penalty: Decimal::from_str("0.3").unwrap(),
+        }))
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Ragequit penalty exceeds party allocation")]
+fn test_instantiate_validates_ragequit_penalty_equal_to_party_allocation() {
+    MultiPartyHolderBuilder::default()
+        .with_ragequit_config(RagequitConfig::Enabled(RagequitTerms {
+            penalty: Decimal::from_str("0.25").unwrap(),
+        }))
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "fallback split receivers must be party routers")]
+fn test_instantiate_validates_fallback_split_receivers() {
+    let builder = MultiPartyHolderBuilder::default();
+    let router = builder.instantiate_msg.msg.covenant_config.parties[0]
+        .router
+        .to_string();
+    let faucet = builder.builder.faucet.to_string();
+    builder
+        .with_fallback_split(SplitConfig {
+            receivers: vec![
+                (router, Decimal::from_str("0.5").unwrap()),
+                (faucet, Decimal::from_str("0.5").unwrap()),
+            ]
+            .into_iter()
+            .collect(),
+        })
+        .build();
+}
+
+#[test]
+fn test_query_deposits_attributes_shared_denoms_by_depositor() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    let parties = suite.covenant_config.parties.clone();
+    suite.fund_contract(
+        &[coin(10_000, DENOM_ATOM_ON_NTRN)],
+        suite.holder_addr.clone(),
+    );
+    suite.party_deposit(&parties[2].host_addr, &[coin(5_000, DENOM_LS_ATOM_ON_NTRN)]);
+    suite.party_deposit(&parties[1].host_addr, &[coin(2_000, DENOM_LS_ATOM_ON_NTRN)]);
+    // shared denoms sent outside of a party deposit are not attributed
+    suite.fund_contract(
+        &[coin(1_000, DENOM_LS_ATOM_ON_NTRN)],
+        suite.holder_addr.clone(),
+    );
+
+    let deposits = suite.query_deposits();
+    assert_eq!(deposits.parties.len(), 3);
+    assert_eq!(
+        deposits.parties[0].deposited,
+        vec![coin(10_000, DENOM_ATOM_ON_NTRN)]
+    );
+    assert_eq!(
+        deposits.parties[1].deposited,
+        vec![coin(2_000, DENOM_LS_ATOM_ON_NTRN)]
+    );
+    assert_eq!(
+        deposits.parties[2].deposited,
+        vec![coin(5_000, DENOM_LS_ATOM_ON_NTRN)]
+    );
+    assert!(deposits.parties[0].is_fulfilled());
+    assert!(!deposits.parties[1].is_fulfilled());
+    assert!(deposits.parties[2].is_fulfilled());
+}
+
+#[test]
+#[should_panic(expected = "unauthorized")]
+fn test_execute_deposit_validates_sender() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    let sender = suite.faucet.to_string();
+    suite.party_deposit(&sender, &[coin(5_000, DENOM_LS_ATOM_ON_NTRN)]);
+}
+
+#[test]
+#[should_panic(expected = "is not part of the party contribution")]
+fn test_execute_deposit_validates_contribution_denoms() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    let sender = suite.covenant_config.parties[1].host_addr.to_string();
+    suite.party_deposit(&sender, &[coin(5_000, DENOM_ATOM_ON_NTRN)]);
+}
+
+#[test]
+#[should_panic(expected = "not all parties have deposited")]
+fn test_execute_tick_deposit_validates_insufficient_deposits() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    suite.fund_contract(
+        &[
+            coin(10_000, DENOM_ATOM_ON_NTRN),
+            coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+        ],
+        suite.holder_addr.clone(),
+    );
+    suite.tick_contract(suite.holder_addr.clone());
+}
+
+#[test]
+fn test_execute_tick_deposit_refunds_excess_to_depositor() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    let parties = suite.covenant_config.parties.clone();
+    suite.fund_contract(
+        &[coin(10_000, DENOM_ATOM_ON_NTRN)],
+        suite.holder_addr.clone(),
+    );
+    suite.party_deposit(&parties[1].host_addr, &[coin(5_500, DENOM_LS_ATOM_ON_NTRN)]);
+    suite.party_deposit(&parties[2].host_addr, &[coin(5_000, DENOM_LS_ATOM_ON_NTRN)]);
+    suite.tick_contract(suite.holder_addr.clone());
+
+    suite.assert_balance(&parties[1].router, coin(500, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(&parties[2].router, coin(0, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(
+        suite.next_contract.to_string(),
+        coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.next_contract.to_string(),
+        coin(10_000, DENOM_ATOM_ON_NTRN),
+    );
+    assert_eq!(suite.query_contract_state(), ContractState::Active);
+}
+
+#[test]
+fn test_execute_tick_expired_deposit_refunds_all_parties() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    let parties = suite.covenant_config.parties.clone();
+    suite.fund_contract(
+        &[coin(10_000, DENOM_ATOM_ON_NTRN)],
+        suite.holder_addr.clone(),
+    );
+    suite.party_deposit(&parties[2].host_addr, &[coin(5_000, DENOM_LS_ATOM_ON_NTRN)]);
+    suite.party_deposit(&parties[1].host_addr, &[coin(2_000, DENOM_LS_ATOM_ON_NTRN)]);
+    suite.expire_deposit_deadline();
+    suite.tick_contract(suite.holder_addr.clone());
+    assert_eq!(suite.query_contract_state(), ContractState::Complete);
+    suite.tick_contract(suite.holder_addr.clone());
+
+    suite.assert_balance(&parties[0].router, coin(10_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&parties[1].router, coin(2_000, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(&parties[2].router, coin(5_000, DENOM_LS_ATOM_ON_NTRN));
+
+    // subsequent ticks do not refund the settled deposits again
+    suite.tick_contract(suite.holder_addr.clone());
+    suite.assert_balance(&parties[1].router, coin(2_000, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(&parties[2].router, coin(5_000, DENOM_LS_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_execute_tick_expired_deposit_refunds_unattributed_shared_denoms() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    let parties = suite.covenant_config.parties.clone();
+    suite.fund_contract(
+        &[coin(10_000, DENOM_ATOM_ON_NTRN)],
+        suite.holder_addr.clone(),
+    );
+    suite.party_deposit(&parties[2].host_addr, &[coin(2_000, DENOM_LS_ATOM_ON_NTRN)]);
+    // shared denom sent with a plain bank transfer, as an ibc forwarder would
+    suite.fund_contract(
+        &[coin(5_001, DENOM_LS_ATOM_ON_NTRN)],
+        suite.holder_addr.clone(),
+    );
+    suite.expire_deposit_deadline();
+    suite.tick_contract(suite.holder_addr.clone());
+    suite.tick_contract(suite.holder_addr.clone());
+
+    // unattributed balance is split pro rata to the agreed contributions
+    suite.assert_balance(&parties[0].router, coin(10_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&parties[1].router, coin(2_500, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(&parties[2].router, coin(4_501, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(
+        suite.holder_addr.to_string(),
+        coin(0, DENOM_LS_ATOM_ON_NTRN),
+    );
+}
+
+#[test]
+#[should_panic(expected = "contract needs to be in expired state in order to claim")]
+fn test_execute_claim_validates_claim_state() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    suite.deposit_and_provide_liquidity();
+
+    let sender = suite.covenant_config.parties[0].host_addr.to_string();
+    suite.claim(&sender);
+}
+
+#[test]
+fn test_execute_claim_rescales_remaining_allocations() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    suite.deposit_and_provide_liquidity();
+    suite.expire_lockup_config();
+    suite.tick_contract(suite.holder_addr.clone());
+
+    let party = suite.covenant_config.parties[0].clone();
+    suite.claim(&party.host_addr);
+
+    suite.assert_balance(&party.router, coin(5_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&party.router, coin(5_000, DENOM_LS_ATOM_ON_NTRN));
+
+    let allocations: Vec<Decimal> = suite
+        .query_covenant_config()
+        .parties
+        .into_iter()
+        .map(|p| p.allocation)
+        .collect();
+    assert_eq!(
+        allocations,
+        vec![
+            Decimal::zero(),
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("0.5").unwrap(),
+        ]
+    );
+    assert_eq!(suite.query_contract_state(), ContractState::Expired);
+}
+
+#[test]
+fn test_execute_claim_all_parties_completes() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    suite.deposit_and_provide_liquidity();
+    suite.expire_lockup_config();
+    suite.tick_contract(suite.holder_addr.clone());
+
+    for party in suite.covenant_config.parties.clone() {
+        suite.claim(&party.host_addr);
+    }
+
+    assert_eq!(suite.query_contract_state(), ContractState::Complete);
+    assert!(suite
+        .query_covenant_config()
+        .parties
+        .iter()
+        .all(|p| p.allocation.is_zero()));
+}
+
+#[test]
+fn test_execute_ragequit_redistributes_penalty_pro_rata() {
+    let mut suite = MultiPartyHolderBuilder::default()
+        .with_ragequit_config(RagequitConfig::Enabled(RagequitTerms {
+            penalty: Decimal::from_str("0.1").unwrap(),
+        }))
+        .build();
+    suite.deposit_and_provide_liquidity();
+
+    let rq_party = suite.covenant_config.parties[1].clone();
+    suite.ragequit(&rq_party.host_addr);
+
+    assert!(!suite
+        .query_balance(&Addr::unchecked(&rq_party.router), DENOM_ATOM_ON_NTRN)
+        .amount
+        .is_zero());
+
+    // remaining parties own 0.5 and 0.25 of the initial position,
+    // plus the penalty split pro-rata to those allocations
+    let allocations: Vec<Decimal> = suite
+        .query_covenant_config()
+        .parties
+        .into_iter()
+        .map(|p| p.allocation)
+        .collect();
+    let first_allocation = Decimal::from_ratio(2u128, 3u128);
+    assert_eq!(
+        allocations,
+        vec![
+            first_allocation,
+            Decimal::zero(),
+            Decimal::one() - first_allocation,
+        ]
+    );
+    assert_eq!(suite.query_contract_state(), ContractState::Active);
+}
+
+#[test]
+#[should_panic(expected = "last party with an active position cannot ragequit")]
+fn test_execute_ragequit_validates_last_party() {
+    let mut suite = MultiPartyHolderBuilder::default()
+        .with_ragequit_config(RagequitConfig::Enabled(RagequitTerms {
+            penalty: Decimal::zero(),
+        }))
+        .build();
+    suite.deposit_and_provide_liquidity();
+
+    for party in suite.covenant_config.parties.clone() {
+        suite.ragequit(&party.host_addr);
+    }
+}
+
+#[test]
+#[should_panic(expected = "unauthorized")]
+fn test_execute_ragequit_validates_sender() {
+    let mut suite = MultiPartyHolderBuilder::default()
+        .with_ragequit_config(RagequitConfig::Enabled(RagequitTerms {
+            penalty: Decimal::from_str("0.1").unwrap(),
+        }))
+        .build();
+    suite.deposit_and_provide_liquidity();
+
+    let sender = suite.faucet.to_string();
+    suite.ragequit(&sender);
+}
+
+#[test]
+fn test_execute_emergency_withdraw_to_parties_splits_by_allocation() {
+    let e_c_addr = "cosmos10a6yf8khw53pvmafngsq2vjgqgu3p9kjsgpzpa2vm9ceg0c70eysqg42pu";
+    let mut suite = MultiPartyHolderBuilder::default()
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![e_c_addr.to_string()],
+            threshold: 1,
+            timelock: Duration::Height(0),
+            safe_mode: false,
+        })
+        .build();
+    suite.deposit_and_provide_liquidity();
+
+    suite.propose_emergency_action(e_c_addr, EmergencyAction::WithdrawToParties {});
+    suite.execute_emergency_action(e_c_addr, 0);
+
+    let routers: Vec<String> = suite
+        .covenant_config
+        .parties
+        .iter()
+        .map(|p| p.router.to_string())
+        .collect();
+    for denom in [DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN] {
+        suite.assert_balance(&routers[0], coin(5_000, denom));
+        suite.assert_balance(&routers[1], coin(2_500, denom));
+        suite.assert_balance(&routers[2], coin(2_500, denom));
+    }
+    assert_eq!(suite.query_contract_state(), ContractState::Complete);
+}
+
+#[test]
+fn test_query_history_records_party_deposits() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    suite.deposit_and_provide_liquidity();
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 3);
+    for (entry, party) in history.iter().zip(suite.covenant_config.parties.iter()) {
+        assert_eq!(entry.action, HistoryAction::Deposit);
+        assert_eq!(entry.party, Some(party.host_addr.to_string()));
+        assert_eq!(entry.coins, party.contribution);
+    }
+}
+
+#[test]
+#[should_panic(expected = "unauthorized to distribute explicitly defined denom")]
+fn test_distribute_fallback_validates_contribution_denoms() {
+    let mut suite = MultiPartyHolderBuilder::default().build();
+    let sender = suite.clock_addr.to_string();
+    suite.distribute_fallback_split(&sender, vec![DENOM_LS_ATOM_ON_NTRN.to_string()]);
+}
@@ -0,0 +1,197 @@
+use cosmwasm_std::Addr;
+use valence_covenant_multi_party_swap::msg::CovenantContractCodes;
+use valence_covenant_swap::msg::CovenantPartyConfig;
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    instantiates::multi_party_swap_covenant::MultiPartySwapCovenantInstantiate,
+    suite_builder::SuiteBuilder,
+    CustomApp, MULTI_PARTY_SWAP_COVENANT_SALT,
+};
+
+pub struct MultiPartySwapCovenantBuilder {
+    pub builder: SuiteBuilder,
+    pub instantiate_msg: MultiPartySwapCovenantInstantiate,
+    pub party_addrs: [Addr; 3],
+}
+
+impl Default for MultiPartySwapCovenantBuilder {
+    fn default() -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        let party_addrs = [
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+        ];
+
+        let instantiate_msg =
+            MultiPartySwapCovenantInstantiate::default(&builder, party_addrs.clone());
+
+        Self {
+            builder,
+            instantiate_msg,
+            party_addrs,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl MultiPartySwapCovenantBuilder {
+    pub fn with_parties(mut self, parties: Vec<CovenantPartyConfig>) -> Self {
+        self.instantiate_msg.with_parties(parties);
+        self
+    }
+
+    pub fn with_splits(
+        mut self,
+        splits: std::collections::BTreeMap<String, covenant_utils::split::SplitConfig>,
+    ) -> Self {
+        self.instantiate_msg.with_splits(splits);
+        self
+    }
+
+    pub fn build(mut self) -> Suite {
+        let covenant_addr = self.builder.contract_init2(
+            self.builder.multi_party_swap_covenant_code_id,
+            MULTI_PARTY_SWAP_COVENANT_SALT,
+            &self.instantiate_msg.msg,
+            &[],
+        );
+
+        let clock_addr = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                covenant_addr.clone(),
+                &valence_covenant_multi_party_swap::msg::QueryMsg::ClockAddress {},
+            )
+            .unwrap();
+
+        let holder_addr = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                covenant_addr.clone(),
+                &valence_covenant_multi_party_swap::msg::QueryMsg::HolderAddress {},
+            )
+            .unwrap();
+
+        let splitter_addr = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                covenant_addr.clone(),
+                &valence_covenant_multi_party_swap::msg::QueryMsg::SplitterAddress {},
+            )
+            .unwrap();
+
+        Suite {
+            faucet: self.builder.faucet.clone(),
+            admin: self.builder.admin.clone(),
+            covenant_addr,
+            clock_addr,
+            holder_addr,
+            splitter_addr,
+            party_addrs: self.party_addrs,
+            parties: self.instantiate_msg.msg.parties,
+            app: self.builder.build(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub struct Suite {
+    pub app: CustomApp,
+
+    pub faucet: Addr,
+    pub admin: Addr,
+
+    pub covenant_addr: Addr,
+    pub clock_addr: Addr,
+    pub holder_addr: Addr,
+    pub splitter_addr: Addr,
+    pub party_addrs: [Addr; 3],
+    pub parties: Vec<CovenantPartyConfig>,
+}
+
+impl Suite {
+    pub fn query_ibc_forwarder_address(&self, party: &str) -> Option<Addr> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_multi_party_swap::msg::QueryMsg::IbcForwarderAddress {
+                    party: party.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_router_address(&self, party: &str) -> Addr {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_multi_party_swap::msg::QueryMsg::InterchainRouterAddress {
+                    party: party.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_party_deposit_address(&self, party: &str) -> Option<String> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_multi_party_swap::msg::QueryMsg::PartyDepositAddress {
+                    party: party.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_contract_codes(&self) -> CovenantContractCodes {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_multi_party_swap::msg::QueryMsg::ContractCodes {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_holder_parties(&self) -> Vec<valence_multi_party_swap_holder::msg::SwapParty> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_multi_party_swap_holder::msg::QueryMsg::Parties {},
+            )
+            .unwrap()
+    }
+}
+
+impl BaseSuiteMut for Suite {
+    fn get_app(&mut self) -> &mut CustomApp {
+        &mut self.app
+    }
+
+    fn get_clock_addr(&mut self) -> Addr {
+        self.clock_addr.clone()
+    }
+
+    fn get_faucet_addr(&mut self) -> Addr {
+        self.faucet.clone()
+    }
+}
+
+impl BaseSuite for Suite {
+    fn get_app(&self) -> &CustomApp {
+        &self.app
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{coin, Decimal, Uint64};
+use covenant_utils::split::SplitConfig;
+use valence_covenant_swap::msg::CovenantPartyConfig;
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    DENOM_ATOM, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, DENOM_NTRN, NTRN_HUB_CHANNEL,
+};
+
+use super::suite::MultiPartySwapCovenantBuilder;
+
+#[test]
+fn test_instantiate_native_parties() {
+    let suite = MultiPartySwapCovenantBuilder::default().build();
+
+    let holder_parties = suite.query_holder_parties();
+    assert_eq!(holder_parties.len(), 3);
+
+    for party in holder_parties {
+        let router = suite.query_router_address(&party.addr);
+        assert_eq!(party.refund_address, router.to_string());
+        assert_eq!(suite.query_ibc_forwarder_address(&party.addr), None);
+        assert_eq!(
+            suite.query_party_deposit_address(&party.addr),
+            Some(suite.holder_addr.to_string())
+        );
+    }
+}
+
+#[test]
+fn test_instantiate_interchain_party() {
+    let builder = MultiPartySwapCovenantBuilder::default();
+    let mut parties = builder.instantiate_msg.msg.parties.clone();
+    let party_address = builder.party_addrs[0].to_string();
+    parties[0] = CovenantPartyConfig::Interchain(covenant_utils::InterchainCovenantParty {
+        party_receiver_addr: party_address.to_string(),
+        party_chain_connection_id: "connection-0".to_string(),
+        ibc_transfer_timeout: Uint64::new(100),
+        party_to_host_chain_channel_id: NTRN_HUB_CHANNEL.0.to_string(),
+        host_to_party_chain_channel_id: NTRN_HUB_CHANNEL.1.to_string(),
+        remote_chain_denom: DENOM_ATOM.to_string(),
+        addr: party_address.to_string(),
+        native_denom: DENOM_ATOM_ON_NTRN.to_string(),
+        contribution: coin(100_000, DENOM_ATOM_ON_NTRN),
+        denom_to_pfm_map: BTreeMap::new(),
+        fallback_address: None,
+    });
+    let suite = builder.with_parties(parties).build();
+
+    assert!(suite.query_ibc_forwarder_address(&party_address).is_some());
+    let native_party = suite.party_addrs[1].to_string();
+    assert_eq!(suite.query_ibc_forwarder_address(&native_party), None);
+}
+
+#[test]
+#[should_panic(expected = "contribution missing an explicit split configuration")]
+fn test_instantiate_validates_contribution_splits() {
+    let builder = MultiPartySwapCovenantBuilder::default();
+    let mut splits = builder.instantiate_msg.msg.splits.clone();
+    splits.remove(DENOM_NTRN);
+    builder.with_splits(splits).build();
+}
+
+#[test]
+#[should_panic(expected = "not found")]
+fn test_instantiate_validates_split_receivers() {
+    let builder = MultiPartySwapCovenantBuilder::default();
+    let mut splits = builder.instantiate_msg.msg.splits.clone();
+    splits.insert(
+        DENOM_NTRN.to_string(),
+        SplitConfig {
+            receivers: BTreeMap::from_iter(vec![(
+                builder.builder.faucet.to_string(),
+                Decimal::one(),
+            )]),
+        },
+    );
+    builder.with_splits(splits).build();
+}
+
+#[test]
+fn test_ring_swap_settles() {
+    let mut suite = MultiPartySwapCovenantBuilder::default().build();
+
+    let holder = suite.holder_addr.clone();
+    for party in suite.parties.clone() {
+        suite.fund_contract(&[party.get_contribution()], holder.clone());
+    }
+
+    // every party receives the contribution of the party preceding it
+    let receivers = suite.party_addrs.clone();
+    while suite
+        .query_balance(&receivers[0], DENOM_NTRN)
+        .amount
+        .is_zero()
+        || suite
+            .query_balance(&receivers[1], DENOM_ATOM_ON_NTRN)
+            .amount
+            .is_zero()
+        || suite
+            .query_balance(&receivers[2], DENOM_LS_ATOM_ON_NTRN)
+            .amount
+            .is_zero()
+    {
+        suite.tick("Wait for the parties to receive their splits");
+    }
+
+    suite.assert_balance(&receivers[0], coin(300_000, DENOM_NTRN));
+    suite.assert_balance(&receivers[1], coin(100_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&receivers[2], coin(200_000, DENOM_LS_ATOM_ON_NTRN));
+}
+
+#[test]
+#[should_panic(expected = "not found")]
+fn test_query_party_deposit_address_validates_party() {
+    let suite = MultiPartySwapCovenantBuilder::default().build();
+    let faucet = suite.faucet.to_string();
+    suite.query_party_deposit_address(&faucet);
+}
+
+#[test]
+fn test_query_contract_codes() {
+    let builder = MultiPartySwapCovenantBuilder::default();
+    let holder_code = builder.builder.multi_party_swap_holder_code_id;
+    let splitter_code = builder.builder.native_splitter_code_id;
+    let suite = builder.build();
+
+    let codes = suite.query_contract_codes();
+    assert_eq!(codes.holder, holder_code);
+    assert_eq!(codes.splitter, splitter_code);
+}
@@ -0,0 +1,261 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    instantiates::multi_party_swap_holder::MultiPartySwapHolderInstantiate,
+    suite_builder::SuiteBuilder,
+    CustomApp, CLOCK_SALT, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, DENOM_NTRN,
+    MULTI_PARTY_SWAP_HOLDER_SALT, NATIVE_SPLITTER_SALT,
+};
+use cosmwasm_std::{Addr, Decimal};
+use covenant_utils::{
+    history::HistoryEntry, op_mode::ContractOperationModeConfig, split::SplitConfig,
+};
+use cw_utils::Expiration;
+use valence_multi_party_swap_holder::msg::{ContractState, SwapParty};
+
+pub struct MultiPartySwapHolderBuilder {
+    pub builder: SuiteBuilder,
+    pub instantiate_msg: MultiPartySwapHolderInstantiate,
+    pub clock_addr: Addr,
+    pub splitter_addr: Addr,
+    pub party_addrs: [Addr; 3],
+    pub party_receivers: [Addr; 3],
+    pub party_routers: [Addr; 3],
+}
+
+impl Default for MultiPartySwapHolderBuilder {
+    fn default() -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        let holder_addr = builder.get_contract_addr(
+            builder.multi_party_swap_holder_code_id,
+            MULTI_PARTY_SWAP_HOLDER_SALT,
+        );
+        let clock_addr = builder.get_contract_addr(builder.clock_code_id, CLOCK_SALT);
+        let native_splitter_addr =
+            builder.get_contract_addr(builder.native_splitter_code_id, NATIVE_SPLITTER_SALT);
+
+        let party_addrs = [
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+        ];
+        let party_receivers = [
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+            builder.get_random_addr(),
+        ];
+        let party_routers = [
+            builder.get_contract_addr(builder.native_router_code_id, "party_0"),
+            builder.get_contract_addr(builder.native_router_code_id, "party_1"),
+            builder.get_contract_addr(builder.native_router_code_id, "party_2"),
+        ];
+
+        let mut initial_queue = vec![holder_addr.to_string()];
+        initial_queue.extend(party_routers.iter().map(|addr| addr.to_string()));
+        builder.contract_init2(
+            builder.clock_code_id,
+            CLOCK_SALT,
+            &valence_clock::msg::InstantiateMsg {
+                tick_max_gas: None,
+                whitelist: vec![native_splitter_addr.to_string()],
+                initial_queue,
+            },
+            &[],
+        );
+
+        let denom_set = BTreeSet::from_iter(vec![
+            DENOM_ATOM_ON_NTRN.to_string(),
+            DENOM_LS_ATOM_ON_NTRN.to_string(),
+            DENOM_NTRN.to_string(),
+        ]);
+        for (i, receiver) in party_receivers.iter().enumerate() {
+            builder.contract_init2(
+                builder.native_router_code_id,
+                &format!("party_{i}"),
+                &valence_native_router::msg::InstantiateMsg {
+                    op_mode_cfg: ContractOperationModeConfig::Permissioned(vec![
+                        clock_addr.to_string()
+                    ]),
+                    receiver_address: receiver.to_string(),
+                    denoms: denom_set.clone(),
+                },
+                &[],
+            );
+        }
+
+        // ring settlement: every party receives the
+        // contribution of the party preceding it
+        let mut splits = BTreeMap::new();
+        for (denom, router) in [
+            (DENOM_ATOM_ON_NTRN, &party_routers[1]),
+            (DENOM_LS_ATOM_ON_NTRN, &party_routers[2]),
+            (DENOM_NTRN, &party_routers[0]),
+        ] {
+            splits.insert(
+                denom.to_string(),
+                SplitConfig {
+                    receivers: BTreeMap::from_iter(vec![(router.to_string(), Decimal::one())]),
+                },
+            );
+        }
+
+        builder.contract_init2(
+            builder.native_splitter_code_id,
+            NATIVE_SPLITTER_SALT,
+            &valence_native_splitter::msg::InstantiateMsg {
+                op_mode_cfg: ContractOperationModeConfig::Permissioned(
+                    vec![clock_addr.to_string()],
+                ),
+                splits,
+                fallback_split: None,
+            },
+            &[],
+        );
+
+        let instantiate_msg = MultiPartySwapHolderInstantiate::default(
+            ContractOperationModeConfig::Permissioned(vec![clock_addr.to_string()]),
+            native_splitter_addr.to_string(),
+            party_addrs.clone(),
+            party_routers.clone(),
+        );
+
+        Self {
+            builder,
+            instantiate_msg,
+            clock_addr,
+            splitter_addr: native_splitter_addr,
+            party_addrs,
+            party_receivers,
+            party_routers,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl MultiPartySwapHolderBuilder {
+    pub fn with_op_mode(mut self, op_mode_cfg: ContractOperationModeConfig) -> Self {
+        self.instantiate_msg.with_op_mode(op_mode_cfg);
+        self
+    }
+
+    pub fn with_next_contract(mut self, addr: &str) -> Self {
+        self.instantiate_msg.with_next_contract(addr);
+        self
+    }
+
+    pub fn with_lockup_config(mut self, period: Expiration) -> Self {
+        self.instantiate_msg.with_lockup_config(period);
+        self
+    }
+
+    pub fn with_parties(mut self, parties: Vec<SwapParty>) -> Self {
+        self.instantiate_msg.with_parties(parties);
+        self
+    }
+
+    pub fn build(mut self) -> Suite {
+        let holder_addr = self.builder.contract_init2(
+            self.builder.multi_party_swap_holder_code_id,
+            MULTI_PARTY_SWAP_HOLDER_SALT,
+            &self.instantiate_msg.msg,
+            &[],
+        );
+
+        Suite {
+            faucet: self.builder.faucet.clone(),
+            admin: self.builder.admin.clone(),
+            clock_addr: self.clock_addr,
+            holder: holder_addr,
+            splitter: self.splitter_addr,
+            lockup_config: self.instantiate_msg.msg.lockup_config,
+            parties: self.instantiate_msg.msg.parties,
+            party_receivers: self.party_receivers,
+            party_routers: self.party_routers,
+            app: self.builder.build(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub struct Suite {
+    pub app: CustomApp,
+
+    pub faucet: Addr,
+    pub admin: Addr,
+
+    pub holder: Addr,
+    pub splitter: Addr,
+    pub clock_addr: Addr,
+    pub lockup_config: Expiration,
+    pub parties: Vec<SwapParty>,
+    pub party_receivers: [Addr; 3],
+    pub party_routers: [Addr; 3],
+}
+
+impl Suite {
+    pub fn expire_lockup_config(&mut self) {
+        match self.lockup_config {
+            Expiration::AtHeight(h) => self.app.update_block(|b| b.height = h),
+            Expiration::AtTime(t) => self.app.update_block(|b| b.time = t),
+            Expiration::Never {} => (),
+        };
+    }
+
+    pub fn fund_parties(&mut self) {
+        for party in self.parties.clone() {
+            self.fund_contract(&[party.contribution], self.holder.clone());
+        }
+    }
+
+    pub fn query_parties(&self) -> Vec<SwapParty> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder.clone(),
+                &valence_multi_party_swap_holder::msg::QueryMsg::Parties {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_contract_state(&self) -> ContractState {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder.clone(),
+                &valence_multi_party_swap_holder::msg::QueryMsg::ContractState {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_history(&self, start_after: Option<u64>, limit: Option<u32>) -> Vec<HistoryEntry> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder.clone(),
+                &valence_multi_party_swap_holder::msg::QueryMsg::History { start_after, limit },
+            )
+            .unwrap()
+    }
+}
+
+impl BaseSuiteMut for Suite {
+    fn get_app(&mut self) -> &mut CustomApp {
+        &mut self.app
+    }
+
+    fn get_clock_addr(&mut self) -> Addr {
+        self.clock_addr.clone()
+    }
+
+    fn get_faucet_addr(&mut self) -> Addr {
+        self.faucet.clone()
+    }
+}
+
+impl BaseSuite for Suite {
+    fn get_app(&self) -> &CustomApp {
+        &self.app
+    }
+}
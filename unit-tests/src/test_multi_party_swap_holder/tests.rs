@@ -0,0 +1,136 @@
+use cosmwasm_std::{coin, coins, Uint128};
+use covenant_utils::history::HistoryAction;
+use cw_utils::Expiration;
+use valence_multi_party_swap_holder::msg::{ContractState, SwapParty};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, DENOM_NTRN,
+};
+
+use super::suite::MultiPartySwapHolderBuilder;
+
+#[test]
+#[should_panic(expected = "multi party swap requires at least two parties")]
+fn test_instantiate_validates_party_count() {
+    let builder = MultiPartySwapHolderBuilder::default();
+    let parties = builder.instantiate_msg.msg.parties[..1].to_vec();
+    builder.with_parties(parties).build();
+}
+
+#[test]
+#[should_panic(expected = "Party contribution cannot be zero")]
+fn test_instantiate_validates_contribution_amount() {
+    let builder = MultiPartySwapHolderBuilder::default();
+    let mut parties = builder.instantiate_msg.msg.parties.clone();
+    parties[1].contribution.amount = Uint128::zero();
+    builder.with_parties(parties).build();
+}
+
+#[test]
+#[should_panic(expected = "is configured more than once")]
+fn test_instantiate_validates_duplicate_parties() {
+    let builder = MultiPartySwapHolderBuilder::default();
+    let mut parties = builder.instantiate_msg.msg.parties.clone();
+    parties[2].addr = parties[0].addr.to_string();
+    builder.with_parties(parties).build();
+}
+
+#[test]
+#[should_panic(expected = "Contribution denom untrn is provided more than once")]
+fn test_instantiate_validates_duplicate_denoms() {
+    let builder = MultiPartySwapHolderBuilder::default();
+    let mut parties: Vec<SwapParty> = builder.instantiate_msg.msg.parties.clone();
+    parties[0].contribution = coin(100_000, DENOM_NTRN);
+    builder.with_parties(parties).build();
+}
+
+#[test]
+#[should_panic(expected = "Lockup config must be in the future")]
+fn test_instantiate_validates_lockup_config() {
+    MultiPartySwapHolderBuilder::default()
+        .with_lockup_config(Expiration::AtHeight(0))
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Insufficient funds to forward")]
+fn test_execute_tick_validates_all_parties_deposited() {
+    let mut suite = MultiPartySwapHolderBuilder::default().build();
+
+    let parties = suite.parties.clone();
+    suite.fund_contract(&[parties[0].contribution.clone()], suite.holder.clone());
+    suite.fund_contract(&[parties[1].contribution.clone()], suite.holder.clone());
+
+    suite.tick_contract(suite.holder.clone());
+}
+
+#[test]
+fn test_execute_tick_settles_ring_swap() {
+    let mut suite = MultiPartySwapHolderBuilder::default().build();
+    assert_eq!(suite.query_parties().len(), 3);
+
+    suite.fund_parties();
+    suite.tick_contract(suite.holder.clone());
+    assert_eq!(suite.query_contract_state(), ContractState::Complete {});
+
+    // distribute the contributions and route them to the parties
+    suite.tick_contract(suite.splitter.clone());
+    for router in suite.party_routers.clone() {
+        suite.tick_contract(router);
+    }
+
+    let receivers = suite.party_receivers.clone();
+    suite.assert_balance(&receivers[1], coin(100_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&receivers[2], coin(200_000, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(&receivers[0], coin(300_000, DENOM_NTRN));
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 3);
+    assert!(history
+        .iter()
+        .all(|entry| entry.action == HistoryAction::Deposit));
+}
+
+#[test]
+fn test_execute_tick_refunds_surplus_deposits() {
+    let mut suite = MultiPartySwapHolderBuilder::default().build();
+
+    suite.fund_parties();
+    suite.fund_contract(&coins(50_000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+
+    suite.assert_balance(&suite.splitter, coin(100_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.party_routers[0], coin(50_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.holder, coin(0, DENOM_ATOM_ON_NTRN));
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 4);
+    assert_eq!(history[1].action, HistoryAction::Refund);
+    assert_eq!(history[1].party, Some(suite.parties[0].addr.to_string()));
+    assert_eq!(history[1].coins, coins(50_000, DENOM_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_execute_tick_expired_refunds_parties() {
+    let mut suite = MultiPartySwapHolderBuilder::default().build();
+
+    let parties = suite.parties.clone();
+    suite.fund_contract(&[parties[0].contribution.clone()], suite.holder.clone());
+    suite.fund_contract(&[parties[2].contribution.clone()], suite.holder.clone());
+
+    suite.expire_lockup_config();
+    suite.tick_contract(suite.holder.clone());
+    assert_eq!(suite.query_contract_state(), ContractState::Expired {});
+
+    suite.tick_contract(suite.holder.clone());
+    suite.assert_balance(&suite.party_routers[0], coin(100_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.party_routers[1], coin(0, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.party_routers[2], coin(300_000, DENOM_NTRN));
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 2);
+    assert!(history
+        .iter()
+        .all(|entry| entry.action == HistoryAction::Refund));
+}
@@ -0,0 +1,484 @@
+use cosmwasm_std::{
+    coin, from_json, to_json_binary, Addr, Binary, Coin, CosmosMsg, Decimal, Event, SubMsgResponse,
+    Uint64, WasmMsg,
+};
+use covenant_utils::{
+    op_mode::ContractOperationModeConfig, polytone::PolytoneExecuteMsg, WithdrawPriceGuard,
+};
+use cw_multi_test::{AppResponse, Executor};
+use cw_utils::Expiration;
+use liquid_pooler_interface::LiquidityPosition;
+use osmosis_std::types::cosmos::{
+    bank::v1beta1::QueryBalanceResponse, base::v1beta1::Coin as ProtoCoin,
+};
+use polytone::callbacks::{Callback, CallbackMessage, ErrorResponse, ExecutionResponse};
+use valence_osmo_liquid_pooler::msg::{
+    ContractState, ExecuteMsg, IbcConfig, MigrateMsg, PendingCallback, QueryMsg, StateTimeouts,
+};
+use valence_outpost_osmo_liquid_pooler::msg::{
+    ExecuteMsg as OutpostExecuteMsg, OutpostSingleAssetExit, TickRange,
+};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    instantiates::osmo_liquid_pooler::OsmoLiquidPoolerInstantiate,
+    mock_polytone_note,
+    suite_builder::SuiteBuilder,
+    CustomApp, ADMIN, CLOCK_SALT, DENOM_ATOM, DENOM_OSMO, OSMO_LIQUID_POOLER_SALT,
+    POLYTONE_NOTE_SALT, SINGLE_PARTY_HOLDER_SALT,
+};
+
+/// callback ids used by the pooler to tag its polytone requests
+pub const PROVIDE_LIQUIDITY_CALLBACK_ID: u8 = 1;
+pub const PROXY_BALANCES_QUERY_CALLBACK_ID: u8 = 2;
+pub const CREATE_PROXY_CALLBACK_ID: u8 = 3;
+pub const WITHDRAW_LIQUIDITY_CALLBACK_ID: u8 = 4;
+
+pub const PROXY_ADDRESS: &str = "osmo_proxy";
+pub const OSMO_OUTPOST: &str = "osmo_outpost";
+pub const LP_TOKEN_DENOM: &str = "gamm/pool/1";
+
+pub struct OsmoLiquidPoolerBuilder {
+    pub builder: SuiteBuilder,
+    pub instantiate_msg: OsmoLiquidPoolerInstantiate,
+    pub clock_addr: Addr,
+    pub note_addr: Addr,
+}
+
+impl Default for OsmoLiquidPoolerBuilder {
+    fn default() -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        let clock_addr = builder.get_contract_addr(builder.clock_code_id, CLOCK_SALT);
+        let liquid_pooler_addr =
+            builder.get_contract_addr(builder.osmo_pooler_code_id, OSMO_LIQUID_POOLER_SALT);
+        let holder_addr = builder.get_contract_addr(
+            builder.single_party_holder_code_id,
+            SINGLE_PARTY_HOLDER_SALT,
+        );
+
+        let holder_instantiate_msg = valence_single_party_pol_holder::msg::InstantiateMsg {
+            withdrawer: clock_addr.to_string(),
+            withdraw_to: covenant_utils::ReceiverConfig::Native(holder_addr.to_string()),
+            emergency_committee: None,
+            pooler_address: liquid_pooler_addr.to_string(),
+            lockup_period: cw_utils::Expiration::AtHeight(123665),
+            unlock_schedule: None,
+            early_withdrawal_config:
+                valence_single_party_pol_holder::msg::EarlyWithdrawalConfig::Disabled,
+        };
+
+        let clock_instantiate_msg = valence_clock::msg::InstantiateMsg {
+            tick_max_gas: None,
+            whitelist: vec![],
+            initial_queue: vec![liquid_pooler_addr.to_string()],
+        };
+
+        builder.contract_init2(
+            builder.clock_code_id,
+            CLOCK_SALT,
+            &clock_instantiate_msg,
+            &[],
+        );
+        builder.contract_init2(
+            builder.single_party_holder_code_id,
+            SINGLE_PARTY_HOLDER_SALT,
+            &holder_instantiate_msg,
+            &[],
+        );
+        let note_addr = builder.contract_init2(
+            builder.polytone_note_code_id,
+            POLYTONE_NOTE_SALT,
+            &mock_polytone_note::InstantiateMsg {
+                proxy_address: PROXY_ADDRESS.to_string(),
+            },
+            &[],
+        );
+
+        let liquid_pooler_instantiate = OsmoLiquidPoolerInstantiate::default(
+            ContractOperationModeConfig::Permissioned(vec![clock_addr.to_string()]),
+            holder_addr.to_string(),
+            note_addr.to_string(),
+            OSMO_OUTPOST.to_string(),
+        );
+
+        OsmoLiquidPoolerBuilder {
+            builder,
+            instantiate_msg: liquid_pooler_instantiate,
+            clock_addr,
+            note_addr,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl OsmoLiquidPoolerBuilder {
+    pub fn with_op_mode(mut self, op_mode_cfg: ContractOperationModeConfig) -> Self {
+        self.instantiate_msg.with_op_mode(op_mode_cfg);
+        self
+    }
+
+    pub fn with_state_timeouts(mut self, state_timeouts: StateTimeouts) -> Self {
+        self.instantiate_msg
+            .with_state_timeouts(Some(state_timeouts));
+        self
+    }
+
+    pub fn with_withdraw_price_guard(mut self, withdraw_price_guard: WithdrawPriceGuard) -> Self {
+        self.instantiate_msg
+            .with_withdraw_price_guard(Some(withdraw_price_guard));
+        self
+    }
+
+    pub fn with_tick_range(mut self, tick_range: TickRange) -> Self {
+        self.instantiate_msg.with_tick_range(Some(tick_range));
+        self
+    }
+
+    pub fn with_single_asset_exit(mut self, single_asset_exit: OutpostSingleAssetExit) -> Self {
+        self.instantiate_msg
+            .with_single_asset_exit(Some(single_asset_exit));
+        self
+    }
+
+    pub fn build(mut self) -> Suite {
+        let liquid_pooler_addr = self.builder.contract_init2(
+            self.builder.osmo_pooler_code_id,
+            OSMO_LIQUID_POOLER_SALT,
+            &self.instantiate_msg.msg,
+            &[],
+        );
+
+        let holder_addr: Addr = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(liquid_pooler_addr.to_string(), &QueryMsg::HolderAddress {})
+            .unwrap();
+
+        let faucet = self.builder.faucet.clone();
+        let admin = self.builder.admin.clone();
+
+        Suite {
+            faucet,
+            admin,
+            liquid_pooler_addr,
+            clock_addr: self.clock_addr,
+            holder_addr,
+            note_addr: self.note_addr,
+            app: self.builder.build(),
+        }
+    }
+}
+
+pub struct Suite {
+    pub app: CustomApp,
+
+    pub faucet: Addr,
+    pub admin: Addr,
+
+    pub liquid_pooler_addr: Addr,
+    pub clock_addr: Addr,
+    pub holder_addr: Addr,
+    pub note_addr: Addr,
+}
+
+#[allow(dead_code)]
+impl Suite {
+    pub(crate) fn tick_pooler(&mut self) -> AppResponse {
+        self.tick_contract(self.liquid_pooler_addr.clone())
+    }
+
+    pub(crate) fn advance_time(&mut self, seconds: u64) {
+        self.app
+            .update_block(|b| b.time = b.time.plus_seconds(seconds));
+    }
+
+    pub(crate) fn recover(&mut self, sender: &Addr) -> AppResponse {
+        let liquid_pooler_addr = self.liquid_pooler_addr.clone();
+        self.app
+            .execute_contract(
+                sender.clone(),
+                liquid_pooler_addr,
+                &ExecuteMsg::Recover {},
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub(crate) fn migrate(&mut self, msg: MigrateMsg) -> AppResponse {
+        let liquid_pooler_addr = self.liquid_pooler_addr.clone();
+        self.app
+            .migrate_contract(
+                Addr::unchecked(ADMIN),
+                liquid_pooler_addr,
+                &msg,
+                self.app
+                    .wrap()
+                    .query_wasm_contract_info(self.liquid_pooler_addr.clone())
+                    .unwrap()
+                    .code_id,
+            )
+            .unwrap()
+    }
+
+    /// claims the position through the holder, initiating a withdrawal
+    pub(crate) fn withdraw(&mut self, percentage: Option<Decimal>) -> AppResponse {
+        let holder = self.holder_addr.clone();
+        let withdrawer = self.clock_addr.clone();
+        self.app
+            .execute_contract(
+                withdrawer,
+                holder,
+                &valence_single_party_pol_holder::msg::ExecuteMsg::Claim { percentage },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub(crate) fn expire_lockup(&mut self) {
+        let holder = self.holder_addr.clone();
+        let expiration: Expiration = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                holder.to_string(),
+                &valence_single_party_pol_holder::msg::QueryMsg::LockupConfig {},
+            )
+            .unwrap();
+        self.app.update_block(|b| match expiration {
+            Expiration::AtHeight(h) => b.height = h + 1,
+            Expiration::AtTime(t) => b.time = t,
+            Expiration::Never {} => (),
+        })
+    }
+
+    /// delivers a polytone callback on behalf of the note
+    pub(crate) fn deliver_callback(&mut self, callback_id: u8, result: Callback) -> AppResponse {
+        let note_addr = self.note_addr.clone();
+        let liquid_pooler_addr = self.liquid_pooler_addr.clone();
+        self.app
+            .execute_contract(
+                note_addr,
+                liquid_pooler_addr.clone(),
+                &ExecuteMsg::Callback(CallbackMessage {
+                    initiator: liquid_pooler_addr,
+                    initiator_msg: to_json_binary(&callback_id).unwrap(),
+                    result,
+                }),
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub(crate) fn deliver_execute_callback(&mut self, callback_id: u8, events: Vec<Event>) {
+        self.deliver_callback(
+            callback_id,
+            Callback::Execute(Ok(ExecutionResponse {
+                executed_by: PROXY_ADDRESS.to_string(),
+                result: vec![SubMsgResponse { events, data: None }],
+            })),
+        );
+    }
+
+    pub(crate) fn deliver_execute_error_callback(&mut self, callback_id: u8) {
+        self.deliver_callback(
+            callback_id,
+            Callback::Execute(Err("codespace: wasm, code: 5".to_string())),
+        );
+    }
+
+    pub(crate) fn deliver_fatal_error_callback(&mut self, callback_id: u8) {
+        self.deliver_callback(callback_id, Callback::FatalError("timeout".to_string()));
+    }
+
+    /// answers the proxy balances query with the given party 1, party 2
+    /// and lp token balances
+    pub(crate) fn deliver_proxy_balances(&mut self, party_1: u128, party_2: u128, lp: u128) {
+        let balances: Vec<Binary> = [
+            coin(party_1, DENOM_ATOM),
+            coin(party_2, DENOM_OSMO),
+            coin(lp, LP_TOKEN_DENOM),
+        ]
+        .into_iter()
+        .map(|c| {
+            to_json_binary(&QueryBalanceResponse {
+                balance: Some(ProtoCoin {
+                    denom: c.denom,
+                    amount: c.amount.to_string(),
+                }),
+            })
+            .unwrap()
+        })
+        .collect();
+
+        self.deliver_callback(
+            PROXY_BALANCES_QUERY_CALLBACK_ID,
+            Callback::Query(Ok(balances)),
+        );
+    }
+
+    pub(crate) fn deliver_proxy_balances_error(&mut self) {
+        self.deliver_callback(
+            PROXY_BALANCES_QUERY_CALLBACK_ID,
+            Callback::Query(Err(ErrorResponse {
+                message_index: Uint64::zero(),
+                error: "query failed".to_string(),
+            })),
+        );
+    }
+
+    /// delivers the outpost response to a successful pool exit
+    pub(crate) fn deliver_withdraw_callback(&mut self, refund_tokens: Vec<Coin>) {
+        self.deliver_execute_callback(
+            WITHDRAW_LIQUIDITY_CALLBACK_ID,
+            vec![Event::new("wasm").add_attribute(
+                "refund_tokens",
+                cosmwasm_std::to_json_string(&refund_tokens).unwrap(),
+            )],
+        );
+    }
+
+    /// delivers the outpost response to a concentrated liquidity interaction
+    pub(crate) fn deliver_position_callback(
+        &mut self,
+        callback_id: u8,
+        closed_position_id: Option<u64>,
+        position_id: Option<u64>,
+        refund_tokens: Vec<Coin>,
+    ) {
+        let mut event = Event::new("wasm");
+        if let Some(closed_position_id) = closed_position_id {
+            event = event.add_attribute("closed_position_id", closed_position_id.to_string());
+        }
+        if let Some(position_id) = position_id {
+            event = event.add_attribute("position_id", position_id.to_string());
+        }
+        event = event.add_attribute(
+            "refund_tokens",
+            cosmwasm_std::to_json_string(&refund_tokens).unwrap(),
+        );
+        self.deliver_execute_callback(callback_id, vec![event]);
+    }
+
+    /// takes the pooler from `Instantiated` to `Active` with a funded proxy
+    pub(crate) fn activate(&mut self) {
+        self.tick_pooler();
+        self.deliver_execute_callback(CREATE_PROXY_CALLBACK_ID, vec![]);
+        self.tick_pooler();
+        self.deliver_proxy_balances(100_000, 100_000, 0);
+        self.tick_pooler();
+        self.advance_time(400);
+        self.tick_pooler();
+        assert_eq!(self.query_contract_state(), ContractState::Active);
+    }
+
+    /// takes an active pooler to `PendingWithdrawal`, with the exit pool
+    /// request sent to the note
+    pub(crate) fn start_withdrawal(&mut self, lp_balance: u128) {
+        self.expire_lockup();
+        self.withdraw(None);
+        self.tick_pooler();
+        self.deliver_proxy_balances(0, 0, lp_balance);
+        self.tick_pooler();
+    }
+
+    pub(crate) fn query_note_requests(&self) -> Vec<PolytoneExecuteMsg> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.note_addr.clone(),
+                &mock_polytone_note::QueryMsg::Requests {},
+            )
+            .unwrap()
+    }
+
+    pub(crate) fn query_last_note_request(&self) -> PolytoneExecuteMsg {
+        self.query_note_requests().pop().unwrap()
+    }
+
+    /// the outpost message of the latest relayed execute request
+    pub(crate) fn query_last_outpost_msg(&self) -> OutpostExecuteMsg {
+        let msgs = self
+            .query_note_requests()
+            .into_iter()
+            .rev()
+            .find_map(|request| match request {
+                PolytoneExecuteMsg::Execute { msgs, .. } if !msgs.is_empty() => Some(msgs),
+                _ => None,
+            })
+            .unwrap();
+        match &msgs[0] {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => from_json(msg).unwrap(),
+            msg => panic!("unexpected outpost message: {:?}", msg),
+        }
+    }
+
+    pub(crate) fn query_liquidity_position(&self) -> LiquidityPosition {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.liquid_pooler_addr.clone(),
+                &QueryMsg::LiquidityPosition {},
+            )
+            .unwrap()
+    }
+
+    pub(crate) fn query_contract_state(&self) -> ContractState {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.liquid_pooler_addr.clone(), &QueryMsg::ContractState {})
+            .unwrap()
+    }
+
+    pub(crate) fn query_pending_callback(&self) -> Option<PendingCallback> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.liquid_pooler_addr.clone(),
+                &QueryMsg::PendingCallback {},
+            )
+            .unwrap()
+    }
+
+    pub(crate) fn query_state_deadline(&self) -> Option<Expiration> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.liquid_pooler_addr.clone(), &QueryMsg::StateDeadline {})
+            .unwrap()
+    }
+
+    pub(crate) fn query_ibc_config(&self) -> IbcConfig {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.liquid_pooler_addr.clone(), &QueryMsg::IbcConfig {})
+            .unwrap()
+    }
+
+    pub(crate) fn query_state_timeouts(&self) -> StateTimeouts {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.liquid_pooler_addr.clone(), &QueryMsg::StateTimeouts {})
+            .unwrap()
+    }
+}
+
+impl BaseSuiteMut for Suite {
+    fn get_app(&mut self) -> &mut CustomApp {
+        &mut self.app
+    }
+
+    fn get_clock_addr(&mut self) -> Addr {
+        self.clock_addr.clone()
+    }
+
+    fn get_faucet_addr(&mut self) -> Addr {
+        self.faucet.clone()
+    }
+}
+
+impl BaseSuite for Suite {
+    fn get_app(&self) -> &CustomApp {
+        &self.app
+    }
+}
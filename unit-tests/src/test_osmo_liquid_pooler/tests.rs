@@ -0,0 +1,625 @@
+use cosmwasm_std::{coin, coins, Decimal, Event, Uint128, Uint64};
+use covenant_utils::{
+    op_mode::ContractOperationModeConfig, polytone::PolytoneExecuteMsg, PoolPriceConfig,
+    WithdrawPriceGuard,
+};
+use cw_utils::Duration;
+use valence_osmo_liquid_pooler::msg::{ContractState, MigrateMsg, StateTimeouts};
+use valence_outpost_osmo_liquid_pooler::msg::{
+    ExecuteMsg as OutpostExecuteMsg, OutpostSingleAssetExit, TickRange,
+};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    DENOM_ATOM, DENOM_ATOM_ON_NTRN, DENOM_OSMO, DENOM_OSMO_ON_NTRN,
+};
+
+use super::suite::{
+    OsmoLiquidPoolerBuilder, Suite, CREATE_PROXY_CALLBACK_ID, PROVIDE_LIQUIDITY_CALLBACK_ID,
+    PROXY_BALANCES_QUERY_CALLBACK_ID, WITHDRAW_LIQUIDITY_CALLBACK_ID,
+};
+
+fn get_callback_id(request: &PolytoneExecuteMsg) -> Option<u8> {
+    match request {
+        PolytoneExecuteMsg::Query { callback, .. } => {
+            Some(cosmwasm_std::from_json(&callback.msg).unwrap())
+        }
+        PolytoneExecuteMsg::Execute { callback, .. } => callback
+            .as_ref()
+            .map(|c| cosmwasm_std::from_json(&c.msg).unwrap()),
+    }
+}
+
+#[test]
+fn test_instantiate_derives_default_state_timeouts() {
+    let suite = OsmoLiquidPoolerBuilder::default().build();
+
+    assert_eq!(suite.query_contract_state(), ContractState::Instantiated);
+    assert_eq!(
+        suite.query_state_timeouts(),
+        StateTimeouts {
+            callback_timeout: Duration::Time(600),
+            withdrawal_timeout: Duration::Time(3000),
+            distribution_timeout: Duration::Time(3000),
+        }
+    );
+    assert_eq!(suite.query_pending_callback(), None);
+    assert_eq!(suite.query_state_deadline(), None);
+}
+
+#[test]
+#[should_panic(expected = "state timeouts must be non-zero")]
+fn test_instantiate_validates_state_timeouts() {
+    OsmoLiquidPoolerBuilder::default()
+        .with_state_timeouts(StateTimeouts {
+            callback_timeout: Duration::Time(0),
+            withdrawal_timeout: Duration::Time(3000),
+            distribution_timeout: Duration::Time(3000),
+        })
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "callback timeout must exceed the osmo ibc timeout of 300 seconds")]
+fn test_instantiate_validates_callback_timeout_duration() {
+    OsmoLiquidPoolerBuilder::default()
+        .with_state_timeouts(StateTimeouts {
+            callback_timeout: Duration::Height(100),
+            withdrawal_timeout: Duration::Time(3000),
+            distribution_timeout: Duration::Time(3000),
+        })
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "callback timeout must exceed the osmo ibc timeout of 300 seconds")]
+fn test_instantiate_validates_callback_timeout_exceeds_ibc_timeout() {
+    OsmoLiquidPoolerBuilder::default()
+        .with_state_timeouts(StateTimeouts {
+            callback_timeout: Duration::Time(300),
+            withdrawal_timeout: Duration::Time(3000),
+            distribution_timeout: Duration::Time(3000),
+        })
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "callback timeout must exceed the osmo ibc timeout of 300 seconds")]
+fn test_migrate_validates_state_timeouts() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+
+    suite.migrate(MigrateMsg::UpdateConfig {
+        op_mode: None,
+        holder_address: None,
+        note_address: None,
+        ibc_config: Box::new(None),
+        lp_config: Box::new(None),
+        state_timeouts: Some(StateTimeouts {
+            callback_timeout: Duration::Height(100),
+            withdrawal_timeout: Duration::Time(3000),
+            distribution_timeout: Duration::Time(3000),
+        }),
+    });
+}
+
+#[test]
+#[should_panic(expected = "callback timeout must exceed the osmo ibc timeout of 600 seconds")]
+fn test_migrate_validates_state_timeouts_against_ibc_config() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+
+    // the default callback timeout no longer exceeds the updated ibc timeout
+    let mut ibc_config = suite.query_ibc_config();
+    ibc_config.osmo_ibc_timeout = Uint64::new(600);
+    suite.migrate(MigrateMsg::UpdateConfig {
+        op_mode: None,
+        holder_address: None,
+        note_address: None,
+        ibc_config: Box::new(Some(ibc_config)),
+        lp_config: Box::new(None),
+        state_timeouts: None,
+    });
+}
+
+#[test]
+#[should_panic(expected = "Cannot Sub with 1 and 2")]
+fn test_instantiate_validates_withdraw_price_guard() {
+    OsmoLiquidPoolerBuilder::default()
+        .with_withdraw_price_guard(WithdrawPriceGuard::PriceRange(PoolPriceConfig {
+            expected_spot_price: Decimal::one(),
+            acceptable_price_spread: Decimal::from_ratio(2u128, 1u128),
+        }))
+        .build();
+}
+
+fn get_single_asset_exit(denom: &str) -> OutpostSingleAssetExit {
+    OutpostSingleAssetExit {
+        denom: denom.to_string(),
+        slippage_tolerance: Decimal::percent(1),
+    }
+}
+
+#[test]
+#[should_panic(expected = "uatom is not a party denom")]
+fn test_instantiate_validates_single_asset_exit_denom() {
+    OsmoLiquidPoolerBuilder::default()
+        .with_single_asset_exit(get_single_asset_exit(DENOM_ATOM_ON_NTRN))
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "single asset exit slippage tolerance must be below 1")]
+fn test_instantiate_validates_single_asset_exit_slippage() {
+    OsmoLiquidPoolerBuilder::default()
+        .with_single_asset_exit(OutpostSingleAssetExit {
+            denom: DENOM_OSMO.to_string(),
+            slippage_tolerance: Decimal::one(),
+        })
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "single asset exits do not apply to positions")]
+fn test_instantiate_rejects_single_asset_exit_for_positions() {
+    OsmoLiquidPoolerBuilder::default()
+        .with_tick_range(TickRange {
+            lower_tick: -1000,
+            upper_tick: 1000,
+        })
+        .with_single_asset_exit(get_single_asset_exit(DENOM_OSMO))
+        .build();
+}
+
+#[test]
+fn test_provide_liquidity_joins_with_single_held_party_denom() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+    suite.tick_pooler();
+    suite.deliver_execute_callback(CREATE_PROXY_CALLBACK_ID, vec![]);
+    suite.tick_pooler();
+    suite.deliver_proxy_balances(100_000, 100_000, 0);
+    suite.tick_pooler();
+    suite.tick_pooler();
+
+    // both party denoms are joined together
+    match suite.query_last_outpost_msg() {
+        OutpostExecuteMsg::ProvideLiquidity { config } => {
+            assert_eq!(config.single_asset_join_denom, None);
+        }
+        msg => panic!("unexpected outpost message: {:?}", msg),
+    }
+
+    // leftovers of a single party denom are joined on their own
+    suite.deliver_execute_callback(PROVIDE_LIQUIDITY_CALLBACK_ID, vec![]);
+    suite.tick_pooler();
+    suite.deliver_proxy_balances(0, 50_000, 1_000);
+    suite.tick_pooler();
+
+    match suite.query_last_outpost_msg() {
+        OutpostExecuteMsg::ProvideLiquidity { config } => {
+            assert_eq!(config.single_asset_join_denom, Some(DENOM_OSMO.to_string()));
+        }
+        msg => panic!("unexpected outpost message: {:?}", msg),
+    }
+}
+
+#[test]
+fn test_withdraw_liquidity_passes_single_asset_exit() {
+    let mut suite = OsmoLiquidPoolerBuilder::default()
+        .with_single_asset_exit(get_single_asset_exit(DENOM_OSMO))
+        .build();
+    suite.activate();
+    suite.start_withdrawal(1_000);
+
+    match suite.query_last_outpost_msg() {
+        OutpostExecuteMsg::WithdrawLiquidity { config } => {
+            assert_eq!(
+                config.single_asset_exit,
+                Some(get_single_asset_exit(DENOM_OSMO))
+            );
+        }
+        msg => panic!("unexpected outpost message: {:?}", msg),
+    }
+}
+
+#[test]
+fn test_tick_awaits_pending_callback() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+
+    suite.tick_pooler();
+    let requests = suite.query_note_requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        get_callback_id(&requests[0]),
+        Some(CREATE_PROXY_CALLBACK_ID)
+    );
+    assert_eq!(
+        suite.query_pending_callback().unwrap().callback_id,
+        CREATE_PROXY_CALLBACK_ID
+    );
+
+    // ticks before the callback deadline do not resend the request
+    suite.advance_time(599);
+    let resp = suite.tick_pooler();
+    assert!(resp.has_event(
+        &Event::new("wasm")
+            .add_attribute("awaiting_callback", CREATE_PROXY_CALLBACK_ID.to_string())
+    ));
+    assert_eq!(suite.query_note_requests().len(), 1);
+    assert_eq!(suite.query_contract_state(), ContractState::Instantiated);
+}
+
+#[test]
+fn test_tick_retries_action_after_callback_deadline() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+
+    suite.tick_pooler();
+    suite.advance_time(600);
+    suite.tick_pooler();
+
+    let requests = suite.query_note_requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0], requests[1]);
+    assert_eq!(
+        suite.query_pending_callback().unwrap().callback_id,
+        CREATE_PROXY_CALLBACK_ID
+    );
+
+    // the late callback is still processed
+    suite.deliver_execute_callback(CREATE_PROXY_CALLBACK_ID, vec![]);
+    assert_eq!(suite.query_contract_state(), ContractState::ProxyCreated);
+    assert_eq!(suite.query_pending_callback(), None);
+}
+
+#[test]
+fn test_callback_releases_pending_callback() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+
+    suite.tick_pooler();
+    suite.deliver_execute_callback(CREATE_PROXY_CALLBACK_ID, vec![]);
+    assert_eq!(suite.query_pending_callback(), None);
+
+    // next tick proceeds right away with the proxy balances query
+    suite.tick_pooler();
+    let request = suite.query_last_note_request();
+    assert!(matches!(request, PolytoneExecuteMsg::Query { .. }));
+    assert_eq!(
+        get_callback_id(&request),
+        Some(PROXY_BALANCES_QUERY_CALLBACK_ID)
+    );
+}
+
+#[test]
+fn test_fatal_error_callback_retries_on_next_tick() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+
+    suite.tick_pooler();
+    suite.deliver_fatal_error_callback(CREATE_PROXY_CALLBACK_ID);
+    assert_eq!(suite.query_pending_callback(), None);
+    assert_eq!(suite.query_contract_state(), ContractState::Instantiated);
+
+    suite.tick_pooler();
+    assert_eq!(suite.query_note_requests().len(), 2);
+}
+
+#[test]
+fn test_failed_proxy_balances_query_retries_on_next_tick() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+
+    suite.tick_pooler();
+    suite.deliver_execute_callback(CREATE_PROXY_CALLBACK_ID, vec![]);
+    suite.tick_pooler();
+    suite.deliver_proxy_balances_error();
+    assert_eq!(suite.query_pending_callback(), None);
+
+    suite.tick_pooler();
+    let requests = suite.query_note_requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[1], requests[2]);
+}
+
+#[test]
+fn test_failed_withdrawal_retries_from_resynced_balances() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+    suite.activate();
+    suite.start_withdrawal(1_000);
+    assert_eq!(
+        suite.query_pending_callback().unwrap().callback_id,
+        WITHDRAW_LIQUIDITY_CALLBACK_ID
+    );
+
+    suite.deliver_execute_error_callback(WITHDRAW_LIQUIDITY_CALLBACK_ID);
+    assert_eq!(suite.query_pending_callback(), None);
+    assert!(matches!(
+        suite.query_contract_state(),
+        ContractState::PendingWithdrawal { .. }
+    ));
+
+    // balances are resynced before attempting the withdrawal again
+    suite.tick_pooler();
+    assert_eq!(
+        get_callback_id(&suite.query_last_note_request()),
+        Some(PROXY_BALANCES_QUERY_CALLBACK_ID)
+    );
+    suite.deliver_proxy_balances(0, 0, 1_000);
+    suite.tick_pooler();
+    assert_eq!(
+        get_callback_id(&suite.query_last_note_request()),
+        Some(WITHDRAW_LIQUIDITY_CALLBACK_ID)
+    );
+}
+
+#[test]
+fn test_withdrawal_without_refund_reverts_to_active() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+    suite.activate();
+    suite.start_withdrawal(1_000);
+
+    suite.deliver_execute_callback(WITHDRAW_LIQUIDITY_CALLBACK_ID, vec![]);
+
+    assert_eq!(suite.query_contract_state(), ContractState::Active);
+    assert_eq!(suite.query_state_deadline(), None);
+}
+
+#[test]
+fn test_withdrawal_deadline_enters_recovery() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+    suite.activate();
+    suite.start_withdrawal(1_000);
+    assert!(suite.query_state_deadline().is_some());
+
+    // the withdraw callback never arrives. once the withdrawal
+    // deadline passes, the pooler resyncs the proxy balances
+    suite.advance_time(3000);
+    suite.tick_pooler();
+    assert_eq!(suite.query_contract_state(), ContractState::Recovering {});
+    assert_eq!(suite.query_state_deadline(), None);
+    assert_eq!(
+        get_callback_id(&suite.query_last_note_request()),
+        Some(PROXY_BALANCES_QUERY_CALLBACK_ID)
+    );
+
+    // the exit went through after all, so the proxy holds the party denoms
+    suite.deliver_proxy_balances(100_000, 100_000, 0);
+    suite.tick_pooler();
+    assert_eq!(
+        suite.query_contract_state(),
+        ContractState::Distributing {
+            coins: vec![coin(100_000, DENOM_ATOM), coin(100_000, DENOM_OSMO)],
+        }
+    );
+
+    // funds arrive and get distributed to the holder
+    suite.fund_contract(
+        &coins(100_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.fund_contract(
+        &coins(100_000, DENOM_OSMO_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.tick_pooler();
+    assert_eq!(suite.query_contract_state(), ContractState::Active);
+    suite.assert_balance(suite.holder_addr.clone(), coin(100_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(suite.holder_addr.clone(), coin(100_000, DENOM_OSMO_ON_NTRN));
+}
+
+#[test]
+fn test_late_withdraw_callback_is_ignored_during_recovery() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+    suite.activate();
+    suite.start_withdrawal(1_000);
+    suite.advance_time(3000);
+    suite.tick_pooler();
+    assert_eq!(suite.query_contract_state(), ContractState::Recovering {});
+
+    suite.deliver_withdraw_callback(vec![coin(100_000, DENOM_ATOM), coin(100_000, DENOM_OSMO)]);
+    assert_eq!(suite.query_contract_state(), ContractState::Recovering {});
+}
+
+#[test]
+fn test_distribution_deadline_enters_recovery() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+    suite.activate();
+    suite.start_withdrawal(1_000);
+    suite.deliver_withdraw_callback(vec![coin(100_000, DENOM_ATOM), coin(100_000, DENOM_OSMO)]);
+    assert!(matches!(
+        suite.query_contract_state(),
+        ContractState::Distributing { .. }
+    ));
+
+    // the ibc transfers back to neutron are requested but never land
+    suite.tick_pooler();
+    assert_eq!(get_callback_id(&suite.query_last_note_request()), None);
+
+    suite.advance_time(3000);
+    suite.tick_pooler();
+    assert_eq!(suite.query_contract_state(), ContractState::Recovering {});
+
+    // nothing is left on the proxy, so the withdrawal is reported as failed
+    suite.deliver_proxy_balances(0, 0, 0);
+    suite.tick_pooler();
+    assert_eq!(suite.query_contract_state(), ContractState::Active);
+
+    // the holder accepts a new claim once the failure was reported
+    suite.withdraw(None);
+    assert!(matches!(
+        suite.query_contract_state(),
+        ContractState::PendingWithdrawal { .. }
+    ));
+}
+
+#[test]
+fn test_recover_by_privileged_account() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+    suite.activate();
+    suite.start_withdrawal(1_000);
+
+    let clock_addr = suite.clock_addr.clone();
+    suite.recover(&clock_addr);
+
+    assert_eq!(suite.query_contract_state(), ContractState::Recovering {});
+    assert_eq!(
+        suite.query_pending_callback().unwrap().callback_id,
+        PROXY_BALANCES_QUERY_CALLBACK_ID
+    );
+    assert_eq!(
+        get_callback_id(&suite.query_last_note_request()),
+        Some(PROXY_BALANCES_QUERY_CALLBACK_ID)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_recover_rejects_unprivileged_account() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+    suite.activate();
+    suite.start_withdrawal(1_000);
+
+    let admin_addr = suite.admin.clone();
+    suite.recover(&admin_addr);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_recover_rejects_permissionless_mode() {
+    let mut suite = OsmoLiquidPoolerBuilder::default()
+        .with_op_mode(ContractOperationModeConfig::Permissionless)
+        .build();
+
+    let clock_addr = suite.clock_addr.clone();
+    suite.recover(&clock_addr);
+}
+
+#[test]
+#[should_panic(expected = "recovery is only available during withdrawals")]
+fn test_recover_rejects_active_state() {
+    let mut suite = OsmoLiquidPoolerBuilder::default().build();
+    suite.activate();
+
+    let clock_addr = suite.clock_addr.clone();
+    suite.recover(&clock_addr);
+}
+
+fn get_cl_pooler_suite() -> Suite {
+    OsmoLiquidPoolerBuilder::default()
+        .with_tick_range(TickRange {
+            lower_tick: -1000,
+            upper_tick: 1000,
+        })
+        .build()
+}
+
+/// takes the pooler to `ProxyFunded` and requests the first provision
+fn start_cl_provision(suite: &mut Suite) {
+    suite.tick_pooler();
+    suite.deliver_execute_callback(CREATE_PROXY_CALLBACK_ID, vec![]);
+    suite.tick_pooler();
+    suite.deliver_proxy_balances(100_000, 100_000, 0);
+    suite.tick_pooler();
+    suite.tick_pooler();
+}
+
+/// resyncs the proxy balances and requests another provision
+fn provide_cl_leftovers(suite: &mut Suite) {
+    suite.tick_pooler();
+    suite.deliver_proxy_balances(50_000, 50_000, 0);
+    suite.tick_pooler();
+}
+
+fn get_provided_position_id(suite: &Suite) -> Option<Uint64> {
+    match suite.query_last_outpost_msg() {
+        OutpostExecuteMsg::ProvideConcentratedLiquidity { config } => config.position_id,
+        msg => panic!("unexpected outpost message: {:?}", msg),
+    }
+}
+
+#[test]
+fn test_provide_concentrated_liquidity_captures_position_id() {
+    let mut suite = get_cl_pooler_suite();
+    start_cl_provision(&mut suite);
+    assert_eq!(get_provided_position_id(&suite), None);
+    assert_eq!(suite.query_liquidity_position().amount, Uint128::zero());
+
+    suite.deliver_position_callback(PROVIDE_LIQUIDITY_CALLBACK_ID, None, Some(7), vec![]);
+    assert_eq!(suite.query_liquidity_position().amount, Uint128::one());
+
+    // further provisions are added to the created position
+    provide_cl_leftovers(&mut suite);
+    assert_eq!(get_provided_position_id(&suite), Some(Uint64::new(7)));
+}
+
+#[test]
+fn test_provide_concentrated_liquidity_replaces_position_id() {
+    let mut suite = get_cl_pooler_suite();
+    start_cl_provision(&mut suite);
+    suite.deliver_position_callback(PROVIDE_LIQUIDITY_CALLBACK_ID, None, Some(7), vec![]);
+    provide_cl_leftovers(&mut suite);
+
+    // adding to a position replaces it with a new one
+    suite.deliver_position_callback(PROVIDE_LIQUIDITY_CALLBACK_ID, Some(7), Some(8), vec![]);
+    assert_eq!(suite.query_liquidity_position().amount, Uint128::one());
+
+    provide_cl_leftovers(&mut suite);
+    assert_eq!(get_provided_position_id(&suite), Some(Uint64::new(8)));
+}
+
+#[test]
+fn test_withdraw_concentrated_liquidity_by_position_id() {
+    let mut suite = get_cl_pooler_suite();
+    start_cl_provision(&mut suite);
+    suite.deliver_position_callback(PROVIDE_LIQUIDITY_CALLBACK_ID, None, Some(7), vec![]);
+    suite.advance_time(400);
+    suite.tick_pooler();
+    assert_eq!(suite.query_contract_state(), ContractState::Active);
+
+    suite.start_withdrawal(0);
+    match suite.query_last_outpost_msg() {
+        OutpostExecuteMsg::WithdrawConcentratedLiquidity { config } => {
+            assert_eq!(config.position_id, Uint64::new(7));
+            assert_eq!(config.percentage, Decimal::one());
+        }
+        msg => panic!("unexpected outpost message: {:?}", msg),
+    }
+
+    // closing the position clears it
+    let refund_tokens = vec![coin(100_000, DENOM_ATOM), coin(100_000, DENOM_OSMO)];
+    suite.deliver_position_callback(
+        WITHDRAW_LIQUIDITY_CALLBACK_ID,
+        Some(7),
+        None,
+        refund_tokens.clone(),
+    );
+    assert_eq!(suite.query_liquidity_position().amount, Uint128::zero());
+    assert_eq!(
+        suite.query_contract_state(),
+        ContractState::Distributing {
+            coins: refund_tokens
+        }
+    );
+}
+
+#[test]
+fn test_withdraw_concentrated_liquidity_out_of_range_distributes_single_denom() {
+    let mut suite = get_cl_pooler_suite();
+    start_cl_provision(&mut suite);
+    suite.deliver_position_callback(PROVIDE_LIQUIDITY_CALLBACK_ID, None, Some(7), vec![]);
+    suite.advance_time(400);
+    suite.tick_pooler();
+    suite.start_withdrawal(0);
+
+    // positions out of range hold a single token
+    suite.deliver_position_callback(
+        WITHDRAW_LIQUIDITY_CALLBACK_ID,
+        Some(7),
+        None,
+        vec![coin(200_000, DENOM_ATOM)],
+    );
+    suite.fund_contract(
+        &coins(200_000, DENOM_ATOM_ON_NTRN),
+        suite.liquid_pooler_addr.clone(),
+    );
+    suite.tick_pooler();
+
+    assert_eq!(suite.query_contract_state(), ContractState::Active);
+    suite.assert_balance(suite.holder_addr.clone(), coin(200_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(suite.holder_addr.clone(), coin(0, DENOM_OSMO_ON_NTRN));
+}
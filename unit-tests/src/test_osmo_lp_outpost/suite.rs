@@ -2,8 +2,10 @@ use cosmwasm_std::{Addr, Coin};
 use cw_multi_test::{AppResponse, Executor};
 
 use crate::setup::{
-    base_suite::BaseSuiteMut, instantiates::osmo_lp_outpost::OsmoLpOutpostInstantiate,
-    suite_builder::SuiteBuilder, CustomApp,
+    base_suite::{BaseSuite, BaseSuiteMut},
+    instantiates::osmo_lp_outpost::OsmoLpOutpostInstantiate,
+    suite_builder::SuiteBuilder,
+    CustomApp,
 };
 
 pub struct OsmoLpOutpostBuilder {
@@ -79,6 +81,41 @@ impl Suite {
             )
             .unwrap()
     }
+
+    pub fn provide_concentrated_liquidity(
+        &mut self,
+        funds: Vec<Coin>,
+        sender: Addr,
+        config: valence_outpost_osmo_liquid_pooler::msg::OutpostProvideConcentratedLiquidityConfig,
+    ) -> AppResponse {
+        self.app
+            .execute_contract(
+                sender,
+                self.outpost.clone(),
+                &valence_outpost_osmo_liquid_pooler::msg::ExecuteMsg::ProvideConcentratedLiquidity {
+                    config,
+                },
+                &funds,
+            )
+            .unwrap()
+    }
+
+    pub fn withdraw_concentrated_liquidity(
+        &mut self,
+        sender: Addr,
+        config: valence_outpost_osmo_liquid_pooler::msg::OutpostWithdrawConcentratedLiquidityConfig,
+    ) -> AppResponse {
+        self.app
+            .execute_contract(
+                sender,
+                self.outpost.clone(),
+                &valence_outpost_osmo_liquid_pooler::msg::ExecuteMsg::WithdrawConcentratedLiquidity {
+                    config,
+                },
+                &[],
+            )
+            .unwrap()
+    }
 }
 
 impl BaseSuiteMut for Suite {
@@ -95,3 +132,9 @@ impl BaseSuiteMut for Suite {
         self.faucet.clone()
     }
 }
+
+impl BaseSuite for Suite {
+    fn get_app(&self) -> &CustomApp {
+        &self.app
+    }
+}
@@ -1,13 +1,23 @@
 use std::str::FromStr;
 
-use cosmwasm_std::{coin, coins, Decimal, Uint128, Uint64};
+use cosmwasm_std::{coin, coins, from_json, Coin, Decimal, Uint128, Uint64};
+use cw_multi_test::AppResponse;
 use valence_outpost_osmo_liquid_pooler::msg::{
-    OutpostProvideLiquidityConfig, OutpostWithdrawLiquidityConfig,
+    OutpostProvideConcentratedLiquidityConfig, OutpostProvideLiquidityConfig,
+    OutpostSingleAssetExit, OutpostWithdrawConcentratedLiquidityConfig,
+    OutpostWithdrawLiquidityConfig, OutpostWithdrawPriceGuard, TickRange,
 };
 
 use crate::{
-    setup::{base_suite::BaseSuiteMut, DENOM_ATOM, DENOM_FALLBACK, DENOM_LS_ATOM_ON_NTRN},
-    test_osmo_lp_outpost::suite::OsmoLpOutpostBuilder,
+    setup::{
+        base_suite::{BaseSuite, BaseSuiteMut},
+        custom_keepers::{
+            CL_POSITION_INCENTIVES, CL_POSITION_INCENTIVES_DENOM, STABLESWAP_POOL_ID,
+            WEIGHTED_POOL_ID,
+        },
+        DENOM_ATOM, DENOM_FALLBACK, DENOM_LS_ATOM_ON_NTRN,
+    },
+    test_osmo_lp_outpost::suite::{OsmoLpOutpostBuilder, Suite},
 };
 
 // TODO: these tests are incomplete and should be expanded
@@ -23,6 +33,64 @@ fn test_withdraw_liquidity() {
         suite.faucet.clone(),
         OutpostWithdrawLiquidityConfig {
             pool_id: Uint64::new(1),
+            price_guard: None,
+            single_asset_exit: None,
+        },
+    );
+}
+
+#[test]
+fn test_withdraw_liquidity_within_price_guard() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    suite.withdraw_liquidity(
+        coins(1, DENOM_FALLBACK),
+        suite.faucet.clone(),
+        OutpostWithdrawLiquidityConfig {
+            pool_id: Uint64::new(1),
+            price_guard: Some(OutpostWithdrawPriceGuard::PriceRange {
+                expected_spot_price: Decimal::from_str("1.0").unwrap(),
+                acceptable_price_spread: Decimal::from_str("0.01").unwrap(),
+            }),
+            single_asset_exit: None,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Price range error")]
+fn test_withdraw_liquidity_validates_price_guard_price_range() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    suite.withdraw_liquidity(
+        coins(1, DENOM_FALLBACK),
+        suite.faucet.clone(),
+        OutpostWithdrawLiquidityConfig {
+            pool_id: Uint64::new(1),
+            price_guard: Some(OutpostWithdrawPriceGuard::PriceRange {
+                expected_spot_price: Decimal::from_str("2.0").unwrap(),
+                acceptable_price_spread: Decimal::from_str("0.5").unwrap(),
+            }),
+            single_asset_exit: None,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "withdrawal below the minimum amount")]
+fn test_withdraw_liquidity_validates_price_guard_min_assets_per_share() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    suite.withdraw_liquidity(
+        coins(1, DENOM_FALLBACK),
+        suite.faucet.clone(),
+        OutpostWithdrawLiquidityConfig {
+            pool_id: Uint64::new(1),
+            price_guard: Some(OutpostWithdrawPriceGuard::MinAssetsPerShare {
+                asset_1: Decimal::from_str("2.0").unwrap(),
+                asset_2: Decimal::from_str("1.0").unwrap(),
+            }),
+            single_asset_exit: None,
         },
     );
 }
@@ -44,6 +112,7 @@ fn test_provide_liquidity_double_sided() {
             slippage_tolerance: Decimal::from_str("0.01").unwrap(),
             asset_1_single_side_lp_limit: Uint128::new(100000),
             asset_2_single_side_lp_limit: Uint128::new(100000),
+            single_asset_join_denom: None,
         },
     );
 }
@@ -65,6 +134,7 @@ fn test_provide_liquidity_single_sided_asset_a() {
             slippage_tolerance: Decimal::from_str("0.01").unwrap(),
             asset_1_single_side_lp_limit: Uint128::new(100000),
             asset_2_single_side_lp_limit: Uint128::new(100000),
+            single_asset_join_denom: None,
         },
     );
 }
@@ -86,6 +156,565 @@ fn test_provide_liquidity_single_sided_asset_b() {
             slippage_tolerance: Decimal::from_str("0.01").unwrap(),
             asset_1_single_side_lp_limit: Uint128::new(100000),
             asset_2_single_side_lp_limit: Uint128::new(100000),
+            single_asset_join_denom: None,
+        },
+    );
+}
+
+fn get_provide_config(
+    pool_id: u64,
+    expected_spot_price: &str,
+    acceptable_price_spread: &str,
+) -> OutpostProvideLiquidityConfig {
+    OutpostProvideLiquidityConfig {
+        pool_id: Uint64::new(pool_id),
+        expected_spot_price: Decimal::from_str(expected_spot_price).unwrap(),
+        acceptable_price_spread: Decimal::from_str(acceptable_price_spread).unwrap(),
+        slippage_tolerance: Decimal::from_str("0.01").unwrap(),
+        asset_1_single_side_lp_limit: Uint128::new(100000),
+        asset_2_single_side_lp_limit: Uint128::new(100000),
+        single_asset_join_denom: None,
+    }
+}
+
+#[test]
+fn test_provide_liquidity_weighted_pool() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    // 400:100 reserves in a 80:20 pool are priced at 1
+    suite.provide_liquidity(
+        vec![coin(1, DENOM_ATOM), coin(1, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        get_provide_config(WEIGHTED_POOL_ID, "1.0", "0.01"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Price range error")]
+fn test_provide_liquidity_weighted_pool_validates_weighted_price() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    // the raw reserve ratio is not the pool price
+    suite.provide_liquidity(
+        vec![coin(1, DENOM_ATOM), coin(1, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        get_provide_config(WEIGHTED_POOL_ID, "4.0", "0.01"),
+    );
+}
+
+#[test]
+fn test_provide_liquidity_weighted_pool_single_sided() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    suite.provide_liquidity(
+        coins(1, DENOM_LS_ATOM_ON_NTRN),
+        suite.faucet.clone(),
+        get_provide_config(WEIGHTED_POOL_ID, "1.0", "0.01"),
+    );
+}
+
+#[test]
+fn test_provide_liquidity_stableswap_pool() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    // 100:300 scaled reserves are priced at 7/9 on the stableswap curve,
+    // unscaled by the 1:1000 scaling factors
+    suite.provide_liquidity(
+        vec![coin(1, DENOM_ATOM), coin(1, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        get_provide_config(STABLESWAP_POOL_ID, "0.000778", "0.000001"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Price range error")]
+fn test_provide_liquidity_stableswap_pool_validates_curve_price() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    // the raw reserve ratio is not the pool price
+    suite.provide_liquidity(
+        vec![coin(1, DENOM_ATOM), coin(1, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        get_provide_config(STABLESWAP_POOL_ID, "0.000333", "0.000001"),
+    );
+}
+
+#[test]
+fn test_withdraw_liquidity_stableswap_pool_within_price_guard() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    suite.withdraw_liquidity(
+        coins(1, DENOM_FALLBACK),
+        suite.faucet.clone(),
+        OutpostWithdrawLiquidityConfig {
+            pool_id: Uint64::new(STABLESWAP_POOL_ID),
+            price_guard: Some(OutpostWithdrawPriceGuard::PriceRange {
+                expected_spot_price: Decimal::from_str("0.000778").unwrap(),
+                acceptable_price_spread: Decimal::from_str("0.000001").unwrap(),
+            }),
+            single_asset_exit: None,
+        },
+    );
+}
+
+fn get_single_asset_exit_config(
+    denom: &str,
+    slippage_tolerance: &str,
+) -> OutpostWithdrawLiquidityConfig {
+    OutpostWithdrawLiquidityConfig {
+        pool_id: Uint64::new(1),
+        price_guard: None,
+        single_asset_exit: Some(OutpostSingleAssetExit {
+            denom: denom.to_string(),
+            slippage_tolerance: Decimal::from_str(slippage_tolerance).unwrap(),
+        }),
+    }
+}
+
+#[test]
+fn test_withdraw_liquidity_single_asset_exit() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    // the exit simulation returns 10 of each asset, and swapping 10 atom
+    // through the 100:100 pool is estimated to return 9 ls atom,
+    // short of the 10 ls atom the spot price would suggest
+    let resp = suite.withdraw_liquidity(
+        coins(10, DENOM_FALLBACK),
+        suite.faucet.clone(),
+        get_single_asset_exit_config(DENOM_LS_ATOM_ON_NTRN, "0.0"),
+    );
+
+    assert_eq!(
+        get_wasm_attribute(&resp, "single_asset_exit_denom"),
+        Some(DENOM_LS_ATOM_ON_NTRN.to_string())
+    );
+    assert_eq!(
+        get_wasm_attribute(&resp, "token_out_min_amount"),
+        Some("19".to_string())
+    );
+}
+
+#[test]
+fn test_withdraw_liquidity_single_asset_exit_applies_slippage() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    let resp = suite.withdraw_liquidity(
+        coins(10, DENOM_FALLBACK),
+        suite.faucet.clone(),
+        get_single_asset_exit_config(DENOM_ATOM, "0.5"),
+    );
+
+    assert_eq!(
+        get_wasm_attribute(&resp, "token_out_min_amount"),
+        Some("9".to_string())
+    );
+}
+
+#[test]
+#[should_panic(expected = "is not a pool asset")]
+fn test_withdraw_liquidity_single_asset_exit_validates_denom() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    suite.withdraw_liquidity(
+        coins(1, DENOM_FALLBACK),
+        suite.faucet.clone(),
+        get_single_asset_exit_config(DENOM_FALLBACK, "0.01"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Slippage tolerance cannot be >= 1.0")]
+fn test_withdraw_liquidity_single_asset_exit_validates_slippage() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    suite.withdraw_liquidity(
+        coins(1, DENOM_FALLBACK),
+        suite.faucet.clone(),
+        get_single_asset_exit_config(DENOM_ATOM, "1.0"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "single side lp error")]
+fn test_provide_liquidity_single_asset_join() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    // both assets are paid but only atom joins the pool,
+    // so the single side limit applies
+    let mut config = get_provide_config(1, "1.0", "0.01");
+    config.single_asset_join_denom = Some(DENOM_ATOM.to_string());
+    config.asset_1_single_side_lp_limit = Uint128::one();
+
+    suite.provide_liquidity(
+        vec![coin(2, DENOM_ATOM), coin(2, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        config,
+    );
+}
+
+#[test]
+fn test_provide_liquidity_single_asset_join_refunds_other_asset() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    let mut config = get_provide_config(1, "1.0", "0.01");
+    config.single_asset_join_denom = Some(DENOM_LS_ATOM_ON_NTRN.to_string());
+
+    let resp = suite.provide_liquidity(
+        vec![coin(1, DENOM_ATOM), coin(1, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        config,
+    );
+
+    assert!(get_wasm_attribute(&resp, "refund_tokens")
+        .unwrap()
+        .contains(DENOM_ATOM));
+    suite.assert_balance(&suite.outpost, coin(0, DENOM_ATOM));
+}
+
+#[test]
+#[should_panic(expected = "is not a pool asset")]
+fn test_provide_liquidity_single_asset_join_validates_denom() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    let mut config = get_provide_config(1, "1.0", "0.01");
+    config.single_asset_join_denom = Some(DENOM_FALLBACK.to_string());
+
+    suite.provide_liquidity(
+        vec![coin(1, DENOM_ATOM), coin(1, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        config,
+    );
+}
+
+#[test]
+#[should_panic(expected = "liquidity provision error: no")]
+fn test_provide_liquidity_single_asset_join_requires_payment() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    let mut config = get_provide_config(1, "1.0", "0.01");
+    config.single_asset_join_denom = Some(DENOM_LS_ATOM_ON_NTRN.to_string());
+
+    suite.provide_liquidity(coins(1, DENOM_ATOM), suite.faucet.clone(), config);
+}
+
+fn get_cl_provide_config(position_id: Option<u64>) -> OutpostProvideConcentratedLiquidityConfig {
+    OutpostProvideConcentratedLiquidityConfig {
+        pool_id: Uint64::new(2),
+        tick_range: TickRange {
+            lower_tick: -1000,
+            upper_tick: 1000,
+        },
+        expected_spot_price: Decimal::from_str("1.0").unwrap(),
+        acceptable_price_spread: Decimal::from_str("0.01").unwrap(),
+        position_id: position_id.map(Uint64::new),
+    }
+}
+
+fn get_wasm_attribute(resp: &AppResponse, key: &str) -> Option<String> {
+    resp.events
+        .iter()
+        .filter(|e| e.ty == "wasm")
+        .flat_map(|e| e.attributes.iter())
+        .find(|a| a.key == key)
+        .map(|a| a.value.to_string())
+}
+
+fn create_cl_position(suite: &mut Suite) {
+    suite.provide_concentrated_liquidity(
+        vec![coin(100, DENOM_ATOM), coin(100, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        get_cl_provide_config(None),
+    );
+}
+
+#[test]
+fn test_provide_concentrated_liquidity_creates_position() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    let resp = suite.provide_concentrated_liquidity(
+        vec![coin(100, DENOM_ATOM), coin(100, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        get_cl_provide_config(None),
+    );
+
+    assert_eq!(
+        get_wasm_attribute(&resp, "position_id"),
+        Some("1".to_string())
+    );
+    assert_eq!(get_wasm_attribute(&resp, "closed_position_id"), None);
+    suite.assert_balance(&suite.outpost, coin(0, DENOM_ATOM));
+    suite.assert_balance(&suite.outpost, coin(0, DENOM_LS_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_provide_concentrated_liquidity_single_sided() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    let resp = suite.provide_concentrated_liquidity(
+        coins(100, DENOM_ATOM),
+        suite.faucet.clone(),
+        get_cl_provide_config(None),
+    );
+
+    assert_eq!(
+        get_wasm_attribute(&resp, "position_id"),
+        Some("1".to_string())
+    );
+}
+
+#[test]
+fn test_provide_concentrated_liquidity_adds_to_position() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+    create_cl_position(&mut suite);
+
+    let resp = suite.provide_concentrated_liquidity(
+        vec![coin(50, DENOM_ATOM), coin(50, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        get_cl_provide_config(Some(1)),
+    );
+
+    // osmosis replaces the position with a new one
+    assert_eq!(
+        get_wasm_attribute(&resp, "closed_position_id"),
+        Some("1".to_string())
+    );
+    assert_eq!(
+        get_wasm_attribute(&resp, "position_id"),
+        Some("2".to_string())
+    );
+
+    // the new position holds the liquidity of both provisions
+    let atom_bal = suite.query_balance(&suite.faucet, DENOM_ATOM);
+    suite.withdraw_concentrated_liquidity(
+        suite.faucet.clone(),
+        OutpostWithdrawConcentratedLiquidityConfig {
+            position_id: Uint64::new(2),
+            percentage: Decimal::one(),
+            price_guard: None,
+        },
+    );
+    suite.assert_balance(
+        &suite.faucet,
+        coin(atom_bal.amount.u128() + 150, DENOM_ATOM),
+    );
+}
+
+#[test]
+#[should_panic(expected = "position 1 is not owned by the sender")]
+fn test_provide_concentrated_liquidity_validates_position_owner() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+    create_cl_position(&mut suite);
+
+    let funds = vec![coin(50, DENOM_ATOM), coin(50, DENOM_LS_ATOM_ON_NTRN)];
+    suite.fund_contract(&funds, suite.admin.clone());
+    suite.provide_concentrated_liquidity(
+        funds,
+        suite.admin.clone(),
+        get_cl_provide_config(Some(1)),
+    );
+}
+
+#[test]
+#[should_panic(expected = "ticks must be multiples of the pool tick spacing 100")]
+fn test_provide_concentrated_liquidity_validates_tick_spacing() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    let mut config = get_cl_provide_config(None);
+    config.tick_range.lower_tick = -150;
+    suite.provide_concentrated_liquidity(
+        vec![coin(100, DENOM_ATOM), coin(100, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        config,
+    );
+}
+
+#[test]
+#[should_panic(expected = "lower tick 1000 must be below upper tick -1000")]
+fn test_provide_concentrated_liquidity_validates_tick_bounds() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    let mut config = get_cl_provide_config(None);
+    config.tick_range = TickRange {
+        lower_tick: 1000,
+        upper_tick: -1000,
+    };
+    suite.provide_concentrated_liquidity(
+        vec![coin(100, DENOM_ATOM), coin(100, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        config,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Price range error")]
+fn test_provide_concentrated_liquidity_validates_price_range() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+
+    let mut config = get_cl_provide_config(None);
+    config.expected_spot_price = Decimal::from_str("2.0").unwrap();
+    config.acceptable_price_spread = Decimal::from_str("0.5").unwrap();
+    suite.provide_concentrated_liquidity(
+        vec![coin(100, DENOM_ATOM), coin(100, DENOM_LS_ATOM_ON_NTRN)],
+        suite.faucet.clone(),
+        config,
+    );
+}
+
+#[test]
+fn test_withdraw_concentrated_liquidity_partial() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+    create_cl_position(&mut suite);
+
+    let atom_bal = suite.query_balance(&suite.faucet, DENOM_ATOM);
+    let ls_atom_bal = suite.query_balance(&suite.faucet, DENOM_LS_ATOM_ON_NTRN);
+
+    let resp = suite.withdraw_concentrated_liquidity(
+        suite.faucet.clone(),
+        OutpostWithdrawConcentratedLiquidityConfig {
+            position_id: Uint64::new(1),
+            percentage: Decimal::percent(50),
+            price_guard: Some(OutpostWithdrawPriceGuard::PriceRange {
+                expected_spot_price: Decimal::from_str("1.0").unwrap(),
+                acceptable_price_spread: Decimal::from_str("0.01").unwrap(),
+            }),
+        },
+    );
+
+    assert_eq!(get_wasm_attribute(&resp, "closed_position_id"), None);
+    suite.assert_balance(&suite.faucet, coin(atom_bal.amount.u128() + 50, DENOM_ATOM));
+    suite.assert_balance(
+        &suite.faucet,
+        coin(ls_atom_bal.amount.u128() + 50, DENOM_LS_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(&suite.outpost, coin(0, DENOM_ATOM));
+}
+
+#[test]
+fn test_withdraw_concentrated_liquidity_full() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+    create_cl_position(&mut suite);
+
+    let atom_bal = suite.query_balance(&suite.faucet, DENOM_ATOM);
+    let ls_atom_bal = suite.query_balance(&suite.faucet, DENOM_LS_ATOM_ON_NTRN);
+
+    let resp = suite.withdraw_concentrated_liquidity(
+        suite.faucet.clone(),
+        OutpostWithdrawConcentratedLiquidityConfig {
+            position_id: Uint64::new(1),
+            percentage: Decimal::one(),
+            price_guard: None,
+        },
+    );
+
+    assert_eq!(
+        get_wasm_attribute(&resp, "closed_position_id"),
+        Some("1".to_string())
+    );
+    suite.assert_balance(
+        &suite.faucet,
+        coin(atom_bal.amount.u128() + 100, DENOM_ATOM),
+    );
+    suite.assert_balance(
+        &suite.faucet,
+        coin(ls_atom_bal.amount.u128() + 100, DENOM_LS_ATOM_ON_NTRN),
+    );
+}
+
+#[test]
+fn test_withdraw_concentrated_liquidity_refunds_incentives() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+    create_cl_position(&mut suite);
+
+    let incentives_bal = suite.query_balance(&suite.faucet, CL_POSITION_INCENTIVES_DENOM);
+
+    let resp = suite.withdraw_concentrated_liquidity(
+        suite.faucet.clone(),
+        OutpostWithdrawConcentratedLiquidityConfig {
+            position_id: Uint64::new(1),
+            percentage: Decimal::percent(50),
+            price_guard: None,
+        },
+    );
+
+    let refund_tokens: Vec<Coin> =
+        from_json(get_wasm_attribute(&resp, "refund_tokens").unwrap()).unwrap();
+    assert!(refund_tokens.contains(&coin(CL_POSITION_INCENTIVES, CL_POSITION_INCENTIVES_DENOM)));
+    suite.assert_balance(
+        &suite.faucet,
+        coin(
+            incentives_bal.amount.u128() + CL_POSITION_INCENTIVES,
+            CL_POSITION_INCENTIVES_DENOM,
+        ),
+    );
+    suite.assert_balance(&suite.outpost, coin(0, CL_POSITION_INCENTIVES_DENOM));
+}
+
+#[test]
+#[should_panic(expected = "position 1 is not owned by the sender")]
+fn test_withdraw_concentrated_liquidity_validates_position_owner() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+    create_cl_position(&mut suite);
+
+    suite.withdraw_concentrated_liquidity(
+        suite.admin.clone(),
+        OutpostWithdrawConcentratedLiquidityConfig {
+            position_id: Uint64::new(1),
+            percentage: Decimal::one(),
+            price_guard: None,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "withdraw percentage must be in range (0, 1], got 0")]
+fn test_withdraw_concentrated_liquidity_validates_percentage() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+    create_cl_position(&mut suite);
+
+    suite.withdraw_concentrated_liquidity(
+        suite.faucet.clone(),
+        OutpostWithdrawConcentratedLiquidityConfig {
+            position_id: Uint64::new(1),
+            percentage: Decimal::zero(),
+            price_guard: None,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Price range error")]
+fn test_withdraw_concentrated_liquidity_validates_price_range() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+    create_cl_position(&mut suite);
+
+    suite.withdraw_concentrated_liquidity(
+        suite.faucet.clone(),
+        OutpostWithdrawConcentratedLiquidityConfig {
+            position_id: Uint64::new(1),
+            percentage: Decimal::one(),
+            price_guard: Some(OutpostWithdrawPriceGuard::PriceRange {
+                expected_spot_price: Decimal::from_str("2.0").unwrap(),
+                acceptable_price_spread: Decimal::from_str("0.5").unwrap(),
+            }),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "min assets per share guard does not apply to positions")]
+fn test_withdraw_concentrated_liquidity_rejects_min_assets_per_share() {
+    let mut suite = OsmoLpOutpostBuilder::default().build();
+    create_cl_position(&mut suite);
+
+    suite.withdraw_concentrated_liquidity(
+        suite.faucet.clone(),
+        OutpostWithdrawConcentratedLiquidityConfig {
+            position_id: Uint64::new(1),
+            percentage: Decimal::one(),
+            price_guard: Some(OutpostWithdrawPriceGuard::MinAssetsPerShare {
+                asset_1: Decimal::one(),
+                asset_2: Decimal::one(),
+            }),
         },
     );
 }
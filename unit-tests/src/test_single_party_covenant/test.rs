@@ -1071,6 +1071,7 @@ fn test_migrate_update_config_with_codes() {
             ])),
             holder_address: None,
             lp_config: None,
+            lp_staking_config: None,
         };
 
     let new_remote_chain_info = RemoteChainInfo {
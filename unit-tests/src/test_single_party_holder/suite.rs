@@ -1,8 +1,16 @@
 use astroport::factory::PairType;
 use cosmwasm_std::{coin, Addr, Coin, Decimal, Uint128};
-use covenant_utils::{op_mode::ContractOperationModeConfig, PoolPriceConfig, SingleSideLpLimits};
+use covenant_utils::{
+    emergency_committee::{
+        EmergencyAction, EmergencyCommittee, EmergencyCommitteeConfig, PendingEmergencyAction,
+    },
+    history::HistoryEntry,
+    op_mode::ContractOperationModeConfig,
+    PoolPriceConfig, ReceiverConfig, SingleSideLpLimits,
+};
 use cw_multi_test::{AppResponse, Executor};
 use cw_utils::Expiration;
+use valence_single_party_pol_holder::msg::{ClaimStatus, EarlyWithdrawalConfig, UnlockSchedule};
 
 use crate::setup::{
     base_suite::{BaseSuite, BaseSuiteMut},
@@ -67,6 +75,10 @@ impl Default for SinglePartyHolderBuilder {
             },
             pair_type: PairType::Stable {},
             holder_address: holder_addr.to_string(),
+            lp_staking_config: None,
+            zap_config: None,
+            twap_window_seconds: None,
+            withdraw_price_guard: None,
         };
 
         builder.contract_init2(
@@ -94,13 +106,13 @@ impl SinglePartyHolderBuilder {
         self
     }
 
-    pub fn with_withdraw_to(mut self, addr: String) -> Self {
-        self.instantiate_msg.with_withdraw_to(addr);
+    pub fn with_withdraw_to(mut self, receiver: ReceiverConfig) -> Self {
+        self.instantiate_msg.with_withdraw_to(receiver);
         self
     }
 
-    pub fn with_emergency_committee_addr(mut self, addr: Option<String>) -> Self {
-        self.instantiate_msg.with_emergency_committee_addr(addr);
+    pub fn with_emergency_committee(mut self, config: Option<EmergencyCommitteeConfig>) -> Self {
+        self.instantiate_msg.with_emergency_committee(config);
         self
     }
 
@@ -114,6 +126,16 @@ impl SinglePartyHolderBuilder {
         self
     }
 
+    pub fn with_unlock_schedule(mut self, schedule: UnlockSchedule) -> Self {
+        self.instantiate_msg.with_unlock_schedule(Some(schedule));
+        self
+    }
+
+    pub fn with_early_withdrawal_config(mut self, config: EarlyWithdrawalConfig) -> Self {
+        self.instantiate_msg.with_early_withdrawal_config(config);
+        self
+    }
+
     pub fn build(mut self) -> Suite {
         let holder_addr = self.builder.contract_init2(
             self.builder.single_party_holder_code_id,
@@ -174,7 +196,7 @@ pub struct Suite {
     pub clock: Addr,
 
     pub holder_addr: Addr,
-    pub withdraw_to: Option<Addr>,
+    pub withdraw_to: Option<ReceiverConfig>,
     pub withdrawer: Option<Addr>,
     pub liquid_pooler_address: Addr,
 }
@@ -187,7 +209,35 @@ impl Suite {
             .execute_contract(
                 sender,
                 holder,
-                &valence_single_party_pol_holder::msg::ExecuteMsg::Claim {},
+                &valence_single_party_pol_holder::msg::ExecuteMsg::Claim { percentage: None },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn execute_claim_percentage(&mut self, sender: Addr, percentage: Decimal) -> AppResponse {
+        let holder = self.holder_addr.clone();
+
+        self.app
+            .execute_contract(
+                sender,
+                holder,
+                &valence_single_party_pol_holder::msg::ExecuteMsg::Claim {
+                    percentage: Some(percentage),
+                },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn execute_early_withdraw(&mut self, sender: Addr) -> AppResponse {
+        let holder = self.holder_addr.clone();
+
+        self.app
+            .execute_contract(
+                sender,
+                holder,
+                &valence_single_party_pol_holder::msg::ExecuteMsg::EarlyWithdraw {},
                 &[],
             )
             .unwrap()
@@ -219,14 +269,46 @@ impl Suite {
             .unwrap()
     }
 
-    pub fn execute_emergency_withdraw(&mut self, sender: Addr) -> AppResponse {
+    pub fn execute_propose_emergency_action(
+        &mut self,
+        sender: Addr,
+        action: EmergencyAction,
+    ) -> AppResponse {
         let holder = self.holder_addr.clone();
 
         self.app
             .execute_contract(
                 sender,
                 holder,
-                &valence_single_party_pol_holder::msg::ExecuteMsg::EmergencyWithdraw {},
+                &valence_single_party_pol_holder::msg::ExecuteMsg::ProposeEmergencyAction {
+                    action,
+                },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn execute_approve_emergency_action(&mut self, sender: Addr, id: u64) -> AppResponse {
+        let holder = self.holder_addr.clone();
+
+        self.app
+            .execute_contract(
+                sender,
+                holder,
+                &valence_single_party_pol_holder::msg::ExecuteMsg::ApproveEmergencyAction { id },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn execute_emergency_action(&mut self, sender: Addr, id: u64) -> AppResponse {
+        let holder = self.holder_addr.clone();
+
+        self.app
+            .execute_contract(
+                sender,
+                holder,
+                &valence_single_party_pol_holder::msg::ExecuteMsg::ExecuteEmergencyAction { id },
                 &[],
             )
             .unwrap()
@@ -264,6 +346,20 @@ impl Suite {
             .unwrap()
     }
 
+    pub fn query_history(
+        &mut self,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Vec<HistoryEntry> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_single_party_pol_holder::msg::QueryMsg::History { start_after, limit },
+            )
+            .unwrap()
+    }
+
     pub fn query_withdrawer(&mut self) -> Option<Addr> {
         self.app
             .wrap()
@@ -274,7 +370,7 @@ impl Suite {
             .unwrap()
     }
 
-    pub fn query_withdraw_to(&mut self) -> Option<Addr> {
+    pub fn query_withdraw_to(&mut self) -> Option<ReceiverConfig> {
         self.app
             .wrap()
             .query_wasm_smart(
@@ -294,12 +390,42 @@ impl Suite {
             .unwrap()
     }
 
-    pub fn query_emergency_committee(&mut self) -> Option<Addr> {
+    pub fn query_emergency_committee(&mut self) -> Option<EmergencyCommittee> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_single_party_pol_holder::msg::QueryMsg::EmergencyCommittee {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_pending_emergency_actions(&mut self) -> Vec<PendingEmergencyAction> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_single_party_pol_holder::msg::QueryMsg::PendingEmergencyActions {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_claim_status(&mut self) -> ClaimStatus {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_single_party_pol_holder::msg::QueryMsg::ClaimStatus {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_early_withdrawal_penalty(&mut self) -> Option<Decimal> {
         self.app
             .wrap()
             .query_wasm_smart(
                 self.holder_addr.clone(),
-                &valence_single_party_pol_holder::msg::QueryMsg::EmergencyCommitteeAddr {},
+                &valence_single_party_pol_holder::msg::QueryMsg::EarlyWithdrawalPenalty {},
             )
             .unwrap()
     }
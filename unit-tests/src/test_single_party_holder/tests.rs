@@ -1,8 +1,22 @@
-use cosmwasm_std::{coin, Addr, Event, Storage};
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{coin, coins, to_json_vec, Addr, Decimal, Event, Storage, Uint128, Uint64};
+use covenant_utils::{
+    emergency_committee::{EmergencyAction, EmergencyCommitteeConfig},
+    history::HistoryAction,
+    split::SplitConfig,
+    DestinationConfig, ReceiverConfig,
+};
 use cw_multi_test::Executor;
-use cw_utils::Expiration;
+use cw_utils::{Duration, Expiration};
+use valence_single_party_pol_holder::msg::{
+    EarlyWithdrawalConfig, EarlyWithdrawalTerms, UnlockSchedule,
+};
 
-use crate::setup::{base_suite::BaseSuite, ADMIN, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN};
+use crate::setup::{
+    base_suite::BaseSuite, ADMIN, DENOM_ATOM, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN,
+    DENOM_NTRN, NTRN_HUB_CHANNEL,
+};
 
 use super::suite::SinglePartyHolderBuilder;
 
@@ -26,7 +40,20 @@ fn test_instantiate_invalid_liquid_pooler_addr() {
 #[should_panic]
 fn test_instantiate_invalid_withdraw_to_addr() {
     SinglePartyHolderBuilder::default()
-        .with_withdraw_to("0Oo0Oo".to_string())
+        .with_withdraw_to(ReceiverConfig::Native("0Oo0Oo".to_string()))
+        .build();
+}
+
+#[test]
+#[should_panic]
+fn test_instantiate_invalid_ibc_withdraw_to_addr() {
+    SinglePartyHolderBuilder::default()
+        .with_withdraw_to(ReceiverConfig::Ibc(DestinationConfig {
+            local_to_destination_chain_channel_id: NTRN_HUB_CHANNEL.0.to_string(),
+            destination_receiver_addr: "invalid".to_string(),
+            ibc_transfer_timeout: Uint64::new(1000),
+            denom_to_pfm_map: BTreeMap::new(),
+        }))
         .build();
 }
 
@@ -34,7 +61,27 @@ fn test_instantiate_invalid_withdraw_to_addr() {
 #[should_panic]
 fn test_instantiate_invalid_emergency_committee_addr() {
     SinglePartyHolderBuilder::default()
-        .with_emergency_committee_addr(Some("0Oo0Oo".to_string()))
+        .with_emergency_committee(Some(EmergencyCommitteeConfig {
+            members: vec!["0Oo0Oo".to_string()],
+            threshold: 1,
+            timelock: Duration::Height(0),
+            safe_mode: false,
+        }))
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "emergency committee threshold must be in range of [1, members count]")]
+fn test_instantiate_validates_emergency_committee_threshold() {
+    let builder = SinglePartyHolderBuilder::default();
+    let member = builder.clock_addr.to_string();
+    builder
+        .with_emergency_committee(Some(EmergencyCommitteeConfig {
+            members: vec![member],
+            threshold: 2,
+            timelock: Duration::Height(0),
+            safe_mode: false,
+        }))
         .build();
 }
 
@@ -104,6 +151,371 @@ fn test_execute_claim_happy() {
     assert_eq!(bals.len(), 2);
 }
 
+#[test]
+fn test_execute_claim_withdraws_to_ibc_receiver() {
+    let mut builder = SinglePartyHolderBuilder::default();
+    let receiver = builder.builder.get_random_addr();
+    let mut suite = builder
+        .with_withdraw_to(ReceiverConfig::Ibc(DestinationConfig {
+            local_to_destination_chain_channel_id: NTRN_HUB_CHANNEL.0.to_string(),
+            destination_receiver_addr: receiver.to_string(),
+            ibc_transfer_timeout: Uint64::new(1000),
+            denom_to_pfm_map: BTreeMap::new(),
+        }))
+        .build();
+
+    suite.enter_pool();
+    // holder pays the ibc fees
+    suite.fund_contract(&coins(100_000, DENOM_NTRN), suite.holder_addr.clone());
+    suite.expire_lockup();
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_claim(sender);
+
+    // claimed funds are transferred to the remote receiver
+    suite.assert_balance(&suite.holder_addr, coin(0, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.holder_addr, coin(0, DENOM_LS_ATOM_ON_NTRN));
+    assert!(!suite.query_balance(&receiver, DENOM_ATOM).amount.is_zero());
+    assert_eq!(suite.query_all_balances(&receiver).len(), 2);
+}
+
+#[test]
+fn test_query_history_records_claim_and_distribution() {
+    let mut suite = SinglePartyHolderBuilder::default().build();
+
+    suite.enter_pool();
+    suite.expire_lockup();
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_claim(sender.clone());
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].action, HistoryAction::Claim);
+    assert_eq!(history[0].party, Some(sender.to_string()));
+    assert!(history[0].coins.is_empty());
+    assert_eq!(history[1].action, HistoryAction::Distribution);
+    assert_eq!(history[1].coins.len(), 2);
+
+    let paginated = suite.query_history(Some(history[0].id), Some(1));
+    assert_eq!(paginated, vec![history[1].clone()]);
+}
+
+#[test]
+fn test_execute_claim_partial_percentage() {
+    let mut suite = SinglePartyHolderBuilder::default().build();
+
+    suite.enter_pool();
+    suite.expire_lockup();
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_claim_percentage(sender.clone(), Decimal::percent(25));
+
+    let claim_status = suite.query_claim_status();
+    assert_eq!(claim_status.unlocked, Decimal::one());
+    assert_eq!(claim_status.withdrawn, Decimal::percent(25));
+    assert_eq!(claim_status.claimable, Decimal::percent(75));
+    let partial_claim_amount = suite
+        .query_balance(&suite.liquid_pooler_address, DENOM_ATOM_ON_NTRN)
+        .amount;
+    assert!(!partial_claim_amount.is_zero());
+
+    // claiming the rest of the position
+    suite.execute_claim(sender);
+
+    let claim_status = suite.query_claim_status();
+    assert_eq!(claim_status.withdrawn, Decimal::one());
+    assert!(claim_status.claimable.is_zero());
+    let total_claim_amount = suite
+        .query_balance(&suite.liquid_pooler_address, DENOM_ATOM_ON_NTRN)
+        .amount;
+
+    // first claim got a quarter of the total, give or take rounding
+    let expected_partial_amount = total_claim_amount.multiply_ratio(1u128, 4u128);
+    assert!(partial_claim_amount.abs_diff(expected_partial_amount) <= Uint128::new(10));
+}
+
+#[test]
+#[should_panic(
+    expected = "Claim percentage must be positive and not exceed the claimable share: 0.25"
+)]
+fn test_execute_claim_validates_unlocked_percentage() {
+    let mut suite = SinglePartyHolderBuilder::default()
+        .with_unlock_schedule(UnlockSchedule {
+            unlock_percentage: Decimal::percent(25),
+            interval: Duration::Height(10),
+        })
+        .build();
+
+    suite.enter_pool();
+    suite.expire_lockup();
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_claim_percentage(sender, Decimal::percent(50));
+}
+
+#[test]
+fn test_execute_claim_follows_unlock_schedule() {
+    let mut suite = SinglePartyHolderBuilder::default()
+        .with_unlock_schedule(UnlockSchedule {
+            unlock_percentage: Decimal::percent(25),
+            interval: Duration::Height(10),
+        })
+        .build();
+
+    suite.enter_pool();
+    assert!(suite.query_claim_status().unlocked.is_zero());
+
+    // first tranche unlocks with the lockup period
+    suite.expire_lockup();
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_claim(sender.clone());
+
+    let claim_status = suite.query_claim_status();
+    assert_eq!(claim_status.unlocked, Decimal::percent(25));
+    assert_eq!(claim_status.withdrawn, Decimal::percent(25));
+    assert!(claim_status.claimable.is_zero());
+
+    // two more tranches unlock after two intervals
+    suite.app.update_block(|b| b.height += 20);
+    let claim_status = suite.query_claim_status();
+    assert_eq!(claim_status.unlocked, Decimal::percent(75));
+    assert_eq!(claim_status.claimable, Decimal::percent(50));
+
+    suite.execute_claim_percentage(sender.clone(), Decimal::percent(10));
+    assert_eq!(suite.query_claim_status().claimable, Decimal::percent(40));
+
+    // the whole position is unlocked eventually
+    suite.app.update_block(|b| b.height += 100);
+    suite.execute_claim(sender);
+
+    let claim_status = suite.query_claim_status();
+    assert_eq!(claim_status.unlocked, Decimal::one());
+    assert_eq!(claim_status.withdrawn, Decimal::one());
+    assert!(claim_status.claimable.is_zero());
+
+    let history = suite.query_history(None, None);
+    assert_eq!(
+        history
+            .iter()
+            .filter(|entry| entry.action == HistoryAction::Claim)
+            .count(),
+        3
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Unlock interval must be non-zero and of the same kind as the lockup period"
+)]
+fn test_instantiate_validates_unlock_interval() {
+    SinglePartyHolderBuilder::default()
+        .with_unlock_schedule(UnlockSchedule {
+            unlock_percentage: Decimal::percent(25),
+            interval: Duration::Time(10),
+        })
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Unlock percentage must be between 0 and 1")]
+fn test_instantiate_validates_unlock_percentage() {
+    SinglePartyHolderBuilder::default()
+        .with_unlock_schedule(UnlockSchedule {
+            unlock_percentage: Decimal::zero(),
+            interval: Duration::Height(10),
+        })
+        .build();
+}
+
+fn get_early_withdrawal_config(penalty: Decimal, beneficiary: &Addr) -> EarlyWithdrawalConfig {
+    EarlyWithdrawalConfig::Enabled(EarlyWithdrawalTerms {
+        penalty,
+        penalty_split: SplitConfig {
+            receivers: BTreeMap::from([(beneficiary.to_string(), Decimal::one())]),
+        },
+    })
+}
+
+#[test]
+#[should_panic(expected = "Early withdrawal penalty must be in range of [0.0, 1.0)")]
+fn test_instantiate_validates_early_withdrawal_penalty() {
+    let mut builder = SinglePartyHolderBuilder::default();
+    let beneficiary = builder.builder.get_random_addr();
+    builder
+        .with_early_withdrawal_config(get_early_withdrawal_config(Decimal::one(), &beneficiary))
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "shares must add up to 1.0")]
+fn test_instantiate_validates_early_withdrawal_penalty_split() {
+    let mut builder = SinglePartyHolderBuilder::default();
+    let beneficiary = builder.builder.get_random_addr();
+    builder
+        .with_early_withdrawal_config(EarlyWithdrawalConfig::Enabled(EarlyWithdrawalTerms {
+            penalty: Decimal::percent(10),
+            penalty_split: SplitConfig {
+                receivers: BTreeMap::from([(beneficiary.to_string(), Decimal::percent(50))]),
+            },
+        }))
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Early withdrawal is disabled")]
+fn test_execute_early_withdraw_validates_config() {
+    let mut suite = SinglePartyHolderBuilder::default().build();
+
+    suite.enter_pool();
+    assert_eq!(suite.query_early_withdrawal_penalty(), None);
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_early_withdraw(sender);
+}
+
+#[test]
+#[should_panic(expected = "Early withdrawal is disabled")]
+fn test_execute_early_withdraw_defaults_to_disabled_without_config() {
+    let mut builder = SinglePartyHolderBuilder::default();
+    let beneficiary = builder.builder.get_random_addr();
+    let mut suite = builder
+        .with_early_withdrawal_config(get_early_withdrawal_config(
+            Decimal::percent(10),
+            &beneficiary,
+        ))
+        .build();
+
+    suite.enter_pool();
+
+    // holders migrated from previous versions have no early withdrawal config
+    let early_withdrawal_config_key = "\0\u{4}wasm\0Ocontract_data/cosmos1lxsjav25s55mnxkfzkmvhdkqpsnmlm9whwk8ctqawgj438kda96s54a6mlearly_withdrawal_config".as_bytes();
+    suite.app.storage_mut().remove(early_withdrawal_config_key);
+    assert_eq!(suite.query_early_withdrawal_penalty(), None);
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_early_withdraw(sender);
+}
+
+#[test]
+#[should_panic(expected = "The lockup period is already expired")]
+fn test_execute_early_withdraw_validates_lockup_period() {
+    let mut builder = SinglePartyHolderBuilder::default();
+    let beneficiary = builder.builder.get_random_addr();
+    let mut suite = builder
+        .with_early_withdrawal_config(get_early_withdrawal_config(
+            Decimal::percent(10),
+            &beneficiary,
+        ))
+        .build();
+
+    suite.enter_pool();
+    suite.expire_lockup();
+    assert_eq!(
+        suite.query_early_withdrawal_penalty(),
+        Some(Decimal::zero())
+    );
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_early_withdraw(sender);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_execute_early_withdraw_validates_withdrawer() {
+    let mut builder = SinglePartyHolderBuilder::default();
+    let beneficiary = builder.builder.get_random_addr();
+    let mut suite = builder
+        .with_early_withdrawal_config(get_early_withdrawal_config(
+            Decimal::percent(10),
+            &beneficiary,
+        ))
+        .build();
+
+    suite.enter_pool();
+
+    let sender = suite.faucet.clone();
+    suite.execute_early_withdraw(sender);
+}
+
+#[test]
+fn test_execute_early_withdraw_happy() {
+    let mut builder = SinglePartyHolderBuilder::default();
+    let beneficiary = builder.builder.get_random_addr();
+    let mut suite = builder
+        .with_early_withdrawal_config(get_early_withdrawal_config(
+            Decimal::percent(10),
+            &beneficiary,
+        ))
+        .build();
+
+    suite.enter_pool();
+    assert_eq!(
+        suite.query_early_withdrawal_penalty(),
+        Some(Decimal::percent(10))
+    );
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_early_withdraw(sender.clone()).assert_event(
+        &Event::new("wasm")
+            .add_attribute("method", "try_early_withdraw")
+            .add_attribute("early_withdrawal_penalty", "0.1"),
+    );
+
+    // withdrawn funds are split between the withdraw_to address and the beneficiary
+    for denom in [DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN] {
+        let penalty_amount = suite.query_balance(&beneficiary, denom).amount;
+        let withdrawn_amount = suite
+            .query_balance(&suite.liquid_pooler_address, denom)
+            .amount;
+        assert!(!penalty_amount.is_zero());
+        assert_eq!(
+            penalty_amount,
+            (penalty_amount + withdrawn_amount).multiply_ratio(1u128, 10u128)
+        );
+    }
+
+    assert_eq!(suite.query_claim_status().withdrawn, Decimal::one());
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history[0].action, HistoryAction::EarlyWithdrawal);
+    assert_eq!(history[0].party, Some(sender.to_string()));
+    assert_eq!(history[1].action, HistoryAction::Distribution);
+}
+
+#[test]
+fn test_query_early_withdrawal_penalty_applies_to_locked_share() {
+    let mut builder = SinglePartyHolderBuilder::default();
+    let beneficiary = builder.builder.get_random_addr();
+    let mut suite = builder
+        .with_early_withdrawal_config(get_early_withdrawal_config(
+            Decimal::percent(10),
+            &beneficiary,
+        ))
+        .with_unlock_schedule(UnlockSchedule {
+            unlock_percentage: Decimal::percent(25),
+            interval: Duration::Height(10),
+        })
+        .build();
+
+    suite.enter_pool();
+    suite.expire_lockup();
+
+    // a quarter of the position is unlocked, so only three quarters are penalized
+    assert_eq!(
+        suite.query_early_withdrawal_penalty(),
+        Some(Decimal::permille(75))
+    );
+
+    // once the unlocked quarter is claimed, the whole remaining position is locked
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_claim(sender);
+    assert_eq!(
+        suite.query_early_withdrawal_penalty(),
+        Some(Decimal::percent(10))
+    );
+}
+
 #[test]
 #[should_panic(expected = "A withdraw process already started")]
 fn test_execute_emergency_withdraw_validates_pending_withdrawals() {
@@ -121,15 +533,89 @@ fn test_execute_emergency_withdraw_validates_pending_withdrawals() {
         .storage_mut()
         .set(withdraw_state_key, "true".as_bytes());
 
-    suite.execute_emergency_withdraw(sender);
+    suite.execute_propose_emergency_action(sender.clone(), EmergencyAction::WithdrawToParties {});
+    suite.execute_emergency_action(sender, 0);
 }
 
 #[test]
-#[should_panic(expected = "Unauthorized")]
+#[should_panic(expected = "only emergency committee members can perform this action")]
 fn test_execute_emergency_withdraw_validates_emergency_committee() {
     let mut suite = SinglePartyHolderBuilder::default().build();
     let sender = suite.clock.clone();
-    suite.execute_emergency_withdraw(sender);
+    suite.execute_propose_emergency_action(sender, EmergencyAction::WithdrawToParties {});
+}
+
+#[test]
+#[should_panic(expected = "emergency action has 1 out of 2 required approvals")]
+fn test_execute_emergency_action_validates_threshold() {
+    let builder = SinglePartyHolderBuilder::default();
+    let members = vec![
+        builder.instantiate_msg.msg.pooler_address.to_string(),
+        builder.clock_addr.to_string(),
+    ];
+    let mut suite = builder
+        .with_emergency_committee(Some(EmergencyCommitteeConfig {
+            members,
+            threshold: 2,
+            timelock: Duration::Height(0),
+            safe_mode: false,
+        }))
+        .build();
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_propose_emergency_action(sender.clone(), EmergencyAction::WithdrawToParties {});
+    suite.execute_emergency_action(sender, 0);
+}
+
+#[test]
+#[should_panic(expected = "emergency action timelock is not over")]
+fn test_execute_emergency_action_validates_timelock() {
+    let builder = SinglePartyHolderBuilder::default();
+    let member = builder.instantiate_msg.msg.pooler_address.to_string();
+    let mut suite = builder
+        .with_emergency_committee(Some(EmergencyCommitteeConfig {
+            members: vec![member],
+            threshold: 1,
+            timelock: Duration::Height(10),
+            safe_mode: false,
+        }))
+        .build();
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_propose_emergency_action(sender.clone(), EmergencyAction::WithdrawToParties {});
+    suite.app.update_block(|b| b.height += 9);
+    suite.execute_emergency_action(sender, 0);
+}
+
+#[test]
+#[should_panic(
+    expected = "emergency committee in safe mode can only route funds to the covenant parties"
+)]
+fn test_execute_propose_emergency_action_validates_safe_mode() {
+    let builder = SinglePartyHolderBuilder::default();
+    let member = builder.instantiate_msg.msg.pooler_address.to_string();
+    let mut suite = builder
+        .with_emergency_committee(Some(EmergencyCommitteeConfig {
+            members: vec![member],
+            threshold: 1,
+            timelock: Duration::Height(0),
+            safe_mode: true,
+        }))
+        .build();
+
+    let sender = suite.liquid_pooler_address.clone();
+    let receiver = suite.clock.to_string();
+    suite.execute_propose_emergency_action(sender, EmergencyAction::WithdrawTo { receiver });
+}
+
+#[test]
+#[should_panic(expected = "emergency action already approved by this member")]
+fn test_execute_approve_emergency_action_validates_duplicate_approvals() {
+    let mut suite = SinglePartyHolderBuilder::default().build();
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.execute_propose_emergency_action(sender.clone(), EmergencyAction::WithdrawToParties {});
+    suite.execute_approve_emergency_action(sender, 0);
 }
 
 #[test]
@@ -143,10 +629,58 @@ fn test_execute_emergency_withdraw_happy() {
     assert_eq!(bals.len(), 0);
 
     suite.expire_lockup();
-    suite.execute_emergency_withdraw(sender);
+    suite.execute_propose_emergency_action(sender.clone(), EmergencyAction::WithdrawToParties {});
+    suite.execute_emergency_action(sender, 0);
 
     let bals = suite.query_all_balances(&suite.liquid_pooler_address);
     assert_eq!(bals.len(), 2);
+    assert!(suite.query_pending_emergency_actions().is_empty());
+}
+
+#[test]
+fn test_execute_emergency_withdraw_to_receiver_happy() {
+    let builder = SinglePartyHolderBuilder::default();
+    let members = vec![
+        builder.instantiate_msg.msg.pooler_address.to_string(),
+        builder.clock_addr.to_string(),
+    ];
+    let mut suite = builder
+        .with_emergency_committee(Some(EmergencyCommitteeConfig {
+            members,
+            threshold: 2,
+            timelock: Duration::Height(10),
+            safe_mode: false,
+        }))
+        .build();
+
+    suite.enter_pool();
+
+    let proposer = suite.liquid_pooler_address.clone();
+    let approver = suite.clock.clone();
+    let receiver = suite.faucet.clone();
+    let receiver_bals = suite.query_all_balances(&receiver);
+
+    suite.execute_propose_emergency_action(
+        proposer,
+        EmergencyAction::WithdrawTo {
+            receiver: receiver.to_string(),
+        },
+    );
+    suite.execute_approve_emergency_action(approver.clone(), 0);
+
+    let pending_actions = suite.query_pending_emergency_actions();
+    assert_eq!(pending_actions.len(), 1);
+    assert_eq!(pending_actions[0].approvals.len(), 2);
+
+    suite.app.update_block(|b| b.height += 10);
+    suite.execute_emergency_action(approver, 0);
+
+    assert!(suite.query_pending_emergency_actions().is_empty());
+    assert_eq!(
+        suite.query_all_balances(&suite.liquid_pooler_address).len(),
+        0
+    );
+    assert_ne!(suite.query_all_balances(&receiver), receiver_bals);
 }
 
 #[test]
@@ -165,8 +699,18 @@ fn test_execute_distribute_validates_liquidity_pooler() {
 }
 
 #[test]
-#[should_panic(expected = "We expect 2 denoms to be received from the liquidity pooler")]
-fn test_execute_distribute_ensures_two_denoms_sent() {
+#[should_panic(expected = "We expect 1 or 2 denoms to be received from the liquidity pooler")]
+fn test_execute_distribute_ensures_denoms_sent() {
+    let mut suite = SinglePartyHolderBuilder::default().build();
+
+    let sender = suite.liquid_pooler_address.clone();
+    suite.expire_lockup();
+
+    suite.execute_distribute(sender, vec![]);
+}
+
+#[test]
+fn test_execute_distribute_accepts_single_denom() {
     let mut suite = SinglePartyHolderBuilder::default().build();
 
     let sender = suite.liquid_pooler_address.clone();
@@ -175,6 +719,11 @@ fn test_execute_distribute_ensures_two_denoms_sent() {
     suite.expire_lockup();
 
     suite.execute_distribute(sender, funds);
+
+    assert_eq!(
+        suite.query_all_balances(&suite.liquid_pooler_address).len(),
+        0
+    );
 }
 
 #[test]
@@ -209,6 +758,8 @@ fn test_migrate_update_config_validates_lockup_config() {
                 emergency_committee: None,
                 pooler_address: None,
                 lockup_period: Some(past_expiration),
+                unlock_schedule: None,
+                early_withdrawal_config: None,
             },
             5,
         )
@@ -228,10 +779,17 @@ fn test_migrate_update_config() {
             suite.holder_addr.clone(),
             &valence_single_party_pol_holder::msg::MigrateMsg::UpdateConfig {
                 withdrawer: Some(clock.to_string()),
-                withdraw_to: Some(clock.to_string()),
-                emergency_committee: Some(clock.to_string()),
+                withdraw_to: Some(ReceiverConfig::Native(clock.to_string())),
+                emergency_committee: Some(EmergencyCommitteeConfig {
+                    members: vec![clock.to_string()],
+                    threshold: 1,
+                    timelock: Duration::Height(5),
+                    safe_mode: true,
+                }),
                 pooler_address: Some(clock.to_string()),
                 lockup_period: Some(Expiration::AtHeight(192837465)),
+                unlock_schedule: None,
+                early_withdrawal_config: None,
             },
             5,
         )
@@ -240,20 +798,61 @@ fn test_migrate_update_config() {
     resp.assert_event(
         &Event::new("wasm")
             .add_attribute("withdrawer", clock.to_string())
-            .add_attribute("withdraw_to", clock.to_string())
-            .add_attribute("emergency_committee", clock.to_string())
+            .add_attribute(
+                "withdraw_to",
+                format!("{:?}", ReceiverConfig::Native(clock.to_string())),
+            )
+            .add_attribute("emergency_committee_members", clock.to_string())
+            .add_attribute("emergency_committee_safe_mode", "true")
             .add_attribute("pool_address", clock.to_string()),
     );
 
     let withdrawer = suite.query_withdrawer().unwrap().to_string();
-    let withdraw_to = suite.query_withdraw_to().unwrap().to_string();
-    let emergency_committee = suite.query_emergency_committee().unwrap().to_string();
+    let withdraw_to = suite.query_withdraw_to().unwrap();
+    let emergency_committee = suite.query_emergency_committee().unwrap();
     let pooler_address = suite.query_pooler_address().to_string();
     let lockup_period = suite.query_lockup_period();
 
     assert_eq!(clock, withdrawer);
-    assert_eq!(clock, withdraw_to);
-    assert_eq!(clock, emergency_committee);
+    assert_eq!(ReceiverConfig::Native(clock.to_string()), withdraw_to);
+    assert_eq!(
+        vec![Addr::unchecked(clock.clone())],
+        emergency_committee.members
+    );
+    assert_eq!(Duration::Height(5), emergency_committee.timelock);
+    assert!(emergency_committee.safe_mode);
     assert_eq!(clock, pooler_address);
     assert_eq!(Expiration::AtHeight(192837465), lockup_period);
 }
+
+#[test]
+fn test_migrate_converts_legacy_withdraw_to_address() {
+    let mut suite = SinglePartyHolderBuilder::default().build();
+    let clock = suite.clock.to_string();
+
+    // previous versions stored a plain address under the same key
+    let withdraw_to_key = "\0\u{4}wasm\0Ocontract_data/cosmos1lxsjav25s55mnxkfzkmvhdkqpsnmlm9whwk8ctqawgj438kda96s54a6mlwithdraw_to".as_bytes();
+    suite.app.storage_mut().set(
+        withdraw_to_key,
+        &to_json_vec(&Addr::unchecked(clock.to_string())).unwrap(),
+    );
+
+    suite
+        .app
+        .migrate_contract(
+            Addr::unchecked(ADMIN),
+            suite.holder_addr.clone(),
+            &valence_single_party_pol_holder::msg::MigrateMsg::UpdateCodeId { data: None },
+            5,
+        )
+        .unwrap()
+        .assert_event(&Event::new("wasm").add_attribute(
+            "withdraw_to",
+            format!("{:?}", ReceiverConfig::Native(clock.to_string())),
+        ));
+
+    assert_eq!(
+        suite.query_withdraw_to().unwrap(),
+        ReceiverConfig::Native(clock)
+    );
+}
@@ -1,8 +1,11 @@
 use std::vec;
 
-use cosmwasm_std::{coins, Addr, Coin, Decimal, StdResult};
+use cosmwasm_std::{coin, coins, Addr, Coin, Decimal, StdResult, Uint128, Uint64};
+use covenant_utils::{PriceSource, ReceiverConfig};
 use cw_multi_test::Executor;
 use valence_covenant_swap::msg::CovenantContractCodes;
+use valence_swap_holder::msg::RefundConfig;
+use valence_vesting_escrow::msg::VestingProgress;
 
 use crate::setup::{
     base_suite::{BaseSuite, BaseSuiteMut},
@@ -315,6 +318,156 @@ impl Suite {
         Self::build(builder, covenant_addr, party_a_receiver, party_b_receiver)
     }
 
+    /// Init covenant with 2 native parties refunded directly to their receivers
+    pub fn new_with_native_refund_receivers() -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        let covenant_addr =
+            builder.get_contract_addr(builder.swap_covenant_code_id, SWAP_COVENANT_SALT);
+
+        let party_a_receiver = builder.get_random_addr();
+        let party_b_receiver = builder.get_random_addr();
+
+        let recievers = vec![
+            (&party_a_receiver, Decimal::bps(5000)),
+            (&party_b_receiver, Decimal::bps(5000)),
+        ];
+        let splits = SwapCovenantInstantiate::get_split_custom(vec![
+            (DENOM_ATOM, &recievers),
+            (DENOM_NTRN, &recievers),
+        ]);
+        let party_a_config = SwapCovenantInstantiate::get_party_config_native(
+            &party_a_receiver,
+            DENOM_ATOM,
+            10_000_000_u128,
+        );
+        let party_b_config = SwapCovenantInstantiate::get_party_config_native(
+            &party_b_receiver,
+            DENOM_NTRN,
+            10_000_000_u128,
+        );
+        let init_msg =
+            SwapCovenantInstantiate::default(&builder, party_a_config, party_b_config, splits)
+                .with_refund_config(RefundConfig {
+                    party_a_refund_receiver: ReceiverConfig::Native(party_a_receiver.to_string()),
+                    party_b_refund_receiver: ReceiverConfig::Native(party_b_receiver.to_string()),
+                })
+                .msg
+                .clone();
+
+        builder.contract_init2(
+            builder.swap_covenant_code_id,
+            SWAP_COVENANT_SALT,
+            &init_msg,
+            &[],
+        );
+
+        Self::build(builder, covenant_addr, party_a_receiver, party_b_receiver)
+    }
+
+    pub fn new_with_price_reference(pool_denom_a: &str, pool_denom_b: &str) -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        let covenant_addr =
+            builder.get_contract_addr(builder.swap_covenant_code_id, SWAP_COVENANT_SALT);
+
+        let party_a_receiver = builder.get_random_addr();
+        let party_b_receiver = builder.get_random_addr();
+
+        let (pool_addr, _lp_token_addr) = builder.init_astro_pool(
+            astroport::factory::PairType::Xyk {},
+            coin(1_000_000_000_000, pool_denom_a),
+            coin(1_000_000_000_000, pool_denom_b),
+        );
+
+        let recievers = vec![
+            (&party_a_receiver, Decimal::bps(5000)),
+            (&party_b_receiver, Decimal::bps(5000)),
+        ];
+        let splits = SwapCovenantInstantiate::get_split_custom(vec![
+            (DENOM_ATOM, &recievers),
+            (DENOM_NTRN, &recievers),
+        ]);
+        let party_a_config = SwapCovenantInstantiate::get_party_config_native(
+            &party_a_receiver,
+            DENOM_ATOM,
+            10_000_000_u128,
+        );
+        let party_b_config = SwapCovenantInstantiate::get_party_config_native(
+            &party_b_receiver,
+            DENOM_NTRN,
+            12_000_000_u128,
+        );
+        let mut init_msg =
+            SwapCovenantInstantiate::default(&builder, party_a_config, party_b_config, splits);
+        init_msg.with_price_reference(valence_covenant_swap::msg::PriceReferenceConfig {
+            pool_address: pool_addr.to_string(),
+            price_source: PriceSource::Spot,
+            spread: Decimal::percent(10),
+            min_party_b_amount: Uint128::new(8_000_000),
+            max_party_b_amount: Uint128::new(12_000_000),
+        });
+
+        builder.contract_init2(
+            builder.swap_covenant_code_id,
+            SWAP_COVENANT_SALT,
+            &init_msg.msg,
+            &[],
+        );
+
+        Self::build(builder, covenant_addr, party_a_receiver, party_b_receiver)
+    }
+
+    /// Init covenant with 2 native parties where party a
+    /// receives its side of the swap through a vesting escrow
+    pub fn new_with_party_a_vesting(cliff: u64, duration: u64) -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        let covenant_addr =
+            builder.get_contract_addr(builder.swap_covenant_code_id, SWAP_COVENANT_SALT);
+
+        let party_a_receiver = builder.get_random_addr();
+        let party_b_receiver = builder.get_random_addr();
+
+        let recievers = vec![
+            (&party_a_receiver, Decimal::bps(5000)),
+            (&party_b_receiver, Decimal::bps(5000)),
+        ];
+        let splits = SwapCovenantInstantiate::get_split_custom(vec![
+            (DENOM_ATOM, &recievers),
+            (DENOM_NTRN, &recievers),
+        ]);
+        let party_a_config = SwapCovenantInstantiate::get_party_config_native(
+            &party_a_receiver,
+            DENOM_ATOM,
+            10_000_000_u128,
+        );
+        let party_b_config = SwapCovenantInstantiate::get_party_config_native(
+            &party_b_receiver,
+            DENOM_NTRN,
+            10_000_000_u128,
+        );
+        let mut init_msg =
+            SwapCovenantInstantiate::default(&builder, party_a_config, party_b_config, splits);
+        init_msg.with_vesting(valence_covenant_swap::msg::VestingConfig {
+            escrow_code: builder.vesting_escrow_code_id,
+            party_a: Some(valence_vesting_escrow::msg::VestingSchedule {
+                cliff: Uint64::new(cliff),
+                duration: Uint64::new(duration),
+            }),
+            party_b: None,
+        });
+
+        builder.contract_init2(
+            builder.swap_covenant_code_id,
+            SWAP_COVENANT_SALT,
+            &init_msg.msg,
+            &[],
+        );
+
+        Self::build(builder, covenant_addr, party_a_receiver, party_b_receiver)
+    }
+
     pub fn new_with_2_interchain_configs() -> Self {
         let mut builder = SuiteBuilder::new();
 
@@ -547,6 +700,30 @@ impl Suite {
         )
     }
 
+    pub fn query_vesting_escrow_addr(&self, party: &str) -> Option<Addr> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_swap::msg::QueryMsg::VestingEscrowAddress {
+                    party: party.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_vesting_progress(&self, party: &str) -> Option<VestingProgress> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.covenant_addr.clone(),
+                &valence_covenant_swap::msg::QueryMsg::VestingProgress {
+                    party: party.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
     pub fn query_contract_codes(&self) -> CovenantContractCodes {
         self.app
             .wrap()
@@ -1,4 +1,4 @@
-use cosmwasm_std::{coin, coins, to_json_binary, Addr, Event, Uint128, Uint64};
+use cosmwasm_std::{coin, coins, to_json_binary, Addr, Decimal, Event, Uint128, Uint64};
 use covenant_utils::op_mode::ContractOperationModeConfig;
 use cw_multi_test::Executor;
 
@@ -456,6 +456,46 @@ fn test_valence_native_refund() {
     );
 }
 
+#[test]
+fn test_valence_native_refund_to_configured_receivers() {
+    let mut suite = Suite::new_with_native_refund_receivers();
+
+    suite.get_and_fund_depositors(
+        coin(10_000_000_u128, DENOM_ATOM),
+        coin(10_000_000_u128, DENOM_NTRN),
+    );
+
+    // tick until holder receive both denoms
+    while suite.query_all_balances(&suite.holder_addr).len() < 2 {
+        suite.tick("Waiting for holder to receive both denoms");
+    }
+
+    let init_receiver_b_ntrn_balance = suite.query_balance(&suite.party_b_receiver, DENOM_NTRN);
+    let init_router_a_atom_balance = suite.query_balance(&suite.router_a_addr, DENOM_ATOM);
+
+    // Expire the covenant
+    suite.app.update_block(|block| {
+        block.time = block.time.plus_hours(1_000_000);
+        block.height += 1_000_000;
+    });
+    // tick to trigger the expiration and then the refund
+    suite.tick_contract(suite.holder_addr.clone());
+    suite.tick_contract(suite.holder_addr.clone());
+
+    // refunds skip the routers and go straight to the configured receivers
+    let receiver_a_balance_atom = suite.query_balance(&suite.party_a_receiver, DENOM_ATOM);
+    assert_eq!(receiver_a_balance_atom.amount.u128(), 10_000_000_u128);
+    let receiver_b_balance_ntrn = suite.query_balance(&suite.party_b_receiver, DENOM_NTRN);
+    assert_eq!(
+        receiver_b_balance_ntrn.amount.u128(),
+        10_000_000_u128 + init_receiver_b_ntrn_balance.amount.u128()
+    );
+    assert_eq!(
+        suite.query_balance(&suite.router_a_addr, DENOM_ATOM),
+        init_router_a_atom_balance
+    );
+}
+
 #[test]
 fn test_migrate_update_with_codes() {
     let mut suite = Suite::new_with_2_native_configs();
@@ -479,6 +519,7 @@ fn test_migrate_update_with_codes() {
         parties_config: Box::new(None),
         covenant_terms: None,
         refund_config: None,
+        fallback_split: None,
     };
 
     let splitter_migrate_msg = valence_native_splitter::msg::MigrateMsg::UpdateConfig {
@@ -507,6 +548,8 @@ fn test_migrate_update_with_codes() {
                 )),
                 party_a_forwarder: Box::new(None),
                 party_b_forwarder: Box::new(None),
+                party_a_vesting_escrow: None,
+                party_b_vesting_escrow: None,
             },
             1,
         )
@@ -587,6 +630,8 @@ fn test_migrate_update_without_codes() {
                 )),
                 party_a_forwarder: Box::new(Some(ibc_forwarder_migrate_msg.clone())),
                 party_b_forwarder: Box::new(Some(ibc_forwarder_migrate_msg.clone())),
+                party_a_vesting_escrow: None,
+                party_b_vesting_escrow: None,
             },
             1,
         )
@@ -687,3 +732,92 @@ fn test_migrate_update_without_codes() {
 //     let receiver_b_balance_osmo = suite.query_balance(&suite.party_b_receiver, DENOM_OSMO);
 //     assert_eq!(receiver_b_balance_osmo.amount.u128(), 10_000_000_u128);
 // }
+
+#[test]
+fn test_instantiate_with_price_reference() {
+    let suite = Suite::new_with_price_reference(DENOM_ATOM, DENOM_NTRN);
+
+    let covenant_terms: covenant_utils::CovenantTerms = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            suite.holder_addr.clone(),
+            &valence_swap_holder::msg::QueryMsg::CovenantTerms {},
+        )
+        .unwrap();
+    assert!(matches!(
+        covenant_terms,
+        covenant_utils::CovenantTerms::PriceReferencedTokenSwap(_)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "does not trade untrn")]
+fn test_instantiate_validates_price_reference_pool() {
+    Suite::new_with_price_reference(DENOM_ATOM, DENOM_OSMO);
+}
+
+#[test]
+fn test_covenant_party_a_vesting() {
+    let mut suite = Suite::new_with_party_a_vesting(100, 1000);
+
+    let escrow_addr = suite.query_vesting_escrow_addr("party_a").unwrap();
+    assert!(suite.query_vesting_escrow_addr("party_b").is_none());
+    assert!(suite.query_vesting_progress("party_b").is_none());
+
+    suite.get_and_fund_depositors(
+        coin(10_000_000_u128, DENOM_ATOM),
+        coin(10_000_000_u128, DENOM_NTRN),
+    );
+
+    // tick until party a vesting starts and party b gets its split
+    while suite.query_all_balances(&suite.party_b_receiver).len() < 2
+        || suite
+            .query_vesting_progress("party_a")
+            .unwrap()
+            .start
+            .is_none()
+    {
+        suite.tick("Wait for party a vesting to start");
+    }
+
+    // party a side of the swap is held by the escrow until the cliff
+    suite.tick("Tick before the vesting cliff");
+    assert!(suite.query_all_balances(&suite.party_a_receiver).is_empty());
+    suite.assert_balance(&escrow_addr, coin(5_000_000, DENOM_ATOM));
+    suite.assert_balance(&escrow_addr, coin(5_000_000, DENOM_NTRN));
+
+    // halfway through the vesting, half of the funds get released
+    suite
+        .app
+        .update_block(|b| b.time = b.time.plus_seconds(500));
+    while suite.query_all_balances(&suite.party_a_receiver).len() < 2 {
+        suite.tick("Wait for receiver_a to get the vested funds");
+    }
+    suite.assert_balance(&suite.party_a_receiver, coin(2_500_000, DENOM_ATOM));
+    suite.assert_balance(&suite.party_a_receiver, coin(2_500_000, DENOM_NTRN));
+
+    let progress = suite.query_vesting_progress("party_a").unwrap();
+    assert_eq!(progress.vested_ratio, Decimal::percent(50));
+
+    // after the vesting duration everything gets released
+    suite
+        .app
+        .update_block(|b| b.time = b.time.plus_seconds(500));
+    while !suite.query_all_balances(&escrow_addr).is_empty() {
+        suite.tick("Wait for the escrow to release everything");
+    }
+    while suite
+        .query_balance(&suite.party_a_receiver, DENOM_ATOM)
+        .amount
+        < Uint128::new(5_000_000)
+        || suite
+            .query_balance(&suite.party_a_receiver, DENOM_NTRN)
+            .amount
+            < Uint128::new(5_000_000)
+    {
+        suite.tick("Wait for receiver_a to get the vested funds");
+    }
+    suite.assert_balance(&suite.party_a_receiver, coin(5_000_000, DENOM_ATOM));
+    suite.assert_balance(&suite.party_a_receiver, coin(5_000_000, DENOM_NTRN));
+}
@@ -12,10 +12,12 @@ use crate::setup::{
 };
 use cosmwasm_std::{Addr, Decimal};
 use covenant_utils::{
+    history::HistoryEntry,
     op_mode::{ContractOperationMode, ContractOperationModeConfig},
     split::SplitConfig,
-    CovenantPartiesConfig, CovenantTerms,
+    CovenantPartiesConfig, CovenantTerms, ReceiverConfig,
 };
+use cw_multi_test::Executor;
 use cw_utils::Expiration;
 use valence_swap_holder::msg::RefundConfig;
 
@@ -158,6 +160,11 @@ impl SwapHolderBuilder {
         self
     }
 
+    pub fn with_fallback_split(mut self, split: Option<SplitConfig>) -> Self {
+        self.instantiate_msg.with_fallback_split(split);
+        self
+    }
+
     pub fn build(mut self) -> Suite {
         let holder_addr = self.builder.contract_init2(
             self.builder.swap_holder_code_id,
@@ -243,6 +250,16 @@ impl Suite {
         };
     }
 
+    pub fn query_history(&self, start_after: Option<u64>, limit: Option<u32>) -> Vec<HistoryEntry> {
+        self.get_app()
+            .wrap()
+            .query_wasm_smart(
+                self.holder.clone(),
+                &valence_swap_holder::msg::QueryMsg::History { start_after, limit },
+            )
+            .unwrap()
+    }
+
     pub fn query_next_contract(&self) -> Addr {
         self.get_app()
             .wrap()
@@ -313,6 +330,38 @@ impl Suite {
             .unwrap()
     }
 
+    pub fn query_fill_ratio(&self) -> Option<Decimal> {
+        self.get_app()
+            .wrap()
+            .query_wasm_smart(
+                self.holder.clone(),
+                &valence_swap_holder::msg::QueryMsg::FillRatio {},
+            )
+            .unwrap()
+    }
+
+    pub fn distribute_fallback_split(&mut self, denoms: Vec<String>) {
+        let sender = self.faucet.clone();
+        self.app
+            .execute_contract(
+                sender,
+                self.holder.clone(),
+                &valence_swap_holder::msg::ExecuteMsg::DistributeFallbackSplit { denoms },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn query_fallback_split(&self) -> Option<SplitConfig> {
+        self.get_app()
+            .wrap()
+            .query_wasm_smart(
+                self.holder.clone(),
+                &valence_swap_holder::msg::QueryMsg::FallbackSplit {},
+            )
+            .unwrap()
+    }
+
     pub fn query_refund_config(&self) -> RefundConfig {
         self.get_app()
             .wrap()
@@ -322,6 +371,21 @@ impl Suite {
             )
             .unwrap()
     }
+
+    /// returns the party a and party b refund addresses,
+    /// expecting both refund receivers to be native
+    pub fn query_refund_addresses(&self) -> (String, String) {
+        let refund_config = self.query_refund_config();
+        match (
+            refund_config.party_a_refund_receiver,
+            refund_config.party_b_refund_receiver,
+        ) {
+            (ReceiverConfig::Native(party_a), ReceiverConfig::Native(party_b)) => {
+                (party_a, party_b)
+            }
+            _ => panic!("expected native refund receivers"),
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -1,15 +1,24 @@
-use cosmwasm_std::{coin, coins, Addr, Event, Uint128};
+use std::{collections::BTreeMap, str::FromStr};
+
+use cosmwasm_std::{coin, coins, to_json_vec, Addr, Decimal, Event, Storage, Uint128, Uint64};
 use covenant_utils::{
+    history::HistoryAction,
     op_mode::{ContractOperationMode, ContractOperationModeConfig},
-    CovenantTerms, SwapCovenantTerms,
+    split::SplitConfig,
+    CovenantTerms, DestinationConfig, PartialFillSwapCovenantTerms,
+    PriceReferencedSwapCovenantTerms, PriceSource, ReceiverConfig, SwapCovenantTerms,
 };
 use cw_multi_test::Executor;
 use cw_utils::Expiration;
-use valence_swap_holder::msg::{ContractState, RefundConfig};
+use valence_swap_holder::{
+    msg::{ContractState, RefundConfig},
+    state::LegacyRefundConfig,
+};
 
 use crate::setup::{
     base_suite::{BaseSuite, BaseSuiteMut},
-    ADMIN, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN,
+    get_contract_item_key, ADMIN, DENOM_ATOM, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN,
+    DENOM_NTRN, NTRN_HUB_CHANNEL,
 };
 
 use super::suite::SwapHolderBuilder;
@@ -68,7 +77,7 @@ fn test_instantiate_validates_party_a_refund_addr() {
         .instantiate_msg
         .msg
         .refund_config
-        .party_a_refund_address = "invalid".to_string();
+        .party_a_refund_receiver = ReceiverConfig::Native("invalid".to_string());
     builder.build();
 }
 
@@ -80,7 +89,7 @@ fn test_instantiate_validates_party_b_refund_addr() {
         .instantiate_msg
         .msg
         .refund_config
-        .party_b_refund_address = "invalid".to_string();
+        .party_b_refund_receiver = ReceiverConfig::Native("invalid".to_string());
     builder.build();
 }
 
@@ -132,6 +141,263 @@ fn test_execute_tick_instantiated_forwards_and_completes() {
     assert!(matches!(contract_state, ContractState::Complete {}));
 }
 
+#[test]
+#[should_panic(expected = "min fill ratio must be in range (0.0, 1.0]")]
+fn test_instantiate_validates_min_fill_ratio() {
+    SwapHolderBuilder::default()
+        .with_covenant_terms(CovenantTerms::PartialFillTokenSwap(
+            PartialFillSwapCovenantTerms {
+                party_a_amount: Uint128::new(100000),
+                party_b_amount: Uint128::new(100000),
+                min_fill_ratio: Decimal::zero(),
+            },
+        ))
+        .build();
+}
+
+#[test]
+fn test_execute_tick_full_fill_reports_fill_ratio() {
+    let mut suite = SwapHolderBuilder::default().build();
+    assert_eq!(suite.query_fill_ratio(), None);
+
+    suite.fund_contract(&coins(100000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(100000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+
+    assert_eq!(suite.query_fill_ratio(), Some(Decimal::one()));
+}
+
+#[test]
+fn test_execute_tick_expired_settles_partial_fill() {
+    let mut suite = SwapHolderBuilder::default()
+        .with_covenant_terms(CovenantTerms::PartialFillTokenSwap(
+            PartialFillSwapCovenantTerms {
+                party_a_amount: Uint128::new(100000),
+                party_b_amount: Uint128::new(100000),
+                min_fill_ratio: Decimal::from_str("0.5").unwrap(),
+            },
+        ))
+        .build();
+
+    suite.fund_contract(&coins(100000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(60000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+
+    suite.expire_lockup_config();
+    suite.tick_contract(suite.holder.clone());
+
+    assert_eq!(suite.query_contract_state(), ContractState::Complete {});
+    assert_eq!(
+        suite.query_fill_ratio(),
+        Some(Decimal::from_str("0.6").unwrap())
+    );
+
+    let (party_a_refund_addr, party_b_refund_addr) = suite.query_refund_addresses();
+    suite.assert_balance(party_a_refund_addr, coin(40000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(party_b_refund_addr, coin(0, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(suite.next_contract.clone(), coin(60000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(
+        suite.next_contract.clone(),
+        coin(60000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(suite.holder.clone(), coin(0, DENOM_ATOM_ON_NTRN));
+
+    let parties = suite.query_covenant_parties_config();
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[2].action, HistoryAction::Refund);
+    assert_eq!(history[2].party, Some(parties.party_a.addr));
+    assert_eq!(history[2].coins, coins(40000, DENOM_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_execute_tick_expired_partial_fill_below_minimum_expires() {
+    let mut suite = SwapHolderBuilder::default()
+        .with_covenant_terms(CovenantTerms::PartialFillTokenSwap(
+            PartialFillSwapCovenantTerms {
+                party_a_amount: Uint128::new(100000),
+                party_b_amount: Uint128::new(100000),
+                min_fill_ratio: Decimal::from_str("0.5").unwrap(),
+            },
+        ))
+        .build();
+
+    suite.fund_contract(&coins(100000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(40000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+
+    suite.expire_lockup_config();
+    suite.tick_contract(suite.holder.clone());
+    assert_eq!(suite.query_contract_state(), ContractState::Expired {});
+    assert_eq!(suite.query_fill_ratio(), None);
+
+    suite.tick_contract(suite.holder.clone());
+    let (party_a_refund_addr, party_b_refund_addr) = suite.query_refund_addresses();
+    suite.assert_balance(party_a_refund_addr, coin(100000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(party_b_refund_addr, coin(40000, DENOM_LS_ATOM_ON_NTRN));
+}
+
+/// builds a swap holder referencing an xyk pool where
+/// party a denom trades at 2.0 party b denom
+fn build_price_referenced_suite(
+    price_source: PriceSource,
+    max_party_b_amount: u128,
+) -> super::suite::Suite {
+    let mut builder = SwapHolderBuilder::default();
+    let (pool_addr, _lp_token_addr) = builder.builder.init_astro_pool(
+        astroport::factory::PairType::Xyk {},
+        coin(1_000_000_000_000, DENOM_ATOM_ON_NTRN),
+        coin(2_000_000_000_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+
+    builder
+        .with_covenant_terms(CovenantTerms::PriceReferencedTokenSwap(
+            PriceReferencedSwapCovenantTerms {
+                party_a_amount: Uint128::new(100000),
+                pool_address: pool_addr.to_string(),
+                price_source,
+                spread: Decimal::from_str("0.1").unwrap(),
+                min_party_b_amount: Uint128::new(100000),
+                max_party_b_amount: Uint128::new(max_party_b_amount),
+            },
+        ))
+        .build()
+}
+
+#[test]
+#[should_panic(expected = "does not trade")]
+fn test_instantiate_validates_price_reference_pool_denoms() {
+    let mut builder = SwapHolderBuilder::default();
+    let (pool_addr, _lp_token_addr) = builder.builder.init_astro_pool(
+        astroport::factory::PairType::Xyk {},
+        coin(1_000_000_000_000, DENOM_ATOM_ON_NTRN),
+        coin(1_000_000_000_000, DENOM_NTRN),
+    );
+
+    builder
+        .with_covenant_terms(CovenantTerms::PriceReferencedTokenSwap(
+            PriceReferencedSwapCovenantTerms {
+                party_a_amount: Uint128::new(100000),
+                pool_address: pool_addr.to_string(),
+                price_source: PriceSource::Spot,
+                spread: Decimal::zero(),
+                min_party_b_amount: Uint128::new(100000),
+                max_party_b_amount: Uint128::new(300000),
+            },
+        ))
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "min party b amount must not exceed the max party b amount")]
+fn test_instantiate_validates_price_reference_bounds() {
+    build_price_referenced_suite(PriceSource::Spot, 50000);
+}
+
+#[test]
+fn test_execute_tick_price_referenced_spot_forwards_and_refunds_surplus() {
+    let mut suite = build_price_referenced_suite(PriceSource::Spot, 300000);
+
+    suite.fund_contract(&coins(100000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(250000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+
+    assert_eq!(suite.query_contract_state(), ContractState::Complete {});
+
+    // 100000 * 2.0 * (1 + 0.1)
+    suite.assert_balance(
+        suite.next_contract.clone(),
+        coin(100000, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.next_contract.clone(),
+        coin(220000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    let (_, party_b_refund_addr) = suite.query_refund_addresses();
+    suite.assert_balance(party_b_refund_addr, coin(30000, DENOM_LS_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_execute_tick_price_referenced_applies_bounds() {
+    let mut suite = build_price_referenced_suite(PriceSource::Spot, 150000);
+
+    suite.fund_contract(&coins(100000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(250000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+
+    suite.assert_balance(
+        suite.next_contract.clone(),
+        coin(150000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    let (_, party_b_refund_addr) = suite.query_refund_addresses();
+    suite.assert_balance(party_b_refund_addr, coin(100000, DENOM_LS_ATOM_ON_NTRN));
+}
+
+#[test]
+#[should_panic(expected = "Insufficient funds to forward")]
+fn test_execute_tick_price_referenced_validates_sufficient_funds() {
+    let mut suite = build_price_referenced_suite(PriceSource::Spot, 300000);
+
+    suite.fund_contract(&coins(100000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(200000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+}
+
+#[test]
+fn test_execute_tick_price_referenced_twap() {
+    let mut suite = build_price_referenced_suite(PriceSource::Twap, 300000);
+
+    suite.fund_contract(&coins(100000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(220000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+    suite
+        .app
+        .update_block(|b| b.time = b.time.plus_seconds(100));
+    suite.tick_contract(suite.holder.clone());
+
+    assert_eq!(suite.query_contract_state(), ContractState::Complete {});
+    suite.assert_balance(
+        suite.next_contract.clone(),
+        coin(220000, DENOM_LS_ATOM_ON_NTRN),
+    );
+}
+
+#[test]
+fn test_query_history_records_forwarded_deposits() {
+    let mut suite = SwapHolderBuilder::default().build();
+
+    suite.fund_contract(&coins(100000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(100000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+
+    let parties = suite.query_covenant_parties_config();
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].action, HistoryAction::Deposit);
+    assert_eq!(history[0].party, Some(parties.party_a.addr));
+    assert_eq!(history[0].coins, coins(100000, DENOM_ATOM_ON_NTRN));
+    assert_eq!(history[1].action, HistoryAction::Deposit);
+    assert_eq!(history[1].party, Some(parties.party_b.addr));
+    assert_eq!(history[1].coins, coins(100000, DENOM_LS_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_query_history_records_refunds() {
+    let mut suite = SwapHolderBuilder::default().build();
+
+    suite.fund_contract(&coins(10_000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.expire_lockup_config();
+    suite.tick_contract(suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+
+    let parties = suite.query_covenant_parties_config();
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].action, HistoryAction::Refund);
+    assert_eq!(history[0].party, Some(parties.party_a.addr));
+    assert_eq!(history[0].coins, coins(10_000, DENOM_ATOM_ON_NTRN));
+
+    // no entries after the last one
+    assert!(suite.query_history(Some(history[0].id), None).is_empty());
+}
+
 #[test]
 fn test_execute_expired_refund_both_parties() {
     let mut suite = SwapHolderBuilder::default().build();
@@ -155,15 +421,9 @@ fn test_execute_expired_refund_both_parties() {
     let contract_state = suite.query_contract_state();
     assert!(matches!(contract_state, ContractState::Expired {}));
 
-    let refund_config = suite.query_refund_config();
-    suite.assert_balance(
-        refund_config.party_a_refund_address,
-        coin(10_000, DENOM_ATOM_ON_NTRN),
-    );
-    suite.assert_balance(
-        refund_config.party_b_refund_address,
-        coin(10_000, DENOM_LS_ATOM_ON_NTRN),
-    );
+    let (party_a_refund_addr, party_b_refund_addr) = suite.query_refund_addresses();
+    suite.assert_balance(party_a_refund_addr, coin(10_000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(party_b_refund_addr, coin(10_000, DENOM_LS_ATOM_ON_NTRN));
 }
 
 #[test]
@@ -185,11 +445,8 @@ fn test_execute_expired_refund_party_a() {
     let contract_state = suite.query_contract_state();
     assert!(matches!(contract_state, ContractState::Expired {}));
 
-    let refund_config = suite.query_refund_config();
-    suite.assert_balance(
-        refund_config.party_a_refund_address,
-        coin(10_000, DENOM_ATOM_ON_NTRN),
-    );
+    let (party_a_refund_addr, _) = suite.query_refund_addresses();
+    suite.assert_balance(party_a_refund_addr, coin(10_000, DENOM_ATOM_ON_NTRN));
 }
 
 #[test]
@@ -211,11 +468,105 @@ fn test_execute_expired_refund_party_b() {
     let contract_state = suite.query_contract_state();
     assert!(matches!(contract_state, ContractState::Expired {}));
 
-    let refund_config = suite.query_refund_config();
-    suite.assert_balance(
-        refund_config.party_b_refund_address,
-        coin(10_000, DENOM_LS_ATOM_ON_NTRN),
-    );
+    let (_, party_b_refund_addr) = suite.query_refund_addresses();
+    suite.assert_balance(party_b_refund_addr, coin(10_000, DENOM_LS_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_execute_expired_refund_party_a_over_ibc() {
+    let mut builder = SwapHolderBuilder::default();
+    let party_a_receiver = builder.builder.get_random_addr();
+    builder
+        .instantiate_msg
+        .msg
+        .refund_config
+        .party_a_refund_receiver = ReceiverConfig::Ibc(DestinationConfig {
+        local_to_destination_chain_channel_id: NTRN_HUB_CHANNEL.0.to_string(),
+        destination_receiver_addr: party_a_receiver.to_string(),
+        ibc_transfer_timeout: Uint64::new(1000),
+        denom_to_pfm_map: BTreeMap::new(),
+    });
+    let mut suite = builder.build();
+
+    suite.fund_contract(&coins(10_000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    // holder pays the ibc fees
+    suite.fund_contract(&coins(100_000, DENOM_NTRN), suite.holder.clone());
+
+    suite.expire_lockup_config();
+    suite.tick_contract(suite.holder.clone());
+    let contract_state = suite.query_contract_state();
+    assert!(matches!(contract_state, ContractState::Expired {}));
+
+    suite.tick_contract(suite.holder.clone());
+    suite.assert_balance(suite.holder.clone(), coin(0, DENOM_ATOM_ON_NTRN));
+    // refund is unwound back to the party chain
+    suite.assert_balance(party_a_receiver, coin(10_000, DENOM_ATOM));
+}
+
+#[test]
+fn test_execute_expired_failed_ibc_refund_does_not_block_other_party() {
+    let mut builder = SwapHolderBuilder::default();
+    let party_a_receiver = builder.builder.get_random_addr();
+    builder
+        .instantiate_msg
+        .msg
+        .refund_config
+        .party_a_refund_receiver = ReceiverConfig::Ibc(DestinationConfig {
+        local_to_destination_chain_channel_id: NTRN_HUB_CHANNEL.0.to_string(),
+        destination_receiver_addr: party_a_receiver.to_string(),
+        ibc_transfer_timeout: Uint64::new(1000),
+        denom_to_pfm_map: BTreeMap::new(),
+    });
+    let mut suite = builder.build();
+
+    suite.fund_contract(&coins(10_000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(10_000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+
+    suite.expire_lockup_config();
+    suite.tick_contract(suite.holder.clone());
+    // holder cannot pay the ibc fees of the party a refund
+    suite.tick_contract(suite.holder.clone());
+
+    let ReceiverConfig::Native(party_b_refund_addr) =
+        suite.query_refund_config().party_b_refund_receiver
+    else {
+        panic!("expected native party b refund receiver")
+    };
+    suite.assert_balance(party_b_refund_addr, coin(10_000, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(suite.holder.clone(), coin(10_000, DENOM_ATOM_ON_NTRN));
+
+    let parties = suite.query_covenant_parties_config();
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].party, Some(parties.party_b.addr));
+
+    // failed refund is retried once the fees are available
+    suite.fund_contract(&coins(100_000, DENOM_NTRN), suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+    suite.assert_balance(suite.holder.clone(), coin(0, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(party_a_receiver, coin(10_000, DENOM_ATOM));
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1].action, HistoryAction::Refund);
+    assert_eq!(history[1].party, Some(parties.party_a.addr));
+}
+
+#[test]
+#[should_panic]
+fn test_instantiate_validates_ibc_refund_receiver() {
+    let mut builder = SwapHolderBuilder::default();
+    builder
+        .instantiate_msg
+        .msg
+        .refund_config
+        .party_b_refund_receiver = ReceiverConfig::Ibc(DestinationConfig {
+        local_to_destination_chain_channel_id: NTRN_HUB_CHANNEL.0.to_string(),
+        destination_receiver_addr: "invalid".to_string(),
+        ibc_transfer_timeout: Uint64::new(1000),
+        denom_to_pfm_map: BTreeMap::new(),
+    });
+    builder.build();
 }
 
 #[test]
@@ -249,8 +600,8 @@ fn test_migrate_update_config() {
     });
     let new_expiration = Expiration::AtHeight(192837465);
     let new_refund_config = RefundConfig {
-        party_a_refund_address: clock_address.to_string(),
-        party_b_refund_address: clock_address.to_string(),
+        party_a_refund_receiver: ReceiverConfig::Native(clock_address.to_string()),
+        party_b_refund_receiver: ReceiverConfig::Native(clock_address.to_string()),
     };
     let resp = suite
         .app
@@ -266,6 +617,7 @@ fn test_migrate_update_config() {
                 parties_config: Box::new(Some(parties_config.clone())),
                 covenant_terms: Some(new_covenant_terms.clone()),
                 refund_config: Some(new_refund_config.clone()),
+                fallback_split: None,
             },
             4,
         )
@@ -293,6 +645,42 @@ fn test_migrate_update_config() {
     assert_eq!(suite.query_refund_config(), new_refund_config);
 }
 
+#[test]
+fn test_migrate_converts_legacy_refund_config() {
+    let mut suite = SwapHolderBuilder::default().build();
+    let refund_address = suite.clock_addr.to_string();
+
+    // previous versions stored plain refund addresses under the same key
+    let legacy_refund_config = LegacyRefundConfig {
+        party_a_refund_address: refund_address.to_string(),
+        party_b_refund_address: refund_address.to_string(),
+    };
+    let holder = suite.holder.clone();
+    suite.app.storage_mut().set(
+        &get_contract_item_key(&holder, "refund_config"),
+        &to_json_vec(&legacy_refund_config).unwrap(),
+    );
+
+    let refund_config = RefundConfig {
+        party_a_refund_receiver: ReceiverConfig::Native(refund_address.to_string()),
+        party_b_refund_receiver: ReceiverConfig::Native(refund_address),
+    };
+    suite
+        .app
+        .migrate_contract(
+            Addr::unchecked(ADMIN),
+            holder,
+            &valence_swap_holder::msg::MigrateMsg::UpdateCodeId { data: None },
+            4,
+        )
+        .unwrap()
+        .assert_event(
+            &Event::new("wasm").add_attribute("refund_config", format!("{refund_config:?}")),
+        );
+
+    assert_eq!(suite.query_refund_config(), refund_config);
+}
+
 #[test]
 #[should_panic(expected = "lockup config is already past")]
 fn test_migrate_update_config_validates_lockup_config_expiration() {
@@ -309,6 +697,7 @@ fn test_migrate_update_config_validates_lockup_config_expiration() {
                 parties_config: Box::new(None),
                 covenant_terms: None,
                 refund_config: None,
+                fallback_split: None,
             },
             4,
         )
@@ -347,6 +736,7 @@ fn test_complete_with_mixed_op_mode_address_types() {
                 parties_config: Box::new(None),
                 covenant_terms: None,
                 refund_config: None,
+                fallback_split: None,
             },
             4,
         )
@@ -363,3 +753,116 @@ fn test_complete_with_mixed_op_mode_address_types() {
     let contract_state = suite.query_contract_state();
     assert!(matches!(contract_state, ContractState::Complete {}));
 }
+
+#[test]
+fn test_execute_tick_forwards_terms_and_refunds_surplus() {
+    let mut suite = SwapHolderBuilder::default().build();
+
+    suite.fund_contract(&coins(150000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(100000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+
+    assert_eq!(suite.query_contract_state(), ContractState::Complete {});
+    suite.assert_balance(
+        suite.next_contract.clone(),
+        coin(100000, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.next_contract.clone(),
+        coin(100000, DENOM_LS_ATOM_ON_NTRN),
+    );
+
+    let (party_a_refund_addr, party_b_refund_addr) = suite.query_refund_addresses();
+    suite.assert_balance(party_a_refund_addr, coin(50000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(party_b_refund_addr, coin(0, DENOM_LS_ATOM_ON_NTRN));
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[2].action, HistoryAction::Refund);
+    assert_eq!(history[2].coins, coins(50000, DENOM_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_execute_tick_failed_ibc_surplus_refund_does_not_block_forwarding() {
+    let mut builder = SwapHolderBuilder::default();
+    let party_a_receiver = builder.builder.get_random_addr();
+    builder
+        .instantiate_msg
+        .msg
+        .refund_config
+        .party_a_refund_receiver = ReceiverConfig::Ibc(DestinationConfig {
+        local_to_destination_chain_channel_id: NTRN_HUB_CHANNEL.0.to_string(),
+        destination_receiver_addr: party_a_receiver.to_string(),
+        ibc_transfer_timeout: Uint64::new(1000),
+        denom_to_pfm_map: BTreeMap::new(),
+    });
+    let mut suite = builder.build();
+
+    suite.fund_contract(&coins(150000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.fund_contract(&coins(100000, DENOM_LS_ATOM_ON_NTRN), suite.holder.clone());
+    // holder cannot pay the ibc fees of the surplus refund
+    suite.tick_contract(suite.holder.clone());
+
+    assert_eq!(suite.query_contract_state(), ContractState::Complete {});
+    suite.assert_balance(
+        suite.next_contract.clone(),
+        coin(100000, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(suite.holder.clone(), coin(50000, DENOM_ATOM_ON_NTRN));
+    assert_eq!(suite.query_history(None, None).len(), 2);
+
+    // failed surplus refund is retried once the fees are available
+    suite.fund_contract(&coins(100_000, DENOM_NTRN), suite.holder.clone());
+    suite.tick_contract(suite.holder.clone());
+    suite.assert_balance(suite.holder.clone(), coin(0, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(party_a_receiver, coin(50000, DENOM_ATOM));
+
+    let parties = suite.query_covenant_parties_config();
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[2].action, HistoryAction::Refund);
+    assert_eq!(history[2].party, Some(parties.party_a.addr));
+    assert_eq!(history[2].coins, coins(50000, DENOM_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_execute_distribute_fallback_split_refunds_parties_equally() {
+    let mut suite = SwapHolderBuilder::default().build();
+    assert_eq!(suite.query_fallback_split(), None);
+
+    suite.fund_contract(&coins(1000, DENOM_NTRN), suite.holder.clone());
+    suite.distribute_fallback_split(vec![DENOM_NTRN.to_string()]);
+
+    let (party_a_refund_addr, party_b_refund_addr) = suite.query_refund_addresses();
+    suite.assert_balance(party_a_refund_addr, coin(500, DENOM_NTRN));
+    suite.assert_balance(party_b_refund_addr, coin(500, DENOM_NTRN));
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].action, HistoryAction::Distribution);
+}
+
+#[test]
+fn test_execute_distribute_fallback_split() {
+    let mut builder = SwapHolderBuilder::default();
+    let fallback_receiver = builder.builder.get_random_addr();
+    let mut suite = builder
+        .with_fallback_split(Some(SplitConfig {
+            receivers: BTreeMap::from_iter(vec![(fallback_receiver.to_string(), Decimal::one())]),
+        }))
+        .build();
+
+    suite.fund_contract(&coins(1000, DENOM_NTRN), suite.holder.clone());
+    suite.distribute_fallback_split(vec![DENOM_NTRN.to_string()]);
+
+    suite.assert_balance(fallback_receiver, coin(1000, DENOM_NTRN));
+}
+
+#[test]
+#[should_panic(expected = "unauthorized to distribute party contribution denom")]
+fn test_execute_distribute_fallback_split_validates_denoms() {
+    let mut suite = SwapHolderBuilder::default().build();
+
+    suite.fund_contract(&coins(1000, DENOM_ATOM_ON_NTRN), suite.holder.clone());
+    suite.distribute_fallback_split(vec![DENOM_ATOM_ON_NTRN.to_string()]);
+}
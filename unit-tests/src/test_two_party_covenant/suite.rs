@@ -138,7 +138,10 @@ impl TwoPartyCovenantBuilder {
         self
     }
 
-    pub fn with_emergency_committee(mut self, emergency_committee: Option<String>) -> Self {
+    pub fn with_emergency_committee(
+        mut self,
+        emergency_committee: Option<covenant_utils::emergency_committee::EmergencyCommitteeConfig>,
+    ) -> Self {
         self.instantiate_msg
             .with_emergency_committee(emergency_committee);
         self
@@ -1,9 +1,12 @@
 use std::collections::BTreeMap;
 
-use cosmwasm_std::{coin, to_json_binary, Event, Uint64};
+use cosmwasm_std::{coin, to_json_binary, Coin, Event, Uint64};
 use covenant_utils::op_mode::{ContractOperationMode, ContractOperationModeConfig};
 
-use crate::setup::{base_suite::BaseSuiteMut, DENOM_ATOM, DENOM_ATOM_ON_NTRN, NTRN_HUB_CHANNEL};
+use crate::setup::{
+    base_suite::BaseSuiteMut, DENOM_ATOM, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN, DENOM_NTRN,
+    NTRN_HUB_CHANNEL,
+};
 
 use super::suite::TwoPartyCovenantBuilder;
 
@@ -70,6 +73,66 @@ fn test_instantiate_party_b_interchain() {
         .build();
 }
 
+fn get_native_basket_party_config(
+    builder: &TwoPartyCovenantBuilder,
+    contribution: Vec<Coin>,
+) -> valence_covenant_two_party_pol::msg::CovenantPartyConfig {
+    let party_address = builder
+        .instantiate_msg
+        .msg
+        .party_b_config
+        .get_final_receiver_address();
+    valence_covenant_two_party_pol::msg::CovenantPartyConfig::NativeBasket(
+        valence_covenant_two_party_pol::msg::NativeBasketCovenantParty {
+            party_receiver_addr: party_address.to_string(),
+            addr: party_address.to_string(),
+            contribution,
+        },
+    )
+}
+
+#[test]
+fn test_instantiate_party_b_native_basket() {
+    let builder = TwoPartyCovenantBuilder::default();
+    let mut splits = builder.instantiate_msg.msg.splits.clone();
+    let split = splits.get(DENOM_LS_ATOM_ON_NTRN).unwrap().clone();
+    splits.insert(DENOM_NTRN.to_string(), split);
+    // ntrn is not a pool denom and gets distributed with its split upon deposit
+    let party_b_config = get_native_basket_party_config(
+        &builder,
+        vec![coin(10_000, DENOM_LS_ATOM_ON_NTRN), coin(5_000, DENOM_NTRN)],
+    );
+
+    builder
+        .with_splits(splits)
+        .with_party_b_config(party_b_config)
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "party contribution basket must include a pool denom")]
+fn test_instantiate_party_b_native_basket_validates_pool_denoms() {
+    let builder = TwoPartyCovenantBuilder::default();
+    let mut splits = builder.instantiate_msg.msg.splits.clone();
+    let split = splits.get(DENOM_LS_ATOM_ON_NTRN).unwrap().clone();
+    splits.insert(DENOM_NTRN.to_string(), split);
+    let party_b_config = get_native_basket_party_config(&builder, vec![coin(5_000, DENOM_NTRN)]);
+
+    builder
+        .with_splits(splits)
+        .with_party_b_config(party_b_config)
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "party contribution basket cannot be empty")]
+fn test_instantiate_party_b_native_basket_validates_empty_basket() {
+    let builder = TwoPartyCovenantBuilder::default();
+    let party_b_config = get_native_basket_party_config(&builder, vec![]);
+
+    builder.with_party_b_config(party_b_config).build();
+}
+
 #[test]
 fn test_instantiate_with_fallback_split() {
     let builder = TwoPartyCovenantBuilder::default();
@@ -132,6 +195,7 @@ fn test_migrate_update_config_party_a_interchain() {
             ])),
             holder_address: None,
             lp_config: None,
+            lp_staking_config: None,
         };
 
     let liquid_pooler_migrate_msg =
@@ -347,6 +411,7 @@ fn test_migrate_update_config_party_b_interchain() {
             ])),
             holder_address: None,
             lp_config: None,
+            lp_staking_config: None,
         };
 
     let liquid_pooler_migrate_msg =
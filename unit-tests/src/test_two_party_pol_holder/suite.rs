@@ -3,13 +3,19 @@ use std::collections::BTreeMap;
 use astroport::factory::PairType;
 use cosmwasm_std::{coin, Addr, Decimal, Uint128};
 use covenant_utils::{
+    emergency_committee::{
+        EmergencyAction, EmergencyCommittee, EmergencyCommitteeConfig, PendingEmergencyAction,
+    },
+    history::HistoryEntry,
     op_mode::{ContractOperationMode, ContractOperationModeConfig},
     split::SplitConfig,
     PoolPriceConfig, SingleSideLpLimits,
 };
 use cw_multi_test::{AppResponse, Executor};
 use cw_utils::Expiration;
-use valence_two_party_pol_holder::msg::{ContractState, DenomSplits, RagequitConfig};
+use valence_two_party_pol_holder::msg::{
+    ContractState, DenomSplits, DepositsResponse, RagequitConfig,
+};
 
 use crate::setup::{
     base_suite::{BaseSuite, BaseSuiteMut},
@@ -71,6 +77,10 @@ impl Default for TwoPartyHolderBuilder {
             },
             pair_type: PairType::Stable {},
             holder_address: holder_addr.to_string(),
+            lp_staking_config: None,
+            zap_config: None,
+            twap_window_seconds: None,
+            withdraw_price_guard: None,
         };
 
         builder.contract_init2(
@@ -145,8 +155,13 @@ impl TwoPartyHolderBuilder {
         self
     }
 
-    pub fn with_emergency_committee(mut self, addr: &str) -> Self {
-        self.instantiate_msg.with_emergency_committee(addr);
+    pub fn with_emergency_committee(mut self, config: EmergencyCommitteeConfig) -> Self {
+        self.instantiate_msg.with_emergency_committee(config);
+        self
+    }
+
+    pub fn with_pool_denoms(mut self, pool_denoms: Vec<String>) -> Self {
+        self.instantiate_msg.with_pool_denoms(pool_denoms);
         self
     }
 
@@ -236,7 +251,7 @@ impl TwoPartyHolderBuilder {
             )
             .unwrap();
 
-        let emergency_committee_addr: Option<Addr> = self
+        let emergency_committee: Option<EmergencyCommittee> = self
             .builder
             .app
             .wrap()
@@ -258,7 +273,7 @@ impl TwoPartyHolderBuilder {
             covenant_config,
             splits: denom_splits.clone().explicit_splits,
             fallback_split: denom_splits.clone().fallback_split,
-            emergency_committee_addr,
+            emergency_committee,
             app: self.builder.build(),
         }
     }
@@ -281,7 +296,7 @@ pub struct Suite {
     pub covenant_config: valence_two_party_pol_holder::msg::TwoPartyPolCovenantConfig,
     pub splits: BTreeMap<String, SplitConfig>,
     pub fallback_split: Option<SplitConfig>,
-    pub emergency_committee_addr: Option<Addr>,
+    pub emergency_committee: Option<EmergencyCommittee>,
 }
 
 impl Suite {
@@ -347,12 +362,38 @@ impl Suite {
             .unwrap()
     }
 
-    pub fn emergency_withdraw(&mut self, sender: &str) -> AppResponse {
+    pub fn propose_emergency_action(
+        &mut self,
+        sender: &str,
+        action: EmergencyAction,
+    ) -> AppResponse {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.holder_addr.clone(),
+                &valence_two_party_pol_holder::msg::ExecuteMsg::ProposeEmergencyAction { action },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn approve_emergency_action(&mut self, sender: &str, id: u64) -> AppResponse {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.holder_addr.clone(),
+                &valence_two_party_pol_holder::msg::ExecuteMsg::ApproveEmergencyAction { id },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn execute_emergency_action(&mut self, sender: &str, id: u64) -> AppResponse {
         self.app
             .execute_contract(
                 Addr::unchecked(sender),
                 self.holder_addr.clone(),
-                &valence_two_party_pol_holder::msg::ExecuteMsg::EmergencyWithdraw {},
+                &valence_two_party_pol_holder::msg::ExecuteMsg::ExecuteEmergencyAction { id },
                 &[],
             )
             .unwrap()
@@ -451,7 +492,7 @@ impl Suite {
             .unwrap()
     }
 
-    pub fn query_emergency_committee(&mut self) -> Addr {
+    pub fn query_emergency_committee(&mut self) -> Option<EmergencyCommittee> {
         self.app
             .wrap()
             .query_wasm_smart(
@@ -460,6 +501,40 @@ impl Suite {
             )
             .unwrap()
     }
+
+    pub fn query_deposits(&mut self) -> DepositsResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_two_party_pol_holder::msg::QueryMsg::Deposits {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_history(
+        &mut self,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Vec<HistoryEntry> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_two_party_pol_holder::msg::QueryMsg::History { start_after, limit },
+            )
+            .unwrap()
+    }
+
+    pub fn query_pending_emergency_actions(&mut self) -> Vec<PendingEmergencyAction> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.holder_addr.clone(),
+                &valence_two_party_pol_holder::msg::QueryMsg::PendingEmergencyActions {},
+            )
+            .unwrap()
+    }
 }
 
 impl BaseSuiteMut for Suite {
@@ -1,14 +1,26 @@
 use std::{collections::BTreeMap, str::FromStr};
 
-use cosmwasm_std::{coin, coins, Addr, Decimal, Event, Timestamp, Uint128};
-use covenant_utils::{op_mode::ContractOperationMode, split::SplitConfig};
+use cosmwasm_std::{coin, coins, to_json_vec, Addr, Decimal, Event, Storage, Timestamp, Uint128};
+use covenant_utils::{
+    emergency_committee::{EmergencyAction, EmergencyCommitteeConfig},
+    history::HistoryAction,
+    op_mode::ContractOperationMode,
+    split::SplitConfig,
+};
 use cw_multi_test::Executor;
-use cw_utils::Expiration;
-use valence_two_party_pol_holder::msg::{ContractState, RagequitConfig, RagequitTerms};
+use cw_utils::{Duration, Expiration};
+use valence_two_party_pol_holder::{
+    msg::{ContractState, RagequitConfig, RagequitTerms, TwoPartyPolCovenantParty},
+    state::{
+        LegacyRagequitConfig, LegacyRagequitState, LegacyRagequitTerms,
+        LegacyTwoPartyPolCovenantConfig, LegacyTwoPartyPolCovenantParty,
+    },
+};
 
 use crate::setup::{
     base_suite::{BaseSuite, BaseSuiteMut},
-    ADMIN, DENOM_ATOM_ON_NTRN, DENOM_FALLBACK, DENOM_LS_ATOM_ON_NTRN,
+    get_contract_item_key, ADMIN, DENOM_ATOM_ON_NTRN, DENOM_FALLBACK, DENOM_LS_ATOM_ON_NTRN,
+    DENOM_NTRN,
 };
 
 use super::suite::TwoPartyHolderBuilder;
@@ -37,9 +49,42 @@ fn test_instantiate_validates_clock_addr() {
 #[should_panic]
 fn test_instantiate_validates_emergency_committee_addr() {
     TwoPartyHolderBuilder::default()
-        .with_emergency_committee(
-            "neutron19yz8hu6dand9lchzrcwezug763h770cv8sfen7kc7gw0jtdqha8qsl7tp9",
-        )
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![
+                "neutron19yz8hu6dand9lchzrcwezug763h770cv8sfen7kc7gw0jtdqha8qsl7tp9".to_string(),
+            ],
+            threshold: 1,
+            timelock: Duration::Height(0),
+            safe_mode: false,
+        })
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "emergency committee members must be unique")]
+fn test_instantiate_validates_emergency_committee_unique_members() {
+    let e_c_addr = "cosmos10a6yf8khw53pvmafngsq2vjgqgu3p9kjsgpzpa2vm9ceg0c70eysqg42pu";
+    TwoPartyHolderBuilder::default()
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![e_c_addr.to_string(), e_c_addr.to_string()],
+            threshold: 2,
+            timelock: Duration::Height(0),
+            safe_mode: false,
+        })
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "emergency committee threshold must be in range of [1, members count]")]
+fn test_instantiate_validates_emergency_committee_threshold() {
+    let e_c_addr = "cosmos10a6yf8khw53pvmafngsq2vjgqgu3p9kjsgpzpa2vm9ceg0c70eysqg42pu";
+    TwoPartyHolderBuilder::default()
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![e_c_addr.to_string()],
+            threshold: 0,
+            timelock: Duration::Height(0),
+            safe_mode: false,
+        })
         .build();
 }
 
@@ -68,7 +113,7 @@ fn test_instantiate_validates_party_a_contribution_amount() {
         .msg
         .covenant_config
         .party_a
-        .contribution
+        .contribution[0]
         .amount = Uint128::zero();
     builder.build();
 }
@@ -82,11 +127,56 @@ fn test_instantiate_validates_party_b_contribution_amount() {
         .msg
         .covenant_config
         .party_b
-        .contribution
+        .contribution[0]
         .amount = Uint128::zero();
     builder.build();
 }
 
+#[test]
+#[should_panic(expected = "Party contribution cannot be zero")]
+fn test_instantiate_validates_empty_contribution() {
+    let mut builder = TwoPartyHolderBuilder::default();
+    builder
+        .instantiate_msg
+        .msg
+        .covenant_config
+        .party_a
+        .contribution = vec![];
+    builder.build();
+}
+
+#[test]
+#[should_panic(expected = "Contribution denom untrn is provided more than once")]
+fn test_instantiate_validates_contribution_denoms_overlap() {
+    let mut builder = TwoPartyHolderBuilder::default();
+    let covenant_config = &mut builder.instantiate_msg.msg.covenant_config;
+    covenant_config
+        .party_a
+        .contribution
+        .push(coin(1_000, DENOM_NTRN));
+    covenant_config
+        .party_b
+        .contribution
+        .push(coin(1_000, DENOM_NTRN));
+    builder.build();
+}
+
+#[test]
+#[should_panic(
+    expected = "Contribution denom untrn has no split configured and no fallback split is provided"
+)]
+fn test_instantiate_validates_contribution_denom_splits() {
+    let mut builder = TwoPartyHolderBuilder::default();
+    builder
+        .instantiate_msg
+        .msg
+        .covenant_config
+        .party_a
+        .contribution
+        .push(coin(5_000, DENOM_NTRN));
+    builder.build();
+}
+
 #[test]
 #[should_panic]
 fn test_instantiate_validates_party_a_host_addr() {
@@ -417,6 +507,307 @@ fn test_execute_tick_deposit_validates_insufficient_deposits() {
     suite.tick_contract(suite.holder_addr.clone());
 }
 
+#[test]
+fn test_execute_tick_deposit_refunds_excess_deposits() {
+    let mut suite = TwoPartyHolderBuilder::default().build();
+    let router_a = Addr::unchecked(suite.covenant_config.party_a.router.to_string());
+    let router_b = Addr::unchecked(suite.covenant_config.party_b.router.to_string());
+
+    suite.fund_contract(
+        &[
+            coin(10_500, DENOM_ATOM_ON_NTRN),
+            coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+        ],
+        suite.holder_addr.clone(),
+    );
+
+    let deposits = suite.query_deposits();
+    assert!(deposits.party_a.is_fulfilled());
+    assert!(deposits.party_b.is_fulfilled());
+    assert_eq!(
+        deposits.party_a.get_excess(),
+        vec![coin(500, DENOM_ATOM_ON_NTRN)]
+    );
+    assert!(deposits.party_b.get_excess().is_empty());
+
+    suite.tick_contract(suite.holder_addr.clone());
+
+    suite.assert_balance(router_a.to_string(), coin(500, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(router_b.to_string(), coin(0, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(suite.holder_addr.to_string(), coin(0, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(
+        suite.next_contract.to_string(),
+        coin(10_000, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.next_contract.to_string(),
+        coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+}
+
+#[test]
+fn test_query_history_records_deposits_and_refunds() {
+    let mut suite = TwoPartyHolderBuilder::default().build();
+
+    suite.fund_contract(
+        &[
+            coin(10_500, DENOM_ATOM_ON_NTRN),
+            coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+        ],
+        suite.holder_addr.clone(),
+    );
+    suite.tick_contract(suite.holder_addr.clone());
+
+    let history = suite.query_history(None, None);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0].action, HistoryAction::Refund);
+    assert_eq!(
+        history[0].party,
+        Some(suite.covenant_config.party_a.host_addr.to_string())
+    );
+    assert_eq!(history[0].coins, vec![coin(500, DENOM_ATOM_ON_NTRN)]);
+    assert_eq!(history[1].action, HistoryAction::Deposit);
+    assert_eq!(history[1].coins, vec![coin(10_000, DENOM_ATOM_ON_NTRN)]);
+    assert_eq!(history[2].action, HistoryAction::Deposit);
+    assert_eq!(
+        history[2].party,
+        Some(suite.covenant_config.party_b.host_addr.to_string())
+    );
+    assert_eq!(history[2].coins, vec![coin(10_000, DENOM_LS_ATOM_ON_NTRN)]);
+
+    // paginate through the entries
+    let first_page = suite.query_history(None, Some(2));
+    assert_eq!(first_page, history[..2].to_vec());
+    let second_page = suite.query_history(Some(first_page[1].id), Some(2));
+    assert_eq!(second_page, history[2..].to_vec());
+}
+
+#[test]
+fn test_query_deposits_reports_partial_deposits() {
+    let mut suite = TwoPartyHolderBuilder::default().build();
+
+    suite.fund_contract(
+        &[coin(4_000, DENOM_ATOM_ON_NTRN)],
+        suite.holder_addr.clone(),
+    );
+
+    let deposits = suite.query_deposits();
+    assert_eq!(
+        deposits.party_a.deposited,
+        vec![coin(4_000, DENOM_ATOM_ON_NTRN)]
+    );
+    assert_eq!(
+        deposits.party_b.deposited,
+        vec![coin(0, DENOM_LS_ATOM_ON_NTRN)]
+    );
+    assert!(!deposits.party_a.is_fulfilled());
+    assert!(!deposits.party_b.is_fulfilled());
+}
+
+#[test]
+fn test_execute_tick_deposit_basket_contribution() {
+    let mut builder = TwoPartyHolderBuilder::default();
+    let split = builder
+        .instantiate_msg
+        .msg
+        .splits
+        .get(DENOM_ATOM_ON_NTRN)
+        .unwrap()
+        .clone();
+    builder
+        .instantiate_msg
+        .msg
+        .splits
+        .insert(DENOM_NTRN.to_string(), split);
+    builder
+        .instantiate_msg
+        .msg
+        .covenant_config
+        .party_a
+        .contribution
+        .push(coin(5_000, DENOM_NTRN));
+    let mut suite = builder.build();
+
+    // only one of the basket denoms is deposited
+    suite.fund_contract(
+        &[
+            coin(10_000, DENOM_ATOM_ON_NTRN),
+            coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+        ],
+        suite.holder_addr.clone(),
+    );
+    let deposits = suite.query_deposits();
+    assert_eq!(
+        deposits.party_a.deposited,
+        vec![coin(10_000, DENOM_ATOM_ON_NTRN), coin(0, DENOM_NTRN)]
+    );
+    assert!(!deposits.party_a.is_fulfilled());
+    assert!(deposits.party_b.is_fulfilled());
+
+    suite.fund_contract(&[coin(5_200, DENOM_NTRN)], suite.holder_addr.clone());
+    let deposits = suite.query_deposits();
+    assert!(deposits.party_a.is_fulfilled());
+    assert_eq!(deposits.party_a.get_excess(), vec![coin(200, DENOM_NTRN)]);
+
+    suite.tick_contract(suite.holder_addr.clone());
+
+    suite.assert_balance(&suite.covenant_config.party_a.router, coin(200, DENOM_NTRN));
+    suite.assert_balance(
+        suite.next_contract.to_string(),
+        coin(10_000, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(suite.next_contract.to_string(), coin(5_000, DENOM_NTRN));
+    suite.assert_balance(
+        suite.next_contract.to_string(),
+        coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    assert!(matches!(
+        suite.query_contract_state(),
+        ContractState::Active {}
+    ));
+}
+
+#[test]
+fn test_execute_tick_deposit_distributes_non_pool_basket_denoms() {
+    let mut builder = TwoPartyHolderBuilder::default().with_pool_denoms(vec![
+        DENOM_ATOM_ON_NTRN.to_string(),
+        DENOM_LS_ATOM_ON_NTRN.to_string(),
+    ]);
+    let split = builder
+        .instantiate_msg
+        .msg
+        .splits
+        .get(DENOM_ATOM_ON_NTRN)
+        .unwrap()
+        .clone();
+    builder
+        .instantiate_msg
+        .msg
+        .splits
+        .insert(DENOM_NTRN.to_string(), split);
+    builder
+        .instantiate_msg
+        .msg
+        .covenant_config
+        .party_b
+        .contribution
+        .push(coin(5_000, DENOM_NTRN));
+    let mut suite = builder.build();
+
+    suite.fund_contract(
+        &[
+            coin(10_000, DENOM_ATOM_ON_NTRN),
+            coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+            coin(5_000, DENOM_NTRN),
+        ],
+        suite.holder_addr.clone(),
+    );
+    suite.tick_contract(suite.holder_addr.clone());
+
+    // only the pool denoms are provided as liquidity
+    suite.assert_balance(
+        suite.next_contract.to_string(),
+        coin(10_000, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        suite.next_contract.to_string(),
+        coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(suite.next_contract.to_string(), coin(0, DENOM_NTRN));
+    // while the rest of the basket is split between the parties
+    suite.assert_balance(
+        &suite.covenant_config.party_a.router,
+        coin(2_500, DENOM_NTRN),
+    );
+    suite.assert_balance(
+        &suite.covenant_config.party_b.router,
+        coin(2_500, DENOM_NTRN),
+    );
+    suite.assert_balance(suite.holder_addr.to_string(), coin(0, DENOM_NTRN));
+    assert!(matches!(
+        suite.query_contract_state(),
+        ContractState::Active {}
+    ));
+}
+
+#[test]
+#[should_panic(expected = "does not contribute any pool denom")]
+fn test_instantiate_validates_pool_contribution_denoms() {
+    let mut builder = TwoPartyHolderBuilder::default()
+        .with_pool_denoms(vec![DENOM_ATOM_ON_NTRN.to_string(), DENOM_NTRN.to_string()]);
+    let split = builder
+        .instantiate_msg
+        .msg
+        .splits
+        .get(DENOM_ATOM_ON_NTRN)
+        .unwrap()
+        .clone();
+    builder
+        .instantiate_msg
+        .msg
+        .splits
+        .insert(DENOM_NTRN.to_string(), split);
+
+    builder.build();
+}
+
+#[test]
+fn test_execute_tick_expired_deposit_refunds_basket_contribution() {
+    let mut builder = TwoPartyHolderBuilder::default();
+    builder
+        .instantiate_msg
+        .msg
+        .covenant_config
+        .party_a
+        .contribution
+        .push(coin(5_000, DENOM_NTRN));
+    let fallback_split = builder
+        .instantiate_msg
+        .msg
+        .splits
+        .get(DENOM_ATOM_ON_NTRN)
+        .unwrap()
+        .clone();
+    let mut suite = builder.with_fallback_split(fallback_split).build();
+    suite.expire_deposit_deadline();
+
+    suite.fund_contract(
+        &[coin(10_000, DENOM_ATOM_ON_NTRN), coin(3_000, DENOM_NTRN)],
+        suite.holder_addr.clone(),
+    );
+
+    // first tick completes, second one refunds
+    suite.tick_contract(suite.holder_addr.clone());
+    suite.tick_contract(suite.holder_addr.clone());
+
+    suite.assert_balance(
+        &suite.covenant_config.party_a.router,
+        coin(10_000, DENOM_ATOM_ON_NTRN),
+    );
+    suite.assert_balance(
+        &suite.covenant_config.party_a.router,
+        coin(3_000, DENOM_NTRN),
+    );
+    suite.assert_balance(suite.holder_addr.to_string(), coin(0, DENOM_NTRN));
+}
+
+#[test]
+#[should_panic(expected = "deposits are only tracked in instantiated state")]
+fn test_query_deposits_validates_instantiated_state() {
+    let mut suite = TwoPartyHolderBuilder::default().build();
+
+    suite.fund_contract(
+        &[
+            coin(10_000, DENOM_ATOM_ON_NTRN),
+            coin(10_000, DENOM_LS_ATOM_ON_NTRN),
+        ],
+        suite.holder_addr.clone(),
+    );
+    suite.tick_contract(suite.holder_addr.clone());
+
+    suite.query_deposits();
+}
+
 #[test]
 fn test_execute_tick_expired_noop() {
     let mut suite = TwoPartyHolderBuilder::default().build();
@@ -678,7 +1069,7 @@ fn test_execute_claim_happy() {
 }
 
 #[test]
-#[should_panic(expected = "unauthorized")]
+#[should_panic(expected = "only emergency committee members can perform this action")]
 fn test_execute_emergency_withdraw_validates_committee_address() {
     let builder = TwoPartyHolderBuilder::default();
     let op_mode_cfg = builder.instantiate_msg.msg.op_mode_cfg.clone();
@@ -686,7 +1077,14 @@ fn test_execute_emergency_withdraw_validates_committee_address() {
         covenant_utils::op_mode::ContractOperationModeConfig::Permissioned(vec) => vec[0].clone(),
         _ => panic!("unexpected op_mode_cfg"),
     };
-    let mut suite = builder.with_emergency_committee(clock.as_str()).build();
+    let mut suite = builder
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![clock],
+            threshold: 1,
+            timelock: Duration::Height(0),
+            safe_mode: true,
+        })
+        .build();
 
     suite.fund_contract(
         &[
@@ -700,14 +1098,102 @@ fn test_execute_emergency_withdraw_validates_committee_address() {
 
     let sender = suite.faucet.clone();
 
-    suite.emergency_withdraw(sender.as_str());
+    suite.propose_emergency_action(sender.as_str(), EmergencyAction::WithdrawToParties {});
 }
 
 #[test]
-fn test_execute_emergency_withdraw_happy() {
+#[should_panic(expected = "emergency action has 1 out of 2 required approvals")]
+fn test_execute_emergency_action_validates_threshold() {
+    let e_c_addr = "cosmos10a6yf8khw53pvmafngsq2vjgqgu3p9kjsgpzpa2vm9ceg0c70eysqg42pu";
+    let builder = TwoPartyHolderBuilder::default();
+    let faucet = builder.builder.faucet.to_string();
+    let mut suite = builder
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![e_c_addr.to_string(), faucet],
+            threshold: 2,
+            timelock: Duration::Height(0),
+            safe_mode: true,
+        })
+        .build();
+
+    suite.propose_emergency_action(e_c_addr, EmergencyAction::WithdrawToParties {});
+    suite.execute_emergency_action(e_c_addr, 0);
+}
+
+#[test]
+#[should_panic(expected = "emergency action timelock is not over")]
+fn test_execute_emergency_action_validates_timelock() {
     let e_c_addr = "cosmos10a6yf8khw53pvmafngsq2vjgqgu3p9kjsgpzpa2vm9ceg0c70eysqg42pu";
     let mut suite = TwoPartyHolderBuilder::default()
-        .with_emergency_committee(e_c_addr)
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![e_c_addr.to_string()],
+            threshold: 1,
+            timelock: Duration::Time(600),
+            safe_mode: true,
+        })
+        .build();
+
+    suite.propose_emergency_action(e_c_addr, EmergencyAction::WithdrawToParties {});
+    suite
+        .app
+        .update_block(|b| b.time = b.time.plus_seconds(599));
+    suite.execute_emergency_action(e_c_addr, 0);
+}
+
+#[test]
+#[should_panic(
+    expected = "emergency committee in safe mode can only route funds to the covenant parties"
+)]
+fn test_execute_propose_emergency_action_validates_safe_mode() {
+    let e_c_addr = "cosmos10a6yf8khw53pvmafngsq2vjgqgu3p9kjsgpzpa2vm9ceg0c70eysqg42pu";
+    let mut suite = TwoPartyHolderBuilder::default()
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![e_c_addr.to_string()],
+            threshold: 1,
+            timelock: Duration::Height(0),
+            safe_mode: true,
+        })
+        .build();
+
+    suite.propose_emergency_action(
+        e_c_addr,
+        EmergencyAction::WithdrawTo {
+            receiver: e_c_addr.to_string(),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "emergency action 1 not found")]
+fn test_execute_approve_emergency_action_validates_action_id() {
+    let e_c_addr = "cosmos10a6yf8khw53pvmafngsq2vjgqgu3p9kjsgpzpa2vm9ceg0c70eysqg42pu";
+    let builder = TwoPartyHolderBuilder::default();
+    let faucet = builder.builder.faucet.to_string();
+    let mut suite = builder
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![e_c_addr.to_string(), faucet.to_string()],
+            threshold: 2,
+            timelock: Duration::Height(0),
+            safe_mode: true,
+        })
+        .build();
+
+    suite.propose_emergency_action(e_c_addr, EmergencyAction::WithdrawToParties {});
+    suite.approve_emergency_action(&faucet, 1);
+}
+
+#[test]
+fn test_execute_emergency_withdraw_happy() {
+    let e_c_addr = "cosmos10a6yf8khw53pvmafngsq2vjgqgu3p9kjsgpzpa2vm9ceg0c70eysqg42pu";
+    let builder = TwoPartyHolderBuilder::default();
+    let faucet = builder.builder.faucet.to_string();
+    let mut suite = builder
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![e_c_addr.to_string(), faucet.to_string()],
+            threshold: 2,
+            timelock: Duration::Height(10),
+            safe_mode: true,
+        })
         .build();
 
     suite.fund_contract(
@@ -720,7 +1206,23 @@ fn test_execute_emergency_withdraw_happy() {
     suite.tick_contract(suite.holder_addr.clone());
     suite.tick_contract(suite.next_contract.clone());
 
-    suite.emergency_withdraw(e_c_addr);
+    suite.propose_emergency_action(e_c_addr, EmergencyAction::WithdrawToParties {});
+    suite.approve_emergency_action(&faucet, 0);
+
+    let pending_actions = suite.query_pending_emergency_actions();
+    assert_eq!(pending_actions.len(), 1);
+    assert_eq!(
+        pending_actions[0].approvals,
+        vec![
+            Addr::unchecked(e_c_addr),
+            Addr::unchecked(faucet.to_string())
+        ]
+    );
+
+    suite.app.update_block(|b| b.height += 10);
+    suite.execute_emergency_action(&faucet, 0);
+    assert!(suite.query_pending_emergency_actions().is_empty());
+
     suite.tick_contract(suite.holder_addr.clone());
     suite.tick_contract(suite.next_contract.clone());
 
@@ -745,6 +1247,66 @@ fn test_execute_emergency_withdraw_happy() {
     );
 }
 
+#[test]
+fn test_execute_emergency_withdraw_to_receiver_happy() {
+    let e_c_addr = "cosmos10a6yf8khw53pvmafngsq2vjgqgu3p9kjsgpzpa2vm9ceg0c70eysqg42pu";
+    let mut suite = TwoPartyHolderBuilder::default()
+        .with_emergency_committee(EmergencyCommitteeConfig {
+            members: vec![e_c_addr.to_string()],
+            threshold: 1,
+            timelock: Duration::Height(0),
+            safe_mode: false,
+        })
+        .build();
+
+    suite.fund_contract(
+        &[
+            coin(10_001, DENOM_ATOM_ON_NTRN),
+            coin(10_001, DENOM_LS_ATOM_ON_NTRN),
+        ],
+        suite.holder_addr.clone(),
+    );
+    suite.tick_contract(suite.holder_addr.clone());
+    suite.tick_contract(suite.next_contract.clone());
+
+    suite.propose_emergency_action(
+        e_c_addr,
+        EmergencyAction::WithdrawTo {
+            receiver: e_c_addr.to_string(),
+        },
+    );
+    suite.execute_emergency_action(e_c_addr, 0);
+
+    let receiver = Addr::unchecked(e_c_addr);
+    let party_a = Addr::unchecked(suite.covenant_config.party_a.router.to_string());
+    let party_b = Addr::unchecked(suite.covenant_config.party_b.router.to_string());
+
+    assert!(!suite
+        .query_balance(&receiver, DENOM_ATOM_ON_NTRN)
+        .amount
+        .is_zero());
+    assert!(!suite
+        .query_balance(&receiver, DENOM_LS_ATOM_ON_NTRN)
+        .amount
+        .is_zero());
+    assert!(suite
+        .query_balance(&party_a, DENOM_ATOM_ON_NTRN)
+        .amount
+        .is_zero());
+    assert!(suite
+        .query_balance(&party_b, DENOM_LS_ATOM_ON_NTRN)
+        .amount
+        .is_zero());
+
+    let contract_state = suite.query_contract_state();
+    assert!(
+        matches!(contract_state, ContractState::Complete {}),
+        "unexpected contract state: {:?}, expected: {:?}",
+        contract_state,
+        ContractState::Complete {},
+    );
+}
+
 #[test]
 #[should_panic(expected = "unauthorized to distribute explicitly defined denom")]
 fn test_distribute_fallback_validates_denoms() {
@@ -821,7 +1383,7 @@ fn test_migrate_update_config() {
     let next_contract = suite.query_next_contract();
     let mut covenant_config = suite.query_covenant_config();
     let denom_splits = suite.query_denom_splits();
-    covenant_config.party_a.contribution.amount = Uint128::one();
+    covenant_config.party_a.contribution[0].amount = Uint128::one();
     let random_split = denom_splits
         .explicit_splits
         .get(DENOM_ATOM_ON_NTRN)
@@ -839,7 +1401,12 @@ fn test_migrate_update_config() {
                     ]),
                 ),
                 next_contract: Some(clock.to_string()),
-                emergency_committee: Some(clock.to_string()),
+                emergency_committee: Some(EmergencyCommitteeConfig {
+                    members: vec![clock.to_string()],
+                    threshold: 1,
+                    timelock: Duration::Height(100),
+                    safe_mode: true,
+                }),
                 lockup_config: Some(Expiration::AtHeight(543210)),
                 deposit_deadline: Some(Expiration::AtHeight(543210)),
                 ragequit_config: Box::new(Some(RagequitConfig::Enabled(RagequitTerms {
@@ -865,10 +1432,13 @@ fn test_migrate_update_config() {
     let deposit_deadline = suite.query_deposit_deadline();
     let covenant_config = suite.query_covenant_config();
     let denom_splits = suite.query_denom_splits();
-    let emergency_committee = suite.query_emergency_committee();
+    let emergency_committee = suite.query_emergency_committee().unwrap();
 
     assert_eq!(random_split, &denom_splits.fallback_split.unwrap());
-    assert_eq!(Uint128::one(), covenant_config.party_a.contribution.amount);
+    assert_eq!(
+        Uint128::one(),
+        covenant_config.party_a.contribution[0].amount
+    );
     assert_eq!(Expiration::AtHeight(543210), deposit_deadline);
     assert_eq!(Expiration::AtHeight(543210), lockup_config);
     assert_eq!(
@@ -880,7 +1450,9 @@ fn test_migrate_update_config() {
     );
     assert_eq!(next_contract, new_clock);
     assert_eq!(clock, new_next_contract);
-    assert_eq!(clock, emergency_committee);
+    assert_eq!(vec![clock], emergency_committee.members);
+    assert_eq!(1, emergency_committee.threshold);
+    assert_eq!(Duration::Height(100), emergency_committee.timelock);
 }
 
 #[test]
@@ -1009,3 +1581,66 @@ fn test_migrate_update_config_validates_deposit_deadline_expiration() {
         )
         .unwrap();
 }
+
+#[test]
+fn test_migrate_converts_legacy_single_coin_contributions() {
+    let mut suite = TwoPartyHolderBuilder::default().build();
+    let covenant_config = suite.covenant_config.clone();
+    let get_legacy_party = |party: &TwoPartyPolCovenantParty| LegacyTwoPartyPolCovenantParty {
+        contribution: party.contribution[0].clone(),
+        host_addr: party.host_addr.to_string(),
+        controller_addr: party.controller_addr.to_string(),
+        allocation: party.allocation,
+        router: party.router.to_string(),
+    };
+
+    // previous versions stored single coin contributions under the same keys
+    let legacy_config = LegacyTwoPartyPolCovenantConfig {
+        party_a: get_legacy_party(&covenant_config.party_a),
+        party_b: get_legacy_party(&covenant_config.party_b),
+        covenant_type: covenant_config.covenant_type.clone(),
+    };
+    let legacy_ragequit_config = LegacyRagequitConfig::Enabled(LegacyRagequitTerms {
+        penalty: Decimal::from_str("0.1").unwrap(),
+        state: Some(LegacyRagequitState {
+            coins: vec![coin(10_000, DENOM_ATOM_ON_NTRN)],
+            rq_party: get_legacy_party(&covenant_config.party_a),
+        }),
+    });
+    let holder_addr = suite.holder_addr.clone();
+    suite.app.storage_mut().set(
+        &get_contract_item_key(&holder_addr, "covenant_config"),
+        &to_json_vec(&legacy_config).unwrap(),
+    );
+    suite.app.storage_mut().set(
+        &get_contract_item_key(&holder_addr, "ragequit_config"),
+        &to_json_vec(&legacy_ragequit_config).unwrap(),
+    );
+
+    suite
+        .app
+        .migrate_contract(
+            Addr::unchecked(ADMIN),
+            holder_addr,
+            &valence_two_party_pol_holder::msg::MigrateMsg::UpdateCodeId { data: None },
+            13,
+        )
+        .unwrap()
+        .assert_event(
+            &Event::new("wasm")
+                .add_attribute("covenant_config", format!("{:?}", covenant_config))
+                .add_attribute("ragequit_penalty", "0.1"),
+        );
+
+    assert_eq!(suite.query_covenant_config(), covenant_config);
+    match suite.query_ragequit_config() {
+        RagequitConfig::Enabled(RagequitTerms {
+            penalty,
+            state: Some(state),
+        }) => {
+            assert_eq!(penalty, Decimal::from_str("0.1").unwrap());
+            assert_eq!(state.rq_party, covenant_config.party_a);
+        }
+        config => panic!("unexpected ragequit config: {:?}", config),
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::BTreeSet;
+
+use cosmwasm_std::{Addr, Uint64};
+use covenant_utils::op_mode::{ContractOperationMode, ContractOperationModeConfig};
+use cw_multi_test::{AppResponse, Executor};
+use valence_vesting_escrow::msg::{VestingProgress, VestingSchedule};
+
+use crate::setup::{
+    base_suite::{BaseSuite, BaseSuiteMut},
+    instantiates::vesting_escrow::VestingEscrowInstantiate,
+    suite_builder::SuiteBuilder,
+    CustomApp, CLOCK_SALT, VESTING_ESCROW_SALT,
+};
+
+pub struct VestingEscrowBuilder {
+    pub builder: SuiteBuilder,
+    pub instantiate_msg: VestingEscrowInstantiate,
+    pub clock_addr: Addr,
+}
+
+impl Default for VestingEscrowBuilder {
+    fn default() -> Self {
+        let mut builder = SuiteBuilder::new();
+
+        let clock_addr = builder.get_contract_addr(builder.clock_code_id, CLOCK_SALT);
+
+        let vesting_escrow_addr =
+            builder.get_contract_addr(builder.vesting_escrow_code_id, VESTING_ESCROW_SALT);
+
+        let clock_instantiate_msg = valence_clock::msg::InstantiateMsg {
+            tick_max_gas: None,
+            whitelist: vec![],
+            initial_queue: vec![vesting_escrow_addr.to_string()],
+        };
+        builder.contract_init2(
+            builder.clock_code_id,
+            CLOCK_SALT,
+            &clock_instantiate_msg,
+            &[],
+        );
+
+        let party_receiver = builder.get_random_addr();
+
+        let vesting_escrow_instantiate = VestingEscrowInstantiate::default(
+            ContractOperationModeConfig::Permissioned(vec![clock_addr.to_string()]),
+            party_receiver,
+        );
+
+        Self {
+            builder,
+            instantiate_msg: vesting_escrow_instantiate,
+            clock_addr,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl VestingEscrowBuilder {
+    pub fn with_op_mode(mut self, op_mode_cfg: ContractOperationModeConfig) -> Self {
+        self.instantiate_msg.with_op_mode(op_mode_cfg);
+        self
+    }
+
+    pub fn with_receiver_address(mut self, addr: &str) -> Self {
+        self.instantiate_msg.with_receiver_address(addr.to_string());
+        self
+    }
+
+    pub fn with_denoms(mut self, denoms: Vec<String>) -> Self {
+        let denom_set = BTreeSet::from_iter(denoms);
+        self.instantiate_msg.with_denoms(denom_set);
+        self
+    }
+
+    pub fn with_vesting_schedule(mut self, cliff: u64, duration: u64) -> Self {
+        self.instantiate_msg.with_vesting_schedule(VestingSchedule {
+            cliff: Uint64::new(cliff),
+            duration: Uint64::new(duration),
+        });
+        self
+    }
+
+    pub fn build(mut self) -> Suite {
+        let vesting_escrow_addr = self.builder.contract_init2(
+            self.builder.vesting_escrow_code_id,
+            VESTING_ESCROW_SALT,
+            &self.instantiate_msg.msg,
+            &[],
+        );
+
+        let receiver_addr = self
+            .builder
+            .app
+            .wrap()
+            .query_wasm_smart(
+                vesting_escrow_addr.clone(),
+                &valence_vesting_escrow::msg::QueryMsg::ReceiverAddress {},
+            )
+            .unwrap();
+
+        Suite {
+            escrow_addr: vesting_escrow_addr,
+            escrow_code_id: self.builder.vesting_escrow_code_id,
+            faucet: self.builder.faucet.clone(),
+            admin: self.builder.admin.clone(),
+            clock_addr: self.clock_addr,
+            receiver_addr,
+            app: self.builder.build(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub struct Suite {
+    pub app: CustomApp,
+
+    pub faucet: Addr,
+    pub admin: Addr,
+
+    pub escrow_addr: Addr,
+    pub escrow_code_id: u64,
+    pub clock_addr: Addr,
+    pub receiver_addr: Addr,
+}
+
+impl Suite {
+    pub fn query_receiver_address(&mut self) -> Addr {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.escrow_addr.clone(),
+                &valence_vesting_escrow::msg::QueryMsg::ReceiverAddress {},
+            )
+            .unwrap()
+    }
+
+    pub(crate) fn query_op_mode(&mut self) -> ContractOperationMode {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.escrow_addr.clone(),
+                &valence_vesting_escrow::msg::QueryMsg::OperationMode {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_vesting_schedule(&mut self) -> VestingSchedule {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.escrow_addr.clone(),
+                &valence_vesting_escrow::msg::QueryMsg::VestingSchedule {},
+            )
+            .unwrap()
+    }
+
+    pub fn query_vesting_progress(&mut self) -> VestingProgress {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.escrow_addr.clone(),
+                &valence_vesting_escrow::msg::QueryMsg::VestingProgress {},
+            )
+            .unwrap()
+    }
+
+    pub fn distribute_fallback(&mut self, denoms: Vec<String>) -> AppResponse {
+        self.app
+            .execute_contract(
+                self.receiver_addr.clone(),
+                self.escrow_addr.clone(),
+                &valence_vesting_escrow::msg::ExecuteMsg::DistributeFallback { denoms },
+                &[],
+            )
+            .unwrap()
+    }
+
+    pub fn skip_seconds(&mut self, seconds: u64) {
+        self.app
+            .update_block(|b| b.time = b.time.plus_seconds(seconds));
+    }
+}
+
+impl BaseSuite for Suite {
+    fn get_app(&self) -> &CustomApp {
+        &self.app
+    }
+}
+
+impl BaseSuiteMut for Suite {
+    fn get_app(&mut self) -> &mut CustomApp {
+        &mut self.app
+    }
+
+    fn get_clock_addr(&mut self) -> Addr {
+        self.clock_addr.clone()
+    }
+
+    fn get_faucet_addr(&mut self) -> Addr {
+        self.faucet.clone()
+    }
+}
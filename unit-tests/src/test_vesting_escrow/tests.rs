@@ -0,0 +1,234 @@
+use cosmwasm_std::{coin, coins, Addr, Decimal, Event, Uint128, Uint64};
+use covenant_utils::op_mode::{ContractOperationMode, ContractOperationModeConfig};
+use cw_multi_test::Executor;
+use valence_vesting_escrow::msg::VestingSchedule;
+
+use crate::{
+    setup::{
+        base_suite::{BaseSuite, BaseSuiteMut},
+        ADMIN, DENOM_ATOM_ON_NTRN, DENOM_LS_ATOM_ON_NTRN,
+    },
+    test_vesting_escrow::suite::VestingEscrowBuilder,
+};
+
+#[test]
+fn test_instantiate_with_valid_op_mode() {
+    let _suite = VestingEscrowBuilder::default().build();
+}
+
+#[test]
+#[should_panic]
+fn test_instantiate_validates_privileged_accounts() {
+    VestingEscrowBuilder::default()
+        .with_op_mode(ContractOperationModeConfig::Permissioned(vec![
+            "some contract".to_string(),
+        ]))
+        .build();
+}
+
+#[test]
+#[should_panic]
+fn test_instantiate_validates_receiver_addr() {
+    VestingEscrowBuilder::default()
+        .with_receiver_address("not a receiver")
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "vesting duration cannot be zero")]
+fn test_instantiate_validates_vesting_duration() {
+    VestingEscrowBuilder::default()
+        .with_vesting_schedule(0, 0)
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "vesting cliff cannot exceed the vesting duration")]
+fn test_instantiate_validates_vesting_cliff() {
+    VestingEscrowBuilder::default()
+        .with_vesting_schedule(101, 100)
+        .build();
+}
+
+#[test]
+#[should_panic(expected = "Contract operation unauthorized")]
+fn test_tick_rejects_unprivileged_account() {
+    let mut suite = VestingEscrowBuilder::default().build();
+    let admin_addr = suite.admin.clone();
+    let escrow_addr = suite.escrow_addr.clone();
+    suite
+        .app
+        .execute_contract(
+            admin_addr,
+            escrow_addr,
+            &valence_vesting_escrow::msg::ExecuteMsg::Tick {},
+            &[],
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_execute_tick_without_funds_does_not_start_vesting() {
+    let mut suite = VestingEscrowBuilder::default().build();
+    let escrow = suite.escrow_addr.clone();
+
+    suite.tick_contract(escrow).assert_event(
+        &Event::new("wasm")
+            .add_attribute("method", "try_release")
+            .add_attribute("result", "vesting_not_started"),
+    );
+
+    let progress = suite.query_vesting_progress();
+    assert!(progress.start.is_none());
+    assert_eq!(progress.vested_ratio, Decimal::zero());
+}
+
+#[test]
+fn test_execute_tick_releases_nothing_before_cliff() {
+    let mut suite = VestingEscrowBuilder::default().build();
+    let escrow = suite.escrow_addr.clone();
+
+    suite.fund_contract(&coins(1000, DENOM_ATOM_ON_NTRN), escrow.clone());
+    suite.tick_contract(escrow.clone());
+
+    suite.skip_seconds(99);
+    suite.tick_contract(escrow.clone());
+
+    suite.assert_balance(&escrow, coin(1000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.receiver_addr, coin(0, DENOM_ATOM_ON_NTRN));
+
+    let progress = suite.query_vesting_progress();
+    assert!(progress.start.is_some());
+    assert_eq!(progress.denoms[0].total, Uint128::new(1000));
+    assert_eq!(progress.denoms[0].vested, Uint128::zero());
+}
+
+#[test]
+fn test_execute_tick_releases_linearly_after_cliff() {
+    let mut suite = VestingEscrowBuilder::default().build();
+    let escrow = suite.escrow_addr.clone();
+
+    suite.fund_contract(&coins(1000, DENOM_ATOM_ON_NTRN), escrow.clone());
+    suite.tick_contract(escrow.clone());
+
+    suite.skip_seconds(250);
+    suite.tick_contract(escrow.clone()).assert_event(
+        &Event::new("wasm")
+            .add_attribute("method", "try_release")
+            .add_attribute("vested_ratio", "0.25"),
+    );
+
+    suite.assert_balance(&escrow, coin(750, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.receiver_addr, coin(250, DENOM_ATOM_ON_NTRN));
+
+    suite.skip_seconds(250);
+    suite.tick_contract(escrow.clone());
+
+    suite.assert_balance(&escrow, coin(500, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.receiver_addr, coin(500, DENOM_ATOM_ON_NTRN));
+
+    let progress = suite.query_vesting_progress();
+    assert_eq!(progress.vested_ratio, Decimal::percent(50));
+    assert_eq!(progress.denoms[0].total, Uint128::new(1000));
+    assert_eq!(progress.denoms[0].vested, Uint128::new(500));
+    assert_eq!(progress.denoms[0].released, Uint128::new(500));
+}
+
+#[test]
+fn test_execute_tick_releases_everything_after_duration() {
+    let mut suite = VestingEscrowBuilder::default()
+        .with_denoms(vec![
+            DENOM_ATOM_ON_NTRN.to_string(),
+            DENOM_LS_ATOM_ON_NTRN.to_string(),
+        ])
+        .build();
+    let escrow = suite.escrow_addr.clone();
+
+    suite.fund_contract(&coins(1000, DENOM_ATOM_ON_NTRN), escrow.clone());
+    suite.tick_contract(escrow.clone());
+
+    // funds received after the vesting start follow the same schedule
+    suite.skip_seconds(500);
+    suite.fund_contract(&coins(2000, DENOM_LS_ATOM_ON_NTRN), escrow.clone());
+    suite.tick_contract(escrow.clone());
+
+    suite.assert_balance(&suite.receiver_addr, coin(500, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.receiver_addr, coin(1000, DENOM_LS_ATOM_ON_NTRN));
+
+    suite.skip_seconds(1000);
+    suite.tick_contract(escrow.clone());
+
+    suite.assert_balance(&escrow, coin(0, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&escrow, coin(0, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.receiver_addr, coin(1000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.receiver_addr, coin(2000, DENOM_LS_ATOM_ON_NTRN));
+
+    let progress = suite.query_vesting_progress();
+    assert_eq!(progress.vested_ratio, Decimal::one());
+    for denom_progress in progress.denoms {
+        assert_eq!(denom_progress.released, denom_progress.total);
+    }
+}
+
+#[test]
+#[should_panic(expected = "unauthorized to distribute vesting denom")]
+fn test_execute_distribute_fallback_validates_vesting_denoms() {
+    let mut suite = VestingEscrowBuilder::default().build();
+    let escrow = suite.escrow_addr.clone();
+
+    suite.fund_contract(&coins(5000, DENOM_ATOM_ON_NTRN), escrow);
+
+    suite.distribute_fallback(vec![DENOM_ATOM_ON_NTRN.to_string()]);
+}
+
+#[test]
+fn test_execute_distribute_fallback_happy() {
+    let mut suite = VestingEscrowBuilder::default().build();
+    let escrow = suite.escrow_addr.clone();
+
+    suite.fund_contract(&coins(5000, DENOM_ATOM_ON_NTRN), escrow.clone());
+    suite.fund_contract(&coins(1000, DENOM_LS_ATOM_ON_NTRN), escrow.clone());
+
+    suite
+        .distribute_fallback(vec![DENOM_LS_ATOM_ON_NTRN.to_string()])
+        .assert_event(&Event::new("wasm").add_attribute("method", "try_distribute_fallback"));
+
+    suite.assert_balance(&escrow, coin(5000, DENOM_ATOM_ON_NTRN));
+    suite.assert_balance(&escrow, coin(0, DENOM_LS_ATOM_ON_NTRN));
+    suite.assert_balance(&suite.receiver_addr, coin(1000, DENOM_LS_ATOM_ON_NTRN));
+}
+
+#[test]
+fn test_migrate_update_config() {
+    let mut suite = VestingEscrowBuilder::default().build();
+
+    let escrow_addr = suite.escrow_addr.clone();
+    let clock_addr = suite.clock_addr.clone();
+    let receiver_addr = suite.receiver_addr.clone();
+    let vesting_schedule = VestingSchedule {
+        cliff: Uint64::new(10),
+        duration: Uint64::new(20),
+    };
+
+    suite
+        .app
+        .migrate_contract(
+            Addr::unchecked(ADMIN),
+            escrow_addr,
+            &valence_vesting_escrow::msg::MigrateMsg::UpdateConfig {
+                op_mode: ContractOperationModeConfig::Permissioned(vec![receiver_addr.to_string()])
+                    .into(),
+                receiver_address: Some(clock_addr.to_string()),
+                vesting_schedule: Some(vesting_schedule.clone()),
+            },
+            suite.escrow_code_id,
+        )
+        .unwrap();
+
+    assert_eq!(
+        suite.query_op_mode(),
+        ContractOperationMode::Permissioned(vec![receiver_addr].into())
+    );
+    assert_eq!(suite.query_receiver_address(), clock_addr);
+    assert_eq!(suite.query_vesting_schedule(), vesting_schedule);
+}